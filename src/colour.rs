@@ -7,7 +7,7 @@ use std::fmt;
 /// Colours [`crate::display::Block`] fields based on loose typing of field type
 ///
 /// Considered using HashMap with Colouring Enum like IconTheme but this seemed to suit better, it is less flexiable though...
-#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 #[serde(deny_unknown_fields)]
 pub struct ColourTheme {
     /// Colour to use for name from descriptor
@@ -171,6 +171,104 @@ pub struct ColourTheme {
         deserialize_with = "deserialize_option_color_from_string"
     )]
     pub tree_endpoint_in: Option<Color>,
+    /// Colour for devices operating at SuperSpeed or faster with `--color-by speed`
+    #[serde(
+        default,
+        serialize_with = "color_serializer",
+        deserialize_with = "deserialize_option_color_from_string"
+    )]
+    pub usb3_speed: Option<Color>,
+    /// Colour for devices operating at High Speed with `--color-by speed`
+    #[serde(
+        default,
+        serialize_with = "color_serializer",
+        deserialize_with = "deserialize_option_color_from_string"
+    )]
+    pub usb2_speed: Option<Color>,
+    /// Colour for devices operating at Low or Full Speed with `--color-by speed`
+    #[serde(
+        default,
+        serialize_with = "color_serializer",
+        deserialize_with = "deserialize_option_color_from_string"
+    )]
+    pub usb1_speed: Option<Color>,
+    /// Colour for hubs with `--color-by class`
+    #[serde(
+        default,
+        serialize_with = "color_serializer",
+        deserialize_with = "deserialize_option_color_from_string"
+    )]
+    pub class_hub: Option<Color>,
+    /// Colour for audio and audio/video devices with `--color-by class`
+    #[serde(
+        default,
+        serialize_with = "color_serializer",
+        deserialize_with = "deserialize_option_color_from_string"
+    )]
+    pub class_audio: Option<Color>,
+    /// Colour for video and still image devices with `--color-by class`
+    #[serde(
+        default,
+        serialize_with = "color_serializer",
+        deserialize_with = "deserialize_option_color_from_string"
+    )]
+    pub class_video: Option<Color>,
+    /// Colour for HID devices with `--color-by class`
+    #[serde(
+        default,
+        serialize_with = "color_serializer",
+        deserialize_with = "deserialize_option_color_from_string"
+    )]
+    pub class_hid: Option<Color>,
+    /// Colour for mass storage devices with `--color-by class`
+    #[serde(
+        default,
+        serialize_with = "color_serializer",
+        deserialize_with = "deserialize_option_color_from_string"
+    )]
+    pub class_mass_storage: Option<Color>,
+    /// Colour for CDC communications and data devices with `--color-by class`
+    #[serde(
+        default,
+        serialize_with = "color_serializer",
+        deserialize_with = "deserialize_option_color_from_string"
+    )]
+    pub class_communications: Option<Color>,
+    /// Colour for printers with `--color-by class`
+    #[serde(
+        default,
+        serialize_with = "color_serializer",
+        deserialize_with = "deserialize_option_color_from_string"
+    )]
+    pub class_printer: Option<Color>,
+    /// Colour for wireless controllers with `--color-by class`
+    #[serde(
+        default,
+        serialize_with = "color_serializer",
+        deserialize_with = "deserialize_option_color_from_string"
+    )]
+    pub class_wireless: Option<Color>,
+    /// Colour for billboard and USB Type-C bridge devices with `--color-by class`
+    #[serde(
+        default,
+        serialize_with = "color_serializer",
+        deserialize_with = "deserialize_option_color_from_string"
+    )]
+    pub class_billboard: Option<Color>,
+    /// Colour for vendor specific devices with `--color-by class`
+    #[serde(
+        default,
+        serialize_with = "color_serializer",
+        deserialize_with = "deserialize_option_color_from_string"
+    )]
+    pub class_vendor_specific: Option<Color>,
+    /// Colour for devices of any other class with `--color-by class`
+    #[serde(
+        default,
+        serialize_with = "color_serializer",
+        deserialize_with = "deserialize_option_color_from_string"
+    )]
+    pub class_other: Option<Color>,
     /// Colour for endpoint out before print
     #[serde(
         default,
@@ -341,6 +439,20 @@ impl ColourTheme {
             tree_interface_terminator: Some(Color::BrightBlack),
             tree_endpoint_in: Some(Color::Yellow),
             tree_endpoint_out: Some(Color::Magenta),
+            usb3_speed: Some(Color::BrightGreen),
+            usb2_speed: Some(Color::Yellow),
+            usb1_speed: Some(Color::Cyan),
+            class_hub: Some(Color::BrightBlack),
+            class_audio: Some(Color::Magenta),
+            class_video: Some(Color::BrightMagenta),
+            class_hid: Some(Color::Cyan),
+            class_mass_storage: Some(Color::Green),
+            class_communications: Some(Color::Yellow),
+            class_printer: Some(Color::Blue),
+            class_wireless: Some(Color::BrightCyan),
+            class_billboard: Some(Color::BrightYellow),
+            class_vendor_specific: Some(Color::Red),
+            class_other: Some(Color::BrightBlue),
         }
     }
 }
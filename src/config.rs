@@ -1,7 +1,8 @@
 //! Config for cyme binary
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::fs::File;
-use std::io::{BufReader, Read};
+use std::io::{BufReader, BufWriter, Read, Write};
 use std::path::{Path, PathBuf};
 
 use crate::colour;
@@ -9,10 +10,32 @@ use crate::display;
 use crate::display::Block;
 use crate::error::{Error, ErrorKind, Result};
 use crate::icon;
+use crate::system_profiler::USBFilter;
 
 const CONF_DIR: &str = "cyme";
 const CONF_NAME: &str = "cyme.json";
 
+/// Read and parse the .json at `file_path`; `what` names the file in the parsing error
+fn read_json_file<T: DeserializeOwned, P: AsRef<Path>>(file_path: P, what: &str) -> Result<T> {
+    let f = File::open(&file_path)?;
+    let mut br = BufReader::new(f);
+    let mut data = String::new();
+
+    br.read_to_string(&mut data)?;
+    serde_json::from_str::<T>(&data).map_err(|e| {
+        Error::new(
+            ErrorKind::Parsing,
+            &format!(
+                "Failed to parse {} at {:?}; Error({})",
+                what,
+                file_path.as_ref(),
+                e
+            ),
+        )
+        .with_source(e)
+    })
+}
+
 /// Allows user supplied icons to replace or add to `DEFAULT_ICONS` and `DEFAULT_TREE`
 #[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case", deny_unknown_fields, default)]
@@ -50,6 +73,8 @@ pub struct Config {
     pub hide_buses: bool,
     /// Hide empty hubs when printing tree; those with no devices. When listing will hide hubs regardless of whether empty of not
     pub hide_hubs: bool,
+    /// Output only leaf devices, excluding all hubs from the list. Has no effect on the tree
+    pub no_hubs: bool,
     /// Show base16 values as base10 decimal instead
     pub decimal: bool,
     /// Disable padding to align blocks
@@ -125,27 +150,121 @@ impl Config {
 
     /// Attempt to read from .json format confg at `file_path`
     pub fn from_file<P: AsRef<Path>>(file_path: P) -> Result<Config> {
-        let f = File::open(&file_path)?;
-        let mut br = BufReader::new(f);
-        let mut data = String::new();
+        read_json_file(file_path, "config")
+    }
+
+    /// This provides the path for a configuration file, specific to OS
+    /// return None if error like PermissionDenied
+    pub fn config_file_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|x| x.join(CONF_DIR))
+    }
+}
+
+/// Saved display preferences: the blocks (columns), theme, icons, sort and filter to use with [`display::display_with_config`]
+///
+/// Unlike [`Config`] this only covers how devices are displayed so that a library consumer can persist a view without the binary arguments. Missing fields use the defaults.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", default)]
+pub struct DisplayConfig {
+    /// Layout of the devices; None to leave it to the settings or arguments
+    pub mode: Option<display::DisplayMode>,
+    /// [`display::DeviceBlocks`] to use for displaying devices
+    pub blocks: Option<Vec<display::DeviceBlocks>>,
+    /// [`display::BusBlocks`] to use for displaying buses
+    pub bus_blocks: Option<Vec<display::BusBlocks>>,
+    /// [`display::ConfigurationBlocks`] to use for device configurations
+    pub config_blocks: Option<Vec<display::ConfigurationBlocks>>,
+    /// [`display::InterfaceBlocks`] to use for device interfaces
+    pub interface_blocks: Option<Vec<display::InterfaceBlocks>>,
+    /// [`display::EndpointBlocks`] to use for device endpoints
+    pub endpoint_blocks: Option<Vec<display::EndpointBlocks>>,
+    /// [`colour::ColourTheme`] to use; None to leave the colours of the settings unchanged
+    pub colours: Option<colour::ColourTheme>,
+    /// [`icon::IconTheme`] to use; None to leave the icons of the settings unchanged
+    pub icons: Option<icon::IconTheme>,
+    /// Sort devices by; None for the [`display::Sort`] default
+    pub sort_devices: Option<display::Sort>,
+    /// Sort devices by bus number
+    pub sort_buses: bool,
+    /// Filter to apply before displaying
+    pub filter: Option<USBFilter>,
+}
+
+impl DisplayConfig {
+    /// Attempt to read from .json format display config at `file_path`
+    pub fn from_file<P: AsRef<Path>>(file_path: P) -> Result<DisplayConfig> {
+        read_json_file(file_path, "display config")
+    }
 
-        br.read_to_string(&mut data)?;
-        serde_json::from_str::<Config>(&data).map_err(|e| {
+    /// Save as .json format display config at `file_path`
+    ///
+    /// ```
+    /// use cyme::config::DisplayConfig;
+    /// use cyme::display::{DeviceBlocks, DisplayMode};
+    ///
+    /// let config = DisplayConfig {
+    ///     mode: Some(DisplayMode::Tree),
+    ///     blocks: Some(vec![DeviceBlocks::Name, DeviceBlocks::VendorId]),
+    ///     ..Default::default()
+    /// };
+    /// let path = std::env::temp_dir().join("cyme_display_config_doctest.json");
+    /// config.save_file(&path).unwrap();
+    /// let loaded = DisplayConfig::from_file(&path).unwrap();
+    /// assert_eq!(loaded.mode, Some(DisplayMode::Tree));
+    /// assert_eq!(loaded.blocks, config.blocks);
+    /// std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn save_file<P: AsRef<Path>>(&self, file_path: P) -> Result<()> {
+        let f = File::create(&file_path)?;
+        let mut bw = BufWriter::new(f);
+        serde_json::to_writer_pretty(&mut bw, self).map_err(|e| {
             Error::new(
-                ErrorKind::Parsing,
+                ErrorKind::Io,
                 &format!(
-                    "Failed to parse config at {:?}; Error({})",
+                    "Failed to write display config to {:?}; Error({})",
                     file_path.as_ref(),
                     e
                 ),
             )
-        })
+            .with_source(e)
+        })?;
+        bw.flush()?;
+        Ok(())
     }
 
-    /// This provides the path for a configuration file, specific to OS
-    /// return None if error like PermissionDenied
-    pub fn config_file_path() -> Option<PathBuf> {
-        dirs::config_dir().map(|x| x.join(CONF_DIR))
+    /// Apply the display config to `settings`; unset fields leave `settings` unchanged
+    ///
+    /// ```
+    /// use cyme::config::DisplayConfig;
+    /// use cyme::display::{DisplayMode, PrintSettings};
+    ///
+    /// let mut settings = PrintSettings { tree: true, ..Default::default() };
+    /// DisplayConfig::default().apply(&mut settings);
+    /// assert!(settings.tree);
+    /// DisplayConfig { mode: Some(DisplayMode::Flat), ..Default::default() }.apply(&mut settings);
+    /// assert!(!settings.tree);
+    /// ```
+    pub fn apply(&self, settings: &mut display::PrintSettings) {
+        if let Some(mode) = self.mode {
+            settings.tree = mode == display::DisplayMode::Tree;
+        }
+        settings.device_blocks = self.blocks.clone().or(settings.device_blocks.take());
+        settings.bus_blocks = self.bus_blocks.clone().or(settings.bus_blocks.take());
+        settings.config_blocks = self.config_blocks.clone().or(settings.config_blocks.take());
+        settings.interface_blocks = self
+            .interface_blocks
+            .clone()
+            .or(settings.interface_blocks.take());
+        settings.endpoint_blocks = self
+            .endpoint_blocks
+            .clone()
+            .or(settings.endpoint_blocks.take());
+        settings.colours = self.colours.clone().or(settings.colours.take());
+        settings.icons = self.icons.clone().or(settings.icons.take());
+        if let Some(sort) = self.sort_devices.as_ref() {
+            settings.sort_devices = sort.to_owned();
+        }
+        settings.sort_buses |= self.sort_buses;
     }
 }
 
@@ -7,7 +7,7 @@ use itertools::Itertools;
 use rand::{distributions::Alphanumeric, seq::IteratorRandom, Rng};
 use serde::{Deserialize, Serialize};
 use std::cmp;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::hash::Hash;
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
@@ -16,9 +16,13 @@ use terminal_size::{Height, Width};
 use crate::colour;
 use crate::icon;
 use crate::system_profiler;
-use crate::system_profiler::{SPUSBDataType, USBBus, USBDevice, USBFilter};
+use crate::system_profiler::{DeviceSpeed, SPUSBDataType, USBBus, USBDevice, USBFilter};
+use crate::usb::descriptors::InterfaceAssociationDescriptor;
 use crate::usb::USBDeviceExtra;
-use crate::usb::{ConfigAttributes, Direction, USBConfiguration, USBEndpoint, USBInterface};
+use crate::usb::{
+    ClassCode, ConfigAttributes, Direction, EndpointAddress, Speed, USBConfiguration, USBEndpoint,
+    USBInterface,
+};
 
 const MAX_VERBOSITY: u8 = 4;
 const ICON_HEADING: &str = "I";
@@ -45,6 +49,63 @@ impl std::fmt::Display for ColorWhen {
     }
 }
 
+/// Attribute to colour whole device lines by rather than colouring each block
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum ColorBy {
+    /// Operating speed; SuperSpeed, High Speed and Low/Full Speed devices have distinct colours
+    Speed,
+    /// Class of the primary function of the device
+    Class,
+}
+
+/// Colour of the line for `device` with [`PrintSettings::color_by`]; None if the attribute is unknown or has no colour in the `theme`
+///
+/// ```
+/// use cyme::colour::ColourTheme;
+/// use cyme::display::{color_for, ColorBy};
+/// use cyme::system_profiler::SPUSBDataType;
+///
+/// let sp_usb = SPUSBDataType::example();
+/// let theme = ColourTheme::new();
+/// let hub = sp_usb.get_node("1-1").unwrap();
+/// let device = sp_usb.get_node("1-1.2").unwrap();
+/// // the example hub is High Speed and the device SuperSpeed
+/// assert_eq!(color_for(hub, &ColorBy::Speed, &theme), theme.usb2_speed);
+/// assert_eq!(color_for(device, &ColorBy::Speed, &theme), theme.usb3_speed);
+/// assert_ne!(color_for(hub, &ColorBy::Class, &theme), color_for(device, &ColorBy::Class, &theme));
+/// ```
+pub fn color_for(
+    device: &USBDevice,
+    color_by: &ColorBy,
+    theme: &colour::ColourTheme,
+) -> Option<Color> {
+    match color_by {
+        ColorBy::Speed => match device.device_speed.as_ref()? {
+            DeviceSpeed::SpeedValue(Speed::SuperSpeed | Speed::SuperSpeedPlus) => theme.usb3_speed,
+            DeviceSpeed::SpeedValue(Speed::HighSpeed | Speed::HighBandwidth) => theme.usb2_speed,
+            DeviceSpeed::SpeedValue(Speed::LowSpeed | Speed::FullSpeed) => theme.usb1_speed,
+            _ => None,
+        },
+        ColorBy::Class => {
+            let class = device.primary_function().class_code().or(device.class)?;
+            match class {
+                ClassCode::Hub => theme.class_hub,
+                ClassCode::Audio | ClassCode::AudioVideo => theme.class_audio,
+                ClassCode::Video | ClassCode::Image => theme.class_video,
+                ClassCode::HID => theme.class_hid,
+                ClassCode::MassStorage => theme.class_mass_storage,
+                ClassCode::CDCCommunications | ClassCode::CDCData => theme.class_communications,
+                ClassCode::Printer => theme.class_printer,
+                ClassCode::WirelessController => theme.class_wireless,
+                ClassCode::Billboard | ClassCode::USBTypeCBridge => theme.class_billboard,
+                ClassCode::VendorSpecificClass => theme.class_vendor_specific,
+                _ => theme.class_other,
+            }
+        }
+    }
+}
+
 /// Icon control for the output
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize, ValueEnum, Default)]
 #[serde(rename_all = "kebab-case")]
@@ -239,6 +300,8 @@ pub enum DeviceBlocks {
     SysPath,
     /// Linux udev reported driver loaded for device
     Driver,
+    /// Linux runtime power management annotation, shown when the device is suspended
+    PowerState,
     /// Icon based on VID/PID
     Icon,
     /// Unique vendor identifier - purchased from USB IF
@@ -285,6 +348,12 @@ pub enum DeviceBlocks {
     Class,
     /// Base class as number value
     ClassValue,
+    /// Interface count of the active configuration and which have multiple alternate settings - only available when using libusb
+    InterfaceSummary,
+    /// Declared strings which could not be read from the device and the source of any fallback, like 'iSerialNumber: none' - only available when using libusb
+    MissingStrings,
+    /// Maximum packet size of the default control endpoint in bytes; decoded from bMaxPacketSize0 at the device speed - only available when using libusb
+    MaxPacketSize0,
 }
 
 /// Info that can be printed about a [`USBBus`]
@@ -379,6 +448,8 @@ pub enum InterfaceBlocks {
     Class,
     /// Base class as number value
     ClassValue,
+    /// Class, sub-class and protocol names from USB IDs repository like 'Communications > Abstract (modem) > AT-commands (3G)'
+    UidClassChain,
 }
 
 /// Info that can be printed about a [`USBEndpoint`]
@@ -400,6 +471,8 @@ pub enum EndpointBlocks {
     MaxPacketSize,
     /// Interval for polling endpoint data transfers. Value in frame counts. Ignored for Bulk & Control Endpoints. Isochronous must equal 1 and field may range from 1 to 255 for interrupt endpoints.
     Interval,
+    /// Endpoint which synchronises this one (bSynchAddress), such as the feedback endpoint of an audio data endpoint
+    SynchAddress,
 }
 
 /// Length of field printed by block
@@ -513,6 +586,60 @@ pub trait Block<B: Eq + Hash, T> {
     }
 }
 
+/// Annotation such as "[suspended]" if the device is in a low-power state, otherwise empty
+fn power_state_annotation(d: &USBDevice) -> String {
+    match d.extra.as_ref().and_then(|e| e.power_state) {
+        Some(state) if state.is_suspended() => format!("[{}]", state),
+        _ => String::new(),
+    }
+}
+
+/// [`USBDevice::missing_strings`] with the source of any fallback or 'none'; None if no strings are missing
+fn missing_strings(d: &USBDevice) -> Option<String> {
+    let missing = d.missing_strings();
+    (!missing.is_empty()).then(|| {
+        missing
+            .iter()
+            .map(|(field, source)| match source {
+                Some(s) => format!("{}: {}", field, s),
+                None => format!("{}: none", field),
+            })
+            .collect::<Vec<String>>()
+            .join(", ")
+    })
+}
+
+/// [`crate::usb::InterfaceSummary`] of the active configuration if the device has one
+fn interface_summary(d: &USBDevice) -> Option<String> {
+    d.extra
+        .as_ref()
+        .and_then(|e| e.active_configuration())
+        .map(|c| c.interface_summary().to_string())
+}
+
+impl InterfaceBlocks {
+    /// Compact `InterfaceBlocks` for interfaces nested directly under a device with [`PrintSettings::show_interfaces`]
+    pub fn default_inline_blocks() -> Vec<Self> {
+        vec![
+            InterfaceBlocks::Number,
+            InterfaceBlocks::ClassCode,
+            InterfaceBlocks::Driver,
+        ]
+    }
+}
+
+impl EndpointBlocks {
+    /// Compact `EndpointBlocks` for the endpoint summary under interfaces with [`PrintSettings::show_interfaces`]
+    pub fn default_inline_blocks() -> Vec<Self> {
+        vec![
+            EndpointBlocks::Number,
+            EndpointBlocks::Direction,
+            EndpointBlocks::TransferType,
+            EndpointBlocks::MaxPacketSize,
+        ]
+    }
+}
+
 impl DeviceBlocks {
     /// Default `DeviceBlocks` for tree printing are different to list, get them here
     pub fn default_device_tree_blocks() -> Vec<Self> {
@@ -525,6 +652,25 @@ impl DeviceBlocks {
             DeviceBlocks::Serial,
         ]
     }
+
+    /// Unpadded and uncoloured value of the block for `d`, for selecting a single field with [`print_selected`]. None if the device has no value for the block
+    ///
+    /// ```
+    /// use cyme::display::{DeviceBlocks, PrintSettings};
+    /// use cyme::system_profiler::SPUSBDataType;
+    ///
+    /// let sp_usb = SPUSBDataType::example();
+    /// let mut device = sp_usb.get_node("1-1.2").unwrap().clone();
+    /// let settings = PrintSettings::default();
+    /// assert_eq!(DeviceBlocks::Serial.select_value(&device, &settings), Some(String::from("0123456789")));
+    /// device.serial_num = None;
+    /// assert_eq!(DeviceBlocks::Serial.select_value(&device, &settings), None);
+    /// ```
+    pub fn select_value(&self, d: &USBDevice, settings: &PrintSettings) -> Option<String> {
+        self.format_value(d, &HashMap::new(), settings)
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty() && v != "-")
+    }
 }
 
 impl Block<DeviceBlocks, USBDevice> for DeviceBlocks {
@@ -550,6 +696,7 @@ impl Block<DeviceBlocks, USBDevice> for DeviceBlocks {
                 DeviceBlocks::Manufacturer,
                 DeviceBlocks::Serial,
                 DeviceBlocks::Driver,
+                DeviceBlocks::PowerState,
                 DeviceBlocks::Speed,
             ]
         } else {
@@ -568,7 +715,11 @@ impl Block<DeviceBlocks, USBDevice> for DeviceBlocks {
 
     fn len(&self, d: &[&USBDevice]) -> usize {
         match self {
-            DeviceBlocks::Name => d.iter().map(|d| d.name.len()).max().unwrap_or(0),
+            DeviceBlocks::Name => d
+                .iter()
+                .map(|d| d.display_name().unwrap_or_default().len())
+                .max()
+                .unwrap_or(0),
             DeviceBlocks::Serial => d
                 .iter()
                 .map(|d| d.serial_num.as_ref().unwrap_or(&String::new()).len())
@@ -576,7 +727,7 @@ impl Block<DeviceBlocks, USBDevice> for DeviceBlocks {
                 .unwrap_or(0),
             DeviceBlocks::Manufacturer => d
                 .iter()
-                .map(|d| d.manufacturer.as_ref().unwrap_or(&String::new()).len())
+                .map(|d| d.display_manufacturer().unwrap_or_default().len())
                 .max()
                 .unwrap_or(0),
             DeviceBlocks::TreePositions => d
@@ -603,6 +754,11 @@ impl Block<DeviceBlocks, USBDevice> for DeviceBlocks {
                 })
                 .max()
                 .unwrap_or(0),
+            DeviceBlocks::PowerState => d
+                .iter()
+                .map(|d| power_state_annotation(d).len())
+                .max()
+                .unwrap_or(0),
             DeviceBlocks::ProductName => d
                 .iter()
                 .map(|d| {
@@ -648,7 +804,17 @@ impl Block<DeviceBlocks, USBDevice> for DeviceBlocks {
                 .unwrap_or(0),
             DeviceBlocks::Class => d
                 .iter()
-                .map(|d| d.fully_defined_class().map_or(0, |c| c.to_string().len()))
+                .map(|d| d.fully_defined_class().map_or(0, |c| c.description().len()))
+                .max()
+                .unwrap_or(0),
+            DeviceBlocks::InterfaceSummary => d
+                .iter()
+                .map(|d| interface_summary(d).map_or(0, |s| s.len()))
+                .max()
+                .unwrap_or(0),
+            DeviceBlocks::MissingStrings => d
+                .iter()
+                .map(|d| missing_strings(d).map_or(0, |s| s.len()))
                 .max()
                 .unwrap_or(0),
             _ => self.block_length().len(),
@@ -688,6 +854,11 @@ impl Block<DeviceBlocks, USBDevice> for DeviceBlocks {
                 ),
                 None => format!("{:pad$}", "-", pad = pad.get(self).unwrap_or(&0)),
             }),
+            DeviceBlocks::PowerState => Some(format!(
+                "{:pad$}",
+                power_state_annotation(d),
+                pad = pad.get(self).unwrap_or(&0)
+            )),
             DeviceBlocks::Driver => Some(match d.extra.as_ref() {
                 Some(e) => format!(
                     "{:pad$}",
@@ -733,12 +904,11 @@ impl Block<DeviceBlocks, USBDevice> for DeviceBlocks {
                 Some(v) => Self::format_base_u16(v, settings),
                 None => format!("{:>6}", "-"),
             }),
-            DeviceBlocks::Name => Some(format!(
-                "{:pad$}",
-                d.name,
-                pad = pad.get(self).unwrap_or(&0)
-            )),
-            DeviceBlocks::Manufacturer => Some(match d.manufacturer.as_ref() {
+            DeviceBlocks::Name => Some(match d.display_name() {
+                Some(v) => format!("{:pad$}", v, pad = pad.get(self).unwrap_or(&0)),
+                None => format!("{:pad$}", "-", pad = pad.get(self).unwrap_or(&0)),
+            }),
+            DeviceBlocks::Manufacturer => Some(match d.display_manufacturer() {
                 Some(v) => format!("{:pad$}", v, pad = pad.get(self).unwrap_or(&0)),
                 None => format!("{:pad$}", "-", pad = pad.get(self).unwrap_or(&0)),
             }),
@@ -800,21 +970,39 @@ impl Block<DeviceBlocks, USBDevice> for DeviceBlocks {
                 None => format!("{:pad$}", "-", pad = pad.get(self).unwrap_or(&0)),
             }),
             DeviceBlocks::Class => Some(match d.fully_defined_class() {
-                Some(v) => format!("{:pad$}", v, pad = pad.get(self).unwrap_or(&0)),
+                Some(v) => format!(
+                    "{:pad$}",
+                    v.description(),
+                    pad = pad.get(self).unwrap_or(&0)
+                ),
                 None => format!("{:pad$}", "-", pad = pad.get(self).unwrap_or(&0)),
             }),
             DeviceBlocks::ClassValue => Some(match d.class.as_ref() {
                 Some(v) => Self::format_base_u8((*v).into(), settings),
                 None => format!("{:pad$}", "-", pad = pad.get(self).unwrap_or(&0)),
             }),
+            DeviceBlocks::InterfaceSummary => Some(match interface_summary(d) {
+                Some(v) => format!("{:pad$}", v, pad = pad.get(self).unwrap_or(&0)),
+                None => format!("{:pad$}", "-", pad = pad.get(self).unwrap_or(&0)),
+            }),
+            DeviceBlocks::MissingStrings => Some(match missing_strings(d) {
+                Some(v) => format!("{:pad$}", v, pad = pad.get(self).unwrap_or(&0)),
+                None => format!("{:pad$}", "-", pad = pad.get(self).unwrap_or(&0)),
+            }),
+            DeviceBlocks::MaxPacketSize0 => Some(match d.control_max_packet_size() {
+                Some(v) => format!("{:4}", v),
+                None => format!("{:4}", "-"),
+            }),
         }
     }
 
     fn colour(&self, s: &str, ct: &colour::ColourTheme) -> ColoredString {
         match self {
-            DeviceBlocks::BcdUsb | DeviceBlocks::BcdDevice | DeviceBlocks::DeviceNumber => {
-                ct.number.map_or(s.normal(), |c| s.color(c))
-            }
+            DeviceBlocks::BcdUsb
+            | DeviceBlocks::BcdDevice
+            | DeviceBlocks::DeviceNumber
+            | DeviceBlocks::InterfaceSummary
+            | DeviceBlocks::MaxPacketSize0 => ct.number.map_or(s.normal(), |c| s.color(c)),
             DeviceBlocks::BusNumber
             | DeviceBlocks::BranchPosition
             | DeviceBlocks::TreePositions => ct.location.map_or(s.normal(), |c| s.color(c)),
@@ -827,11 +1015,14 @@ impl Block<DeviceBlocks, USBDevice> for DeviceBlocks {
             DeviceBlocks::Name | DeviceBlocks::ProductName => {
                 ct.name.map_or(s.normal(), |c| s.color(c))
             }
-            DeviceBlocks::Serial => ct.serial.map_or(s.normal(), |c| s.color(c)),
+            DeviceBlocks::Serial | DeviceBlocks::MissingStrings => {
+                ct.serial.map_or(s.normal(), |c| s.color(c))
+            }
             DeviceBlocks::Manufacturer | DeviceBlocks::VendorName => {
                 ct.manufacturer.map_or(s.normal(), |c| s.color(c))
             }
             DeviceBlocks::Driver => ct.driver.map_or(s.normal(), |c| s.color(c)),
+            DeviceBlocks::PowerState => ct.power.map_or(s.normal(), |c| s.color(c)),
             DeviceBlocks::Speed => ct.speed.map_or(s.normal(), |c| s.color(c)),
             DeviceBlocks::BusPower
             | DeviceBlocks::BusPowerUsed
@@ -857,6 +1048,7 @@ impl Block<DeviceBlocks, USBDevice> for DeviceBlocks {
             DeviceBlocks::PortPath => "PPath",
             DeviceBlocks::SysPath => "SPath",
             DeviceBlocks::Driver => "Driver",
+            DeviceBlocks::PowerState => "PState",
             DeviceBlocks::VendorId => "VID",
             DeviceBlocks::ProductId => "PID",
             DeviceBlocks::Name => "Name",
@@ -881,6 +1073,9 @@ impl Block<DeviceBlocks, USBDevice> for DeviceBlocks {
             DeviceBlocks::UidProtocol => "UidPc",
             DeviceBlocks::Class => "Class",
             DeviceBlocks::ClassValue => "CVal",
+            DeviceBlocks::InterfaceSummary => "Interfaces",
+            DeviceBlocks::MissingStrings => "Missing",
+            DeviceBlocks::MaxPacketSize0 => "MPS0",
             DeviceBlocks::Icon => ICON_HEADING,
         }
     }
@@ -905,9 +1100,10 @@ impl Block<DeviceBlocks, USBDevice> for DeviceBlocks {
             | DeviceBlocks::BusPowerUsed
             | DeviceBlocks::ExtraCurrentUsed => BlockLength::Fixed(6),
             DeviceBlocks::BcdDevice | DeviceBlocks::BcdUsb => BlockLength::Fixed(5),
-            DeviceBlocks::SubClass | DeviceBlocks::Protocol | DeviceBlocks::ClassValue => {
-                BlockLength::Fixed(4)
-            }
+            DeviceBlocks::SubClass
+            | DeviceBlocks::Protocol
+            | DeviceBlocks::ClassValue
+            | DeviceBlocks::MaxPacketSize0 => BlockLength::Fixed(4),
             _ => BlockLength::Variable(self.heading().len()),
         }
     }
@@ -1066,7 +1262,11 @@ impl Block<ConfigurationBlocks, USBConfiguration> for ConfigurationBlocks {
 
     fn len(&self, d: &[&USBConfiguration]) -> usize {
         match self {
-            ConfigurationBlocks::Name => d.iter().map(|d| d.name.len()).max().unwrap_or(0),
+            ConfigurationBlocks::Name => d
+                .iter()
+                .map(|d| d.configuration_string().unwrap_or_default().len())
+                .max()
+                .unwrap_or(0),
             ConfigurationBlocks::Attributes => d
                 .iter()
                 .map(|d| d.attributes_string().len())
@@ -1104,7 +1304,7 @@ impl Block<ConfigurationBlocks, USBConfiguration> for ConfigurationBlocks {
             ConfigurationBlocks::NumInterfaces => Some(format!("{:2}", config.interfaces.len())),
             ConfigurationBlocks::Name => Some(format!(
                 "{:pad$}",
-                config.name,
+                config.configuration_string().unwrap_or_default(),
                 pad = pad.get(self).unwrap_or(&0)
             )),
             ConfigurationBlocks::MaxPower => Some(format!("{:6}", config.max_power)),
@@ -1222,9 +1422,12 @@ impl Block<InterfaceBlocks, USBInterface> for InterfaceBlocks {
                 .map(|d| d.protocol_name().unwrap_or_default().len())
                 .max()
                 .unwrap_or(0),
+            InterfaceBlocks::UidClassChain => {
+                d.iter().map(|d| d.class_chain().len()).max().unwrap_or(0)
+            }
             InterfaceBlocks::Class => d
                 .iter()
-                .map(|d| d.fully_defined_class().to_string().len())
+                .map(|d| d.fully_defined_class().description().len())
                 .max()
                 .unwrap_or(0),
             _ => self.block_length().len(),
@@ -1247,6 +1450,7 @@ impl Block<InterfaceBlocks, USBInterface> for InterfaceBlocks {
             InterfaceBlocks::Icon => ct.icon.map_or(s.normal(), |c| s.color(c)),
             InterfaceBlocks::ClassCode
             | InterfaceBlocks::UidClass
+            | InterfaceBlocks::UidClassChain
             | InterfaceBlocks::Class
             | InterfaceBlocks::ClassValue => ct.class_code.map_or(s.normal(), |c| s.color(c)),
             InterfaceBlocks::SubClass | InterfaceBlocks::UidSubClass => {
@@ -1314,9 +1518,14 @@ impl Block<InterfaceBlocks, USBInterface> for InterfaceBlocks {
                 Some(v) => format!("{:pad$}", v, pad = pad.get(self).unwrap_or(&0)),
                 None => format!("{:pad$}", "-", pad = pad.get(self).unwrap_or(&0)),
             }),
+            InterfaceBlocks::UidClassChain => Some(format!(
+                "{:pad$}",
+                interface.class_chain(),
+                pad = pad.get(self).unwrap_or(&0)
+            )),
             InterfaceBlocks::Class => Some(format!(
                 "{:pad$}",
-                interface.fully_defined_class(),
+                interface.fully_defined_class().description(),
                 pad = pad.get(self).unwrap_or(&0)
             )),
             InterfaceBlocks::ClassValue => {
@@ -1340,6 +1549,7 @@ impl Block<InterfaceBlocks, USBInterface> for InterfaceBlocks {
             InterfaceBlocks::UidClass => "UidCl",
             InterfaceBlocks::UidSubClass => "UidSc",
             InterfaceBlocks::UidProtocol => "UidPc",
+            InterfaceBlocks::UidClassChain => "UidChain",
             InterfaceBlocks::Class => "Class",
             InterfaceBlocks::ClassValue => "CVal",
             InterfaceBlocks::Icon => ICON_HEADING,
@@ -1372,6 +1582,14 @@ impl Block<InterfaceBlocks, USBInterface> for InterfaceBlocks {
     }
 }
 
+/// 'EP 1 IN' of the [`USBEndpoint::synch_address`] or '-' if None
+fn synch_address_string(end: &USBEndpoint) -> String {
+    match end.synch_address() {
+        Some(a) => EndpointAddress::from(a).to_string(),
+        None => String::from("-"),
+    }
+}
+
 impl Block<EndpointBlocks, USBEndpoint> for EndpointBlocks {
     const INSET: u8 = 3;
 
@@ -1417,7 +1635,7 @@ impl Block<EndpointBlocks, USBEndpoint> for EndpointBlocks {
                 .unwrap_or(0),
             EndpointBlocks::Direction => d
                 .iter()
-                .map(|d| d.address.direction.to_string().len())
+                .map(|d| d.direction().to_string().len())
                 .max()
                 .unwrap_or(0),
             EndpointBlocks::MaxPacketSize => d
@@ -1425,6 +1643,11 @@ impl Block<EndpointBlocks, USBEndpoint> for EndpointBlocks {
                 .map(|d| d.max_packet_string().len())
                 .max()
                 .unwrap_or(0),
+            EndpointBlocks::SynchAddress => d
+                .iter()
+                .map(|d| synch_address_string(d).len())
+                .max()
+                .unwrap_or(0),
             _ => self.block_length().len(),
         }
     }
@@ -1440,7 +1663,8 @@ impl Block<EndpointBlocks, USBEndpoint> for EndpointBlocks {
             EndpointBlocks::Number | EndpointBlocks::Interval | EndpointBlocks::MaxPacketSize => {
                 ct.number.map_or(s.normal(), |c| s.color(c))
             }
-            EndpointBlocks::Direction
+            EndpointBlocks::SynchAddress
+            | EndpointBlocks::Direction
             | EndpointBlocks::UsageType
             | EndpointBlocks::TransferType
             | EndpointBlocks::SyncType => ct.attributes.map_or(s.normal(), |c| s.color(c)),
@@ -1454,7 +1678,7 @@ impl Block<EndpointBlocks, USBEndpoint> for EndpointBlocks {
         _settings: &PrintSettings,
     ) -> Option<String> {
         match self {
-            EndpointBlocks::Number => Some(format!("{:2}", end.address.number)),
+            EndpointBlocks::Number => Some(format!("{:2}", end.endpoint_number())),
             EndpointBlocks::Interval => Some(format!("{:2}", end.interval)),
             EndpointBlocks::MaxPacketSize => Some(format!(
                 "{:pad$}",
@@ -1463,7 +1687,7 @@ impl Block<EndpointBlocks, USBEndpoint> for EndpointBlocks {
             )),
             EndpointBlocks::Direction => Some(format!(
                 "{:pad$}",
-                end.address.direction.to_string(),
+                end.direction().to_string(),
                 pad = pad.get(self).unwrap_or(&0)
             )),
             EndpointBlocks::TransferType => Some(format!(
@@ -1481,6 +1705,11 @@ impl Block<EndpointBlocks, USBEndpoint> for EndpointBlocks {
                 end.usage_type.to_string(),
                 pad = pad.get(self).unwrap_or(&0)
             )),
+            EndpointBlocks::SynchAddress => Some(format!(
+                "{:pad$}",
+                synch_address_string(end),
+                pad = pad.get(self).unwrap_or(&0)
+            )),
         }
     }
 
@@ -1493,6 +1722,7 @@ impl Block<EndpointBlocks, USBEndpoint> for EndpointBlocks {
             EndpointBlocks::TransferType => "TranT",
             EndpointBlocks::SyncType => "SyncT",
             EndpointBlocks::UsageType => "UsgeT",
+            EndpointBlocks::SynchAddress => "Synch",
         }
     }
 
@@ -1560,6 +1790,99 @@ pub enum Group {
     NoGroup,
     /// Group into buses with bus info as heading - like a flat tree
     Bus,
+    /// Group by device class; composite devices and those which defer to their interfaces are listed under each interface class
+    Class,
+    /// Group by bound driver; the interface drivers or the device driver if it has no interfaces
+    Driver,
+    /// Group by the primary function of the device, such as Audio or Storage; each device is in one group
+    Function,
+}
+
+const NO_GROUP_KEY: &str = "[none]";
+
+/// Keys of the groups `device` belongs to when grouping by `group`; a device can be in more than one group when its interfaces differ
+fn device_group_keys(device: &USBDevice, group: &Group) -> Vec<String> {
+    let interfaces: Vec<&USBInterface> = device
+        .extra
+        .as_ref()
+        .and_then(|e| e.active_configuration())
+        .map(|c| c.interfaces.iter().collect())
+        .unwrap_or_default();
+
+    let keys: Vec<String> = match group {
+        Group::NoGroup => vec![String::new()],
+        Group::Bus => vec![format!("Bus {:03}", device.location_id.bus)],
+        Group::Class => match device.class {
+            Some(ClassCode::UseInterfaceDescriptor) | Some(ClassCode::Miscellaneous) | None => {
+                interfaces
+                    .iter()
+                    .map(|i| i.class.to_string())
+                    .unique()
+                    .collect()
+            }
+            Some(c) => vec![c.to_string()],
+        },
+        Group::Function => vec![device.primary_function().to_string()],
+        Group::Driver => {
+            let drivers: Vec<String> = interfaces
+                .iter()
+                .filter_map(|i| i.driver.to_owned())
+                .unique()
+                .collect();
+            if drivers.is_empty() {
+                device
+                    .extra
+                    .as_ref()
+                    .and_then(|e| e.driver.to_owned())
+                    .into_iter()
+                    .collect()
+            } else {
+                drivers
+            }
+        }
+    };
+
+    if keys.is_empty() {
+        vec![NO_GROUP_KEY.to_string()]
+    } else {
+        keys
+    }
+}
+
+/// Group the flattened devices of `sp_usb` by `group`, ordered by group key
+///
+/// Devices without a value for the key are under "[none]". [`Group::NoGroup`] returns all devices under an empty key.
+///
+/// ```
+/// use cyme::display::{self, Group};
+/// use cyme::system_profiler::SPUSBDataType;
+///
+/// let sp_usb = SPUSBDataType::example();
+/// let groups = display::group_devices(&sp_usb, &Group::Class);
+/// assert_eq!(groups["Hub"][0].name, "USB2.0 Hub");
+/// // composite device is in the group of each interface class
+/// assert_eq!(groups["Audio"][0].name, "Example Composite");
+/// assert_eq!(groups["HID"][0].name, "Example Composite");
+///
+/// let groups = display::group_devices(&sp_usb, &Group::Driver);
+/// assert_eq!(groups["usbhid"].len(), 1);
+///
+/// let groups = display::group_devices(&sp_usb, &Group::Function);
+/// assert_eq!(groups["Audio"][0].name, "Example Composite");
+/// assert!(!groups.contains_key("Input"));
+/// ```
+pub fn group_devices<'a>(
+    sp_usb: &'a SPUSBDataType,
+    group: &Group,
+) -> BTreeMap<String, Vec<&'a USBDevice>> {
+    let mut groups: BTreeMap<String, Vec<&USBDevice>> = BTreeMap::new();
+    for device in sp_usb.flatten_devices() {
+        for key in device_group_keys(device, group) {
+            groups.entry(key).or_default().push(device);
+        }
+    }
+
+    groups
 }
 
 /// Options for [`PrintSettings`] mask_serials
@@ -1576,7 +1899,7 @@ pub enum MaskSerial {
 }
 
 /// Passed to printing functions allows default args
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct PrintSettings {
     /// Don't pad in order to align blocks
     pub no_padding: bool,
@@ -1600,6 +1923,10 @@ pub struct PrintSettings {
     pub more: bool,
     /// Print as json
     pub json: bool,
+    /// Print json on a single line rather than pretty printed
+    pub json_compact: bool,
+    /// Omit raw descriptor bytes from json, leaving only decoded fields
+    pub json_omit_raw_bytes: bool,
     /// Charactor encoding to use
     pub encoding: Encoding,
     /// Scramble serial numbers, useful if sharing sensitive device dumps
@@ -1626,6 +1953,20 @@ pub struct PrintSettings {
     pub terminal_size: Option<(Width, Height)>,
     /// When to print icon blocks
     pub icon_when: IconWhen,
+    /// Print a one line [`USBDevice::summary`] above each device in lsusb verbose dumps
+    pub device_summary: bool,
+    /// Limit lsusb verbose dumps to standard descriptors; see [`crate::lsusb::Verbosity::Standard`]
+    pub lsusb_brief: bool,
+    /// Append a badge with the interface and endpoint counts of the active configuration to each device in the tree
+    pub show_counts: bool,
+    /// Append `[over-budget]` to devices in the tree drawing more than their bus-powered parent hub can supply; see [`USBDevice::exceeds_hub_budget`]
+    pub power_budget: bool,
+    /// Keep all configurations of devices rather than only the active one, marking which is active
+    pub all_configurations: bool,
+    /// Nest the interfaces and endpoints of the active configuration under each device in the tree with compact blocks; has no effect at verbosity which prints configurations
+    pub show_interfaces: bool,
+    /// Colour each device line by an attribute with [`color_for`] rather than colouring blocks; no effect without `colours`
+    pub color_by: Option<ColorBy>,
 }
 
 /// Converts a HashSet of [`ConfigAttributes`] a String of nerd icons
@@ -1783,6 +2124,25 @@ pub fn render_value<B: Eq + Hash, T>(
     pad: &HashMap<B, usize>,
     settings: &PrintSettings,
     max_string_length: Option<usize>,
+) -> Vec<String> {
+    render_value_with_colours(
+        d,
+        blocks,
+        pad,
+        settings,
+        max_string_length,
+        settings.colours.as_ref(),
+    )
+}
+
+/// [`render_value`] colouring blocks with `colours` rather than those of `settings`
+fn render_value_with_colours<B: Eq + Hash, T>(
+    d: &T,
+    blocks: &[impl Block<B, T>],
+    pad: &HashMap<B, usize>,
+    settings: &PrintSettings,
+    max_string_length: Option<usize>,
+    colours: Option<&colour::ColourTheme>,
 ) -> Vec<String> {
     let mut ret = Vec::new();
     for b in blocks {
@@ -1793,7 +2153,7 @@ pub fn render_value<B: Eq + Hash, T>(
                     truncate_string(&mut string, ml)
                 }
             }
-            match &settings.colours {
+            match colours {
                 Some(c) => ret.push(format!("{}", b.colour(&string, c))),
                 None => ret.push(string.to_string()),
             };
@@ -1994,7 +2354,7 @@ pub fn print_flattened_devices(devices: &Vec<&USBDevice>, settings: &PrintSettin
     for (i, device) in sorted.into_iter().enumerate() {
         println!(
             "{}",
-            render_value(device, &db, &pad, settings, max_variable_string_len).join(" ")
+            render_device_values(device, &db, &pad, settings, max_variable_string_len)
         );
         // print the configurations
         if let Some(extra) = device.extra.as_ref() {
@@ -2004,6 +2364,7 @@ pub fn print_flattened_devices(devices: &Vec<&USBDevice>, settings: &PrintSettin
                 // pass branch length as number of configurations for this device plus devices still to print
                 print_configurations(
                     &extra.configurations,
+                    extra.active_config,
                     (&blocks.0, &blocks.1, &blocks.2),
                     settings,
                     &generate_tree_data(
@@ -2011,7 +2372,8 @@ pub fn print_flattened_devices(devices: &Vec<&USBDevice>, settings: &PrintSettin
                         extra.configurations.len() + device.devices.as_ref().map_or(0, |d| d.len()),
                         i,
                         settings,
-                    ),
+                    )
+                    .with_device_speed(device),
                 );
             }
         } else if settings.verbosity >= 1 {
@@ -2074,6 +2436,22 @@ pub fn print_bus_grouped(bus_devices: Vec<(&USBBus, Vec<&USBDevice>)>, settings:
     }
 }
 
+/// Print each group from [`group_devices`] as a heading with the device count followed by the devices in the group
+pub fn print_grouped(groups: &BTreeMap<String, Vec<&USBDevice>>, settings: &PrintSettings) {
+    for (key, devices) in groups {
+        let devices = settings.sort_devices.sort_devices_ref(devices);
+        let heading = format!("{} ({})", key, devices.len());
+        if settings.colours.is_some() {
+            println!("{}", heading.bold());
+        } else {
+            println!("{}", heading);
+        }
+        print_flattened_devices(&devices, settings);
+        // new line for each group
+        println!();
+    }
+}
+
 /// Passed to print functions to support tree building
 #[derive(Debug, Default, Clone)]
 pub struct TreeData {
@@ -2085,6 +2463,31 @@ pub struct TreeData {
     depth: usize,
     /// Prefix to apply, builds up as depth increases
     prefix: String,
+    /// Current the parent device can supply to each port if it is a bus-powered hub
+    bus_powered_parent_ma: Option<u32>,
+    /// Operating speed of the device owning the configurations being printed
+    device_speed: Option<Speed>,
+}
+
+impl TreeData {
+    /// Take the operating speed of `device` for the configurations printed below it
+    fn with_device_speed(mut self, device: &USBDevice) -> Self {
+        self.device_speed = match &device.device_speed {
+            Some(DeviceSpeed::SpeedValue(s)) => Some(s.to_owned()),
+            _ => None,
+        };
+        self
+    }
+}
+
+/// Bandwidth annotation for a periodic endpoint such as " ~1.5 MB/s reserved", empty if the speed is unknown or the endpoint is not periodic
+fn periodic_bandwidth_annotation(endpoint: &USBEndpoint, speed: Option<Speed>) -> String {
+    match speed.and_then(|s| endpoint.periodic_bandwidth(s)) {
+        Some(b) if b >= 1_000_000 => format!(" ~{:.1} MB/s reserved", b as f64 / 1_000_000.0),
+        Some(b) if b >= 1_000 => format!(" ~{:.1} kB/s reserved", b as f64 / 1_000.0),
+        Some(b) => format!(" ~{} B/s reserved", b),
+        None => String::new(),
+    }
 }
 
 /// All device [`USBEndpoint`]
@@ -2153,12 +2556,12 @@ pub fn print_endpoints(
 
             let mut terminator = settings.icons.as_ref().map_or(
                 icon::get_default_tree_icon(
-                    &icon::Icon::Endpoint(endpoint.address.direction),
+                    &icon::Icon::Endpoint(endpoint.direction()),
                     &settings.encoding,
                 ),
                 |i| {
                     i.get_tree_icon(
-                        &icon::Icon::Endpoint(endpoint.address.direction),
+                        &icon::Icon::Endpoint(endpoint.direction()),
                         &settings.encoding,
                     )
                 },
@@ -2170,7 +2573,7 @@ pub fn print_endpoints(
                     .tree
                     .map_or(prefix.normal(), |c| prefix.color(c))
                     .to_string();
-                terminator = if endpoint.address.direction == Direction::In {
+                terminator = if endpoint.direction() == Direction::In {
                     ct.tree_endpoint_in
                         .map_or(terminator.normal(), |c| terminator.color(c))
                         .to_string()
@@ -2190,8 +2593,9 @@ pub fn print_endpoints(
             // render and print tree if doing it
             print!("{}{} ", prefix, terminator);
             println!(
-                "{}",
-                render_value(endpoint, blocks, &pad, settings, max_variable_string_len).join(" ")
+                "{}{}",
+                render_value(endpoint, blocks, &pad, settings, max_variable_string_len).join(" "),
+                periodic_bandwidth_annotation(endpoint, tree.device_speed)
             );
         } else {
             if settings.headings && i == 0 {
@@ -2200,21 +2604,67 @@ pub fn print_endpoints(
             }
 
             println!(
-                "{:spaces$}{}",
+                "{:spaces$}{}{}",
                 "",
                 render_value(endpoint, blocks, &pad, settings, max_variable_string_len).join(" "),
+                periodic_bandwidth_annotation(endpoint, tree.device_speed),
                 spaces = (EndpointBlocks::INSET * LIST_INSET_SPACES) as usize
             );
         }
     }
 }
 
+/// " (active)" if `interface` is the alternate setting in use and its number has others in `interfaces`, otherwise empty
+fn active_alt_annotation(interface: &USBInterface, interfaces: &[USBInterface]) -> &'static str {
+    let has_alts = interfaces
+        .iter()
+        .any(|i| i.number == interface.number && i.alt_setting != interface.alt_setting);
+    if has_alts && interface.is_active_alt_setting() == Some(true) {
+        " (active)"
+    } else {
+        ""
+    }
+}
+
 /// All device [`USBInterface`]
 pub fn print_interfaces(
     interfaces: &[USBInterface],
     blocks: (&Vec<InterfaceBlocks>, &Vec<EndpointBlocks>),
     settings: &PrintSettings,
     tree: &TreeData,
+) {
+    print_interface_functions(interfaces, &[], blocks, settings, tree)
+}
+
+/// Heading for interfaces grouped into a function by an [`InterfaceAssociationDescriptor`], such as "Function: Video (interfaces 0-1)"
+fn function_heading(iad: &InterfaceAssociationDescriptor) -> String {
+    let mut ret = format!(
+        "Function: {}",
+        ClassCode::from(iad.function_class).to_title_case()
+    );
+    if let Some(s) = iad.function_string.as_ref().filter(|s| !s.is_empty()) {
+        ret.push_str(&format!(" \"{}\"", s));
+    }
+    if iad.interface_count > 1 {
+        ret.push_str(&format!(
+            " (interfaces {}-{})",
+            iad.first_interface,
+            iad.last_interface()
+        ));
+    } else {
+        ret.push_str(&format!(" (interface {})", iad.first_interface));
+    }
+
+    ret
+}
+
+/// All device [`USBInterface`] with a function heading above those grouped by the Interface Association Descriptors `functions`
+pub fn print_interface_functions(
+    interfaces: &[USBInterface],
+    functions: &[&InterfaceAssociationDescriptor],
+    blocks: (&Vec<InterfaceBlocks>, &Vec<EndpointBlocks>),
+    settings: &PrintSettings,
+    tree: &TreeData,
 ) {
     let mut pad = if !settings.no_padding {
         let interfaces: Vec<&USBInterface> = interfaces.iter().collect();
@@ -2255,8 +2705,30 @@ pub fn print_interfaces(
     log::trace!("Print interfaces padding {:?}, tree {:?}", pad, tree);
 
     for (i, interface) in interfaces.iter().enumerate() {
+        // heading above first interface of a function; alternate settings share a number
+        let function = functions
+            .iter()
+            .find(|f| f.first_interface == interface.number)
+            .filter(|_| i == 0 || interfaces[i - 1].number != interface.number);
+
         // get current prefix based on if last in tree and whether we are within the tree
         if settings.tree {
+            if let Some(f) = function {
+                let mut line = if tree.depth > 0 {
+                    let line = settings.icons.as_ref().map_or(
+                        icon::get_default_tree_icon(&icon::Icon::TreeLine, &settings.encoding),
+                        |i| i.get_tree_icon(&icon::Icon::TreeLine, &settings.encoding),
+                    );
+                    format!("{}{}", tree.prefix, line)
+                } else {
+                    tree.prefix.to_string()
+                };
+                if let Some(ct) = settings.colours.as_ref() {
+                    line = ct.tree.map_or(line.normal(), |c| line.color(c)).to_string();
+                }
+                println!("{}{}", line, function_heading(f).bold());
+            }
+
             let mut prefix = if tree.depth > 0 {
                 let edge_icon = if i + 1 != tree.branch_length {
                     icon::Icon::TreeEdge
@@ -2303,9 +2775,10 @@ pub fn print_interfaces(
             print!("{}{} ", prefix, terminator);
 
             println!(
-                "{}",
+                "{}{}",
                 render_value(interface, blocks.0, &pad, settings, max_variable_string_len)
-                    .join(" ")
+                    .join(" "),
+                active_alt_annotation(interface, interfaces)
             );
         } else {
             if settings.headings && i == 0 {
@@ -2313,11 +2786,21 @@ pub fn print_interfaces(
                 println!("{:spaces$}{}", "", heading.bold().underline(), spaces = 4);
             }
 
+            if let Some(f) = function {
+                println!(
+                    "{:spaces$}{}",
+                    "",
+                    function_heading(f).bold(),
+                    spaces = (InterfaceBlocks::INSET * LIST_INSET_SPACES) as usize
+                );
+            }
+
             println!(
-                "{:spaces$}{}",
+                "{:spaces$}{}{}",
                 "",
                 render_value(interface, blocks.0, &pad, settings, max_variable_string_len)
                     .join(" "),
+                active_alt_annotation(interface, interfaces),
                 spaces = (InterfaceBlocks::INSET * LIST_INSET_SPACES) as usize
             );
         }
@@ -2337,6 +2820,7 @@ pub fn print_interfaces(
 /// All device [`USBConfiguration`]
 pub fn print_configurations(
     configs: &[USBConfiguration],
+    active_config: Option<u8>,
     blocks: (
         &Vec<ConfigurationBlocks>,
         &Vec<InterfaceBlocks>,
@@ -2384,6 +2868,13 @@ pub fn print_configurations(
     log::trace!("Print configs padding {:?}, tree {:?}", pad, tree);
 
     for (i, config) in configs.iter().enumerate() {
+        let mut line =
+            render_value(config, blocks.0, &pad, settings, max_variable_string_len).join(" ");
+        // only worth marking if there is a choice
+        if configs.len() > 1 && active_config == Some(config.number) {
+            line = format!("{} (active)", line);
+        }
+
         // get current prefix based on if last in tree and whether we are within the tree
         if settings.tree {
             let mut prefix = if tree.depth > 0 {
@@ -2431,10 +2922,7 @@ pub fn print_configurations(
             // render and print tree if doing it
             print!("{}{} ", prefix, terminator);
 
-            println!(
-                "{}",
-                render_value(config, blocks.0, &pad, settings, max_variable_string_len).join(" ")
-            );
+            println!("{}", line);
         } else {
             if settings.headings && i == 0 {
                 let heading = render_heading(blocks.0, &pad, max_variable_string_len).join(" ");
@@ -2444,15 +2932,16 @@ pub fn print_configurations(
             println!(
                 "{:spaces$}{}",
                 "",
-                render_value(config, blocks.0, &pad, settings, max_variable_string_len).join(" "),
+                line,
                 spaces = (ConfigurationBlocks::INSET * LIST_INSET_SPACES) as usize
             );
         }
 
         // print the interfaces
         if settings.verbosity >= 2 {
-            print_interfaces(
+            print_interface_functions(
                 &config.interfaces,
+                &config.interface_associations(),
                 ((blocks.1), (blocks.2)),
                 settings,
                 &generate_tree_data(tree, config.interfaces.len(), i, settings),
@@ -2461,6 +2950,162 @@ pub fn print_configurations(
     }
 }
 
+/// Badge like `[2 if, 4 ep]` for the default alternate settings of the active configuration
+fn counts_badge(device: &USBDevice) -> Option<String> {
+    device
+        .extra
+        .as_ref()
+        .and_then(|e| e.active_configuration())
+        .map(|c| {
+            let (interfaces, endpoints) = c.interface_endpoint_counts();
+            format!("[{} if, {} ep]", interfaces, endpoints)
+        })
+}
+
+/// Blocks of `device` joined into a line; coloured as a whole by [`PrintSettings::color_by`] if set, otherwise block by block
+///
+/// With `color_by` the line is left uncoloured if stdout is not a terminal or `NO_COLOR` is set, whatever `--color` is.
+fn render_device_values(
+    device: &USBDevice,
+    blocks: &[DeviceBlocks],
+    pad: &HashMap<DeviceBlocks, usize>,
+    settings: &PrintSettings,
+    max_variable_string_len: Option<usize>,
+) -> String {
+    match (settings.color_by.as_ref(), settings.colours.as_ref()) {
+        (Some(by), Some(ct)) => {
+            let line = render_value_with_colours(
+                device,
+                blocks,
+                pad,
+                settings,
+                max_variable_string_len,
+                None,
+            )
+            .join(" ");
+            match color_for(device, by, ct) {
+                Some(c) => line.color(c).to_string(),
+                None => line,
+            }
+        }
+        _ => render_value(device, blocks, pad, settings, max_variable_string_len).join(" "),
+    }
+}
+
+/// Render the `blocks` values of `device` with optional [`counts_badge`] and `[over-budget]` mark as printed on a device line
+fn render_device_line(
+    device: &USBDevice,
+    blocks: &[DeviceBlocks],
+    pad: &HashMap<DeviceBlocks, usize>,
+    settings: &PrintSettings,
+    max_variable_string_len: Option<usize>,
+    over_budget: bool,
+) -> String {
+    let mut line = render_device_values(device, blocks, pad, settings, max_variable_string_len);
+    if settings.show_counts {
+        if let Some(badge) = counts_badge(device) {
+            line = format!("{} {}", line, badge);
+        }
+    }
+    if over_budget {
+        line.push_str(" [over-budget]");
+    }
+    line
+}
+
+/// Render a single `device` line as [`print_sp_usb`] would, without needing a full [`SPUSBDataType`]
+///
+/// Uses `settings.device_blocks` or the defaults for the mode. Padding is generated from the device alone. If `settings.tree`, `depth` is the number of levels below the bus, drawn as a corner edge after blank branches so the line can be placed independently of its siblings; it is ignored for list output. Child devices and configurations are not rendered.
+///
+/// ```
+/// use cyme::display::{render_device, PrintSettings};
+/// use cyme::system_profiler::SPUSBDataType;
+///
+/// let sp = SPUSBDataType::example();
+/// let device = sp.flatten_devices()[1];
+/// let settings = PrintSettings {
+///     no_padding: true,
+///     ..Default::default()
+/// };
+/// let line = render_device(device, &settings, 0);
+/// assert!(line.contains("Example Composite"));
+/// assert!(!line.contains('\n'));
+/// ```
+pub fn render_device(device: &USBDevice, settings: &PrintSettings, depth: usize) -> String {
+    let mut db = default_device_blocks(settings);
+    settings.icon_when.retain_ref(&[device], &mut db, settings);
+
+    let mut pad = if !settings.no_padding {
+        DeviceBlocks::generate_padding(&[device])
+    } else {
+        HashMap::new()
+    };
+    pad.retain(|k, _| db.contains(k));
+    if let Some(ml) = settings.max_variable_string_len {
+        for (k, v) in pad.iter_mut() {
+            if k.value_is_variable_length() {
+                *v = cmp::min(*v, ml);
+            }
+        }
+    }
+
+    let line = render_device_line(
+        device,
+        &db,
+        &pad,
+        settings,
+        settings.max_variable_string_len,
+        false,
+    );
+    if !settings.tree {
+        return line;
+    }
+
+    let tree_icon = |i: &icon::Icon| {
+        settings
+            .icons
+            .as_ref()
+            .map_or(icon::get_default_tree_icon(i, &settings.encoding), |t| {
+                t.get_tree_icon(i, &settings.encoding)
+            })
+    };
+    let mut prefix = if depth > 0 {
+        format!(
+            "{}{}",
+            tree_icon(&icon::Icon::TreeBlank).repeat(depth - 1),
+            tree_icon(&icon::Icon::TreeCorner)
+        )
+    } else {
+        String::new()
+    };
+    let mut terminator = tree_icon(&icon::Icon::TreeDeviceTerminator);
+    if let Some(ct) = settings.colours.as_ref() {
+        prefix = ct
+            .tree
+            .map_or(prefix.normal(), |c| prefix.color(c))
+            .to_string();
+        terminator = ct
+            .tree_bus_terminator
+            .map_or(terminator.normal(), |c| terminator.color(c))
+            .to_string();
+    }
+
+    format!("{}{} {}", prefix, terminator, line)
+}
+
+/// Device blocks from `settings` or the defaults for the output mode
+fn default_device_blocks(settings: &PrintSettings) -> Vec<DeviceBlocks> {
+    settings.device_blocks.to_owned().unwrap_or(
+        if settings.verbosity >= MAX_VERBOSITY || settings.more {
+            DeviceBlocks::default_blocks(true)
+        } else if settings.tree {
+            DeviceBlocks::default_device_tree_blocks()
+        } else {
+            DeviceBlocks::default_blocks(false)
+        },
+    )
+}
+
 /// Recursively print `devices`; will call for each `USBDevice` devices if `Some`
 ///
 /// Will draw tree if `settings.tree`, otherwise it will be flat
@@ -2502,6 +3147,21 @@ pub fn print_devices(
     // sort so that can be ascending along branch
     let sorted = settings.sort_devices.sort_devices(devices);
 
+    // interfaces nested without configurations use compact blocks unless specified and always include endpoints
+    let inline_settings = (settings.tree && settings.show_interfaces && settings.verbosity == 0)
+        .then(|| PrintSettings {
+            interface_blocks: settings
+                .interface_blocks
+                .to_owned()
+                .or(Some(InterfaceBlocks::default_inline_blocks())),
+            endpoint_blocks: settings
+                .endpoint_blocks
+                .to_owned()
+                .or(Some(EndpointBlocks::default_inline_blocks())),
+            verbosity: MAX_VERBOSITY,
+            ..settings.clone()
+        });
+
     for (i, device) in sorted.iter().enumerate() {
         // get current prefix based on if last in tree and whether we are within the tree
         if settings.tree {
@@ -2552,9 +3212,20 @@ pub fn print_devices(
         }
 
         // print the device
+        let over_budget = settings.power_budget
+            && tree
+                .bus_powered_parent_ma
+                .is_some_and(|ma| device.exceeds_power(ma));
         println!(
             "{}",
-            render_value(device, db, &pad, settings, max_variable_string_len).join(" ")
+            render_device_line(
+                device,
+                db,
+                &pad,
+                settings,
+                max_variable_string_len,
+                over_budget
+            )
         );
 
         // print the configurations
@@ -2566,6 +3237,7 @@ pub fn print_devices(
                 // pass branch length as number of configurations for this device plus devices still to print
                 print_configurations(
                     &extra.configurations,
+                    extra.active_config,
                     (&blocks.0, &blocks.1, &blocks.2),
                     settings,
                     &generate_tree_data(
@@ -2573,6 +3245,22 @@ pub fn print_devices(
                         extra.configurations.len() + device.devices.as_ref().map_or(0, |d| d.len()),
                         i,
                         settings,
+                    )
+                    .with_device_speed(device),
+                );
+            } else if let Some((inline_settings, config)) =
+                inline_settings.as_ref().zip(extra.active_configuration())
+            {
+                let blocks = generate_extra_blocks(extra, inline_settings);
+                print_interfaces(
+                    &config.interfaces,
+                    (&blocks.1, &blocks.2),
+                    inline_settings,
+                    &generate_tree_data(
+                        tree,
+                        config.interfaces.len() + device.devices.as_ref().map_or(0, |d| d.len()),
+                        i,
+                        settings,
                     ),
                 );
             }
@@ -2585,12 +3273,11 @@ pub fn print_devices(
 
         if let Some(d) = device.devices.as_ref() {
             // and then walk down devices printing them too
-            print_devices(
-                d,
-                db,
-                settings,
-                &generate_tree_data(tree, d.len(), i, settings),
-            );
+            let mut child_tree = generate_tree_data(tree, d.len(), i, settings);
+            child_tree.bus_powered_parent_ma = device
+                .is_bus_powered_hub()
+                .then(|| device.bus_powered_hub_port_ma());
+            print_devices(d, db, settings, &child_tree);
         }
     }
 }
@@ -2604,15 +3291,7 @@ pub fn print_sp_usb(sp_usb: &SPUSBDataType, settings: &PrintSettings) {
             .unwrap_or(Block::<BusBlocks, USBBus>::default_blocks(
                 settings.verbosity >= MAX_VERBOSITY || settings.more,
             ));
-    let mut db = settings.device_blocks.to_owned().unwrap_or(
-        if settings.verbosity >= MAX_VERBOSITY || settings.more {
-            DeviceBlocks::default_blocks(true)
-        } else if settings.tree {
-            DeviceBlocks::default_device_tree_blocks()
-        } else {
-            DeviceBlocks::default_blocks(false)
-        },
-    );
+    let mut db = default_device_blocks(settings);
 
     // remove icon blocks if not supported by encoding
     match settings.icon_when {
@@ -2768,7 +3447,8 @@ pub fn prepare(sp_usb: &mut SPUSBDataType, filter: Option<USBFilter>, settings:
     // if not printing tree, hard flatten now before filtering as filter will retain non-matching parents with matching devices in tree
     // but only do it if there is a filter, grouping by bus (which uses tree print without tree...) or json
     // flattening now will also mean hubs will be removed when listing if `hide_hubs` because they will appear empty
-    if !settings.tree && (filter.is_some() || settings.group_devices == Group::Bus || settings.json)
+    if !settings.tree
+        && (filter.is_some() || settings.group_devices != Group::NoGroup || settings.json)
     {
         sp_usb.flatten();
     }
@@ -2794,6 +3474,15 @@ pub fn prepare(sp_usb: &mut SPUSBDataType, filter: Option<USBFilter>, settings:
         sp_usb.buses.sort_by_key(|d| d.get_bus_number());
     }
 
+    if !settings.all_configurations {
+        for bus in &mut sp_usb.buses {
+            bus.devices
+                .iter_mut()
+                .flatten()
+                .for_each(retain_active_configuration);
+        }
+    }
+
     // hide serials Recursively
     if let Some(hide) = settings.mask_serials.as_ref() {
         for bus in &mut sp_usb.buses {
@@ -2810,26 +3499,224 @@ pub fn prepare(sp_usb: &mut SPUSBDataType, filter: Option<USBFilter>, settings:
     log::trace!("sp_usb data post filter and bus sort\n\r{:#}", sp_usb);
 }
 
+/// Drop all but the [`USBDeviceExtra::active_configuration`] of `device` and its children
+///
+/// ```
+/// use cyme::display;
+/// use cyme::system_profiler::SPUSBDataType;
+///
+/// let mut sp_usb = SPUSBDataType::example();
+/// let device = sp_usb.get_node_mut("1-1.2").unwrap();
+/// let extra = device.extra.as_mut().unwrap();
+/// let mut second = extra.configurations[0].clone();
+/// second.number = 2;
+/// extra.configurations.push(second);
+/// extra.active_config = Some(2);
+///
+/// display::retain_active_configuration(device);
+/// let configs = &device.extra.as_ref().unwrap().configurations;
+/// assert_eq!(configs.len(), 1);
+/// assert_eq!(configs[0].number, 2);
+/// ```
+pub fn retain_active_configuration(device: &mut USBDevice) {
+    if let Some(extra) = device.extra.as_mut() {
+        if let Some(active) = extra.active_configuration().map(|c| c.number) {
+            extra.configurations.retain(|c| c.number == active);
+        }
+    }
+
+    device
+        .devices
+        .iter_mut()
+        .flatten()
+        .for_each(retain_active_configuration);
+}
+
+/// Serialize `value` to json, pretty printed unless `compact`
+///
+/// Profile data contains no hash maps so the output is deterministic: the same profile always serializes to the same bytes, allowing captures to be diffed.
+pub fn json_string<T: Serialize + ?Sized>(value: &T, compact: bool) -> serde_json::Result<String> {
+    if compact {
+        serde_json::to_string(value)
+    } else {
+        serde_json::to_string_pretty(value)
+    }
+}
+
+/// Options for [`json_string_with_options`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsonOptions {
+    /// Single line rather than pretty printed
+    pub compact: bool,
+    /// Include raw descriptor bytes, encoded as described in [`crate::usb::descriptors::raw_bytes`]; when false only decoded fields are serialized
+    pub include_raw_bytes: bool,
+}
+
+impl Default for JsonOptions {
+    fn default() -> Self {
+        JsonOptions {
+            compact: false,
+            include_raw_bytes: true,
+        }
+    }
+}
+
+impl From<&PrintSettings> for JsonOptions {
+    fn from(settings: &PrintSettings) -> Self {
+        JsonOptions {
+            compact: settings.json_compact,
+            include_raw_bytes: !settings.json_omit_raw_bytes,
+        }
+    }
+}
+
+/// Serialize `value` to json with [`JsonOptions`]
+///
+/// ```
+/// use cyme::display::{json_string_with_options, JsonOptions};
+/// use cyme::usb::descriptors::Descriptor;
+///
+/// let desc = vec![Descriptor::Junk(vec![0x01, 0x02])];
+/// let options = JsonOptions {
+///     compact: true,
+///     include_raw_bytes: false,
+/// };
+/// assert_eq!(json_string_with_options(&desc, &options).unwrap(), r#"[{"junk":null}]"#);
+/// assert_eq!(
+///     json_string_with_options(&desc, &JsonOptions { compact: true, ..Default::default() }).unwrap(),
+///     r#"[{"junk":"0102"}]"#
+/// );
+/// ```
+pub fn json_string_with_options<T: Serialize + ?Sized>(
+    value: &T,
+    options: &JsonOptions,
+) -> serde_json::Result<String> {
+    if options.include_raw_bytes {
+        json_string(value, options.compact)
+    } else {
+        json_string(
+            &crate::usb::descriptors::raw_bytes::OmitRawBytes(value),
+            options.compact,
+        )
+    }
+}
+
 /// Main cyme bin print function
 pub fn print(sp_usb: &system_profiler::SPUSBDataType, settings: &PrintSettings) {
     log::debug!("Printing with {:?}", settings);
 
     if settings.tree || settings.group_devices == Group::Bus {
         if settings.json {
-            println!("{}", serde_json::to_string_pretty(&sp_usb).unwrap());
+            println!(
+                "{}",
+                json_string_with_options(&sp_usb, &settings.into()).unwrap()
+            );
         } else {
             print_sp_usb(sp_usb, settings);
         }
+    } else if settings.group_devices != Group::NoGroup {
+        let groups = group_devices(sp_usb, &settings.group_devices);
+
+        if settings.json {
+            println!(
+                "{}",
+                json_string_with_options(&groups, &settings.into()).unwrap()
+            );
+        } else {
+            print_grouped(&groups, settings);
+        }
     } else {
         {
             // get a list of all devices
             let devs = sp_usb.flatten_devices();
 
             if settings.json {
-                println!("{}", serde_json::to_string_pretty(&devs).unwrap());
+                println!(
+                    "{}",
+                    json_string_with_options(&devs, &settings.into()).unwrap()
+                );
             } else {
                 print_flattened_devices(&devs, settings);
             }
         }
     }
 }
+
+/// Print the value of `block` for each device in `sp_usb` on its own line for scripting
+///
+/// Devices without a value print a blank line so lines match up with devices, unless `skip_empty`
+pub fn print_selected(
+    sp_usb: &system_profiler::SPUSBDataType,
+    block: &DeviceBlocks,
+    skip_empty: bool,
+    settings: &PrintSettings,
+) {
+    for device in sp_usb.flatten_devices() {
+        match block.select_value(device, settings) {
+            Some(v) => println!("{}", v),
+            None if !skip_empty => println!(),
+            None => (),
+        }
+    }
+}
+
+/// Layout used by [`display`]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DisplayMode {
+    /// List every device on its own line with its full port path; better for grep and scripts
+    #[default]
+    Flat,
+    /// Nest devices under their bus and parent hub; better for humans
+    Tree,
+}
+
+/// Display `sp_usb` in `mode`, applying the `filter` and the sort, blocks and theme of `settings`
+///
+/// Library consumers can use this rather than knowing which of the internal print functions to call. `settings.tree` is ignored in favour of `mode` and `sp_usb` is not modified.
+///
+/// ```
+/// use cyme::display::{self, DisplayMode, PrintSettings};
+/// use cyme::system_profiler::SPUSBDataType;
+///
+/// let sp_usb = SPUSBDataType::example();
+/// display::display(&sp_usb, DisplayMode::Tree, None, &PrintSettings::default());
+/// display::display(&sp_usb, DisplayMode::Flat, None, &PrintSettings::default());
+/// ```
+pub fn display(
+    sp_usb: &SPUSBDataType,
+    mode: DisplayMode,
+    filter: Option<USBFilter>,
+    settings: &PrintSettings,
+) {
+    let settings = PrintSettings {
+        tree: mode == DisplayMode::Tree,
+        ..settings.clone()
+    };
+    let mut sp_usb = sp_usb.clone();
+    prepare(&mut sp_usb, filter, &settings);
+    print(&sp_usb, &settings);
+}
+
+/// Display `sp_usb` with a saved [`crate::config::DisplayConfig`]; its mode, filter, blocks, theme, icons and sort
+///
+/// ```
+/// use cyme::config::DisplayConfig;
+/// use cyme::display::{self, DisplayMode};
+/// use cyme::system_profiler::SPUSBDataType;
+///
+/// let config = DisplayConfig {
+///     mode: Some(DisplayMode::Tree),
+///     ..Default::default()
+/// };
+/// display::display_with_config(&SPUSBDataType::example(), &config);
+/// ```
+pub fn display_with_config(sp_usb: &SPUSBDataType, config: &crate::config::DisplayConfig) {
+    let mut settings = PrintSettings::default();
+    config.apply(&mut settings);
+    display(
+        sp_usb,
+        config.mode.unwrap_or_default(),
+        config.filter.clone(),
+        &settings,
+    );
+}
@@ -1,5 +1,6 @@
 //! Error type used within crate with From for commonly used crate errors
 use std::error;
+use std::sync::Arc;
 use std::{fmt, io};
 
 /// Result type used within crate
@@ -54,6 +55,10 @@ pub enum ErrorKind {
     NotFound,
     /// Unable to open device to query device descriptors - check permissions
     Opening,
+    /// Insufficient permissions to access device or file; running as root may help
+    Permission,
+    /// Operation timed out, may succeed if retried
+    Timeout,
     /// Error parsing a string into a value - used for u32 to json deserialization
     Parsing,
     /// Error decoding an encoded value into a type
@@ -76,13 +81,17 @@ pub enum ErrorKind {
     DescriptorLength(ErrorArg<usize, usize>),
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone)]
 /// Cyme error which impl [`std::error`]
+///
+/// Errors from backends (libusb, udev, io) are kept as the [`error::Error::source`] so they can be downcast by callers
 pub struct Error {
     /// The [`ErrorKind`]
     pub kind: ErrorKind,
     /// String description
     pub message: String,
+    /// Underlying error which caused this one
+    source: Option<Arc<dyn error::Error + Send + Sync + 'static>>,
 }
 
 impl Error {
@@ -91,9 +100,26 @@ impl Error {
         Error {
             kind,
             message: message.to_string(),
+            source: None,
         }
     }
 
+    /// Set the underlying error returned by [`error::Error::source`]
+    ///
+    /// ```
+    /// use std::error::Error as _;
+    /// use cyme::error::{Error, ErrorKind};
+    ///
+    /// let io = std::io::Error::new(std::io::ErrorKind::TimedOut, "slow");
+    /// let e = Error::new(ErrorKind::Timeout, "Failed to read").with_source(io);
+    /// let source = e.source().unwrap().downcast_ref::<std::io::Error>().unwrap();
+    /// assert_eq!(source.kind(), std::io::ErrorKind::TimedOut);
+    /// ```
+    pub fn with_source<E: error::Error + Send + Sync + 'static>(mut self, source: E) -> Error {
+        self.source = Some(Arc::new(source));
+        self
+    }
+
     /// New error helper for descriptor length
     pub fn new_descriptor_len(name: &str, expected: usize, got: usize) -> Error {
         let error_arg = ErrorArg::new(expected, got);
@@ -103,6 +129,7 @@ impl Error {
                 "Invalid descriptor length for {}. Expected: {}, Got {}",
                 name, expected, got
             ),
+            source: None,
         }
     }
 
@@ -117,7 +144,20 @@ impl Error {
     }
 }
 
-impl error::Error for Error {}
+/// Compares [`ErrorKind`] and message only; the source is not comparable
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind && self.message == other.message
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|e| e as &(dyn error::Error + 'static))
+    }
+}
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -129,30 +169,33 @@ impl fmt::Display for Error {
     }
 }
 
+impl From<io::ErrorKind> for ErrorKind {
+    fn from(kind: io::ErrorKind) -> Self {
+        match kind {
+            io::ErrorKind::PermissionDenied => ErrorKind::Permission,
+            io::ErrorKind::TimedOut => ErrorKind::Timeout,
+            io::ErrorKind::NotFound => ErrorKind::NotFound,
+            io::ErrorKind::Unsupported => ErrorKind::Unsupported,
+            _ => ErrorKind::Io,
+        }
+    }
+}
+
 impl From<io::Error> for Error {
     fn from(error: io::Error) -> Self {
-        Error {
-            kind: ErrorKind::Io,
-            message: error.to_string(),
-        }
+        Error::new(ErrorKind::from(error.kind()), &error.to_string()).with_source(error)
     }
 }
 
 impl From<serde_json::Error> for Error {
     fn from(error: serde_json::Error) -> Self {
-        Error {
-            kind: ErrorKind::Parsing,
-            message: error.to_string(),
-        }
+        Error::new(ErrorKind::Parsing, &error.to_string()).with_source(error)
     }
 }
 
 impl From<std::string::FromUtf8Error> for Error {
     fn from(error: std::string::FromUtf8Error) -> Self {
-        Error {
-            kind: ErrorKind::Other("FromUtf8Error"),
-            message: error.to_string(),
-        }
+        Error::new(ErrorKind::Other("FromUtf8Error"), &error.to_string()).with_source(error)
     }
 }
 
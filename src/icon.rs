@@ -195,7 +195,7 @@ impl fmt::Display for Icon {
 }
 
 /// Allows user supplied icons to replace or add to [`DEFAULT_ICONS`] and [`DEFAULT_UTF8_TREE`]
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
 #[serde(deny_unknown_fields)]
 #[serde(default)]
@@ -388,31 +388,56 @@ impl IconTheme {
         }
     }
 
-    /// Get icon for USBDevice `d` by checking `Self` using Name, Vendor ID and Product ID
+    /// Whether `Self` or [`DEFAULT_ICONS`] has a `VidPid`, `VidPidMsb` or `Vid` icon for `vid` and `pid`; so [`IconTheme::get_vidpid_icon`] will not be the `UnknownVendor` icon
+    fn has_vidpid_icon(&self, vid: u16, pid: u16) -> bool {
+        [
+            Icon::VidPid((vid, pid)),
+            Icon::VidPidMsb((vid, (pid >> 8) as u8)),
+            Icon::Vid(vid),
+        ]
+        .iter()
+        .any(|k| {
+            DEFAULT_ICONS.contains_key(k) || self.user.as_ref().is_some_and(|u| u.contains_key(k))
+        })
+    }
+
+    /// `Classifier` icon in `Self` or [`DEFAULT_ICONS`] for the class of the [`crate::system_profiler::USBDevice::primary_function`] of `d`
+    fn get_function_icon(&self, d: &USBDevice) -> Option<String> {
+        let key = Icon::Classifier(d.primary_function().class_code()?);
+        self.user
+            .as_ref()
+            .and_then(|u| u.get(&key).cloned())
+            .or_else(|| DEFAULT_ICONS.get(&key).map(|s| s.to_string()))
+    }
+
+    /// Vendor ID and Product ID icon of `d`, or if the vendor has no icon the icon of the device primary function before falling back to `UnknownVendor`
+    fn get_vidpid_or_function_icon(&self, d: &USBDevice) -> String {
+        match (d.vendor_id, d.product_id) {
+            (Some(vid), Some(pid)) if self.has_vidpid_icon(vid, pid) => {
+                self.get_vidpid_icon(vid, pid)
+            }
+            (Some(vid), Some(pid)) => self
+                .get_function_icon(d)
+                .unwrap_or_else(|| self.get_vidpid_icon(vid, pid)),
+            _ => self.get_function_icon(d).unwrap_or_default(),
+        }
+    }
+
+    /// Get icon for USBDevice `d` by checking `Self` using Name, Vendor ID and Product ID then the primary function of the device
     #[cfg(feature = "regex_icon")]
     pub fn get_device_icon(&self, d: &USBDevice) -> String {
         // try name first since vidpid will return UnknownVendor default icon if not found
         // does mean regex will be built/checked for every device
         match self.get_name_icon(&d.name) {
             s if !s.is_empty() => s,
-            _ => {
-                if let (Some(vid), Some(pid)) = (d.vendor_id, d.product_id) {
-                    self.get_vidpid_icon(vid, pid)
-                } else {
-                    String::new()
-                }
-            }
+            _ => self.get_vidpid_or_function_icon(d),
         }
     }
 
-    /// Get icon for USBDevice `d` by checking `Self` using Vendor ID and Product ID
+    /// Get icon for USBDevice `d` by checking `Self` using Vendor ID and Product ID then the primary function of the device
     #[cfg(not(feature = "regex_icon"))]
     pub fn get_device_icon(&self, d: &USBDevice) -> String {
-        if let (Some(vid), Some(pid)) = (d.vendor_id, d.product_id) {
-            self.get_vidpid_icon(vid, pid)
-        } else {
-            String::new()
-        }
+        self.get_vidpid_or_function_icon(d)
     }
 
     /// Get icon for USBBus `d` by checking `Self` using PCI Vendor and PCI Device
@@ -561,6 +586,24 @@ pub fn example_theme() -> IconTheme {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_device_icon_function_fallback() {
+        let theme = IconTheme::new();
+        let sp_usb = crate::system_profiler::SPUSBDataType::example();
+        let mut device = sp_usb.get_node("1-1.2").unwrap().to_owned();
+        device.name = String::from("Example");
+        // known vidpid takes precedence
+        assert_eq!(theme.get_device_icon(&device), "\u{f188}");
+
+        // unknown vendor uses the icon of the primary function, Audio
+        device.vendor_id = Some(0xfffe);
+        assert_eq!(theme.get_device_icon(&device), "\u{f001}");
+
+        // then unknown vendor if there is no function
+        device.extra = None;
+        assert_eq!(theme.get_device_icon(&device), "\u{f287}");
+    }
+
     #[test]
     fn test_serialize_theme() {
         let theme = IconTheme {
@@ -51,6 +51,7 @@ pub mod udev;
 #[path = "udev_ffi.rs"]
 pub mod udev;
 pub mod usb;
+pub mod verify;
 
 /// Set cyme module and binary log level
 pub fn set_log_level(debug: u8) -> crate::error::Result<()> {
@@ -5,6 +5,10 @@
 use crate::display::PrintSettings;
 use crate::error::{Error, ErrorKind};
 use crate::system_profiler;
+use colored::Color;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::RwLock;
 use uuid::Uuid;
 
 use crate::usb::descriptors::audio;
@@ -29,211 +33,630 @@ const TREE_LSUSB_SPACE: &str = "    ";
 const LSUSB_DUMP_WIDTH: usize = 24;
 const LSUSB_DUMP_INDENT_BASE: usize = 2;
 
+/// Decoder of vendor-specific descriptor bytes into lsusb style text; see [`register_vendor_decoder`]
+pub type VendorDecoder = Box<dyn Fn(&[u8]) -> Option<String> + Send + Sync>;
+
+lazy_static! {
+    /// Decoders added with [`register_vendor_decoder`] keyed by VID
+    static ref VENDOR_DECODERS: RwLock<HashMap<u16, VendorDecoder>> = RwLock::new(HashMap::new());
+}
+
+/// Register `decoder` for the vendor-specific descriptors of devices with `vid`, replacing any existing decoder for the VID
+///
+/// During a verbose dump, descriptors which would be dumped as '** UNRECOGNIZED' for a device with `vid` are passed to `decoder` including the bLength and bDescriptorType bytes. Each line of the returned String is printed at the indent of the descriptor; returning None falls back to the hex dump. The VID is that of the device being dumped, or [`DumpContext::vendor_id`] for [`dump_config_with_context`] which has no device. The decoder must not register or unregister decoders itself.
+///
+/// ```
+/// use cyme::lsusb;
+///
+/// lsusb::register_vendor_decoder(
+///     0x1d50,
+///     Box::new(|bytes| (bytes.get(1) == Some(&0x41)).then(|| format!("Firmware Descriptor:\n  bVersion {:5}", bytes[2]))),
+/// );
+/// assert!(lsusb::unregister_vendor_decoder(0x1d50).is_some());
+/// ```
+pub fn register_vendor_decoder(vid: u16, decoder: VendorDecoder) {
+    if let Ok(mut decoders) = VENDOR_DECODERS.write() {
+        decoders.insert(vid, decoder);
+    }
+}
+
+/// Remove the decoder registered for `vid` with [`register_vendor_decoder`], returning it if there was one
+pub fn unregister_vendor_decoder(vid: u16) -> Option<VendorDecoder> {
+    VENDOR_DECODERS
+        .write()
+        .ok()
+        .and_then(|mut decoders| decoders.remove(&vid))
+}
+
+/// Terminal styling of the verbose lsusb dump; the default is plain text like lsusb
+///
+/// ANSI codes are added from the style alone rather than the `colored` global state, so a plain style always gives clean output whatever the terminal. Alignment is calculated on the unstyled text.
+///
+/// ```
+/// use cyme::lsusb::DumpStyle;
+///
+/// assert_eq!(DumpStyle::default(), DumpStyle::plain());
+/// let style = DumpStyle::coloured();
+/// assert_eq!(style.style_field("bLength"), "\x1b[2mbLength\x1b[0m");
+/// assert_eq!(style.style_value("0x09"), "\x1b[36m0x09\x1b[0m");
+/// // only hex values are coloured
+/// assert_eq!(style.style_value("9"), "9");
+/// assert_eq!(DumpStyle::plain().style_warning("Warning: junk"), "Warning: junk");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DumpStyle {
+    /// Dim field names such as bLength
+    pub dim_fields: bool,
+    /// Colour of hex values
+    pub hex: Option<Color>,
+    /// Colour of warnings and descriptor errors
+    pub warning: Option<Color>,
+}
+
+impl DumpStyle {
+    /// No styling
+    pub const fn plain() -> Self {
+        DumpStyle {
+            dim_fields: false,
+            hex: None,
+            warning: None,
+        }
+    }
+
+    /// Dimmed field names, cyan hex values and red warnings
+    pub const fn coloured() -> Self {
+        DumpStyle {
+            dim_fields: true,
+            hex: Some(Color::Cyan),
+            warning: Some(Color::Red),
+        }
+    }
+
+    /// [`DumpStyle::coloured`] if the `colored` crate would colour output, which follows `NO_COLOR`, `CLICOLOR` and any override such as the CLI `--color`; otherwise [`DumpStyle::plain`]
+    pub fn auto() -> Self {
+        DumpStyle::from_colours(colored::control::SHOULD_COLORIZE.should_colorize())
+    }
+
+    /// [`DumpStyle::coloured`] if `settings` has colours, which are None when `--color` resolved to no colour; otherwise [`DumpStyle::plain`]
+    pub fn from_settings(settings: &PrintSettings) -> Self {
+        DumpStyle::from_colours(settings.colours.is_some())
+    }
+
+    fn from_colours(colours: bool) -> Self {
+        if colours {
+            DumpStyle::coloured()
+        } else {
+            DumpStyle::plain()
+        }
+    }
+
+    /// Field name dimmed if [`DumpStyle::dim_fields`]
+    pub fn style_field(&self, field: &str) -> String {
+        if self.dim_fields && !field.is_empty() {
+            format!("\x1b[2m{}\x1b[0m", field)
+        } else {
+            field.to_string()
+        }
+    }
+
+    /// Value in the [`DumpStyle::hex`] colour if it is a hex value
+    pub fn style_value(&self, value: &str) -> String {
+        match self.hex {
+            Some(c) if value.starts_with("0x") => paint(value, c),
+            _ => value.to_string(),
+        }
+    }
+
+    /// Warning in the [`DumpStyle::warning`] colour
+    pub fn style_warning(&self, warning: &str) -> String {
+        match self.warning {
+            Some(c) => paint(warning, c),
+            None => warning.to_string(),
+        }
+    }
+}
+
+fn paint(s: &str, colour: Color) -> String {
+    format!("\x1b[{}m{}\x1b[0m", colour.to_fg_str(), s)
+}
+
+/// Kind of problem with a descriptor found during a verbose dump
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DumpWarningKind {
+    /// Descriptor is not allowed for the class protocol of the interface
+    Illegal,
+    /// Descriptor is shorter than its contents require
+    TooShort,
+    /// Bytes beyond the expected descriptor length
+    Junk,
+}
+
+/// Problem with a descriptor found during a verbose dump
+///
+/// Printed inline like lsusb unless a `Vec` to collect them is passed to the dump entry point, such as [`print_styled`].
+///
+/// ```
+/// use cyme::lsusb::{DumpWarning, DumpWarningKind};
+///
+/// let w = DumpWarning {
+///     kind: DumpWarningKind::TooShort,
+///     descriptor: "Report".into(),
+///     offset: 12,
+///     message: "Descriptor too short".into(),
+/// };
+/// assert_eq!(w.to_string(), "Report descriptor at byte 12: Descriptor too short");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DumpWarning {
+    /// What is wrong
+    pub kind: DumpWarningKind,
+    /// Name of the descriptor such as "AudioControl Interface"
+    pub descriptor: String,
+    /// Byte offset within the descriptor the problem was found at
+    pub offset: usize,
+    /// Description of the problem; printed inline after the [`DumpWarningKind::inline_prefix`] when not collected
+    pub message: String,
+}
+
+impl DumpWarningKind {
+    /// Prefix of the message when printed inline, matching lsusb
+    pub fn inline_prefix(&self) -> &'static str {
+        match self {
+            DumpWarningKind::Illegal => "Warning: ",
+            DumpWarningKind::TooShort => "Error: ",
+            DumpWarningKind::Junk => "",
+        }
+    }
+}
+
+impl std::fmt::Display for DumpWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} descriptor at byte {}: {}",
+            self.descriptor, self.offset, self.message
+        )
+    }
+}
+
+/// Options of a verbose dump passed down to each descriptor dump
+///
+/// The default dumps like lsusb, printing warnings inline.
+///
+/// ```
+/// use cyme::lsusb::{DumpContext, DumpStyle};
+///
+/// let mut warnings = Vec::new();
+/// let ctx = DumpContext {
+///     style: DumpStyle::coloured(),
+///     warnings: Some(&mut warnings),
+///     vendor_id: Some(0x1d50),
+/// };
+/// assert!(ctx.warnings.is_some());
+/// ```
+#[derive(Debug, Default)]
+pub struct DumpContext<'a> {
+    /// Styling of the dump text
+    pub style: DumpStyle,
+    /// Collect [`DumpWarning`]s here rather than printing them inline
+    pub warnings: Option<&'a mut Vec<DumpWarning>>,
+    /// VID of the device being dumped so that its vendor-specific descriptors are passed to a decoder added with [`register_vendor_decoder`]; set for each device by the device dumps
+    pub vendor_id: Option<u16>,
+}
+
+/// Writer of a verbose dump with the [`DumpContext`] the dump helpers need
+struct DumpWriter<'w, 'a> {
+    out: &'w mut dyn fmt::Write,
+    ctx: DumpContext<'a>,
+}
+
+impl<'w, 'a> DumpWriter<'w, 'a> {
+    fn new(out: &'w mut dyn fmt::Write, ctx: DumpContext<'a>) -> Self {
+        DumpWriter { out, ctx }
+    }
+
+    /// For `write!` and `writeln!`
+    fn write_fmt(&mut self, args: fmt::Arguments) -> fmt::Result {
+        self.out.write_fmt(args)
+    }
+}
+
+/// [`fmt::Write`] to stdout for the printing entry points; the dumps write to any [`fmt::Write`] so that they can be captured in a `String`
+struct StdoutWriter;
+
+impl fmt::Write for StdoutWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        print!("{}", s);
+        Ok(())
+    }
+}
+
 fn get_spaces(value_len: usize, field_len: usize, width: usize) -> String {
     if value_len >= width || value_len == usize::MAX {
         String::from(" ")
     } else {
-        " ".repeat((width - value_len).saturating_sub(field_len))
+        // lsusb printf widths are minimums so a long value is still separated from the field
+        " ".repeat((width - value_len).saturating_sub(field_len).max(1))
     }
 }
 
 /// Dump an array of value like lsusb
-fn dump_array<T: std::fmt::Display>(array: &[T], field_name: &str, indent: usize, width: usize) {
+fn dump_array<T: std::fmt::Display>(
+    out: &mut DumpWriter,
+    array: &[T],
+    field_name: &str,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
     for (i, b) in array.iter().enumerate() {
-        dump_value(b, &format!("{}({:2})", field_name, i), indent, width);
+        dump_value(out, b, &format!("{}({:2})", field_name, i), indent, width)?;
     }
+
+    Ok(())
 }
 
 /// Dump a bitmap value mapping as hex like lsusb
 fn dump_bitmap_array<T: std::fmt::LowerHex + Into<u64> + Copy>(
+    out: &mut DumpWriter,
     array: &[T],
     field_name: &str,
     indent: usize,
     width: usize,
-) {
+) -> fmt::Result {
     for (i, b) in array.iter().enumerate() {
-        dump_hex(*b, &format!("{}({:2})", field_name, i), indent, width);
+        dump_hex(out, *b, &format!("{}({:2})", field_name, i), indent, width)?;
     }
+
+    Ok(())
 }
 
 /// Dump just indented string
-fn dump_string(field_name: &str, indent: usize) {
-    println!("{:indent$}{}", "", field_name);
+fn dump_string(out: &mut DumpWriter, field_name: &str, indent: usize) -> fmt::Result {
+    writeln!(out, "{:indent$}{}", "", field_name)?;
+    Ok(())
+}
+
+/// Dump an indented warning highlighted by the [`DumpStyle`] or collect it in [`DumpContext::warnings`]; returns true if collected
+fn dump_warning(
+    out: &mut DumpWriter,
+    warning: DumpWarning,
+    indent: usize,
+) -> Result<bool, fmt::Error> {
+    let collected = match out.ctx.warnings.as_mut() {
+        Some(c) => {
+            c.push(warning.clone());
+            true
+        }
+        None => false,
+    };
+    if !collected {
+        writeln!(
+            out,
+            "{:indent$}{}",
+            "",
+            out.ctx.style.style_warning(&format!(
+                "{}{}",
+                warning.kind.inline_prefix(),
+                warning.message
+            ))
+        )?;
+    }
+
+    Ok(collected)
 }
 
 /// Dump a single value like lsusb
-fn dump_value<T: std::fmt::Display>(value: T, field_name: &str, indent: usize, width: usize) {
+fn dump_value<T: std::fmt::Display>(
+    out: &mut DumpWriter,
+    value: T,
+    field_name: &str,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
     let value = value.to_string();
     let spaces = get_spaces(value.len(), field_name.len(), width);
-    println!("{:indent$}{}{}{}", "", field_name, spaces, value);
+    let style = out.ctx.style;
+    writeln!(
+        out,
+        "{:indent$}{}{}{}",
+        "",
+        style.style_field(field_name),
+        spaces,
+        style.style_value(&value)
+    )?;
+
+    Ok(())
 }
 
 /// Dump a single hex value like lsusb
 fn dump_hex<T: std::fmt::LowerHex + Into<u64>>(
+    out: &mut DumpWriter,
     value: T,
     field_name: &str,
     indent: usize,
     width: usize,
-) {
+) -> fmt::Result {
     let value_as_u64: u64 = value.into();
     let hex_value = format!(
         "0x{:0width$x}",
         value_as_u64,
         width = (std::mem::size_of::<T>() * 2)
     );
-    dump_value(hex_value, field_name, indent, width);
+    dump_value(out, hex_value, field_name, indent, width)?;
+
+    Ok(())
+}
+
+/// Name returned by a [`names`] lookup or "(unknown `what` 0x..)" so values missing from usb.ids are not dumped as a blank
+fn name_or_unknown<T: std::fmt::LowerHex>(name: Option<String>, what: &str, value: T) -> String {
+    name.unwrap_or_else(|| {
+        format!(
+            "(unknown {} 0x{:0width$x})",
+            what,
+            value,
+            width = (std::mem::size_of::<T>() * 2)
+        )
+    })
 }
 
-/// Lookup the name of the value from passed function and dump it
-fn dump_name<T: std::fmt::Display>(
+/// Lookup the name of the value from passed function and dump it, falling back to [`name_or_unknown`] with `what`
+fn dump_name<T: std::fmt::Display + std::fmt::LowerHex + Copy>(
+    out: &mut DumpWriter,
     value: T,
     names_f: fn(T) -> Option<String>,
+    what: &str,
     field_name: &str,
     indent: usize,
     width: usize,
-) {
+) -> fmt::Result {
     let value_string = value.to_string();
     let spaces = get_spaces(value_string.len(), field_name.len(), width);
-    let dump = format!("{:indent$}{}{}{}", "", field_name, spaces, value_string,);
-    if let Some(name) = names_f(value) {
-        println!("{} {}", dump, name);
-    }
+    writeln!(
+        out,
+        "{:indent$}{}{}{} {}",
+        "",
+        field_name,
+        spaces,
+        value_string,
+        name_or_unknown(names_f(value), what, value)
+    )?;
+
+    Ok(())
 }
 
 /// Dumps the value and the string representation of the value to the right of width
 fn dump_value_string<T: std::fmt::Display, S: std::fmt::Display>(
+    out: &mut DumpWriter,
     value: T,
     field_name: &str,
     value_string: S,
     indent: usize,
     width: usize,
-) {
+) -> fmt::Result {
     let value = value.to_string();
     let spaces = get_spaces(value.len(), field_name.len(), width);
-    println!(
+    let style = out.ctx.style;
+    writeln!(
+        out,
         "{:indent$}{}{}{} {}",
-        "", field_name, spaces, value, value_string,
-    );
+        "",
+        style.style_field(field_name),
+        spaces,
+        style.style_value(&value),
+        value_string,
+    )?;
+
+    Ok(())
 }
 
 /// Dumps a string starting at value position, right aligned
-fn dump_string_right<T: std::fmt::Display>(guid: T, field_name: &str, indent: usize, width: usize) {
+fn dump_string_right<T: std::fmt::Display>(
+    out: &mut DumpWriter,
+    guid: T,
+    field_name: &str,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
     // 1 to account for space
     let spaces = get_spaces(1, field_name.len(), width);
-    println!("{:indent$}{}{}{}", "", field_name, spaces, guid);
+    writeln!(
+        out,
+        "{:indent$}{}{}{}",
+        "",
+        out.ctx.style.style_field(field_name),
+        spaces,
+        guid
+    )?;
+
+    Ok(())
 }
 
 /// Dumps GUID enclosed in braces like lsusb
-fn dump_guid(guid: &Uuid, field_name: &str, indent: usize, width: usize) {
-    dump_string_right(guid.braced().to_string(), field_name, indent, width);
+fn dump_guid(
+    out: &mut DumpWriter,
+    guid: &Uuid,
+    field_name: &str,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_string_right(
+        out,
+        format_guid(&guid.to_bytes_le()),
+        field_name,
+        indent,
+        width,
+    )?;
+
+    Ok(())
 }
 
 /// Dumps junk descriptor bytes as hex like lsusb
-fn dump_junk(extra: &[u8], indent: usize, reported_len: usize, expected_len: usize) {
+fn dump_junk(
+    out: &mut DumpWriter,
+    extra: &[u8],
+    indent: usize,
+    reported_len: usize,
+    expected_len: usize,
+) -> fmt::Result {
     if reported_len > expected_len && extra.len() >= reported_len {
-        println!(
-            "{:^indent$}junk at descriptor end: {}",
-            "",
-            extra[expected_len..reported_len]
+        dump_warning(
+            out,
+            DumpWarning {
+                kind: DumpWarningKind::Junk,
+                descriptor: DescriptorType::from(extra.get(1).copied().unwrap_or(0)).to_string(),
+                offset: expected_len,
+                message: format!(
+                    "junk at descriptor end: {}",
+                    extra[expected_len..reported_len]
+                        .iter()
+                        .map(|b| format!("{:02x}", b))
+                        .collect::<Vec<String>>()
+                        .join(" ")
+                ),
+            },
+            indent,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Dumps unknown descriptor bytes as hex like lsusb
+fn dump_unrecognised(out: &mut DumpWriter, extra: &[u8], indent: usize) -> fmt::Result {
+    writeln!(
+        out,
+        "{:indent$}{}",
+        "",
+        out.ctx.style.style_warning(&format!(
+            "** UNRECOGNIZED: {}",
+            extra
                 .iter()
                 .map(|b| format!("{:02x}", b))
                 .collect::<Vec<String>>()
                 .join(" ")
-        )
+        ))
+    )?;
+    Ok(())
+}
+
+/// Dumps vendor-specific descriptor bytes with the decoder registered for the VID of the device being dumped, otherwise as [`dump_unrecognised`]
+fn dump_vendor_descriptor(out: &mut DumpWriter, extra: &[u8], indent: usize) -> fmt::Result {
+    let decoded = out.ctx.vendor_id.and_then(|vid| {
+        VENDOR_DECODERS
+            .read()
+            .ok()
+            .and_then(|decoders| decoders.get(&vid).and_then(|d| d(extra)))
+    });
+    match decoded {
+        Some(s) => {
+            for line in s.lines() {
+                writeln!(out, "{:indent$}{}", "", line)?;
+            }
+        }
+        None => dump_unrecognised(out, extra, indent)?,
     }
+
+    Ok(())
 }
 
-/// Dumps unknown descriptor bytes as hex like lsusb
-fn dump_unrecognised(extra: &[u8], indent: usize) {
-    println!(
-        "{:^indent$}** UNRECOGNIZED: {}",
-        "",
-        extra
-            .iter()
-            .map(|b| format!("{:02x}", b))
-            .collect::<Vec<String>>()
-            .join(" ")
-    )
+/// Decode the strings matching the bits set in `bitmap` using `strings_f`, from LSB to MSB. Bits for which `strings_f` returns None are skipped.
+///
+/// This is the decoding used by the lsusb verbose dump for attribute bitmaps, so structured consumers can get the same flag names
+///
+/// ```
+/// use cyme::lsusb::decode_bitmap_strings;
+///
+/// let names = ["Zero", "One", "Two"];
+/// let decoded = decode_bitmap_strings(0b101u8, |i| names.get(i).copied());
+/// assert_eq!(decoded, vec!["Zero", "Two"]);
+/// ```
+pub fn decode_bitmap_strings<T, F>(bitmap: T, strings_f: F) -> Vec<&'static str>
+where
+    T: Copy + Into<u64>,
+    F: Fn(usize) -> Option<&'static str>,
+{
+    let bitmap_u64: u64 = bitmap.into();
+    let num_bits = std::mem::size_of::<T>() * 8;
+    (0..num_bits)
+        .filter(|index| (bitmap_u64 >> index) & 0x1 != 0)
+        .filter_map(strings_f)
+        .collect()
 }
 
 /// Dumps strings matching the bits set in `bitmap` using `strings_f` function from LSB to MSB
-fn dump_bitmap_strings<T>(bitmap: T, strings_f: fn(usize) -> Option<&'static str>, indent: usize)
+fn dump_bitmap_strings<T>(
+    out: &mut DumpWriter,
+    bitmap: T,
+    strings_f: fn(usize) -> Option<&'static str>,
+    indent: usize,
+) -> fmt::Result
 where
     T: std::fmt::Display + std::fmt::LowerHex + Copy + Into<u64>,
 {
-    let bitmap_u64: u64 = bitmap.into();
-    let num_bits = std::mem::size_of::<T>() * 8;
-    for index in 0..num_bits {
-        if (bitmap_u64 >> index) & 0x1 != 0 {
-            if let Some(string) = strings_f(index) {
-                println!("{:indent$}{}", "", string);
-            }
-        }
+    for string in decode_bitmap_strings(bitmap, strings_f) {
+        writeln!(out, "{:indent$}{}", "", string)?;
     }
+
+    Ok(())
 }
 
 /// Dumps strings matching the bits set in `bitmap` using `strings_f` function from MSB to LSB
 fn dump_bitmap_strings_invert<T>(
+    out: &mut DumpWriter,
     bitmap: T,
     strings_f: fn(usize) -> Option<&'static str>,
     indent: usize,
-) where
+) -> fmt::Result
+where
     T: std::fmt::Display + std::fmt::LowerHex + Copy + Into<u64>,
 {
-    let bitmap_u64: u64 = bitmap.into();
-    let num_bits = std::mem::size_of::<T>() * 8;
-    for index in (0..num_bits).rev() {
-        if (bitmap_u64 >> index) & 0x1 != 0 {
-            if let Some(string) = strings_f(index) {
-                println!("{:indent$}{}", "", string);
-            }
-        }
+    for string in decode_bitmap_strings(bitmap, strings_f).iter().rev() {
+        writeln!(out, "{:indent$}{}", "", string)?;
     }
+
+    Ok(())
 }
 
 /// Dump a single value and the string representation of the value to the right of width
 fn dump_bitmap_strings_inline<T, V>(
+    out: &mut DumpWriter,
     value: V,
     bitmap: T,
     field_name: &str,
     strings_f: fn(usize) -> Option<&'static str>,
     indent: usize,
     width: usize,
-) where
+) -> fmt::Result
+where
     T: std::fmt::Display + std::fmt::LowerHex + Copy + Into<u64>,
     V: std::fmt::Display,
 {
     let value = value.to_string();
     let spaces = get_spaces(value.len(), field_name.len(), width);
-    print!("{:indent$}{}{}{}", "", field_name, spaces, value,);
-    let bitmap_u64: u64 = bitmap.into();
-    let num_bits = std::mem::size_of::<T>() * 8;
-    for index in 0..num_bits {
-        if (bitmap_u64 >> index) & 0x1 != 0 {
-            if let Some(string) = strings_f(index) {
-                print!(" {}", string);
-            }
-        }
-    }
-    println!();
-}
-
-fn get_guid(buf: &[u8]) -> String {
-    if buf.len() < 16 {
-        return String::from("INVALID GUID");
+    write!(out, "{:indent$}{}{}{}", "", field_name, spaces, value,)?;
+    for string in decode_bitmap_strings(bitmap, strings_f) {
+        write!(out, " {}", string)?;
     }
+    writeln!(out)?;
 
-    format!("{{{:02x}{:02x}{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}}}", 
-        buf[3], buf[2], buf[1], buf[0],
-        buf[5], buf[4],
-        buf[7], buf[6],
-        buf[8], buf[9],
-        buf[10], buf[11], buf[12], buf[13], buf[14], buf[15])
+    Ok(())
 }
 
 /// Print [`system_profiler::SPUSBDataType`] as a lsusb style tree with the two optional `verbosity` levels
 pub fn print_tree(spusb: &system_profiler::SPUSBDataType, settings: &PrintSettings) {
-    fn print_tree_devices(devices: &Vec<system_profiler::USBDevice>, settings: &PrintSettings) {
+    write_tree(&mut StdoutWriter, spusb, settings).expect("stdout write does not fail")
+}
+
+fn write_tree(
+    out: &mut dyn fmt::Write,
+    spusb: &system_profiler::SPUSBDataType,
+    settings: &PrintSettings,
+) -> fmt::Result {
+    fn print_tree_devices(
+        out: &mut dyn fmt::Write,
+        devices: &Vec<system_profiler::USBDevice>,
+        settings: &PrintSettings,
+    ) -> fmt::Result {
         let sorted = settings.sort_devices.sort_devices(devices);
 
         for device in sorted {
@@ -246,45 +669,49 @@ pub fn print_tree(spusb: &system_profiler::SPUSBDataType, settings: &PrintSettin
             let device_tree_strings: Vec<(String, String, String)> = device.to_lsusb_tree_string();
 
             for strings in device_tree_strings {
-                println!("{:>indent$}{}", TREE_LSUSB_DEVICE, strings.0);
+                writeln!(out, "{:>indent$}{}", TREE_LSUSB_DEVICE, strings.0)?;
                 if settings.verbosity >= 1 {
-                    println!("{:>indent$}{}", TREE_LSUSB_SPACE, strings.1);
+                    writeln!(out, "{:>indent$}{}", TREE_LSUSB_SPACE, strings.1)?;
                 }
                 if settings.verbosity >= 2 {
-                    println!("{:>indent$}{}", TREE_LSUSB_SPACE, strings.2);
+                    writeln!(out, "{:>indent$}{}", TREE_LSUSB_SPACE, strings.2)?;
                 }
             }
             // print all devices with this device - if hub for example
-            device
-                .devices
-                .as_ref()
-                .map_or((), |d| print_tree_devices(d, settings))
+            if let Some(d) = device.devices.as_ref() {
+                print_tree_devices(out, d, settings)?;
+            }
         }
+
+        Ok(())
     }
 
     for bus in &spusb.buses {
         let bus_tree_strings: Vec<(String, String, String)> = bus.to_lsusb_tree_string();
         for strings in bus_tree_strings {
-            println!("{}{}", TREE_LSUSB_BUS, strings.0);
+            writeln!(out, "{}{}", TREE_LSUSB_BUS, strings.0)?;
             if settings.verbosity >= 1 {
-                println!("{}{}", TREE_LSUSB_SPACE, strings.1);
+                writeln!(out, "{}{}", TREE_LSUSB_SPACE, strings.1)?;
             }
             if settings.verbosity >= 2 {
-                println!("{}{}", TREE_LSUSB_SPACE, strings.2);
+                writeln!(out, "{}{}", TREE_LSUSB_SPACE, strings.2)?;
             }
         }
 
         // followed by devices if there are some
-        bus.devices
-            .as_ref()
-            .map_or((), |d| print_tree_devices(d, settings))
+        if let Some(d) = bus.devices.as_ref() {
+            print_tree_devices(out, d, settings)?;
+        }
     }
+
+    Ok(())
 }
 
-/// Dump a single [`system_profiler::USBDevice`] matching `dev_path` verbosely
+/// Dump a single [`system_profiler::USBDevice`] matching `dev_path` verbosely with the [`DumpContext`] `ctx`
 pub fn dump_one_device(
     devices: &Vec<&system_profiler::USBDevice>,
     dev_path: &String,
+    ctx: DumpContext,
 ) -> Result<(), Error> {
     for device in devices {
         if &device.dev_path() == dev_path {
@@ -296,7 +723,7 @@ pub fn dump_one_device(
                 ));
             }
 
-            print(&vec![device], true);
+            print_styled(&vec![device], Verbosity::Full, false, ctx);
             return Ok(());
         }
     }
@@ -307,26 +734,123 @@ pub fn dump_one_device(
     ))
 }
 
-fn find_otg(extra: &[Descriptor]) -> Option<&OnTheGoDescriptor> {
-    extra.iter().find_map(|d| match d {
-        Descriptor::Otg(otg) => {
+fn find_otg<'a>(
+    out: &mut DumpWriter,
+    extra: &'a [Descriptor],
+) -> Result<Option<&'a OnTheGoDescriptor>, fmt::Error> {
+    for d in extra {
+        if let Descriptor::Otg(otg) = d {
             log::debug!("Found OTG descriptor: {:?}", otg);
-            dump_otg(otg, LSUSB_DUMP_INDENT_BASE);
-            Some(otg)
+            dump_otg(out, otg, LSUSB_DUMP_INDENT_BASE)?;
+            return Ok(Some(otg));
         }
-        _ => None,
-    })
+    }
+
+    Ok(None)
+}
+
+/// How much of each device the lsusb style flat dump prints
+///
+/// A `bool` verbose flag converts to [`Verbosity::Devices`] or [`Verbosity::Full`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    /// One line per device like lsusb
+    #[default]
+    Devices,
+    /// Device, configuration, interface and endpoint descriptors; omits class-specific descriptors and those requested from the device such as BOS, hub and status
+    Standard,
+    /// Everything like lsusb --verbose - a huge dump!
+    Full,
+}
+
+impl From<bool> for Verbosity {
+    fn from(verbose: bool) -> Self {
+        if verbose {
+            Verbosity::Full
+        } else {
+            Verbosity::Devices
+        }
+    }
+}
+
+impl Verbosity {
+    /// From the [`PrintSettings`] verbosity count; `lsusb_brief` limits a verbose dump to [`Verbosity::Standard`]
+    ///
+    /// ```
+    /// use cyme::display::PrintSettings;
+    /// use cyme::lsusb::Verbosity;
+    ///
+    /// let mut settings = PrintSettings::default();
+    /// assert_eq!(Verbosity::from_settings(&settings), Verbosity::Devices);
+    /// settings.verbosity = 1;
+    /// assert_eq!(Verbosity::from_settings(&settings), Verbosity::Full);
+    /// settings.lsusb_brief = true;
+    /// assert_eq!(Verbosity::from_settings(&settings), Verbosity::Standard);
+    /// ```
+    pub fn from_settings(settings: &PrintSettings) -> Self {
+        match (settings.verbosity, settings.lsusb_brief) {
+            (0, _) => Verbosity::Devices,
+            (_, true) => Verbosity::Standard,
+            (_, false) => Verbosity::Full,
+        }
+    }
 }
 
 /// Print USB devices in lsusb style flat dump
 ///
-/// `verbose` flag enables verbose printing like lsusb (configs, interfaces and endpoints) - a huge dump!
-pub fn print(devices: &Vec<&system_profiler::USBDevice>, verbose: bool) {
-    if !verbose {
+/// `verbosity` above [`Verbosity::Devices`] enables verbose printing like lsusb (configs, interfaces and endpoints)
+pub fn print(devices: &Vec<&system_profiler::USBDevice>, verbosity: impl Into<Verbosity>) {
+    print_devices(
+        &mut DumpWriter::new(&mut StdoutWriter, DumpContext::default()),
+        devices,
+        verbosity.into(),
+        false,
+    )
+    .expect("stdout write does not fail")
+}
+
+/// Print USB devices in lsusb style flat dump like [`print`] or [`print_with_summary`] with the verbose dump styled and its warnings collected by `ctx`
+pub fn print_styled(
+    devices: &Vec<&system_profiler::USBDevice>,
+    verbosity: impl Into<Verbosity>,
+    summary: bool,
+    ctx: DumpContext,
+) {
+    print_devices(
+        &mut DumpWriter::new(&mut StdoutWriter, ctx),
+        devices,
+        verbosity.into(),
+        summary,
+    )
+    .expect("stdout write does not fail")
+}
+
+/// Print USB devices in lsusb style flat dump like [`print`] but with a [`system_profiler::USBDevice::summary`] line above each device when verbose to make the dump easier to navigate
+pub fn print_with_summary(
+    devices: &Vec<&system_profiler::USBDevice>,
+    verbosity: impl Into<Verbosity>,
+) {
+    print_devices(
+        &mut DumpWriter::new(&mut StdoutWriter, DumpContext::default()),
+        devices,
+        verbosity.into(),
+        true,
+    )
+    .expect("stdout write does not fail")
+}
+
+fn print_devices(
+    out: &mut DumpWriter,
+    devices: &Vec<&system_profiler::USBDevice>,
+    verbosity: Verbosity,
+    summary: bool,
+) -> fmt::Result {
+    if verbosity == Verbosity::Devices {
         for device in devices {
-            println!("{}", device.to_lsusb_string());
+            writeln!(out, "{}", device.to_lsusb_string())?;
         }
     } else {
+        let class_specific = verbosity == Verbosity::Full;
         for device in devices {
             match device.extra.as_ref() {
                 None => log::warn!(
@@ -334,32 +858,69 @@ pub fn print(devices: &Vec<&system_profiler::USBDevice>, verbose: bool) {
                     device
                 ),
                 Some(device_extra) => {
-                    println!(); // new lines separate in verbose lsusb
-                    println!("{}", device.to_lsusb_string());
+                    out.ctx.vendor_id = device.vendor_id;
+                    writeln!(out)?; // new lines separate in verbose lsusb
+                    if summary {
+                        writeln!(out, "{}", device.summary())?;
+                    }
+                    writeln!(out, "{}", device.to_lsusb_string())?;
                     // print error regarding open if non-critcal during probe like lsusb --verbose
                     if device.profiler_error.is_some() {
                         eprintln!("Couldn't open device, some information will be missing");
                     }
-                    dump_device(device);
+                    dump_device(out, device)?;
 
                     let mut otg = None;
                     for config in &device_extra.configurations {
-                        dump_config(config, LSUSB_DUMP_INDENT_BASE);
-                        otg = config.extra.as_ref().map(|e| find_otg(e));
+                        dump_config_descriptor(
+                            out,
+                            config,
+                            "Configuration Descriptor:",
+                            0x02,
+                            LSUSB_DUMP_INDENT_BASE,
+                            class_specific,
+                        )?;
+                        otg = config
+                            .extra
+                            .as_ref()
+                            .map(|e| find_otg(out, e))
+                            .transpose()?;
 
                         for interface in &config.interfaces {
-                            dump_interface(interface, LSUSB_DUMP_INDENT_BASE * 2);
-                            otg = config.extra.as_ref().map(|e| find_otg(e));
+                            dump_interface(
+                                out,
+                                interface,
+                                LSUSB_DUMP_INDENT_BASE * 2,
+                                class_specific,
+                            )?;
+                            otg = config
+                                .extra
+                                .as_ref()
+                                .map(|e| find_otg(out, e))
+                                .transpose()?;
 
                             for endpoint in &interface.endpoints {
-                                dump_endpoint(endpoint, LSUSB_DUMP_INDENT_BASE * 3);
-                                otg = config.extra.as_ref().map(|e| find_otg(e));
+                                dump_endpoint(
+                                    out,
+                                    endpoint,
+                                    LSUSB_DUMP_INDENT_BASE * 3,
+                                    class_specific,
+                                )?;
+                                otg = config
+                                    .extra
+                                    .as_ref()
+                                    .map(|e| find_otg(out, e))
+                                    .transpose()?;
                             }
                         }
                     }
 
+                    if !class_specific {
+                        continue;
+                    }
+
                     let has_ssp = if let Some(bos) = &device_extra.binary_object_store {
-                        dump_bos_descriptor(bos, 0);
+                        dump_bos_descriptor(out, bos, 0)?;
                         bos.capabilities
                             .iter()
                             .any(|c| matches!(c, bos::BosCapability::SuperSpeedPlus(_)))
@@ -368,32 +929,51 @@ pub fn print(devices: &Vec<&system_profiler::USBDevice>, verbose: bool) {
                     };
                     if let Some(hub) = &device_extra.hub {
                         let bcd = device.bcd_usb.map_or(0x0100, |v| v.into());
-                        dump_hub(hub, device.protocol.unwrap_or(1), bcd, has_ssp, 0);
+                        dump_hub(out, hub, device.protocol.unwrap_or(1), bcd, has_ssp, 0)?;
                     }
                     // lsusb do_dualspeed: dump_device_qualifier
                     if let Some(qualifier) = &device_extra.qualifier {
-                        dump_device_qualifier(qualifier, 0);
+                        dump_device_qualifier(out, qualifier, 0)?;
+                    }
+                    for config in &device_extra.other_speed_configurations {
+                        dump_config_descriptor(
+                            out,
+                            config,
+                            "Other Speed Configuration Descriptor:",
+                            0x07,
+                            0,
+                            true,
+                        )?;
+                        for interface in &config.interfaces {
+                            dump_interface(out, interface, LSUSB_DUMP_INDENT_BASE, true)?;
+                            for endpoint in &interface.endpoints {
+                                dump_endpoint(out, endpoint, LSUSB_DUMP_INDENT_BASE * 2, true)?;
+                            }
+                        }
                     }
                     if let Some(debug) = &device_extra.debug {
-                        dump_debug(debug, 0);
+                        dump_debug(out, debug, 0)?;
                     }
 
                     if let Some(status) = device_extra.status {
                         dump_device_status(
+                            out,
                             status,
                             otg.is_some(),
                             device.bcd_usb.map_or(false, |v| v.major() >= 3),
                             0,
-                        );
+                        )?;
                     }
                 }
             }
         }
     }
+
+    Ok(())
 }
 
 /// Dump a [`system_profiler::USBDevice`] in style of lsusb --verbose
-fn dump_device(device: &system_profiler::USBDevice) {
+fn dump_device(out: &mut DumpWriter, device: &system_profiler::USBDevice) -> fmt::Result {
     let device_extra = device
         .extra
         .as_ref()
@@ -412,11 +992,12 @@ fn dump_device(device: &system_profiler::USBDevice) {
             _ => unreachable!(),
         };
 
-    println!("Device Descriptor:");
+    writeln!(out, "Device Descriptor:")?;
     // These are constants - length is 18 bytes for descriptor, type is 1
-    dump_value(18, "bLength", 2, LSUSB_DUMP_WIDTH);
-    dump_value(1, "bDescriptorType", 2, LSUSB_DUMP_WIDTH);
+    dump_value(out, 18, "bLength", 2, LSUSB_DUMP_WIDTH)?;
+    dump_value(out, 1, "bDescriptorType", 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         device
             .bcd_usb
             .as_ref()
@@ -424,40 +1005,45 @@ fn dump_device(device: &system_profiler::USBDevice) {
         "bcdUSB",
         2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     dump_value_string(
+        out,
         device.base_class_code().unwrap_or(0),
         "bDeviceClass",
         class_name.unwrap_or(String::from("[unknown]")),
         2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     dump_value_string(
+        out,
         device.sub_class.unwrap_or(0),
         "bDeviceSubClass",
         sub_class_name.unwrap_or(String::from("[unknown]")),
         2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     dump_value_string(
+        out,
         device.protocol.unwrap_or(0),
         "bDeviceProtocol",
         protocol_name.unwrap_or_default(),
         2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     dump_value(
+        out,
         device_extra.max_packet_size,
         "bMaxPacketSize0",
         2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     dump_value_string(
+        out,
         format!("0x{:04x}", device.vendor_id.unwrap_or(0)),
         "idVendor",
         device_extra
@@ -466,9 +1052,10 @@ fn dump_device(device: &system_profiler::USBDevice) {
             .unwrap_or(&String::from("[unknown]")),
         2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     dump_value_string(
+        out,
         format!("0x{:04x}", device.product_id.unwrap_or(0)),
         "idProduct",
         device_extra
@@ -477,9 +1064,10 @@ fn dump_device(device: &system_profiler::USBDevice) {
             .unwrap_or(&String::from("[unknown]")),
         2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     dump_value(
+        out,
         device
             .bcd_device
             .as_ref()
@@ -487,10 +1075,11 @@ fn dump_device(device: &system_profiler::USBDevice) {
         "bcdDevice",
         2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     dump_value_string(
-        device_extra.string_indexes.0,
+        out,
+        device_extra.string_indexes.1,
         "iManufacturer",
         device
             .manufacturer
@@ -498,110 +1087,221 @@ fn dump_device(device: &system_profiler::USBDevice) {
             .unwrap_or(&String::from("[unknown]")),
         2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     dump_value_string(
-        device_extra.string_indexes.1,
+        out,
+        device_extra.string_indexes.0,
         "iProduct",
         &device.name,
         2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     dump_value_string(
+        out,
         device_extra.string_indexes.2,
         "iSerialNumber",
         device.serial_num.as_ref().unwrap_or(&String::new()),
         2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     dump_value(
-        device_extra.configurations.len(),
+        out,
+        device_extra
+            .num_configurations
+            .unwrap_or(device_extra.configurations.len() as u8),
         "bNumConfigurations",
         2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
+
+    Ok(())
+}
+
+/// Dump a [`USBConfiguration`] with its interfaces, endpoints and class specific descriptors in the style of lsusb --verbose to `writer`
+///
+/// Class specific descriptors still [`ClassDescriptor::Generic`] are first decoded with the class of their interface, so a configuration built by hand dumps the same as one from the profiler. Interfaces are indented by `indent` more than the configuration and endpoints by `indent` again.
+///
+/// ```
+/// use cyme::lsusb;
+/// use cyme::system_profiler::SPUSBDataType;
+///
+/// let sp_usb = SPUSBDataType::example();
+/// let device = sp_usb.flatten_devices().into_iter().find(|d| d.extra.is_some()).unwrap();
+/// let mut out = Vec::new();
+/// lsusb::dump_config(&device.extra.as_ref().unwrap().configurations[0], &mut out, 2).unwrap();
+/// let out = String::from_utf8(out).unwrap();
+/// assert!(out.starts_with("  Configuration Descriptor:"));
+/// assert!(out.contains("    Interface Descriptor:"));
+/// ```
+pub fn dump_config<W: std::io::Write>(
+    config: &USBConfiguration,
+    writer: &mut W,
+    indent: usize,
+) -> Result<(), Error> {
+    dump_config_with_context(config, writer, indent, DumpContext::default())
 }
 
-/// Dump a [`USBConfiguration`] in style of lsusb --verbose
-fn dump_config(config: &USBConfiguration, indent: usize) {
-    dump_string("Configuration Descriptor:", indent);
-    dump_value(config.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
-    dump_value(2, "bDescriptorType", indent + 2, LSUSB_DUMP_WIDTH); // type 2 for configuration
+/// [`dump_config`] with the dump styled and its warnings collected by `ctx`
+pub fn dump_config_with_context<W: std::io::Write>(
+    config: &USBConfiguration,
+    writer: &mut W,
+    indent: usize,
+    ctx: DumpContext,
+) -> Result<(), Error> {
+    let mut config = config.to_owned();
+    for interface in config.interfaces.iter_mut() {
+        let triplet = (interface.class, interface.sub_class, interface.protocol);
+        let endpoint_extras = interface
+            .endpoints
+            .iter_mut()
+            .filter_map(|e| e.extra.as_mut());
+        for dt in interface.extra.iter_mut().chain(endpoint_extras).flatten() {
+            if let Err(e) = dt.update_with_class_context(triplet) {
+                log::debug!(
+                    "Failed to update {} descriptor with class context: {}",
+                    dt.descriptor_type(),
+                    e
+                );
+            }
+        }
+    }
+
+    let mut output = String::new();
+    write_config(&mut DumpWriter::new(&mut output, ctx), &config, indent)
+        .expect("String write cannot fail");
+    writer.write_all(output.as_bytes())?;
+
+    Ok(())
+}
+
+fn write_config(out: &mut DumpWriter, config: &USBConfiguration, indent: usize) -> fmt::Result {
+    dump_config_descriptor(out, config, "Configuration Descriptor:", 0x02, indent, true)?;
+    for interface in &config.interfaces {
+        dump_interface(out, interface, indent * 2, true)?;
+        for endpoint in &interface.endpoints {
+            dump_endpoint(out, endpoint, indent * 3, true)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Dump a configuration or other speed configuration, which share a layout, with `heading` and `descriptor_type`
+///
+/// The descriptors following the configuration descriptor, such as IADs, are only dumped if `class_specific`
+fn dump_config_descriptor(
+    out: &mut DumpWriter,
+    config: &USBConfiguration,
+    heading: &str,
+    descriptor_type: u8,
+    indent: usize,
+    class_specific: bool,
+) -> fmt::Result {
+    dump_string(out, heading, indent)?;
+    dump_value(out, config.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
+    dump_value(
+        out,
+        descriptor_type,
+        "bDescriptorType",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
     dump_value(
+        out,
         config.total_length,
         "wTotalLength",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         config.interfaces.len(),
         "bNumInterfaces",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         config.number,
         "bConfigurationValue",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value_string(
+        out,
         config.string_index,
         "iConfiguration",
         &config.name,
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         config.attributes_value(),
         "bmAttributes",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     // no attributes is bus powered
     if config.attributes.is_empty() {
-        dump_string("(Bus Powered)", indent + 4);
+        dump_string(out, "(Bus Powered)", indent + 4)?;
     } else {
         if config.attributes.contains(&ConfigAttributes::SelfPowered) {
-            dump_string("Self Powered", indent + 4);
+            dump_string(out, "Self Powered", indent + 4)?;
         }
         if config.attributes.contains(&ConfigAttributes::RemoteWakeup) {
-            dump_string("Remote Wakeup", indent + 4);
+            dump_string(out, "Remote Wakeup", indent + 4)?;
         }
     }
     dump_value(
+        out,
         format!("{}{}", config.max_power.value, config.max_power.unit),
         "MaxPower",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
+
+    if !class_specific {
+        return Ok(());
+    }
 
     // dump extra descriptors
     if let Some(dt_vec) = &config.extra {
         for dt in dt_vec {
             match dt {
                 Descriptor::InterfaceAssociation(iad) => {
-                    dump_interface_association(iad, indent + 2);
+                    dump_interface_association(out, iad, indent + 2)?;
                 }
                 Descriptor::Security(sec) => {
-                    dump_security(sec, indent + 2);
+                    dump_security(out, sec, indent + 2)?;
                 }
                 Descriptor::Encrypted(enc) => {
-                    dump_encryption_type(enc, indent + 2);
+                    dump_encryption_type(out, enc, indent + 2)?;
+                }
+                Descriptor::InterfacePower(ipd) => {
+                    dump_interface_power(out, ipd, indent + 2)?;
                 }
                 Descriptor::Unknown(junk) | Descriptor::Junk(junk) => {
-                    dump_unrecognised(junk, indent + 2);
+                    dump_vendor_descriptor(out, junk, indent + 2)?;
                 }
                 _ => (),
             }
         }
     }
+
+    Ok(())
 }
 
-/// Dump a [`USBInterfaceAssociation`] in style of lsusb --verbose
-fn dump_interface(interface: &USBInterface, indent: usize) {
+/// Dump a [`USBInterface`] in style of lsusb --verbose; class-specific descriptors only if `class_specific`
+fn dump_interface(
+    out: &mut DumpWriter,
+    interface: &USBInterface,
+    indent: usize,
+    class_specific: bool,
+) -> fmt::Result {
     let interface_name = names::class(interface.class.into());
     let sub_class_name = names::subclass(interface.class.into(), interface.sub_class);
     let protocol_name = names::protocol(
@@ -610,190 +1310,269 @@ fn dump_interface(interface: &USBInterface, indent: usize) {
         interface.protocol,
     );
 
-    dump_string("Interface Descriptor:", indent);
-    dump_value(interface.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
-    dump_value(4, "bDescriptorType", indent + 2, LSUSB_DUMP_WIDTH); // type 4 for interface
+    dump_string(out, "Interface Descriptor:", indent)?;
+    dump_value(
+        out,
+        interface.length,
+        "bLength",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    dump_value(out, 4, "bDescriptorType", indent + 2, LSUSB_DUMP_WIDTH)?; // type 4 for interface
     dump_value(
+        out,
         interface.number,
         "bInterfaceNumber",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         interface.alt_setting,
         "bAlternateSetting",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         interface.endpoints.len(),
         "bNumEndpoints",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value_string(
+        out,
         u8::from(interface.class.to_owned()),
         "bInterfaceClass",
         interface_name.unwrap_or(String::from("[unknown]")),
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value_string(
+        out,
         interface.sub_class,
         "bInterfaceSubClass",
         sub_class_name.unwrap_or(String::from("[unknown]")),
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value_string(
+        out,
         interface.protocol,
         "bInterfaceProtocol",
         protocol_name.unwrap_or_default(),
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value_string(
+        out,
         interface.string_index,
         "iInterface",
         &interface.name,
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
+
+    if !class_specific {
+        return Ok(());
+    }
 
-    // dump extra descriptors
     if let Some(dt_vec) = &interface.extra {
         for dt in dt_vec {
-            match dt {
-                // Should only be Device or Interface as we mask out the rest
-                Descriptor::Device(cd) | Descriptor::Interface(cd) => match cd {
-                    ClassDescriptor::Hid(hidd) => dump_hid_device(hidd, indent + 2),
-                    ClassDescriptor::Ccid(ccid) => dump_ccid_desc(ccid, indent + 2),
-                    ClassDescriptor::Printer(pd) => dump_printer_desc(pd, indent + 2),
-                    ClassDescriptor::Communication(cd) => dump_comm_descriptor(cd, indent + 2),
-                    ClassDescriptor::Dfu(dfud) => dump_dfu_interface(dfud, indent + 2),
-                    ClassDescriptor::Midi(md, _) => dump_midistreaming_interface(md, indent + 2),
-                    ClassDescriptor::Audio(uacd, uacp) => match &uacd.descriptor_subtype {
-                        audio::UacType::Control(cs) => {
-                            dump_audiocontrol_interface(uacd, cs, uacp, indent + 2)
-                        }
-                        audio::UacType::Streaming(ss) => {
-                            dump_audiostreaming_interface(uacd, ss, uacp, indent + 2)
-                        }
-                        _ => (),
-                    },
-                    ClassDescriptor::Video(vcd, p) => match &vcd.descriptor_subtype {
-                        video::UvcType::Control(cs) => {
-                            dump_videocontrol_interface(vcd, cs, *p, indent + 2)
-                        }
-                        video::UvcType::Streaming(ss) => {
-                            dump_videostreaming_interface(vcd, ss, *p, indent + 2);
-                        }
-                    },
-                    ClassDescriptor::Generic(cc, gd) => match cc {
-                        Some((ClassCode::Audio, 3, _)) => {
-                            if let Ok(md) = audio::MidiDescriptor::try_from(gd.to_owned()) {
-                                dump_midistreaming_interface(&md, indent + 2);
-                            }
-                        }
-                        Some((ClassCode::Audio, s, p)) => {
-                            if let Ok(uacd) =
-                                audio::UacDescriptor::try_from((gd.to_owned(), *s, *p))
-                            {
-                                let uacp = audio::UacProtocol::from(*p);
-                                match &uacd.descriptor_subtype {
-                                    audio::UacType::Control(cs) => {
-                                        dump_audiocontrol_interface(&uacd, cs, &uacp, indent + 2)
-                                    }
-                                    audio::UacType::Streaming(ss) => {
-                                        dump_audiostreaming_interface(&uacd, ss, &uacp, indent + 2)
-                                    }
-                                    _ => (),
-                                }
+            dump_interface_extra(out, dt, indent)?;
+        }
+    }
+
+    // UAC3 clusters and connectors are requested from the device rather than part of the configuration
+    for id in interface.uac3_cluster_ids() {
+        dump_uac3_cluster(out, id, interface.uac3_cluster(id), indent + 2)?;
+    }
+    for id in interface.uac3_connectors_ids() {
+        dump_uac3_connectors(out, id, interface.uac3_connectors(id), indent + 2)?;
+    }
+
+    Ok(())
+}
+
+/// Dump a class-specific or other extra descriptor `dt` following an interface descriptor at `indent`
+fn dump_interface_extra(out: &mut DumpWriter, dt: &Descriptor, indent: usize) -> fmt::Result {
+    match dt {
+        // Should only be Device or Interface as we mask out the rest
+        Descriptor::Device(cd) | Descriptor::Interface(cd) => match cd {
+            ClassDescriptor::Hid(hidd) => dump_hid_device(out, hidd, indent + 2)?,
+            ClassDescriptor::Ccid(ccid) => dump_ccid_desc(out, ccid, indent + 2)?,
+            ClassDescriptor::Printer(pd) => dump_printer_desc(out, pd, indent + 2)?,
+            ClassDescriptor::Communication(cd) => dump_comm_descriptor(out, cd, indent + 2)?,
+            ClassDescriptor::Dfu(dfud) => dump_dfu_interface(out, dfud, indent + 2)?,
+            ClassDescriptor::WireAdaptor(wad) => dump_wire_adaptor(out, wad, indent + 2)?,
+            ClassDescriptor::Midi(md, _) => dump_midistreaming_interface(out, md, indent + 2)?,
+            ClassDescriptor::Audio(uacd, uacp) => match &uacd.descriptor_subtype {
+                audio::UacType::Control(cs) => {
+                    dump_audiocontrol_interface(out, uacd, cs, uacp, indent + 2)?
+                }
+                audio::UacType::Streaming(ss) => {
+                    dump_audiostreaming_interface(out, uacd, ss, uacp, indent + 2)?
+                }
+                // MIDIStreaming should be ClassDescriptor::Midi but re-parse if not
+                audio::UacType::Midi(_) => {
+                    if let Ok(md) = GenericDescriptor::try_from(uacd.to_owned())
+                        .and_then(audio::MidiDescriptor::try_from)
+                    {
+                        dump_midistreaming_interface(out, &md, indent + 2)?;
+                    }
+                }
+            },
+            ClassDescriptor::Video(vcd, p) => match &vcd.descriptor_subtype {
+                video::UvcType::Control(cs) => {
+                    dump_videocontrol_interface(out, vcd, cs, *p, indent + 2)?
+                }
+                video::UvcType::Streaming(ss) => {
+                    dump_videostreaming_interface(out, vcd, ss, *p, indent + 2)?;
+                }
+            },
+            ClassDescriptor::Generic(cc, gd) => match cc {
+                Some((ClassCode::Audio, _, _))
+                    if cd.audio_subclass() == Some(audio::AudioSubclass::MidiStreaming) =>
+                {
+                    if let Ok(md) = audio::MidiDescriptor::try_from(gd.to_owned()) {
+                        dump_midistreaming_interface(out, &md, indent + 2)?;
+                    }
+                }
+                Some((ClassCode::Audio, s, p)) => {
+                    if let Ok(uacd) = audio::UacDescriptor::try_from((gd.to_owned(), *s, *p)) {
+                        let uacp = audio::UacProtocol::from(*p);
+                        match &uacd.descriptor_subtype {
+                            audio::UacType::Control(cs) => {
+                                dump_audiocontrol_interface(out, &uacd, cs, &uacp, indent + 2)?
                             }
-                        }
-                        Some((ClassCode::Video, s, p)) => {
-                            if let Ok(uvcd) =
-                                video::UvcDescriptor::try_from((gd.to_owned(), *s, *p))
-                            {
-                                match &uvcd.descriptor_subtype {
-                                    video::UvcType::Control(cs) => {
-                                        dump_videocontrol_interface(&uvcd, cs, *p, indent + 2);
-                                    }
-                                    video::UvcType::Streaming(ss) => {
-                                        dump_videostreaming_interface(&uvcd, ss, *p, indent + 2);
-                                    }
-                                }
+                            audio::UacType::Streaming(ss) => {
+                                dump_audiostreaming_interface(out, &uacd, ss, &uacp, indent + 2)?
                             }
+                            _ => (),
                         }
-                        Some((ClassCode::ApplicationSpecificInterface, 1, _)) => {
-                            if let Ok(dfud) = DfuDescriptor::try_from(gd.to_owned()) {
-                                dump_dfu_interface(&dfud, indent + 2);
+                    }
+                }
+                Some((ClassCode::Video, s, p)) => {
+                    if let Ok(uvcd) = video::UvcDescriptor::try_from((gd.to_owned(), *s, *p)) {
+                        match &uvcd.descriptor_subtype {
+                            video::UvcType::Control(cs) => {
+                                dump_videocontrol_interface(out, &uvcd, cs, *p, indent + 2)?;
+                            }
+                            video::UvcType::Streaming(ss) => {
+                                dump_videostreaming_interface(out, &uvcd, ss, *p, indent + 2)?;
                             }
                         }
-                        _ => {
-                            let junk = Vec::from(cd.to_owned());
-                            dump_unrecognised(&junk, indent + 2);
-                        }
-                    },
-                },
-                Descriptor::Unknown(junk) | Descriptor::Junk(junk) => {
-                    dump_unrecognised(junk, 6);
+                    }
                 }
-                _ => (),
-            }
+                Some((ClassCode::ApplicationSpecificInterface, 1, _)) => {
+                    if let Ok(dfud) = DfuDescriptor::try_from(gd.to_owned()) {
+                        dump_dfu_interface(out, &dfud, indent + 2)?;
+                    }
+                }
+                _ => {
+                    let junk = Vec::from(cd.to_owned());
+                    dump_vendor_descriptor(out, &junk, indent + 2)?;
+                }
+            },
+        },
+        Descriptor::InterfacePower(ipd) => dump_interface_power(out, ipd, indent + 2)?,
+        Descriptor::Unknown(junk) | Descriptor::Junk(junk) => {
+            dump_vendor_descriptor(out, junk, 6)?;
         }
+        _ => (),
     }
+
+    Ok(())
 }
 
-/// Dump a [`USBEndpoint`] in style of lsusb --verbose
-fn dump_endpoint(endpoint: &USBEndpoint, indent: usize) {
-    dump_string("Endpoint Descriptor:", indent);
-    dump_value(endpoint.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
-    dump_value(5, "bDescriptorType", indent + 2, LSUSB_DUMP_WIDTH); // type 5 for endpoint
+/// Dump a [`USBEndpoint`] in style of lsusb --verbose; class-specific descriptors only if `class_specific`
+fn dump_endpoint(
+    out: &mut DumpWriter,
+    endpoint: &USBEndpoint,
+    indent: usize,
+    class_specific: bool,
+) -> fmt::Result {
+    dump_string(out, "Endpoint Descriptor:", indent)?;
+    dump_value(
+        out,
+        endpoint.length,
+        "bLength",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    dump_value(out, 5, "bDescriptorType", indent + 2, LSUSB_DUMP_WIDTH)?; // type 5 for endpoint
     dump_value_string(
+        out,
         format!("0x{:02x}", endpoint.address.address),
         "bEndpointAddress",
-        format!(
-            "EP {} {}",
-            endpoint.address.number,
-            endpoint.address.direction.to_string().to_uppercase()
-        ),
+        endpoint.address.to_string(),
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     // this is printed as int even though it's a bitmap
     dump_value(
+        out,
         endpoint.attributes(),
         "bmAttributes",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
-    println!(
+    )?;
+    writeln!(
+        out,
         "{:indent$}Transfer Type          {:?}",
         "",
         endpoint.transfer_type,
         indent = indent + 4
-    );
-    println!(
+    )?;
+    writeln!(
+        out,
         "{:indent$}Sync Type              {:?}",
         "",
         endpoint.sync_type,
         indent = indent + 4
-    );
-    println!(
+    )?;
+    writeln!(
+        out,
         "{:indent$}Usage Type             {:?}",
         "",
         endpoint.usage_type,
         indent = indent + 4
-    );
+    )?;
     dump_value_string(
+        out,
         format!("0x{:04x}", endpoint.max_packet_size),
         "wMaxPacketSize",
         format!("{} bytes", endpoint.max_packet_string()),
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
-    dump_value(endpoint.interval, "bInterval", indent + 2, LSUSB_DUMP_WIDTH);
+    )?;
+    dump_value(
+        out,
+        endpoint.interval,
+        "bInterval",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    if endpoint.length >= 9 {
+        dump_value(
+            out,
+            endpoint.refresh.unwrap_or(0),
+            "bRefresh",
+            indent + 2,
+            LSUSB_DUMP_WIDTH,
+        )?;
+        dump_value(
+            out,
+            endpoint.synch_address.unwrap_or(0),
+            "bSynchAddress",
+            indent + 2,
+            LSUSB_DUMP_WIDTH,
+        )?;
+    }
+
+    if !class_specific {
+        return Ok(());
+    }
 
     // dump extra descriptors
     // kind of messy but it's out lsusb does it
@@ -802,22 +1581,22 @@ fn dump_endpoint(endpoint: &USBEndpoint, indent: usize) {
             match dt {
                 Descriptor::Endpoint(cd) => match cd {
                     ClassDescriptor::Audio(ad, _) => {
-                        dump_audiostreaming_endpoint(ad, indent + 2);
+                        dump_audiostreaming_endpoint(out, ad, indent + 2)?;
                     }
                     ClassDescriptor::Midi(md, _) => {
-                        dump_midistreaming_endpoint(md, indent + 2);
+                        dump_midistreaming_endpoint(out, md, indent + 2)?;
                     }
                     // legacy as context should have been added to the descriptor
                     ClassDescriptor::Generic(cc, gd) => match cc {
                         Some((ClassCode::Audio, 2, p)) => {
                             if let Ok(uacd) = audio::UacDescriptor::try_from((gd.to_owned(), 2, *p))
                             {
-                                dump_audiostreaming_endpoint(&uacd, indent + 2);
+                                dump_audiostreaming_endpoint(out, &uacd, indent + 2)?;
                             }
                         }
                         Some((ClassCode::Audio, 3, _)) => {
                             if let Ok(md) = audio::MidiDescriptor::try_from(gd.to_owned()) {
-                                dump_midistreaming_endpoint(&md, indent + 2);
+                                dump_midistreaming_endpoint(out, &md, indent + 2)?;
                             }
                         }
                         _ => (),
@@ -827,10 +1606,11 @@ fn dump_endpoint(endpoint: &USBEndpoint, indent: usize) {
                 // Misplaced descriptors
                 Descriptor::Device(cd) => match cd {
                     ClassDescriptor::Ccid(ccid) => {
-                        dump_ccid_desc(ccid, indent);
+                        dump_ccid_desc(out, ccid, indent)?;
                     }
                     _ => {
-                        println!(
+                        writeln!(
+                            out,
                             "{:indent$}DEVICE CLASS: {}",
                             "",
                             Vec::<u8>::from(cd.to_owned())
@@ -839,7 +1619,7 @@ fn dump_endpoint(endpoint: &USBEndpoint, indent: usize) {
                                 .collect::<Vec<String>>()
                                 .join(" "),
                             indent = indent + 2
-                        );
+                        )?;
                     }
                 },
                 Descriptor::Interface(cd) => match cd {
@@ -847,14 +1627,15 @@ fn dump_endpoint(endpoint: &USBEndpoint, indent: usize) {
                         Some((ClassCode::CDCData, _, _))
                         | Some((ClassCode::CDCCommunications, _, _)) => {
                             if let Ok(cd) = gd.to_owned().try_into() {
-                                dump_comm_descriptor(&cd, indent)
+                                dump_comm_descriptor(out, &cd, indent)?
                             }
                         }
                         Some((ClassCode::MassStorage, _, _)) => {
-                            dump_pipe_desc(gd, indent + 2);
+                            dump_pipe_desc(out, gd, indent + 2)?;
                         }
                         _ => {
-                            println!(
+                            writeln!(
+                                out,
                                 "{:indent$}INTERFACE CLASS: {}",
                                 "",
                                 Vec::<u8>::from(cd.to_owned())
@@ -863,12 +1644,13 @@ fn dump_endpoint(endpoint: &USBEndpoint, indent: usize) {
                                     .collect::<Vec<String>>()
                                     .join(" "),
                                 indent = indent + 2
-                            );
+                            )?;
                         }
                     },
-                    ClassDescriptor::Communication(cd) => dump_comm_descriptor(cd, 6),
+                    ClassDescriptor::Communication(cd) => dump_comm_descriptor(out, cd, 6)?,
                     _ => {
-                        println!(
+                        writeln!(
+                            out,
                             "{:indent$}INTERFACE CLASS: {}",
                             "",
                             Vec::<u8>::from(cd.to_owned())
@@ -877,80 +1659,114 @@ fn dump_endpoint(endpoint: &USBEndpoint, indent: usize) {
                                 .collect::<Vec<String>>()
                                 .join(" "),
                             indent = indent + 2
-                        );
+                        )?;
                     }
                 },
                 Descriptor::InterfaceAssociation(iad) => {
-                    dump_interface_association(iad, indent + 2);
+                    dump_interface_association(out, iad, indent + 2)?;
                 }
                 Descriptor::SsEndpointCompanion(ss) => {
-                    println!(
+                    writeln!(
+                        out,
                         "{:indent$}bMaxBurst {:>14}",
                         "",
                         ss.max_burst,
                         indent = indent + 2
-                    );
+                    )?;
                     match endpoint.transfer_type {
                         TransferType::Bulk => {
                             if ss.attributes & 0x1f != 0 {
-                                println!(
+                                writeln!(
+                                    out,
                                     "{:indent$}MaxStreams {:>13}",
                                     "",
                                     1 << ss.attributes,
                                     indent = indent + 2
-                                );
+                                )?;
                             }
                         }
                         TransferType::Isochronous => {
                             if ss.attributes & 0x03 != 0 {
-                                println!(
+                                writeln!(
+                                    out,
                                     "{:indent$}Mult {:>19}",
                                     "",
                                     ss.attributes & 0x3,
                                     indent = indent + 2
-                                );
+                                )?;
                             }
                         }
                         _ => (),
                     }
                 }
+                Descriptor::WirelessEndpointCompanion(wec) => {
+                    dump_wireless_endpoint_companion(out, wec, indent + 2)?;
+                }
+                Descriptor::SsIsocEndpointCompanion(sic) => {
+                    dump_string(
+                        out,
+                        "SuperSpeedPlus Isochronous Endpoint Companion:",
+                        indent + 2,
+                    )?;
+                    dump_value(out, sic.length, "bLength", indent + 4, LSUSB_DUMP_WIDTH)?;
+                    dump_value(
+                        out,
+                        sic.descriptor_type,
+                        "bDescriptorType",
+                        indent + 4,
+                        LSUSB_DUMP_WIDTH,
+                    )?;
+                    dump_value(
+                        out,
+                        sic.bytes_per_interval,
+                        "dwBytesPerInterval",
+                        indent + 4,
+                        LSUSB_DUMP_WIDTH,
+                    )?;
+                }
                 Descriptor::Unknown(junk) | Descriptor::Junk(junk) => {
-                    dump_unrecognised(junk, indent + 2);
+                    dump_vendor_descriptor(out, junk, indent + 2)?;
                 }
                 _ => (),
             }
         }
     }
+
+    Ok(())
 }
 
-fn dump_ccid_desc(ccid: &CcidDescriptor, indent: usize) {
-    dump_string("ChipCard Interface Descriptor:", indent);
-    dump_value(ccid.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
+fn dump_ccid_desc(out: &mut DumpWriter, ccid: &CcidDescriptor, indent: usize) -> fmt::Result {
+    dump_string(out, "ChipCard Interface Descriptor:", indent)?;
+    dump_value(out, ccid.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         ccid.descriptor_type,
         "bDescriptorType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     if ccid.version.major() != 1 || ccid.version.minor() != 0 {
         dump_value_string(
+            out,
             ccid.version,
             "bcdCCID",
             "(Warning: Only accurate for version 1.0)",
             indent + 2,
             LSUSB_DUMP_WIDTH,
-        );
+        )?;
     } else {
-        dump_value(ccid.version, "bcdCCID", indent + 2, LSUSB_DUMP_WIDTH);
+        dump_value(out, ccid.version, "bcdCCID", indent + 2, LSUSB_DUMP_WIDTH)?;
     }
 
     dump_value(
+        out,
         ccid.max_slot_index,
         "bMaxSlotIndex",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_bitmap_strings_inline(
+        out,
         ccid.voltage_support,
         ccid.voltage_support,
         "bVoltageSupport",
@@ -962,9 +1778,10 @@ fn dump_ccid_desc(ccid: &CcidDescriptor, indent: usize) {
         },
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     dump_bitmap_strings_inline(
+        out,
         ccid.protocols,
         ccid.protocols,
         "dwProtocols",
@@ -975,83 +1792,99 @@ fn dump_ccid_desc(ccid: &CcidDescriptor, indent: usize) {
         },
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     dump_value(
+        out,
         ccid.default_clock,
         "dwDefaultClock",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         ccid.max_clock,
         "dwMaxiumumClock",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         ccid.num_clock_supported,
         "bNumClockSupported",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value_string(
+        out,
         ccid.data_rate,
         "dwDataRate",
         "bps",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value_string(
+        out,
         ccid.max_data_rate,
         "dwMaxDataRate",
         "bps",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         ccid.num_data_rates_supp,
         "bNumDataRatesSupp.",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
-    dump_value(ccid.max_ifsd, "dwMaxIFSD", indent + 2, LSUSB_DUMP_WIDTH);
+    )?;
+    dump_value(
+        out,
+        ccid.max_ifsd,
+        "dwMaxIFSD",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
     dump_bitmap_strings_inline(
+        out,
         format!("{:08X}", ccid.sync_protocols),
         ccid.sync_protocols,
         "dwSyncProtocols",
-        |index| match index {
-            0 => Some("2-wire"),
-            1 => Some("3-wire"),
-            2 => Some("I2C"),
-            _ => None,
-        },
+        CcidDescriptor::sync_protocol_string,
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
-    dump_bitmap_strings_inline(
-        format!("{:08X}", ccid.mechanical),
-        ccid.mechanical,
-        "dwMechanical",
-        |index| match index {
-            0 => Some("accept"),
-            1 => Some("eject"),
-            2 => Some("capture"),
-            3 => Some("lock"),
-            _ => None,
-        },
-        indent + 2,
-        LSUSB_DUMP_WIDTH,
-    );
+    if ccid.mechanical_features().is_empty() {
+        dump_value_string(
+            out,
+            format!("{:08X}", ccid.mechanical),
+            "dwMechanical",
+            "no special characteristics",
+            indent + 2,
+            LSUSB_DUMP_WIDTH,
+        )?;
+    } else {
+        dump_bitmap_strings_inline(
+            out,
+            format!("{:08X}", ccid.mechanical),
+            ccid.mechanical,
+            "dwMechanical",
+            CcidDescriptor::mechanical_string,
+            indent + 2,
+            LSUSB_DUMP_WIDTH,
+        )?;
+    }
 
     dump_value(
+        out,
         format!("{:08X}", ccid.features),
         "dwFeatures",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_bitmap_strings(
+        out,
         ccid.features,
         |index| match index {
             0 => Some("Auto configuration based on ATR"),
@@ -1070,63 +1903,76 @@ fn dump_ccid_desc(ccid: &CcidDescriptor, indent: usize) {
             _ => None,
         },
         indent + 4,
-    );
+    )?;
     if (ccid.features & (0x0040 | 0x0080)) != 0 {
-        println!(
+        writeln!(
+            out,
             "{:indent$}WARNING: conflicting negotiation features",
             "",
             indent = indent + 2
-        );
+        )?;
     }
     if ccid.features & 0x00070000 != 0 {
-        println!(
+        writeln!(
+            out,
             "{:indent$}WARNING: conflicting exchange levels",
             "",
             indent = indent + 2
-        );
+        )?;
     }
 
     dump_value(
+        out,
         ccid.max_ccid_msg_len,
         "dwMaxCCIDMsgLen",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     if ccid.class_get_response == 0xff {
-        dump_value("echo", "bClassGetResponse", indent + 2, LSUSB_DUMP_WIDTH);
+        dump_value(
+            out,
+            "echo",
+            "bClassGetResponse",
+            indent + 2,
+            LSUSB_DUMP_WIDTH,
+        )?;
     } else {
         dump_value(
+            out,
             format!("{:02X}", ccid.class_get_response),
             "bClassGetResponse",
             indent + 2,
             LSUSB_DUMP_WIDTH,
-        );
+        )?;
     }
 
     if ccid.class_envelope == 0xff {
-        dump_value("echo", "bClassEnvelope", indent + 2, LSUSB_DUMP_WIDTH);
+        dump_value(out, "echo", "bClassEnvelope", indent + 2, LSUSB_DUMP_WIDTH)?;
     } else {
         dump_value(
+            out,
             format!("{:02X}", ccid.class_envelope),
             "bClassEnvelope",
             indent + 2,
             LSUSB_DUMP_WIDTH,
-        );
+        )?;
     }
 
     if ccid.lcd_layout == (0, 0) {
-        dump_value("none", "wlcdLayout", indent + 2, LSUSB_DUMP_WIDTH);
+        dump_value(out, "none", "wlcdLayout", indent + 2, LSUSB_DUMP_WIDTH)?;
     } else {
         dump_value_string(
+            out,
             ccid.lcd_layout.0,
             "wlcdLayout",
             format!(" cols {} lines", ccid.lcd_layout.1),
             indent + 2,
             LSUSB_DUMP_WIDTH,
-        );
+        )?;
     }
 
     dump_bitmap_strings_inline(
+        out,
         ccid.pin_support,
         ccid.pin_support,
         "bPINSupport",
@@ -1137,103 +1983,120 @@ fn dump_ccid_desc(ccid: &CcidDescriptor, indent: usize) {
         },
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     dump_value(
+        out,
         ccid.max_ccid_busy_slots,
         "bMaxCCIDBusySlots",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
+
+    Ok(())
 }
 
-fn dump_printer_desc(pd: &PrinterDescriptor, indent: usize) {
-    dump_string("Printer Interface Descriptor:", indent);
-    dump_value(pd.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
+fn dump_printer_desc(out: &mut DumpWriter, pd: &PrinterDescriptor, indent: usize) -> fmt::Result {
+    dump_string(out, "Printer Interface Descriptor:", indent)?;
+    dump_value(out, pd.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         pd.descriptor_type,
         "bDescriptorType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         pd.release_number,
         "bcdReleaseNumber",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         pd.descriptors.len(),
         "bcdNumDescriptors",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     for desc in &pd.descriptors {
         // basic capabilities
         if desc.descriptor_type == 0x00 {
             dump_value(
+                out,
                 desc.versions_supported,
                 "iIPPVersionsSupported",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
             dump_value_string(
+                out,
                 desc.uuid_string_index,
                 "iIPPPrinterUUID",
                 desc.uuid_string.as_ref().unwrap_or(&String::new()),
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
-            print!(
+            )?;
+            write!(
+                out,
                 "{:indent$}wBasicCapabilities   0x{:04x} ",
                 "",
                 desc.capabilities,
                 indent = indent + 2
-            );
+            )?;
 
             // capabilities
             if desc.capabilities & 0x0001 != 0 {
-                print!(" Print");
+                write!(out, " Print")?;
             }
             if desc.capabilities & 0x0002 != 0 {
-                print!(" Scan");
+                write!(out, " Scan")?;
             }
             if desc.capabilities & 0x0004 != 0 {
-                print!(" Fax");
+                write!(out, " Fax")?;
             }
             if desc.capabilities & 0x0008 != 0 {
-                print!(" Other");
+                write!(out, " Other")?;
             }
             if desc.capabilities & 0x0010 != 0 {
-                print!(" HTTP-over-USB");
+                write!(out, " HTTP-over-USB")?;
             }
             if (desc.capabilities & 0x0060) != 0 {
-                print!(" No-Auth");
+                write!(out, " No-Auth")?;
             } else if (desc.capabilities & 0x0060) != 0x20 {
-                print!(" Username-Auth");
+                write!(out, " Username-Auth")?;
             } else if (desc.capabilities & 0x0060) != 0x40 {
-                print!(" Reserved-Auth");
+                write!(out, " Reserved-Auth")?;
             } else if (desc.capabilities & 0x0060) != 0x60 {
-                print!(" Negotiable-Auth");
+                write!(out, " Negotiable-Auth")?;
             }
-            println!();
+            writeln!(out)?;
         // vendor specific
         } else {
             dump_value_string(
+                out,
                 desc.descriptor_type,
                 "UnknownCapabilities",
                 desc.length,
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
         }
     }
+
+    Ok(())
 }
 
-fn dump_bad_comm(cd: &cdc::CommunicationDescriptor, indent: usize) {
+fn dump_bad_comm(
+    out: &mut DumpWriter,
+    cd: &cdc::CommunicationDescriptor,
+    indent: usize,
+) -> fmt::Result {
     let data = Into::<Vec<u8>>::into(cd.to_owned());
-    println!(
+    writeln!(
+        out,
         "{:^indent$}INVALID CDC ({:#}): {}",
         "",
         cd.descriptor_subtype,
@@ -1241,62 +2104,77 @@ fn dump_bad_comm(cd: &cdc::CommunicationDescriptor, indent: usize) {
             .map(|b| format!("{:02x}", b))
             .collect::<Vec<String>>()
             .join(" ")
-    );
+    )?;
+
+    Ok(())
 }
 
-fn dump_comm_descriptor(cd: &cdc::CommunicationDescriptor, indent: usize) {
+fn dump_comm_descriptor(
+    out: &mut DumpWriter,
+    cd: &cdc::CommunicationDescriptor,
+    indent: usize,
+) -> fmt::Result {
     match &cd.interface {
         cdc::CdcInterfaceDescriptor::Header(d) => {
-            dump_string("CDC Header:", indent);
-            dump_value(d.version, "bcdCDC", indent + 2, LSUSB_DUMP_WIDTH);
+            dump_string(out, "CDC Header:", indent)?;
+            dump_value(out, d.version, "bcdCDC", indent + 2, LSUSB_DUMP_WIDTH)?;
         }
         cdc::CdcInterfaceDescriptor::CallManagement(cd) => {
-            dump_string("CDC Call Management:", indent);
+            dump_string(out, "CDC Call Management:", indent)?;
             dump_hex(
+                out,
                 cd.capabilities,
                 "bmCapabilities",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
-            dump_bitmap_strings(
-                cd.capabilities,
-                |b| match b {
-                    0 => Some("call management"),
-                    1 => Some("dataInterface"),
-                    _ => None,
-                },
-                indent + 4,
-            );
+            )?;
+            if cd.handles_call_management() {
+                dump_string(out, "call management", indent + 4)?;
+            }
+            if cd.over_data_interface() {
+                dump_string(out, "use DataInterface", indent + 4)?;
+            }
+            dump_value(
+                out,
+                cd.data_interface,
+                "bDataInterface",
+                indent + 2,
+                LSUSB_DUMP_WIDTH,
+            )?;
         }
         cdc::CdcInterfaceDescriptor::AbstractControlManagement(cd) => {
-            dump_string("CDC ACM:", indent);
+            dump_string(out, "CDC ACM:", indent)?;
             dump_hex(
+                out,
                 cd.capabilities,
                 "bmCapabilities",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
-            dump_bitmap_strings_invert(
-                cd.capabilities,
-                |b| match b {
-                    0 => Some("get/set/clear comm features"),
-                    1 => Some("line coding and serial state"),
-                    2 => Some("sends break"),
-                    3 => Some("connection notifications"),
-                    _ => None,
-                },
-                indent + 4,
-            );
+            )?;
+            if cd.network_connection() {
+                dump_string(out, "connection notifications", indent + 4)?;
+            }
+            if cd.send_break() {
+                dump_string(out, "sends break", indent + 4)?;
+            }
+            if cd.line_coding() {
+                dump_string(out, "line coding and serial state", indent + 4)?;
+            }
+            if cd.comm_features() {
+                dump_string(out, "get/set/clear comm features", indent + 4)?;
+            }
         }
         cdc::CdcInterfaceDescriptor::Union(cd) => {
-            dump_string("CDC Union:", indent);
+            dump_string(out, "CDC Union:", indent)?;
             dump_value(
+                out,
                 cd.master_interface,
                 "bMasterInterface",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
-            println!(
+            )?;
+            writeln!(
+                out,
                 "{:indent$}bSlaveInterface      {}",
                 "",
                 cd.slave_interface
@@ -1305,11 +2183,12 @@ fn dump_comm_descriptor(cd: &cdc::CommunicationDescriptor, indent: usize) {
                     .collect::<Vec<String>>()
                     .join(" "),
                 indent = indent + 2
-            );
+            )?;
         }
         cdc::CdcInterfaceDescriptor::CountrySelection(cd) => {
-            dump_string("Country Selection:", indent);
+            dump_string(out, "Country Selection:", indent)?;
             dump_value_string(
+                out,
                 cd.country_code_date_index,
                 "iCountryCodeRelDate",
                 cd.country_code_date
@@ -1317,110 +2196,121 @@ fn dump_comm_descriptor(cd: &cdc::CommunicationDescriptor, indent: usize) {
                     .unwrap_or(&String::from("(?)")),
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
             for d in &cd.country_codes {
                 dump_value(
+                    out,
                     format!("{:04x}", d),
                     "wCountryCode",
                     indent + 2,
                     LSUSB_DUMP_WIDTH,
-                );
+                )?;
             }
         }
         cdc::CdcInterfaceDescriptor::TelephoneOperations(d) => {
-            dump_string("CDC Telephone operations:", indent);
+            dump_string(out, "CDC Telephone operations:", indent)?;
             dump_hex(
+                out,
                 d.capabilities,
                 "bmCapabilities",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
-            dump_bitmap_strings_invert(
-                d.capabilities,
-                |b| match b {
-                    0 => Some("simple mode"),
-                    1 => Some("standalone mode"),
-                    2 => Some("computer centric mode"),
-                    _ => None,
-                },
-                indent + 4,
-            );
+            )?;
+            if d.computer_centric_mode() {
+                dump_string(out, "computer centric mode", indent + 4)?;
+            }
+            if d.standalone_mode() {
+                dump_string(out, "standalone mode", indent + 4)?;
+            }
+            if d.simple_mode() {
+                dump_string(out, "simple mode", indent + 4)?;
+            }
         }
         cdc::CdcInterfaceDescriptor::NetworkChannel(d) => {
-            dump_string("Network Channel Terminal:", indent);
-            dump_value(d.entity_id, "bEntityId", indent + 2, LSUSB_DUMP_WIDTH);
+            dump_string(out, "Network Channel Terminal:", indent)?;
+            dump_value(out, d.entity_id, "bEntityId", indent + 2, LSUSB_DUMP_WIDTH)?;
             dump_value_string(
+                out,
                 d.name_string_index,
                 "iName",
                 d.name.as_ref().unwrap_or(&String::from("(?)")),
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
             dump_value(
+                out,
                 d.channel_index,
                 "bChannelIndex",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
             dump_value(
+                out,
                 d.physical_interface,
                 "bPhysicalInterface",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
         }
         cdc::CdcInterfaceDescriptor::EthernetNetworking(d) => {
-            dump_string("CDC Ethernet:", indent);
+            dump_string(out, "CDC Ethernet:", indent)?;
             dump_value_string(
+                out,
                 d.mac_address_index,
                 "iMacAddress",
                 d.mac_address.as_ref().unwrap_or(&String::from("(?)")),
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
             dump_hex(
+                out,
                 d.ethernet_statistics,
                 "bmEthernetStatistics",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
             dump_value(
+                out,
                 d.max_segment_size,
                 "wMaxSegmentSize",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
             dump_hex(
+                out,
                 d.num_multicast_filters,
                 "wNumberMCFilters",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
             dump_hex(
+                out,
                 d.num_power_filters,
                 "bNumberPowerFilters",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
         }
         cdc::CdcInterfaceDescriptor::WirelessHandsetControlModel(d) => {
-            dump_string("CDC WHCM:", indent);
-            dump_value(d.version, "bcdVersion", indent + 2, LSUSB_DUMP_WIDTH);
+            dump_string(out, "CDC WHCM:", indent)?;
+            dump_value(out, d.version, "bcdVersion", indent + 2, LSUSB_DUMP_WIDTH)?;
         }
         cdc::CdcInterfaceDescriptor::MobileDirectLineModelFunctional(d) => {
-            dump_string("CDC MDLM:", indent);
-            dump_value(d.version, "bcdVersion", indent + 2, LSUSB_DUMP_WIDTH);
-            dump_guid(&d.guid, "bGUID", indent + 2, LSUSB_DUMP_WIDTH);
+            dump_string(out, "CDC MDLM:", indent)?;
+            dump_value(out, d.version, "bcdVersion", indent + 2, LSUSB_DUMP_WIDTH)?;
+            dump_guid(out, &d.guid, "bGUID", indent + 2, LSUSB_DUMP_WIDTH)?;
         }
         cdc::CdcInterfaceDescriptor::MobileDirectLineModelDetail(d) => {
-            dump_string("CDC MDLM detail:", indent);
+            dump_string(out, "CDC MDLM detail:", indent)?;
             dump_value(
+                out,
                 format!("{:02x}", d.guid_descriptor_type),
                 "bGuidDescriptorType",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
-            println!(
+            )?;
+            writeln!(
+                out,
                 "{:indent$}bDetailData          {}",
                 "",
                 d.detail_data
@@ -1429,21 +2319,28 @@ fn dump_comm_descriptor(cd: &cdc::CommunicationDescriptor, indent: usize) {
                     .collect::<Vec<String>>()
                     .join(" "),
                 indent = indent + 2
-            );
+            )?;
         }
         cdc::CdcInterfaceDescriptor::DeviceManagement(d) => {
-            dump_string("CDC MDLM:", indent);
-            dump_value(d.version, "bcdVersion", indent + 2, LSUSB_DUMP_WIDTH);
-            dump_value(d.max_command, "wMaxCommand", indent + 2, LSUSB_DUMP_WIDTH);
+            dump_string(out, "CDC MDLM:", indent)?;
+            dump_value(out, d.version, "bcdVersion", indent + 2, LSUSB_DUMP_WIDTH)?;
+            dump_value(
+                out,
+                d.max_command,
+                "wMaxCommand",
+                indent + 2,
+                LSUSB_DUMP_WIDTH,
+            )?;
         }
         cdc::CdcInterfaceDescriptor::Obex(d) => {
-            dump_string("CDC OBEX:", indent);
-            dump_value(d.version, "bcdVersion", indent + 2, LSUSB_DUMP_WIDTH);
+            dump_string(out, "CDC OBEX:", indent)?;
+            dump_value(out, d.version, "bcdVersion", indent + 2, LSUSB_DUMP_WIDTH)?;
         }
         cdc::CdcInterfaceDescriptor::CommandSet(d) => {
-            dump_string("CDC Command Set:", indent);
-            dump_value(d.version, "bcdVersion", indent + 2, LSUSB_DUMP_WIDTH);
+            dump_string(out, "CDC Command Set:", indent)?;
+            dump_value(out, d.version, "bcdVersion", indent + 2, LSUSB_DUMP_WIDTH)?;
             dump_value_string(
+                out,
                 d.command_set_string_index,
                 "iCommandSet",
                 d.command_set_string
@@ -1451,19 +2348,27 @@ fn dump_comm_descriptor(cd: &cdc::CommunicationDescriptor, indent: usize) {
                     .unwrap_or(&String::from("(?)")),
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
-            dump_guid(&d.guid, "bGUID", indent + 2, LSUSB_DUMP_WIDTH);
+            )?;
+            dump_guid(out, &d.guid, "bGUID", indent + 2, LSUSB_DUMP_WIDTH)?;
         }
         cdc::CdcInterfaceDescriptor::Ncm(d) => {
-            dump_string("CDC NCM:", indent);
-            dump_value(d.version, "bcdNcmVersion", indent + 2, LSUSB_DUMP_WIDTH);
+            dump_string(out, "CDC NCM:", indent)?;
+            dump_value(
+                out,
+                d.version,
+                "bcdNcmVersion",
+                indent + 2,
+                LSUSB_DUMP_WIDTH,
+            )?;
             dump_hex(
+                out,
                 d.network_capabilities,
                 "bmNetworkCapabilities",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
             dump_bitmap_strings_invert(
+                out,
                 d.network_capabilities,
                 |b| match b {
                     0 => Some("packet filter"),
@@ -1475,42 +2380,54 @@ fn dump_comm_descriptor(cd: &cdc::CommunicationDescriptor, indent: usize) {
                     _ => None,
                 },
                 indent + 4,
-            );
+            )?;
         }
         cdc::CdcInterfaceDescriptor::Mbim(d) => {
-            dump_string("CDC MBIM:", indent);
-            dump_value(d.version, "bcdMBIMVersion", indent + 2, LSUSB_DUMP_WIDTH);
+            dump_string(out, "CDC MBIM:", indent)?;
+            dump_value(
+                out,
+                d.version,
+                "bcdMBIMVersion",
+                indent + 2,
+                LSUSB_DUMP_WIDTH,
+            )?;
             dump_value(
+                out,
                 d.max_control_message,
                 "wMaxControlMessage",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
             dump_value(
+                out,
                 d.number_filters,
                 "bNumberFilters",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
             dump_value(
+                out,
                 d.max_filter_size,
                 "bMaxFilterSize",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
             dump_value(
+                out,
                 d.max_segment_size,
                 "wMaxSegmentSize",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
             dump_hex(
+                out,
                 d.network_capabilities,
                 "bmNetworkCapabilities",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
             dump_bitmap_strings_invert(
+                out,
                 d.network_capabilities,
                 |b| match b {
                     3 => Some("max cd.datagram size"),
@@ -1518,29 +2435,32 @@ fn dump_comm_descriptor(cd: &cdc::CommunicationDescriptor, indent: usize) {
                     _ => None,
                 },
                 indent + 4,
-            );
+            )?;
         }
         cdc::CdcInterfaceDescriptor::MbimExtended(d) => {
-            dump_string("CDC MBIM Extended:", indent);
+            dump_string(out, "CDC MBIM Extended:", indent)?;
             dump_value(
+                out,
                 d.version,
                 "bcdMBIMExtendedVersion",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
             dump_value(
+                out,
                 d.max_outstanding_command_messages,
                 "bMaxOutstandingCommandMessages",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
-            dump_value(d.mtu, "wMTU", indent + 2, LSUSB_DUMP_WIDTH);
+            )?;
+            dump_value(out, d.mtu, "wMTU", indent + 2, LSUSB_DUMP_WIDTH)?;
         }
         cdc::CdcInterfaceDescriptor::Invalid(_) => {
-            dump_bad_comm(cd, indent);
+            dump_bad_comm(out, cd, indent)?;
         }
         _ => {
-            println!(
+            writeln!(
+                out,
                 "{:^indent$}UNRECOGNIZED CDC: {}",
                 "",
                 Vec::<u8>::from(cd.to_owned())
@@ -1549,74 +2469,249 @@ fn dump_comm_descriptor(cd: &cdc::CommunicationDescriptor, indent: usize) {
                     .collect::<Vec<String>>()
                     .join(" "),
                 indent = indent
-            );
+            )?;
         }
     }
+
+    Ok(())
 }
 
-fn dump_dfu_interface(dfud: &DfuDescriptor, indent: usize) {
+fn dump_dfu_interface(out: &mut DumpWriter, dfud: &DfuDescriptor, indent: usize) -> fmt::Result {
     // wider in lsusb but I prefer standard
     //const DFU_WIDTH: usize = 36;
     const DFU_WIDTH: usize = LSUSB_DUMP_WIDTH;
 
-    dump_string("Device Firmware Upgrade Interface Descriptor:", indent);
-    dump_value(dfud.length, "bLength", indent + 2, DFU_WIDTH);
+    dump_string(out, "Device Firmware Upgrade Interface Descriptor:", indent)?;
+    dump_value(out, dfud.length, "bLength", indent + 2, DFU_WIDTH)?;
     dump_value(
+        out,
         dfud.descriptor_type,
         "bDescriptorType",
         indent + 2,
         DFU_WIDTH,
-    );
-    dump_value(dfud.attributes, "bmAttributes", indent + 2, DFU_WIDTH);
+    )?;
+    dump_value(out, dfud.attributes, "bmAttributes", indent + 2, DFU_WIDTH)?;
 
     if dfud.attributes & 0xf0 != 0 {
-        println!("{:indent$}(unknown attributes!)", "", indent = indent + 4);
-    }
-    if dfud.attributes & 0x08 != 0 {
-        println!("{:indent$}Will Detach", "", indent = indent + 4);
-    } else {
-        println!("{:indent$}Will Not Detach", "", indent = indent + 4);
-    }
-    if dfud.attributes & 0x04 != 0 {
-        println!(
-            "{:indent$}Manifestation Intolerant",
+        writeln!(
+            out,
+            "{:indent$}(unknown attributes!)",
             "",
             indent = indent + 4
-        );
-    } else {
-        println!("{:indent$}Manifestation Tolerant", "", indent = indent + 4);
-    }
-    if dfud.attributes & 0x02 != 0 {
-        println!("{:indent$}Upload Supported", "", indent = indent + 4);
-    } else {
-        println!("{:indent$}Upload Unsupported", "", indent = indent + 4);
-    }
-    if dfud.attributes & 0x01 != 0 {
-        println!("{:indent$}Download Supported", "", indent = indent + 4);
-    } else {
-        println!("{:indent$}Download Unsupported", "", indent = indent + 4);
+        )?;
     }
+    writeln!(
+        out,
+        "{:indent$}{}",
+        "",
+        if dfud.will_detach() {
+            "Will Detach"
+        } else {
+            "Will Not Detach"
+        },
+        indent = indent + 4
+    )?;
+    writeln!(
+        out,
+        "{:indent$}Manifestation {}",
+        "",
+        if dfud.manifestation_tolerant() {
+            "Tolerant"
+        } else {
+            "Intolerant"
+        },
+        indent = indent + 4
+    )?;
+    writeln!(
+        out,
+        "{:indent$}Upload {}",
+        "",
+        if dfud.can_upload() {
+            "Supported"
+        } else {
+            "Unsupported"
+        },
+        indent = indent + 4
+    )?;
+    writeln!(
+        out,
+        "{:indent$}Download {}",
+        "",
+        if dfud.can_download() {
+            "Supported"
+        } else {
+            "Unsupported"
+        },
+        indent = indent + 4
+    )?;
 
     dump_value_string(
+        out,
         dfud.detach_timeout,
         "wDetachTimeout",
         "milliseconds",
         indent + 2,
-        DFU_WIDTH,
-    );
-    dump_value_string(
-        dfud.transfer_size,
-        "wTransferSize",
-        "bytes",
+        DFU_WIDTH,
+    )?;
+    dump_value_string(
+        out,
+        dfud.transfer_size,
+        "wTransferSize",
+        "bytes",
+        indent + 2,
+        DFU_WIDTH,
+    )?;
+    if let Some(bcd) = dfud.dfu_version.as_ref() {
+        dump_value(out, bcd, "bcdDFUVersion", indent + 2, DFU_WIDTH)?;
+    }
+
+    Ok(())
+}
+
+fn dump_wire_adaptor(
+    out: &mut DumpWriter,
+    wad: &WireAdaptorDescriptor,
+    indent: usize,
+) -> fmt::Result {
+    dump_string(out, "Wire Adapter Class Descriptor:", indent)?;
+    dump_value(out, wad.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
+    dump_value(
+        out,
+        wad.descriptor_type,
+        "bDescriptorType",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    dump_value(
+        out,
+        wad.wa_version,
+        "bcdWAVersion",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    dump_value(
+        out,
+        wad.num_ports,
+        "bNumPorts",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    dump_value(
+        out,
+        wad.attributes,
+        "bmAttributes",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    dump_value(
+        out,
+        wad.num_rpipes,
+        "wNumRPipes",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    dump_value(
+        out,
+        wad.rpipe_max_block,
+        "wRPipeMaxBlock",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    dump_value(
+        out,
+        wad.rpipe_block_size,
+        "bRPipeBlockSize",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    dump_value(
+        out,
+        wad.pwr_on_2_pwr_good,
+        "bPwrOn2PwrGood",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    dump_value(
+        out,
+        wad.num_mmc_ies,
+        "bNumMMCIEs",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    for removable in wad.device_removable.iter() {
+        dump_hex(
+            out,
+            *removable,
+            "DeviceRemovable",
+            indent + 2,
+            LSUSB_DUMP_WIDTH,
+        )?;
+    }
+
+    Ok(())
+}
+
+fn dump_wireless_endpoint_companion(
+    out: &mut DumpWriter,
+    wec: &WirelessEndpointCompanionDescriptor,
+    indent: usize,
+) -> fmt::Result {
+    dump_string(out, "Wireless Endpoint Companion:", indent)?;
+    dump_value(out, wec.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
+    dump_value(
+        out,
+        wec.descriptor_type,
+        "bDescriptorType",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    dump_value(
+        out,
+        wec.max_burst,
+        "bMaxBurst",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    dump_value(
+        out,
+        wec.max_sequence,
+        "bMaxSequence",
         indent + 2,
-        DFU_WIDTH,
-    );
-    if let Some(bcd) = dfud.dfu_version.as_ref() {
-        dump_value(bcd, "bcdDFUVersion", indent + 2, DFU_WIDTH);
-    }
+        LSUSB_DUMP_WIDTH,
+    )?;
+    dump_value(
+        out,
+        wec.max_stream_delay,
+        "wMaxStreamDelay",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    dump_value(
+        out,
+        wec.over_the_air_packet_size,
+        "wOverTheAirPacketSize",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    dump_value(
+        out,
+        wec.over_the_air_interval,
+        "bOverTheAirInterval",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    dump_hex(
+        out,
+        wec.attributes,
+        "bmCompAttributes",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+
+    Ok(())
 }
 
-fn dump_pipe_desc(gd: &GenericDescriptor, indent: usize) {
+fn dump_pipe_desc(out: &mut DumpWriter, gd: &GenericDescriptor, indent: usize) -> fmt::Result {
     if gd.length == 4 && gd.descriptor_type == 0x24 {
         let subtype_string = match gd.descriptor_subtype {
             1 => "Command pipe",
@@ -1627,15 +2722,17 @@ fn dump_pipe_desc(gd: &GenericDescriptor, indent: usize) {
             0xe0..=0xef => "Vendor-specific",
         };
 
-        println!(
+        writeln!(
+            out,
             "{:indent$}{} (0x{:02x})",
             "",
             subtype_string,
             gd.descriptor_subtype,
             indent = indent
-        );
+        )?;
     } else {
-        println!(
+        writeln!(
+            out,
             "{:indent$}INTERFACE CLASS: {}",
             "",
             Vec::<u8>::from(gd.to_owned())
@@ -1644,34 +2741,45 @@ fn dump_pipe_desc(gd: &GenericDescriptor, indent: usize) {
                 .collect::<Vec<String>>()
                 .join(" "),
             indent = indent
-        );
+        )?;
     }
+
+    Ok(())
 }
 
-fn dump_security(sec: &SecurityDescriptor, indent: usize) {
-    dump_string("Security Descriptor:", indent);
-    dump_value(sec.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
+fn dump_security(out: &mut DumpWriter, sec: &SecurityDescriptor, indent: usize) -> fmt::Result {
+    dump_string(out, "Security Descriptor:", indent)?;
+    dump_value(out, sec.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         sec.descriptor_type,
         "bDescriptorType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_hex(
+        out,
         sec.total_length,
         "wTotalLength",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         sec.encryption_types,
         "bNumEncryptionTypes",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
+
+    Ok(())
 }
 
-fn dump_encryption_type(enc: &EncryptionDescriptor, indent: usize) {
+fn dump_encryption_type(
+    out: &mut DumpWriter,
+    enc: &EncryptionDescriptor,
+    indent: usize,
+) -> fmt::Result {
     let enct_string = match enc.encryption_type as u8 {
         0 => "UNSECURE",
         1 => "WIRED",
@@ -1680,71 +2788,91 @@ fn dump_encryption_type(enc: &EncryptionDescriptor, indent: usize) {
         _ => "RESERVED",
     };
 
-    dump_string("Encryption Type:", indent);
-    dump_value(enc.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
+    dump_string(out, "Encryption Type:", indent)?;
+    dump_value(out, enc.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         enc.descriptor_type,
         "bDescriptorType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value_string(
+        out,
         enc.encryption_type as u8,
         "bEncryptionType",
         enct_string,
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         enc.encryption_value,
         "bEncryptionValue",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         enc.auth_key_index,
         "bAuthKeyIndex",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
+
+    Ok(())
 }
 
-fn dump_interface_association(iad: &InterfaceAssociationDescriptor, indent: usize) {
-    dump_string("Interface Association:", indent);
-    dump_value(iad.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
+fn dump_interface_association(
+    out: &mut DumpWriter,
+    iad: &InterfaceAssociationDescriptor,
+    indent: usize,
+) -> fmt::Result {
+    dump_string(out, "Interface Association:", indent)?;
+    dump_value(out, iad.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         iad.descriptor_type,
         "bDescriptorType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         iad.first_interface,
         "bFirstInterface",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         iad.interface_count,
         "bInterfaceCount",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value_string(
+        out,
         iad.function_class,
         "bFunctionClass",
-        names::class(iad.function_class).unwrap_or_default(),
+        name_or_unknown(
+            names::class(iad.function_class),
+            "class",
+            iad.function_class,
+        ),
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value_string(
+        out,
         iad.function_sub_class,
         "bFunctionSubClass",
         names::subclass(iad.function_class, iad.function_sub_class).unwrap_or_default(),
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value_string(
+        out,
         iad.function_protocol,
         "bFunctionProtocol",
         names::protocol(
@@ -1755,53 +2883,65 @@ fn dump_interface_association(iad: &InterfaceAssociationDescriptor, indent: usiz
         .unwrap_or_default(),
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value_string(
+        out,
         iad.function_string_index,
         "iFunction",
         iad.function_string.as_ref().unwrap_or(&String::new()),
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
+
+    Ok(())
 }
 
-fn dump_hid_device(hidd: &HidDescriptor, indent: usize) {
-    dump_string("HID Descriptor:", indent);
-    dump_value(hidd.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
+fn dump_hid_device(out: &mut DumpWriter, hidd: &HidDescriptor, indent: usize) -> fmt::Result {
+    dump_string(out, "HID Descriptor:", indent)?;
+    dump_value(out, hidd.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         hidd.descriptor_type,
         "bDescriptorType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
-    dump_value(hidd.bcd_hid, "bcdHID", indent + 2, LSUSB_DUMP_WIDTH);
+    )?;
+    dump_value(out, hidd.bcd_hid, "bcdHID", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value_string(
+        out,
         hidd.country_code,
         "bCountryCode",
-        names::countrycode(hidd.country_code).unwrap_or_default(),
+        hidd.country_name(),
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         hidd.descriptors.len(),
         "bNumDescriptors",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     for desc in &hidd.descriptors {
         dump_value_string(
+            out,
             desc.descriptor_type,
             "bDescriptorType",
-            names::hid(desc.descriptor_type).unwrap_or_default(),
+            name_or_unknown(
+                names::hid(desc.descriptor_type),
+                "descriptor type",
+                desc.descriptor_type,
+            ),
             indent + 2,
             LSUSB_DUMP_WIDTH,
-        );
+        )?;
         dump_value(
+            out,
             desc.length,
             "wDescriptorLength",
             indent + 2,
             LSUSB_DUMP_WIDTH,
-        );
+        )?;
     }
 
     for desc in &hidd.descriptors {
@@ -1812,102 +2952,173 @@ fn dump_hid_device(hidd: &HidDescriptor, indent: usize) {
 
         match desc.data.as_ref() {
             Some(d) => {
-                dump_report_desc(d, indent + 2);
+                dump_report_desc(out, d, indent + 2)?;
             }
             None => {
-                dump_string("Report Descriptors:", indent + 2);
-                dump_string("** UNAVAILABLE **", indent + 4);
+                dump_string(out, "Report Descriptors:", indent + 2)?;
+                dump_string(out, "** UNAVAILABLE **", indent + 4)?;
             }
         }
     }
+
+    Ok(())
 }
 
-fn dump_device_qualifier(dqd: &DeviceQualifierDescriptor, indent: usize) {
-    dump_string("Device Qualifier:", indent);
-    dump_value(dqd.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
+fn dump_device_qualifier(
+    out: &mut DumpWriter,
+    dqd: &DeviceQualifierDescriptor,
+    indent: usize,
+) -> fmt::Result {
+    dump_string(out, "Device Qualifier (for other device speed):", indent)?;
+    dump_value(out, dqd.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         dqd.descriptor_type,
         "bDescriptorType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
-    dump_value(dqd.version, "bcdUSB", indent + 2, LSUSB_DUMP_WIDTH);
-    let class: u8 = dqd.device_class as u8;
+    )?;
+    dump_value(out, dqd.version, "bcdUSB", indent + 2, LSUSB_DUMP_WIDTH)?;
+    let class: u8 = dqd.device_class.into();
     dump_value_string(
+        out,
         class,
         "bDeviceClass",
-        dqd.device_class,
+        name_or_unknown(names::class(class), "class", class),
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value_string(
+        out,
         dqd.device_subclass,
         "bDeviceSubClass",
-        names::subclass(class, dqd.device_subclass).unwrap_or(String::from("[unknown]")),
+        name_or_unknown(
+            names::subclass(class, dqd.device_subclass),
+            "subclass",
+            dqd.device_subclass,
+        ),
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value_string(
+        out,
         dqd.device_protocol,
         "bDeviceProtocol",
-        names::protocol(class, dqd.device_subclass, dqd.device_protocol)
-            .unwrap_or(String::from("[unknown]")),
+        name_or_unknown(
+            names::protocol(class, dqd.device_subclass, dqd.device_protocol),
+            "protocol",
+            dqd.device_protocol,
+        ),
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         dqd.max_packet_size,
         "bMaxPacketSize0",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         dqd.num_configurations,
         "bNumConfigurations",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
+
+    Ok(())
 }
 
-fn dump_debug(dd: &DebugDescriptor, indent: usize) {
-    dump_string("Debug Descriptor:", indent);
-    dump_value(dd.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
+fn dump_debug(out: &mut DumpWriter, dd: &DebugDescriptor, indent: usize) -> fmt::Result {
+    dump_string(out, "Debug Descriptor:", indent)?;
+    dump_value(out, dd.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         dd.descriptor_type,
         "bDescriptorType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_hex(
+        out,
         dd.debug_in_endpoint,
         "bDebugInEndpoint",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_hex(
+        out,
         dd.debug_out_endpoint,
         "bDebugOutEndpoint",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
+
+    Ok(())
+}
+
+fn dump_interface_power(
+    out: &mut DumpWriter,
+    ipd: &InterfacePowerDescriptor,
+    indent: usize,
+) -> fmt::Result {
+    dump_string(out, "Interface Power Descriptor:", indent)?;
+    dump_value(out, ipd.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
+    dump_value(
+        out,
+        ipd.descriptor_type,
+        "bDescriptorType",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    if let Some(capabilities) = ipd.capabilities {
+        dump_hex(
+            out,
+            capabilities,
+            "bmCapabilitiesFlags",
+            indent + 2,
+            LSUSB_DUMP_WIDTH,
+        )?;
+        dump_bitmap_strings(
+            out,
+            capabilities,
+            InterfacePowerDescriptor::capability_name,
+            indent + 4,
+        )?;
+    }
+    if !ipd.data.is_empty() {
+        dump_unrecognised(out, &ipd.data, indent + 2)?;
+    }
+
+    Ok(())
 }
 
-fn dump_otg(otg: &OnTheGoDescriptor, indent: usize) {
-    dump_string("OTG Descriptor:", indent);
-    dump_value(otg.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
+fn dump_otg(out: &mut DumpWriter, otg: &OnTheGoDescriptor, indent: usize) -> fmt::Result {
+    dump_string(out, "OTG Descriptor:", indent)?;
+    dump_value(out, otg.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         otg.descriptor_type,
         "bDescriptorType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
-    dump_hex(otg.attributes, "bmAttributes", indent + 2, LSUSB_DUMP_WIDTH);
+    )?;
+    dump_hex(
+        out,
+        otg.attributes,
+        "bmAttributes",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
     if otg.attributes & 0x01 != 0 {
-        dump_string("SRP (Session Request Protocol)", indent + 4);
+        dump_string(out, "SRP (Session Request Protocol)", indent + 4)?;
     }
     if otg.attributes & 0x02 != 0 {
-        dump_string("HNP (Host Negotiation Protocol)", indent + 4);
+        dump_string(out, "HNP (Host Negotiation Protocol)", indent + 4)?;
     }
+
+    Ok(())
 }
 
 const LINK_STATE_DESCRIPTIONS: [&str; 12] = [
@@ -1930,120 +3141,109 @@ fn bitmap_strings_port<T>(bitmap: T, strings_f: fn(usize) -> Option<&'static str
 where
     T: std::fmt::Display + std::fmt::LowerHex + Copy + Into<u64>,
 {
-    let bitmap_u64: u64 = bitmap.into();
-    let num_bits = std::mem::size_of::<T>() * 8;
-    let mut ret = String::new();
-    for index in (0..num_bits).rev() {
-        if (bitmap_u64 >> index) & 0x1 != 0 {
-            if let Some(string) = strings_f(index) {
-                ret.push_str(string);
-                ret.push(' ');
-            }
-        }
-    }
-
-    ret
+    decode_bitmap_strings(bitmap, strings_f)
+        .iter()
+        .rev()
+        .fold(String::new(), |mut ret, string| {
+            ret.push_str(string);
+            ret.push(' ');
+            ret
+        })
 }
 
-fn dump_hub(hd: &HubDescriptor, protocol: u8, bcd: u16, has_ssp: bool, indent: usize) {
+fn dump_hub(
+    out: &mut DumpWriter,
+    hd: &HubDescriptor,
+    protocol: u8,
+    bcd: u16,
+    has_ssp: bool,
+    indent: usize,
+) -> fmt::Result {
     let is_ext_status = protocol == 3 && bcd >= 0x0310 && has_ssp;
-    dump_string("Hub Descriptor:", indent);
-    dump_value(hd.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
+    dump_string(out, "Hub Descriptor:", indent)?;
+    dump_value(out, hd.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         hd.descriptor_type,
         "bDescriptorType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
-    dump_value(hd.num_ports, "bNbrPorts", indent + 2, LSUSB_DUMP_WIDTH);
+    )?;
+    dump_value(out, hd.num_ports, "bNbrPorts", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_hex(
+        out,
         hd.characteristics,
         "wHubCharacteristics",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
-    match hd.characteristics & 0x03 {
-        0 => println!("{:indent$}Ganged power switching", "", indent = indent + 4),
-        1 => println!(
-            "{:indent$}Per-port power switching",
-            "",
-            indent = indent + 4
-        ),
-        _ => println!(
-            "{:indent$}No power switching (usb 1.0)",
-            "",
-            indent = indent + 4
-        ),
-    }
-    match hd.characteristics & 0x04 {
-        0 => println!(
-            "{:indent$}Ganged overcurrent protection",
-            "",
-            indent = indent + 4
-        ),
-        1 => println!(
-            "{:indent$}Per-port overcurrent protection",
-            "",
-            indent = indent + 4
-        ),
-        _ => println!(
-            "{:indent$}No overcurrent protection",
-            "",
-            indent = indent + 4
-        ),
+    )?;
+    dump_string(out, &hd.power_switching_mode().to_string(), indent + 4)?;
+    if hd.compound_device() {
+        dump_string(out, "Compound device", indent + 4)?;
     }
+    dump_string(
+        out,
+        &hd.over_current_protection_mode().to_string(),
+        indent + 4,
+    )?;
 
-    if (1..=3).contains(&protocol) {
-        let l = (hd.characteristics >> 5) & 0x03;
+    // full speed hubs do not have TTs and USB 3.0 hubs do not have TTs or port indicators
+    if let Some(think_time) = hd.tt_think_time().filter(|_| (1..3).contains(&protocol)) {
         dump_string(
-            &format!("TT think time {} FS bits", (l + 1) * 8),
+            out,
+            &format!("TT think time {} FS bits", think_time),
             indent + 4,
-        );
+        )?;
     }
-    if protocol != 3 && hd.characteristics & (1 << 7) != 0 {
-        dump_string("Port indicators", indent + 4);
+    if protocol != 3 && hd.port_indicators() {
+        dump_string(out, "Port indicators", indent + 4)?;
     }
     dump_value_string(
+        out,
         hd.power_on_to_power_good,
         "bPwrOn2PwrGood",
         "* 2 milli seconds",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     if protocol == 3 {
         dump_value_string(
+            out,
             (hd.control_current as u32) * 4,
             "bHubContrCurrent",
             "milli Ampere",
             indent + 2,
             LSUSB_DUMP_WIDTH,
-        );
+        )?;
     } else {
         dump_value_string(
+            out,
             hd.control_current,
             "bHubContrCurrent",
             "milli Ampere",
             indent + 2,
             LSUSB_DUMP_WIDTH,
-        );
+        )?;
     }
 
     let offset = if protocol == 3 {
         dump_value_string(
+            out,
             format!("0.{:1}", hd.latency().unwrap_or(0)),
             "bHubDecLat",
             "micro seconds",
             indent + 2,
             LSUSB_DUMP_WIDTH,
-        );
+        )?;
         dump_value_string(
+            out,
             hd.delay().unwrap_or(0),
             "wHubDelay",
             "nano seconds",
             indent + 2,
             LSUSB_DUMP_WIDTH,
-        );
+        )?;
         3
     } else {
         0
@@ -2055,6 +3255,7 @@ fn dump_hub(hd: &HubDescriptor, protocol: u8, bcd: u16, has_ssp: bool, indent: u
         l = 3;
     }
     dump_value(
+        out,
         hd.data
             .iter()
             .skip(offset)
@@ -2065,9 +3266,10 @@ fn dump_hub(hd: &HubDescriptor, protocol: u8, bcd: u16, has_ssp: bool, indent: u
         "DeviceRemovable",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     if protocol != 3 {
         dump_value(
+            out,
             hd.data
                 .iter()
                 .skip(offset + l)
@@ -2078,11 +3280,11 @@ fn dump_hub(hd: &HubDescriptor, protocol: u8, bcd: u16, has_ssp: bool, indent: u
             "PortPwrCtrlMask",
             indent + 2,
             LSUSB_DUMP_WIDTH,
-        );
+        )?;
     }
 
     if let Some(ps) = hd.port_statuses.as_ref() {
-        dump_string("Hub Port Status:", indent + 2);
+        dump_string(out, "Hub Port Status:", indent + 2)?;
         for (i, p) in ps.iter().enumerate() {
             let port_status_string = format!(
                 "Port {}: {:02x}{:02x}.{:02x}{:02x}",
@@ -2119,12 +3321,13 @@ fn dump_hub(hd: &HubDescriptor, protocol: u8, bcd: u16, has_ssp: bool, indent: u
                     _ => None,
                 });
                 dump_string(
+                    out,
                     &format!(
                         "{}: {}{}{}",
                         port_status_string, s2_string, s1_string, s0_string
                     ),
                     indent + 4,
-                );
+                )?;
             } else {
                 let link_state = (((p[0] & 0xe0) >> 5) + ((p[1] & 0x01) << 3)) as usize;
                 let s2_string = bitmap_strings_port(p[2], |b| match b {
@@ -2154,6 +3357,7 @@ fn dump_hub(hd: &HubDescriptor, protocol: u8, bcd: u16, has_ssp: bool, indent: u
                 });
                 if link_state < LINK_STATE_DESCRIPTIONS.len() {
                     dump_string(
+                        out,
                         &format!(
                             "{}: {}{}{}{}",
                             port_status_string,
@@ -2163,90 +3367,115 @@ fn dump_hub(hd: &HubDescriptor, protocol: u8, bcd: u16, has_ssp: bool, indent: u
                             s0_string
                         ),
                         indent + 4,
-                    );
+                    )?;
                 } else {
                     dump_string(
+                        out,
                         &format!(
                             "{}: {}{}{}",
                             port_status_string, s2_string, s1_string, s0_string
                         ),
                         indent + 4,
-                    );
+                    )?;
                 }
             }
 
             if is_ext_status && (p[0] & 0x01 == 0x01) {
                 dump_string(
+                    out,
                     &format!(
                         "Ext Status: {:02x}{:02x}{:02x}{:02x}",
                         p[7], p[6], p[5], p[4]
                     ),
                     indent + 8,
-                );
+                )?;
                 dump_string(
+                    out,
                     &format!(
                         "RX Speed Attribute ID: {} Lanes: {}",
                         p[4] & 0x0f,
                         (p[5] & 0x0f) + 1
                     ),
                     indent + 8,
-                );
+                )?;
                 dump_string(
+                    out,
                     &format!(
                         "TX Speed Attribute ID: {} Lanes: {}",
                         (p[4] >> 4) & 0x0f,
                         ((p[5] >> 4) & 0x0f) + 1
                     ),
                     indent + 8,
-                );
+                )?;
             }
         }
     }
+
+    Ok(())
 }
 
-fn dump_device_status(status: u16, otg: bool, super_speed: bool, indent: usize) {
-    dump_hex(status, "Device Status:", indent, LSUSB_DUMP_WIDTH);
+fn dump_device_status(
+    out: &mut DumpWriter,
+    status: u16,
+    otg: bool,
+    super_speed: bool,
+    indent: usize,
+) -> fmt::Result {
+    dump_hex(out, status, "Device Status:", indent, LSUSB_DUMP_WIDTH)?;
     if status & 0x01 != 0 {
-        println!("{:indent$}Self Powered", "", indent = indent + 2);
+        writeln!(out, "{:indent$}Self Powered", "", indent = indent + 2)?;
     } else {
-        println!("{:indent$}(Bus Powered)", "", indent = indent + 2);
+        writeln!(out, "{:indent$}(Bus Powered)", "", indent = indent + 2)?;
     }
     if status & 0x02 != 0 {
-        println!("{:indent$}Remote Wakeup Enabled", "", indent = indent + 2);
+        writeln!(
+            out,
+            "{:indent$}Remote Wakeup Enabled",
+            "",
+            indent = indent + 2
+        )?;
     }
     if super_speed {
         if status & (1 << 2) != 0 {
-            println!("{:indent$}U1 Enabled", "", indent = indent + 2);
+            writeln!(out, "{:indent$}U1 Enabled", "", indent = indent + 2)?;
         }
         if status & (1 << 3) != 0 {
-            println!("{:indent$}U2 Enabled", "", indent = indent + 2);
+            writeln!(out, "{:indent$}U2 Enabled", "", indent = indent + 2)?;
         }
         if status & (1 << 4) != 0 {
-            println!(
+            writeln!(
+                out,
                 "{:indent$}Latency Tolerance Messaging (LTM) Enabled",
                 "",
                 indent = indent + 2
-            );
+            )?;
         }
     }
     if otg {
         if status & (1 << 3) != 0 {
-            println!("{:indent$}HNP Enabled", "", indent = indent + 2);
+            writeln!(out, "{:indent$}HNP Enabled", "", indent = indent + 2)?;
         }
         if status & (1 << 4) != 0 {
-            println!("{:indent$}HNP Capable", "", indent = indent + 2);
+            writeln!(out, "{:indent$}HNP Capable", "", indent = indent + 2)?;
         }
         if status & (1 << 5) != 0 {
-            println!("{:indent$}ALT port is HNP Capable", "", indent = indent + 2);
+            writeln!(
+                out,
+                "{:indent$}ALT port is HNP Capable",
+                "",
+                indent = indent + 2
+            )?;
         }
     }
     if status & (1 << 6) != 0 {
-        println!("{:indent$}Debug Mode", "", indent = indent + 2);
+        writeln!(out, "{:indent$}Debug Mode", "", indent = indent + 2)?;
     }
+
+    Ok(())
 }
 
 /// Verbatum port of lsusb's dump_unit - not very Rust, don't judge!
-fn dump_unit(mut data: u16, len: usize, indent: usize) {
+fn dump_unit(out: &mut DumpWriter, mut data: u16, len: usize, indent: usize) -> fmt::Result {
     let systems = |t: u16| match t {
         0x01 => "SI Linear",
         0x02 => "SI Rotation",
@@ -2277,15 +3506,15 @@ fn dump_unit(mut data: u16, len: usize, indent: usize) {
 
     if sys > 4 {
         if sys == 0xf {
-            println!("{:indent$}System: Vendor defined, Unit: (unknown)", "");
+            writeln!(out, "{:indent$}System: Vendor defined, Unit: (unknown)", "")?;
         } else {
-            println!("{:indent$}System: Reserved, Unit: (unknown)", "");
+            writeln!(out, "{:indent$}System: Reserved, Unit: (unknown)", "")?;
         }
 
-        return;
+        return Ok(());
     }
 
-    print!("{:indent$}System: {}, Unit: ", "", systems(sys));
+    write!(out, "{:indent$}System: {}, Unit: ", "", systems(sys))?;
 
     let mut earlier_unit = 0;
 
@@ -2294,9 +3523,9 @@ fn dump_unit(mut data: u16, len: usize, indent: usize) {
         data >>= 4;
         if nibble != 0 {
             if earlier_unit > 0 {
-                print!("*");
+                write!(out, "*")?;
             }
-            print!("{}", units(sys, i));
+            write!(out, "{}", units(sys, i))?;
             earlier_unit += 1;
             /* This is a _signed_ nibble(!) */
             if nibble != 1 {
@@ -2304,19 +3533,21 @@ fn dump_unit(mut data: u16, len: usize, indent: usize) {
                 if nibble & 0x08 != 0x00 {
                     val = -((0x7 & !val) + 1);
                 }
-                print!("^{}", val);
+                write!(out, "^{}", val)?;
             }
         }
     }
 
     if earlier_unit == 0 {
-        print!("(None)");
+        write!(out, "(None)")?;
     }
-    println!();
+    writeln!(out)?;
+
+    Ok(())
 }
 
 /// Dumps HID report data ported directly from lsusb - it's not pretty but works...
-fn dump_report_desc(desc: &[u8], indent: usize) {
+fn dump_report_desc(out: &mut DumpWriter, desc: &[u8], indent: usize) -> fmt::Result {
     // ported from lsusb - indented to 28 spaces for some reason...
     const REPORT_INDENT: usize = 12;
     let types = |t: u8| match t {
@@ -2327,9 +3558,10 @@ fn dump_report_desc(desc: &[u8], indent: usize) {
     };
 
     dump_string(
+        out,
         &format!("Report Descriptor: (length is {})", desc.len()),
         indent,
-    );
+    )?;
 
     let mut i = 0;
     while i < desc.len() {
@@ -2344,30 +3576,40 @@ fn dump_report_desc(desc: &[u8], indent: usize) {
         let btag = b & !0x03;
 
         // Item Header
-        print!(
+        write!(
+            out,
             "{:indent$}Item({:>6}): {}, data=",
             "",
             types(btype >> 2),
-            names::report_tag(btag).unwrap_or_default(),
+            name_or_unknown(names::report_tag(btag), "tag", btag),
             indent = indent + 2
-        );
+        )?;
 
         // Check for descriptor bounds
         if i + bsize >= desc.len() {
-            println!("Error: Descriptor too short");
+            let warning = DumpWarning {
+                kind: DumpWarningKind::TooShort,
+                descriptor: "Report".into(),
+                offset: i,
+                message: "Descriptor too short".into(),
+            };
+            // ends the item line whether printed or not
+            if dump_warning(out, warning, 0)? {
+                writeln!(out)?;
+            }
             break;
         }
 
         if bsize > 0 {
-            print!(" [ ");
+            write!(out, " [ ")?;
             data = 0;
             for j in 0..bsize {
                 data |= (desc[i + 1 + j] as u32) << (j * 8);
-                print!("{:02x} ", desc[i + 1 + j]);
+                write!(out, "{:02x} ", desc[i + 1 + j])?;
             }
-            println!("] {}", data);
+            writeln!(out, "] {}", data)?;
         } else {
-            println!("none");
+            writeln!(out, "none")?;
         }
 
         match btag {
@@ -2375,40 +3617,41 @@ fn dump_report_desc(desc: &[u8], indent: usize) {
             0x04 => {
                 hut = data as u8;
                 if let Some(hut) = names::huts(hut) {
-                    println!("{:indent$}{}", "", hut, indent = REPORT_INDENT);
+                    writeln!(out, "{:indent$}{}", "", hut, indent = REPORT_INDENT)?;
                 }
             }
             // usage, usage minimum, usage maximum
             0x08 | 0x18 | 0x28 => {
                 if let Some(hutus) = names::hutus(hut, data as u16) {
-                    println!("{:indent$}{}", "", hutus, indent = REPORT_INDENT);
+                    writeln!(out, "{:indent$}{}", "", hutus, indent = REPORT_INDENT)?;
                 }
             }
             // unit exponent
             0x54 => {
-                println!(
+                writeln!(
+                    out,
                     "{:indent$}Unit Exponent: {}",
                     "",
                     data as u8,
                     indent = REPORT_INDENT
-                );
+                )?;
             }
             // unit
-            0x64 => dump_unit(data as u16, bsize, REPORT_INDENT),
+            0x64 => dump_unit(out, data as u16, bsize, REPORT_INDENT)?,
             // collection
             0xa0 => match data {
-                0x00 => println!("{:indent$}Physical", "", indent = REPORT_INDENT),
-                0x01 => println!("{:indent$}Application", "", indent = REPORT_INDENT),
-                0x02 => println!("{:indent$}Logical", "", indent = REPORT_INDENT),
-                0x03 => println!("{:indent$}Report", "", indent = REPORT_INDENT),
-                0x04 => println!("{:indent$}Named Array", "", indent = REPORT_INDENT),
-                0x05 => println!("{:indent$}Usage Switch", "", indent = REPORT_INDENT),
-                0x06 => println!("{:indent$}Usage Modifier", "", indent = REPORT_INDENT),
+                0x00 => writeln!(out, "{:indent$}Physical", "", indent = REPORT_INDENT)?,
+                0x01 => writeln!(out, "{:indent$}Application", "", indent = REPORT_INDENT)?,
+                0x02 => writeln!(out, "{:indent$}Logical", "", indent = REPORT_INDENT)?,
+                0x03 => writeln!(out, "{:indent$}Report", "", indent = REPORT_INDENT)?,
+                0x04 => writeln!(out, "{:indent$}Named Array", "", indent = REPORT_INDENT)?,
+                0x05 => writeln!(out, "{:indent$}Usage Switch", "", indent = REPORT_INDENT)?,
+                0x06 => writeln!(out, "{:indent$}Usage Modifier", "", indent = REPORT_INDENT)?,
                 _ => {
                     if (data & 0x80) == 0x80 {
-                        println!("{:indent$}Vendor defined", "", indent = REPORT_INDENT)
+                        writeln!(out, "{:indent$}Vendor defined", "", indent = REPORT_INDENT)?
                     } else {
-                        println!("{:indent$}Unknown", "", indent = REPORT_INDENT)
+                        writeln!(out, "{:indent$}Unknown", "", indent = REPORT_INDENT)?
                     }
                 }
             },
@@ -2462,18 +3705,380 @@ fn dump_report_desc(desc: &[u8], indent: usize) {
                     },
                     indent = REPORT_INDENT
                 );
-                println!("{}", attributes_1);
-                println!("{}", attributes_2);
+                writeln!(out, "{}", attributes_1)?;
+                writeln!(out, "{}", attributes_2)?;
             }
             _ => (),
         }
         i += 1 + bsize;
     }
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::path::PathBuf;
+
+    /// Class specific interface descriptor `subtype` with `data` following it, decoded with the interface class `triplet`; bDescriptorType is the Interface the profiler masks CS_INTERFACE to
+    fn cs_interface(triplet: (u8, u8, u8), subtype: u8, data: &[u8]) -> Descriptor {
+        Descriptor::Interface(
+            ClassDescriptor::builder(0x04, subtype)
+                .data(data)
+                .class_context(triplet)
+                .build()
+                .unwrap(),
+        )
+    }
+
+    /// Name of the golden file and Audio Control and Audio Streaming descriptor for golden dump tests
+    fn uac_fixtures() -> Vec<(&'static str, Descriptor)> {
+        vec![
+            (
+                "uac1_header",
+                cs_interface((1, 1, 0x00), 0x01, &[0x00, 0x01, 0x1e, 0x00, 0x01, 0x01]),
+            ),
+            (
+                "uac1_input_terminal",
+                cs_interface(
+                    (1, 1, 0x00),
+                    0x02,
+                    &[0x01, 0x01, 0x02, 0x00, 0x02, 0x03, 0x00, 0x00, 0x00],
+                ),
+            ),
+            (
+                "uac1_output_terminal",
+                cs_interface((1, 1, 0x00), 0x03, &[0x03, 0x01, 0x01, 0x00, 0x02, 0x00]),
+            ),
+            (
+                "uac1_mixer_unit",
+                cs_interface(
+                    (1, 1, 0x00),
+                    0x04,
+                    &[0x04, 0x02, 0x01, 0x02, 0x02, 0x03, 0x00, 0x00, 0x01, 0x00],
+                ),
+            ),
+            (
+                "uac1_selector_unit",
+                cs_interface((1, 1, 0x00), 0x05, &[0x05, 0x01, 0x04, 0x00]),
+            ),
+            (
+                "uac1_feature_unit",
+                cs_interface(
+                    (1, 1, 0x00),
+                    0x06,
+                    &[0x02, 0x01, 0x01, 0x01, 0x02, 0x02, 0x00],
+                ),
+            ),
+            (
+                "uac1_processing_unit",
+                cs_interface(
+                    (1, 1, 0x00),
+                    0x07,
+                    &[
+                        0x06, 0x01, 0x00, 0x01, 0x05, 0x02, 0x03, 0x00, 0x00, 0x01, 0x01, 0x00,
+                        0x01, 0x03, 0x00,
+                    ],
+                ),
+            ),
+            (
+                "uac1_extension_unit",
+                cs_interface(
+                    (1, 1, 0x00),
+                    0x08,
+                    &[
+                        0x07, 0x34, 0x12, 0x01, 0x06, 0x02, 0x03, 0x00, 0x00, 0x01, 0x01, 0x00,
+                    ],
+                ),
+            ),
+            (
+                "uac1_as_general",
+                cs_interface((1, 2, 0x00), 0x01, &[0x01, 0x01, 0x01, 0x00]),
+            ),
+            (
+                "uac1_format_type_i",
+                cs_interface(
+                    (1, 2, 0x00),
+                    0x02,
+                    &[0x01, 0x02, 0x02, 0x10, 0x01, 0x80, 0xbb, 0x00],
+                ),
+            ),
+            (
+                "uac2_header",
+                cs_interface((1, 1, 0x20), 0x01, &[0x00, 0x02, 0x08, 0x40, 0x00, 0x00]),
+            ),
+            (
+                "uac2_clock_source",
+                cs_interface((1, 1, 0x20), 0x0a, &[0x10, 0x03, 0x07, 0x00, 0x00]),
+            ),
+            (
+                "uac2_clock_selector",
+                cs_interface((1, 1, 0x20), 0x0b, &[0x11, 0x01, 0x10, 0x03, 0x00]),
+            ),
+            (
+                "uac2_input_terminal",
+                cs_interface(
+                    (1, 1, 0x20),
+                    0x02,
+                    &[
+                        0x01, 0x01, 0x01, 0x00, 0x10, 0x02, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+                        0x00, 0x00,
+                    ],
+                ),
+            ),
+            (
+                "uac2_output_terminal",
+                cs_interface(
+                    (1, 1, 0x20),
+                    0x03,
+                    &[0x03, 0x01, 0x03, 0x00, 0x02, 0x10, 0x00, 0x00, 0x00],
+                ),
+            ),
+            (
+                "uac2_selector_unit",
+                cs_interface((1, 1, 0x20), 0x05, &[0x05, 0x01, 0x04, 0x03, 0x00]),
+            ),
+            (
+                "uac2_feature_unit",
+                cs_interface(
+                    (1, 1, 0x20),
+                    0x06,
+                    &[
+                        0x02, 0x01, 0x0f, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+                        0x00, 0x00, 0x00,
+                    ],
+                ),
+            ),
+            (
+                "uac2_as_general",
+                cs_interface(
+                    (1, 2, 0x20),
+                    0x01,
+                    &[
+                        0x01, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00, 0x02, 0x03, 0x00, 0x00, 0x00,
+                        0x00,
+                    ],
+                ),
+            ),
+            (
+                "uac2_format_type_i",
+                cs_interface((1, 2, 0x20), 0x02, &[0x01, 0x02, 0x10]),
+            ),
+            (
+                "uac3_header",
+                cs_interface(
+                    (1, 1, 0x30),
+                    0x01,
+                    &[0x08, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00],
+                ),
+            ),
+            (
+                "uac3_input_terminal",
+                cs_interface(
+                    (1, 1, 0x30),
+                    0x02,
+                    &[
+                        0x01, 0x01, 0x02, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+                        0x00, 0x00, 0x00, 0x00, 0x00,
+                    ],
+                ),
+            ),
+            (
+                "uac3_feature_unit",
+                cs_interface(
+                    (1, 1, 0x30),
+                    0x07,
+                    &[
+                        0x02, 0x01, 0x0f, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+                        0x00, 0x00, 0x00, 0x00,
+                    ],
+                ),
+            ),
+            (
+                "uac3_clock_selector",
+                cs_interface(
+                    (1, 1, 0x30),
+                    0x0c,
+                    &[0x11, 0x01, 0x10, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00],
+                ),
+            ),
+            (
+                "uac3_clock_source",
+                cs_interface(
+                    (1, 1, 0x30),
+                    0x0b,
+                    &[0x10, 0x03, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+                ),
+            ),
+            (
+                "uac3_power_domain",
+                cs_interface(
+                    (1, 1, 0x30),
+                    0x10,
+                    &[0x01, 0x0a, 0x00, 0x64, 0x00, 0x01, 0x02, 0x00, 0x00, 0x00],
+                ),
+            ),
+        ]
+    }
+
+    /// Compare `output` with the golden file tests/data/dumps/`name`.txt; run with `UPDATE_GOLDEN=1` to write the golden files instead
+    fn assert_golden(name: &str, output: &str) {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/data/dumps")
+            .join(format!("{}.txt", name));
+        if std::env::var_os("UPDATE_GOLDEN").is_some() {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, output).unwrap();
+            return;
+        }
+        let golden = std::fs::read_to_string(&path).unwrap_or_else(|e| {
+            panic!(
+                "{}: {}; run with UPDATE_GOLDEN=1 to create",
+                path.display(),
+                e
+            )
+        });
+        assert_eq!(
+            output,
+            golden,
+            "dump of {} differs from {}",
+            name,
+            path.display()
+        );
+    }
+
+    /// Output of the dump `f` writes
+    fn dumped<F: FnOnce(&mut DumpWriter) -> fmt::Result>(f: F) -> String {
+        dumped_with(DumpContext::default(), f)
+    }
+
+    /// Output of the dump `f` writes with `ctx`
+    fn dumped_with<F: FnOnce(&mut DumpWriter) -> fmt::Result>(ctx: DumpContext, f: F) -> String {
+        let mut out = String::new();
+        f(&mut DumpWriter::new(&mut out, ctx)).unwrap();
+        out
+    }
+
+    #[test]
+    fn test_uac_dump_golden() {
+        for (name, dt) in uac_fixtures() {
+            assert_golden(name, &dumped(|out| dump_interface_extra(out, &dt, 4)));
+        }
+    }
+
+    #[test]
+    fn test_dump_uac3_connectors() {
+        let connectors = audio::ConnectorsDescriptor3::try_from(
+            [
+                0x12, 0x00, 0x24, 0x0f, 0x02, 0x00, 0x01, 0x01, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00,
+                0x00, 0xff, 0x00, 0x00,
+            ]
+            .as_slice(),
+        )
+        .unwrap();
+        let dump = dumped(|out| dump_uac3_connectors(out, 2, Some(&connectors), 4));
+        assert!(dump.contains("dwConColor    0x0000ff00 Green (line out)"));
+
+        let dump = dumped(|out| dump_uac3_connectors(out, 2, None, 4));
+        assert!(dump.trim_start().starts_with("wConnectorsDescrID"));
+        assert!(dump.trim_end().ends_with("2 (connectors not found)"));
+    }
+
+    #[test]
+    fn test_dump_config_decodes_generic() {
+        let sp_usb = system_profiler::SPUSBDataType::example();
+        for device in sp_usb.flatten_devices() {
+            for config in &device.extra.as_ref().unwrap().configurations {
+                // as if built by hand without the class context the profiler applies
+                let mut generic = config.to_owned();
+                for interface in generic.interfaces.iter_mut() {
+                    for dt in interface.extra.iter_mut().flatten() {
+                        if let Descriptor::Interface(
+                            cd @ (ClassDescriptor::Audio(..)
+                            | ClassDescriptor::Midi(..)
+                            | ClassDescriptor::Video(..)),
+                        ) = dt
+                        {
+                            let bytes: Vec<u8> = cd.to_owned().into();
+                            *cd = ClassDescriptor::try_from(bytes.as_slice()).unwrap();
+                        }
+                    }
+                }
+
+                let mut expected = Vec::new();
+                dump_config(config, &mut expected, LSUSB_DUMP_INDENT_BASE).unwrap();
+                let mut dumped = Vec::new();
+                dump_config(&generic, &mut dumped, LSUSB_DUMP_INDENT_BASE).unwrap();
+                assert_eq!(
+                    String::from_utf8(dumped).unwrap(),
+                    String::from_utf8(expected).unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_dump_style_only_adds_escapes() {
+        let sp_usb = system_profiler::SPUSBDataType::example();
+        let devices = sp_usb.flatten_devices();
+        let plain = dumped(|out| print_devices(out, &devices, Verbosity::Full, false));
+        let ctx = DumpContext {
+            style: DumpStyle::coloured(),
+            ..Default::default()
+        };
+        let coloured = dumped_with(ctx, |out| {
+            print_devices(out, &devices, Verbosity::Full, false)
+        });
+        assert!(!plain.contains('\x1b'));
+        assert!(coloured.contains("\x1b[2mbLength\x1b[0m"));
+
+        let mut stripped = String::new();
+        let mut escapes = coloured.split("\x1b[");
+        stripped.push_str(escapes.next().unwrap());
+        for part in escapes {
+            stripped.push_str(&part[part.find('m').unwrap() + 1..]);
+        }
+        assert_eq!(stripped, plain);
+    }
+
+    #[test]
+    fn test_dump_style_from_settings() {
+        // only the resolved colours decide, so --color always colours a piped dump like the rest of the output
+        let mut settings = PrintSettings {
+            colours: Some(crate::colour::ColourTheme::new()),
+            ..Default::default()
+        };
+        assert_eq!(DumpStyle::from_settings(&settings), DumpStyle::coloured());
+        settings.colours = None;
+        assert_eq!(DumpStyle::from_settings(&settings), DumpStyle::plain());
+    }
+
+    #[test]
+    fn test_dump_warning_collected() {
+        // Usage Page item with its data byte missing
+        let report = [0x05];
+        let inline = dumped(|out| dump_report_desc(out, &report, 0));
+        assert!(inline.contains("data=Error: Descriptor too short\n"));
+
+        let mut warnings = Vec::new();
+        let ctx = DumpContext {
+            warnings: Some(&mut warnings),
+            ..Default::default()
+        };
+        let collected = dumped_with(ctx, |out| dump_report_desc(out, &report, 0));
+        assert!(!collected.contains("too short"));
+        assert!(collected.ends_with("data=\n"));
+        assert_eq!(
+            warnings,
+            vec![DumpWarning {
+                kind: DumpWarningKind::TooShort,
+                descriptor: "Report".into(),
+                offset: 0,
+                message: "Descriptor too short".into(),
+            }]
+        );
+    }
 
     #[test]
     fn test_get_spaces() {
@@ -2482,6 +4087,28 @@ mod tests {
         assert_eq!(get_spaces(2, 17, 20), " ");
         assert_eq!(get_spaces(17, 2, 20), " ");
         assert_eq!(get_spaces(16, 2, 20), "  ");
+        assert_eq!(get_spaces(10, 14, 24), " ");
+    }
+
+    #[test]
+    fn test_name_or_unknown() {
+        assert_eq!(
+            name_or_unknown(names::videoterminal(0x0101), "terminal", 0x0101u16),
+            "USB Streaming"
+        );
+        assert_eq!(
+            name_or_unknown(names::videoterminal(0x0bad), "terminal", 0x0badu16),
+            "(unknown terminal 0x0bad)"
+        );
+        assert_eq!(name_or_unknown(None, "tag", 0x0cu8), "(unknown tag 0x0c)");
+        assert_eq!(
+            name_or_unknown(
+                names::terminal(0x0301, names::TerminalDomain::Audio),
+                "terminal",
+                0x0301u16
+            ),
+            "Speaker"
+        );
     }
 
     #[test]
@@ -2492,7 +4119,73 @@ mod tests {
             .map(|b| format!("{:02x}", b))
             .collect::<Vec<String>>()
             .join(" ");
-        // test no panic since is to stdout
-        dump_value(bytes_string, "bmConfigured", 4, LSUSB_DUMP_WIDTH);
+        let output =
+            dumped(|out| dump_value(out, &bytes_string, "bmConfigured", 4, LSUSB_DUMP_WIDTH));
+        // value longer than the width is separated by a single space
+        assert_eq!(output, format!("    bmConfigured {}\n", bytes_string));
+    }
+
+    #[test]
+    fn test_vendor_decoder() {
+        // VID not used elsewhere in the tests since the registry is global
+        register_vendor_decoder(
+            0xfff0,
+            Box::new(|bytes| {
+                (bytes.get(1) == Some(&0x41))
+                    .then(|| format!("Vendor Descriptor:\n  bVersion {:>16}", bytes[2]))
+            }),
+        );
+        let bytes = [0x03, 0x41, 0x02];
+        let vendor = |vendor_id| DumpContext {
+            vendor_id: Some(vendor_id),
+            ..Default::default()
+        };
+
+        let output = dumped_with(vendor(0xfff0), |out| dump_vendor_descriptor(out, &bytes, 4));
+        assert_eq!(
+            output,
+            "    Vendor Descriptor:\n      bVersion                2\n"
+        );
+        // decoder declines so falls back to hex
+        let output = dumped_with(vendor(0xfff0), |out| {
+            dump_vendor_descriptor(out, &[0x02, 0x42], 4)
+        });
+        assert_eq!(output, "    ** UNRECOGNIZED: 02 42\n");
+
+        let output = dumped_with(vendor(0xfff1), |out| dump_vendor_descriptor(out, &bytes, 4));
+        assert_eq!(output, "    ** UNRECOGNIZED: 03 41 02\n");
+
+        // public dump_config takes the VID from the context
+        let sp_usb = system_profiler::SPUSBDataType::example();
+        let device = sp_usb.get_node("1-1.2").unwrap();
+        let mut config = device.extra.as_ref().unwrap().configurations[0].to_owned();
+        config
+            .extra
+            .get_or_insert_with(Vec::new)
+            .push(Descriptor::Unknown(bytes.to_vec()));
+        let mut out = Vec::new();
+        dump_config_with_context(&config, &mut out, 2, vendor(0xfff0)).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("    Vendor Descriptor:\n      bVersion                2\n"));
+        let mut out = Vec::new();
+        dump_config(&config, &mut out, 2).unwrap();
+        assert!(String::from_utf8(out)
+            .unwrap()
+            .contains("    ** UNRECOGNIZED: 03 41 02\n"));
+
+        assert!(unregister_vendor_decoder(0xfff0).is_some());
+    }
+
+    #[test]
+    fn test_decode_bitmap_strings() {
+        let strings = |i: usize| ["A", "B", "C"].get(i).copied();
+        assert_eq!(decode_bitmap_strings(0x00u8, strings), Vec::<&str>::new());
+        assert_eq!(decode_bitmap_strings(0x07u8, strings), vec!["A", "B", "C"]);
+        // bits without a string are skipped
+        assert_eq!(decode_bitmap_strings(0x8002u16, strings), vec!["B"]);
+        assert_eq!(
+            bitmap_strings_port(0x05u8, |i| ["A", "B", "C"].get(i).copied()),
+            "C A "
+        );
     }
 }
@@ -53,6 +53,23 @@ const UAC1_FEATURE_UNIT_BMCONTROLS: [&str; 13] = [
     "Input gain pad",
     "Phase invert",
 ];
+const UAC2_FEATURE_UNIT_BMCONTROLS: [&str; 15] = [
+    "Mute",
+    "Volume",
+    "Bass",
+    "Mid",
+    "Treble",
+    "Graphic Equalizer",
+    "Automatic Gain",
+    "Delay",
+    "Bass Boost",
+    "Loudness",
+    "Input gain",
+    "Input gain pad",
+    "Phase invert",
+    "Underflow",
+    "Overflow",
+];
 const UAC2_EXTENSION_UNIT_BMCONTROLS: [&str; 4] = ["Enable", "Cluster", "Underflow", "Overflow"];
 const UAC3_EXTENSION_UNIT_BMCONTROLS: [&str; 2] = ["Underflow", "Overflow"];
 const UAC2_CLOCK_SOURCE_BMCONTROLS: [&str; 2] = ["Clock Frequency", "Clock Validity"];
@@ -64,539 +81,806 @@ const UAC3_PROCESSING_UNIT_STEREO_EXTENDER_BMCONTROLS: [&str; 3] =
 const UAC3_PROCESSING_UNIT_MULTI_FUNC_BMCONTROLS: [&str; 2] = ["Underflow", "Overflow"];
 
 fn dump_bitmap_controls<T: Into<u32>>(
+    out: &mut DumpWriter,
     controls: T,
     control_descriptions: &[&'static str],
     desc_type: &audio::ControlType,
     indent: usize,
-) {
+) -> fmt::Result {
     let controls: u32 = controls.into();
     for (index, control) in control_descriptions.iter().enumerate() {
         match desc_type {
             audio::ControlType::BmControl1 => {
                 if (controls >> index) & 0x1 != 0 {
-                    println!("{:indent$}{} Control", "", control, indent = indent);
+                    writeln!(out, "{:indent$}{} Control", "", control, indent = indent)?;
                 }
             }
+            // 0b00 is not present
             audio::ControlType::BmControl2 => {
-                println!(
-                    "{:indent$}{} Control ({})",
-                    "",
-                    control,
-                    audio::ControlSetting::from(((controls >> (index * 2)) & 0x3) as u8),
-                    indent = indent
-                )
+                let setting = ((controls >> (index * 2)) & 0x3) as u8;
+                if setting != 0 {
+                    writeln!(
+                        out,
+                        "{:indent$}{} Control ({})",
+                        "",
+                        control,
+                        audio::ControlSetting::from(setting),
+                        indent = indent
+                    )?
+                }
             }
         }
     }
+
+    Ok(())
 }
 
 fn dump_bitmap_controls_array<T: Into<u32> + std::fmt::Display + Copy>(
+    out: &mut DumpWriter,
     field_name: &str,
     controls: &[T],
     control_descriptions: &[&'static str],
     desc_type: &audio::ControlType,
     indent: usize,
     width: usize,
-) {
+) -> fmt::Result {
     for (i, control) in controls.iter().enumerate() {
         let control = control.to_owned();
         let control: u32 = control.into();
-        dump_value(control, &format!("{}({:2})", field_name, i), indent, width);
-        dump_bitmap_controls(control, control_descriptions, desc_type, indent + 2);
+        dump_value(
+            out,
+            control,
+            &format!("{}({:2})", field_name, i),
+            indent,
+            width,
+        )?;
+        dump_bitmap_controls(out, control, control_descriptions, desc_type, indent + 2)?;
     }
+
+    Ok(())
 }
 
-fn dump_audio_mixer_unit1(mixer_unit: &audio::MixerUnit1, indent: usize, width: usize) {
-    dump_value(mixer_unit.unit_id, "bUnitID", indent, width);
-    dump_value(mixer_unit.nr_in_pins, "bNrInPins", indent, width);
-    dump_array(&mixer_unit.source_ids, "baSourceID", indent, width);
-    dump_value(mixer_unit.nr_channels, "bNrChannels", indent, width);
-    dump_hex(mixer_unit.channel_config, "wChannelConfig", indent, width);
+fn dump_audio_mixer_unit1(
+    out: &mut DumpWriter,
+    mixer_unit: &audio::MixerUnit1,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, mixer_unit.unit_id, "bUnitID", indent, width)?;
+    dump_value(out, mixer_unit.nr_in_pins, "bNrInPins", indent, width)?;
+    dump_array(out, &mixer_unit.source_ids, "baSourceID", indent, width)?;
+    dump_value(out, mixer_unit.nr_channels, "bNrChannels", indent, width)?;
+    dump_hex(
+        out,
+        mixer_unit.channel_config,
+        "wChannelConfig",
+        indent,
+        width,
+    )?;
     let channel_names = audio::UacInterfaceDescriptor::get_channel_name_strings(
         &audio::UacProtocol::Uac1,
         mixer_unit.channel_config as u32,
     );
     for name in channel_names.iter() {
-        println!("{:indent$}{}", "", name, indent = indent + 2);
+        writeln!(out, "{:indent$}{}", "", name, indent = indent + 2)?;
     }
-    dump_value(mixer_unit.channel_names, "iChannelNames", indent, width);
-    dump_bitmap_array(&mixer_unit.controls, "bmControls", indent, width);
-    dump_value(mixer_unit.mixer, "iMixer", indent, width);
+    dump_value_string(
+        out,
+        mixer_unit.channel_names_index,
+        "iChannelNames",
+        mixer_unit.channel_names.as_ref().unwrap_or(&"".into()),
+        indent,
+        width,
+    )?;
+    dump_bitmap_array(out, &mixer_unit.controls, "bmControls", indent, width)?;
+    dump_value_string(
+        out,
+        mixer_unit.mixer_index,
+        "iMixer",
+        mixer_unit.mixer.as_ref().unwrap_or(&"".into()),
+        indent,
+        width,
+    )?;
+
+    Ok(())
 }
 
-fn dump_audio_mixer_unit2(mixer_unit: &audio::MixerUnit2, indent: usize, width: usize) {
-    dump_value(mixer_unit.unit_id, "bUnitID", indent, width);
-    dump_value(mixer_unit.nr_in_pins, "bNrInPins", indent, width);
-    dump_array(&mixer_unit.source_ids, "baSourceID", indent, width);
-    dump_value(mixer_unit.nr_channels, "bNrChannels", indent, width);
-    dump_hex(mixer_unit.channel_config, "bmChannelConfig", indent, width);
+fn dump_audio_mixer_unit2(
+    out: &mut DumpWriter,
+    mixer_unit: &audio::MixerUnit2,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, mixer_unit.unit_id, "bUnitID", indent, width)?;
+    dump_value(out, mixer_unit.nr_in_pins, "bNrInPins", indent, width)?;
+    dump_array(out, &mixer_unit.source_ids, "baSourceID", indent, width)?;
+    dump_value(out, mixer_unit.nr_channels, "bNrChannels", indent, width)?;
+    dump_hex(
+        out,
+        mixer_unit.channel_config,
+        "bmChannelConfig",
+        indent,
+        width,
+    )?;
     let channel_names = audio::UacInterfaceDescriptor::get_channel_name_strings(
         &audio::UacProtocol::Uac2,
         mixer_unit.channel_config,
     );
     for name in channel_names.iter() {
-        println!("{:indent$}{}", "", name, indent = indent + 2);
+        writeln!(out, "{:indent$}{}", "", name, indent = indent + 2)?;
     }
-    dump_value(mixer_unit.channel_names, "iChannelNames", indent, width);
-    dump_bitmap_array(&mixer_unit.mixer_controls, "bmMixerControls", indent, width);
-    dump_hex(mixer_unit.controls, "bmControls", indent, width);
+    dump_value_string(
+        out,
+        mixer_unit.channel_names_index,
+        "iChannelNames",
+        mixer_unit.channel_names.as_ref().unwrap_or(&"".into()),
+        indent,
+        width,
+    )?;
+    dump_bitmap_array(
+        out,
+        &mixer_unit.mixer_controls,
+        "bmMixerControls",
+        indent,
+        width,
+    )?;
+    dump_hex(out, mixer_unit.controls, "bmControls", indent, width)?;
     dump_bitmap_controls(
+        out,
         mixer_unit.controls as u32,
         &UAC2_MIXER_UNIT_BMCONTROLS,
         &audio::ControlType::BmControl2,
         indent + 2,
-    );
-    dump_value(mixer_unit.mixer, "iMixer", indent, width);
+    )?;
+    dump_value_string(
+        out,
+        mixer_unit.mixer_index,
+        "iMixer",
+        mixer_unit.mixer.as_ref().unwrap_or(&"".into()),
+        indent,
+        width,
+    )?;
+
+    Ok(())
 }
 
-fn dump_audio_mixer_unit3(mixer_unit: &audio::MixerUnit3, indent: usize, width: usize) {
-    dump_value(mixer_unit.unit_id, "bUnitID", indent, width);
-    dump_value(mixer_unit.nr_in_pins, "bNrInPins", indent, width);
-    dump_array(&mixer_unit.source_ids, "baSourceID", indent, width);
+fn dump_audio_mixer_unit3(
+    out: &mut DumpWriter,
+    mixer_unit: &audio::MixerUnit3,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, mixer_unit.unit_id, "bUnitID", indent, width)?;
+    dump_value(out, mixer_unit.nr_in_pins, "bNrInPins", indent, width)?;
+    dump_array(out, &mixer_unit.source_ids, "baSourceID", indent, width)?;
     dump_value(
+        out,
         mixer_unit.cluster_descr_id,
         "wClusterDescrID",
         indent,
         width,
-    );
-    dump_bitmap_array(&mixer_unit.mixer_controls, "bmMixerControls", indent, width);
-    dump_hex(mixer_unit.controls, "bmControls", indent, width);
+    )?;
+    dump_bitmap_array(
+        out,
+        &mixer_unit.mixer_controls,
+        "bmMixerControls",
+        indent,
+        width,
+    )?;
+    dump_hex(out, mixer_unit.controls, "bmControls", indent, width)?;
     dump_bitmap_controls(
+        out,
         mixer_unit.controls,
         &UAC3_MIXER_UNIT_BMCONTROLS,
         &audio::ControlType::BmControl2,
         indent + 2,
-    );
-    dump_value(mixer_unit.mixer_descr_str, "wMixerDescrStr", indent, width);
+    )?;
+    dump_value(
+        out,
+        mixer_unit.mixer_descr_str,
+        "wMixerDescrStr",
+        indent,
+        width,
+    )?;
+
+    Ok(())
 }
 
-fn dump_audio_power_domain(power_domain: &audio::PowerDomain, indent: usize, width: usize) {
+fn dump_audio_power_domain(
+    out: &mut DumpWriter,
+    power_domain: &audio::PowerDomain,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
     dump_value(
+        out,
         power_domain.power_domain_id,
         "bPowerDomainID",
         indent,
         width,
-    );
+    )?;
     dump_value(
+        out,
         power_domain.recovery_time_1,
         "waRecoveryTime(1)",
         indent,
         width,
-    );
+    )?;
     dump_value(
+        out,
         power_domain.recovery_time_2,
         "waRecoveryTime(2)",
         indent,
         width,
-    );
-    dump_value(power_domain.nr_entities, "bNrEntities", indent, width);
-    dump_array(&power_domain.entity_ids, "baEntityID", indent, width);
+    )?;
+    dump_value(out, power_domain.nr_entities, "bNrEntities", indent, width)?;
+    dump_array(out, &power_domain.entity_ids, "baEntityID", indent, width)?;
     dump_value(
+        out,
         power_domain.domain_descr_str,
         "wPDomainDescrStr",
         indent,
         width,
-    );
+    )?;
+
+    Ok(())
 }
 
 pub(crate) fn dump_audio_selector_unit1(
+    out: &mut DumpWriter,
     selector_unit: &audio::SelectorUnit1,
     indent: usize,
     width: usize,
-) {
-    dump_value(selector_unit.unit_id, "bUnitID", indent, width);
-    dump_value(selector_unit.nr_in_pins, "bNrInPins", indent, width);
-    dump_array(&selector_unit.source_ids, "baSourceID", indent, width);
+) -> fmt::Result {
+    dump_value(out, selector_unit.unit_id, "bUnitID", indent, width)?;
+    dump_value(out, selector_unit.nr_in_pins, "bNrInPins", indent, width)?;
+    dump_array(out, &selector_unit.source_ids, "baSourceID", indent, width)?;
     dump_value_string(
+        out,
         selector_unit.selector_index,
         "iSelector",
         selector_unit.selector.as_ref().unwrap_or(&"".into()),
         indent,
         width,
-    );
+    )?;
+
+    Ok(())
 }
 
-fn dump_audio_selector_unit2(selector_unit: &audio::SelectorUnit2, indent: usize, width: usize) {
-    dump_value(selector_unit.unit_id, "bUnitID", indent, width);
-    dump_value(selector_unit.nr_in_pins, "bNrInPins", indent, width);
-    dump_array(&selector_unit.source_ids, "baSourceID", indent, width);
-    dump_hex(selector_unit.controls, "bmControls", indent, width);
+fn dump_audio_selector_unit2(
+    out: &mut DumpWriter,
+    selector_unit: &audio::SelectorUnit2,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, selector_unit.unit_id, "bUnitID", indent, width)?;
+    dump_value(out, selector_unit.nr_in_pins, "bNrInPins", indent, width)?;
+    dump_array(out, &selector_unit.source_ids, "baSourceID", indent, width)?;
+    dump_hex(out, selector_unit.controls, "bmControls", indent, width)?;
     dump_bitmap_controls(
+        out,
         selector_unit.controls,
         &UAC2_SELECTOR_UNIT_BMCONTROLS,
         &audio::ControlType::BmControl2,
         indent + 2,
-    );
+    )?;
     dump_value_string(
+        out,
         selector_unit.selector_index,
         "iSelector",
         selector_unit.selector.as_ref().unwrap_or(&"".into()),
         indent,
         width,
-    );
+    )?;
+
+    Ok(())
 }
 
-fn dump_audio_selector_unit3(selector_unit: &audio::SelectorUnit3, indent: usize, width: usize) {
-    dump_value(selector_unit.unit_id, "bUnitID", indent, width);
-    dump_value(selector_unit.nr_in_pins, "bNrInPins", indent, width);
-    dump_array(&selector_unit.source_ids, "baSourceID", indent, width);
-    dump_hex(selector_unit.controls, "bmControls", indent, width);
+fn dump_audio_selector_unit3(
+    out: &mut DumpWriter,
+    selector_unit: &audio::SelectorUnit3,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, selector_unit.unit_id, "bUnitID", indent, width)?;
+    dump_value(out, selector_unit.nr_in_pins, "bNrInPins", indent, width)?;
+    dump_array(out, &selector_unit.source_ids, "baSourceID", indent, width)?;
+    dump_hex(out, selector_unit.controls, "bmControls", indent, width)?;
     dump_bitmap_controls(
+        out,
         selector_unit.controls,
         &UAC2_SELECTOR_UNIT_BMCONTROLS,
         &audio::ControlType::BmControl2,
         indent + 2,
-    );
+    )?;
     dump_value(
+        out,
         selector_unit.selector_descr_str,
         "wSelectorDescrStr",
         indent,
         width,
-    );
+    )?;
+
+    Ok(())
 }
 
 /// Dumps the contents of a UAC1 Processing Unit Descriptor
-fn dump_audio_processing_unit1(unit: &audio::ProcessingUnit1, indent: usize, width: usize) {
-    dump_value(unit.unit_id, "bUnitID", indent, width);
+fn dump_audio_processing_unit1(
+    out: &mut DumpWriter,
+    unit: &audio::ProcessingUnit1,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, unit.unit_id, "bUnitID", indent, width)?;
     dump_value_string(
+        out,
         unit.process_type,
         "wProcessType",
         unit.processing_type(),
         indent,
         width,
-    );
-    dump_value(unit.nr_in_pins, "bNrInPins", indent, width);
-    dump_array(&unit.source_ids, "baSourceID", indent, width);
-    dump_value(unit.nr_channels, "bNrChannels", indent, width);
-    dump_hex(unit.channel_config, "wChannelConfig", indent, width);
+    )?;
+    dump_value(out, unit.nr_in_pins, "bNrInPins", indent, width)?;
+    dump_array(out, &unit.source_ids, "baSourceID", indent, width)?;
+    dump_value(out, unit.nr_channels, "bNrChannels", indent, width)?;
+    dump_hex(out, unit.channel_config, "wChannelConfig", indent, width)?;
     let channel_names = audio::UacInterfaceDescriptor::get_channel_name_strings(
         &audio::UacProtocol::Uac1,
         unit.channel_config as u32,
     );
     for name in channel_names.iter() {
-        println!("{:indent$}{}", "", name, indent = indent + 2);
+        writeln!(out, "{:indent$}{}", "", name, indent = indent + 2)?;
     }
     dump_value_string(
+        out,
         unit.channel_names_index,
         "iChannelNames",
         unit.channel_names.as_ref().unwrap_or(&"".into()),
         indent,
         width,
-    );
-    dump_value(unit.control_size, "bControlSize", indent, width);
-    dump_bitmap_array(&unit.controls, "bmControls", indent, width);
+    )?;
+    dump_value(out, unit.control_size, "bControlSize", indent, width)?;
+    dump_bitmap_array(out, &unit.controls, "bmControls", indent, width)?;
     dump_value_string(
+        out,
         unit.processing_index,
         "iProcessing",
         unit.processing.as_ref().unwrap_or(&"".into()),
         indent,
         width,
-    );
+    )?;
     if let Some(ref specific) = unit.specific {
-        dump_value(specific.nr_modes, "bNrModes", indent, width);
-        dump_bitmap_array(&specific.modes, "waModes", indent, width);
+        dump_value(out, specific.nr_modes, "bNrModes", indent, width)?;
+        dump_bitmap_array(out, &specific.modes, "waModes", indent, width)?;
     }
+
+    Ok(())
 }
 
 /// Dumps the contents of a UAC2 Processing Unit Descriptor
-fn dump_audio_processing_unit2(unit: &audio::ProcessingUnit2, indent: usize, width: usize) {
-    dump_value(unit.unit_id, "bUnitID", indent, width);
+fn dump_audio_processing_unit2(
+    out: &mut DumpWriter,
+    unit: &audio::ProcessingUnit2,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, unit.unit_id, "bUnitID", indent, width)?;
     dump_value_string(
+        out,
         unit.process_type,
         "wProcessType",
         unit.processing_type(),
         indent,
         width,
-    );
-    dump_value(unit.nr_in_pins, "bNrInPins", indent, width);
-    dump_array(&unit.source_ids, "baSourceID", indent, width);
-    dump_value(unit.nr_channels, "bNrChannels", indent, width);
-    dump_hex(unit.channel_config, "bmChannelConfig", indent, width);
+    )?;
+    dump_value(out, unit.nr_in_pins, "bNrInPins", indent, width)?;
+    dump_array(out, &unit.source_ids, "baSourceID", indent, width)?;
+    dump_value(out, unit.nr_channels, "bNrChannels", indent, width)?;
+    dump_hex(out, unit.channel_config, "bmChannelConfig", indent, width)?;
     let channel_names = audio::UacInterfaceDescriptor::get_channel_name_strings(
         &audio::UacProtocol::Uac2,
         unit.channel_config,
     );
     for name in channel_names.iter() {
-        println!("{:indent$}{}", "", name, indent = indent + 2);
+        writeln!(out, "{:indent$}{}", "", name, indent = indent + 2)?;
     }
     dump_value_string(
+        out,
         unit.channel_names_index,
         "iChannelNames",
         unit.channel_names.as_ref().unwrap_or(&"".into()),
         indent,
         width,
-    );
-    dump_value(unit.controls, "bmControls", indent, width);
+    )?;
+    dump_value(out, unit.controls, "bmControls", indent, width)?;
     dump_value_string(
+        out,
         unit.processing_index,
         "iProcessing",
         unit.processing.as_ref().unwrap_or(&"".into()),
         indent,
         width,
-    );
+    )?;
     if let Some(ref specific) = unit.specific {
         match specific {
             audio::AudioProcessingUnit2Specific::UpDownMix(up_down_mix) => {
-                dump_value(up_down_mix.nr_modes, "bNrModes", indent, width);
-                dump_bitmap_array(&up_down_mix.modes, "daModes", indent, width);
+                dump_value(out, up_down_mix.nr_modes, "bNrModes", indent, width)?;
+                dump_bitmap_array(out, &up_down_mix.modes, "daModes", indent, width)?;
             }
             audio::AudioProcessingUnit2Specific::DolbyPrologic(dolby_prologic) => {
-                dump_value(dolby_prologic.nr_modes, "bNrModes", indent, width);
-                dump_bitmap_array(&dolby_prologic.modes, "daModes", indent, width);
+                dump_value(out, dolby_prologic.nr_modes, "bNrModes", indent, width)?;
+                dump_bitmap_array(out, &dolby_prologic.modes, "daModes", indent, width)?;
             }
         }
     }
+
+    Ok(())
 }
 
 /// Dumps the contents of a UAC3 Processing Unit Descriptor
-fn dump_audio_processing_unit3(unit: &audio::ProcessingUnit3, indent: usize, width: usize) {
-    dump_value(unit.unit_id, "bUnitID", indent, width);
+fn dump_audio_processing_unit3(
+    out: &mut DumpWriter,
+    unit: &audio::ProcessingUnit3,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, unit.unit_id, "bUnitID", indent, width)?;
     dump_value_string(
+        out,
         unit.process_type,
         "wProcessType",
         unit.processing_type(),
         indent,
         width,
-    );
-    dump_value(unit.nr_in_pins, "bNrInPins", indent, width);
-    dump_array(&unit.source_ids, "baSourceID", indent, width);
+    )?;
+    dump_value(out, unit.nr_in_pins, "bNrInPins", indent, width)?;
+    dump_array(out, &unit.source_ids, "baSourceID", indent, width)?;
     dump_value(
+        out,
         unit.processing_descr_str,
         "wProcessingDescrStr",
         indent,
         width,
-    );
+    )?;
     if let Some(ref specific) = unit.specific {
         match specific {
             audio::AudioProcessingUnit3Specific::UpDownMix(up_down_mix) => {
-                dump_hex(up_down_mix.controls, "bmControls", indent, width);
+                dump_hex(out, up_down_mix.controls, "bmControls", indent, width)?;
                 dump_bitmap_controls(
+                    out,
                     up_down_mix.controls,
                     &UAC3_PROCESSING_UNIT_UP_DOWN_BMCONTROLS,
                     &audio::ControlType::BmControl2,
                     indent + 2,
-                );
-                dump_value(up_down_mix.nr_modes, "bNrModes", indent, width);
+                )?;
+                dump_value(out, up_down_mix.nr_modes, "bNrModes", indent, width)?;
                 dump_array(
+                    out,
                     &up_down_mix.cluster_descr_ids,
                     "waClusterDescrID",
                     indent,
                     width,
-                );
+                )?;
             }
             audio::AudioProcessingUnit3Specific::StereoExtender(stereo_extender) => {
-                dump_hex(stereo_extender.controls, "bmControls", indent, width);
+                dump_hex(out, stereo_extender.controls, "bmControls", indent, width)?;
                 dump_bitmap_controls(
+                    out,
                     stereo_extender.controls,
                     &UAC3_PROCESSING_UNIT_STEREO_EXTENDER_BMCONTROLS,
                     &audio::ControlType::BmControl2,
                     indent + 2,
-                );
+                )?;
             }
             audio::AudioProcessingUnit3Specific::MultiFunction(multi_function) => {
-                dump_hex(multi_function.controls, "bmControls", indent, width);
+                dump_hex(out, multi_function.controls, "bmControls", indent, width)?;
                 dump_bitmap_controls(
+                    out,
                     multi_function.controls,
                     &UAC3_PROCESSING_UNIT_MULTI_FUNC_BMCONTROLS,
                     &audio::ControlType::BmControl2,
                     indent + 2,
-                );
+                )?;
                 dump_value(
+                    out,
                     multi_function.cluster_descr_id,
                     "wClusterDescrID",
                     indent,
                     width,
-                );
-                dump_value(multi_function.algorithms, "bmAlgorithms", indent, width);
+                )?;
+                dump_value(
+                    out,
+                    multi_function.algorithms,
+                    "bmAlgorithms",
+                    indent,
+                    width,
+                )?;
                 if let Some(ref algorithms) = unit.algorithms() {
                     for algorithm in algorithms.iter() {
-                        println!("{:indent$}{}", "", algorithm, indent = indent + 2);
+                        writeln!(out, "{:indent$}{}", "", algorithm, indent = indent + 2)?;
                     }
                 }
             }
         }
     }
+
+    Ok(())
 }
 
 /// Dumps the contents of a UAC2 Effect Unit Descriptor
-fn dump_audio_effect_unit2(unit: &audio::EffectUnit2, indent: usize, width: usize) {
-    dump_value(unit.unit_id, "bUnitID", indent, width);
-    dump_value(unit.effect_type, "wEffectType", indent, width);
-    dump_value(unit.source_id, "bSourceID", indent, width);
-    dump_bitmap_array(&unit.controls, "bmaControls", indent, width);
-    dump_value(unit.effect_index, "iEffects", indent, width);
+fn dump_audio_effect_unit2(
+    out: &mut DumpWriter,
+    unit: &audio::EffectUnit2,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, unit.unit_id, "bUnitID", indent, width)?;
+    dump_value(out, unit.effect_type, "wEffectType", indent, width)?;
+    dump_value(out, unit.source_id, "bSourceID", indent, width)?;
+    dump_bitmap_array(out, &unit.controls, "bmaControls", indent, width)?;
+    dump_value(out, unit.effect_index, "iEffects", indent, width)?;
     dump_value_string(
+        out,
         unit.effect_index,
         "iEffects",
         unit.effect.as_ref().unwrap_or(&"".into()),
         indent,
         width,
-    );
+    )?;
+
+    Ok(())
 }
 
 /// Dumps the contents of a UAC3 Effect Unit Descriptor
-fn dump_audio_effect_unit3(unit: &audio::EffectUnit3, indent: usize, width: usize) {
-    dump_value(unit.unit_id, "bUnitID", indent, width);
-    dump_value(unit.effect_type, "wEffectType", indent, width);
-    dump_value(unit.source_id, "bSourceID", indent, width);
-    dump_bitmap_array(&unit.controls, "bmaControls", indent, width);
-    dump_value(unit.effect_descr_str, "wEffectsDescrStr", indent, width);
+fn dump_audio_effect_unit3(
+    out: &mut DumpWriter,
+    unit: &audio::EffectUnit3,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, unit.unit_id, "bUnitID", indent, width)?;
+    dump_value(out, unit.effect_type, "wEffectType", indent, width)?;
+    dump_value(out, unit.source_id, "bSourceID", indent, width)?;
+    dump_bitmap_array(out, &unit.controls, "bmaControls", indent, width)?;
+    dump_value(
+        out,
+        unit.effect_descr_str,
+        "wEffectsDescrStr",
+        indent,
+        width,
+    )?;
+
+    Ok(())
 }
 
 /// Dumps the contents of a UAC1 Feature Unit Descriptor
-fn dump_audio_feature_unit1(unit: &audio::FeatureUnit1, indent: usize, width: usize) {
-    dump_value(unit.unit_id, "bUnitID", indent, width);
-    dump_value(unit.source_id, "bSourceID", indent, width);
-    dump_value(unit.control_size, "bControlSize", indent, width);
+fn dump_audio_feature_unit1(
+    out: &mut DumpWriter,
+    unit: &audio::FeatureUnit1,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, unit.unit_id, "bUnitID", indent, width)?;
+    dump_value(out, unit.source_id, "bSourceID", indent, width)?;
+    dump_value(out, unit.control_size, "bControlSize", indent, width)?;
+    let controls: Vec<u32> = unit
+        .controls
+        .chunks(unit.control_size.max(1) as usize)
+        .map(|c| {
+            c.iter()
+                .take(4)
+                .enumerate()
+                .fold(0, |acc, (i, b)| acc | (*b as u32) << (i * 8))
+        })
+        .collect();
     dump_bitmap_controls_array(
+        out,
         "bmaControls",
-        &unit.controls,
+        &controls,
         &UAC1_FEATURE_UNIT_BMCONTROLS,
         &audio::ControlType::BmControl1,
         indent,
         width,
-    );
+    )?;
     dump_value_string(
+        out,
         unit.feature_index,
         "iFeature",
         unit.feature.as_ref().unwrap_or(&"".into()),
         indent,
         width,
-    );
+    )?;
+
+    Ok(())
 }
 
 /// Dumps the contents of a UAC2 Feature Unit Descriptor
-fn dump_audio_feature_unit2(unit: &audio::FeatureUnit2, indent: usize, width: usize) {
-    dump_value(unit.unit_id, "bUnitID", indent, width);
-    dump_value(unit.source_id, "bSourceID", indent, width);
+fn dump_audio_feature_unit2(
+    out: &mut DumpWriter,
+    unit: &audio::FeatureUnit2,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, unit.unit_id, "bUnitID", indent, width)?;
+    dump_value(out, unit.source_id, "bSourceID", indent, width)?;
     dump_bitmap_controls_array(
+        out,
         "bmaControls",
         &unit.controls,
-        &UAC1_FEATURE_UNIT_BMCONTROLS,
-        &audio::ControlType::BmControl1,
+        &UAC2_FEATURE_UNIT_BMCONTROLS,
+        &audio::ControlType::BmControl2,
         indent,
         width,
-    );
+    )?;
     dump_value_string(
+        out,
         unit.feature_index,
         "iFeature",
         unit.feature.as_ref().unwrap_or(&"".into()),
         indent,
         width,
-    );
+    )?;
+
+    Ok(())
 }
 
 /// Dumps the contents of a UAC3 Feature Unit Descriptor
-fn dump_audio_feature_unit3(unit: &audio::FeatureUnit3, indent: usize, width: usize) {
-    dump_value(unit.unit_id, "bUnitID", indent, width);
-    dump_value(unit.source_id, "bSourceID", indent, width);
+fn dump_audio_feature_unit3(
+    out: &mut DumpWriter,
+    unit: &audio::FeatureUnit3,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, unit.unit_id, "bUnitID", indent, width)?;
+    dump_value(out, unit.source_id, "bSourceID", indent, width)?;
     dump_bitmap_controls_array(
+        out,
         "bmaControls",
         &unit.controls,
-        &UAC1_FEATURE_UNIT_BMCONTROLS,
-        &audio::ControlType::BmControl1,
+        &UAC2_FEATURE_UNIT_BMCONTROLS,
+        &audio::ControlType::BmControl2,
         indent,
         width,
-    );
-    dump_value(unit.feature_descr_str, "wFeatureDescrStr", indent, width);
+    )?;
+    dump_value(
+        out,
+        unit.feature_descr_str,
+        "wFeatureDescrStr",
+        indent,
+        width,
+    )?;
+
+    Ok(())
 }
 
 /// Dumps the contents of a UAC1 Extension Unit Descriptor
-fn dump_audio_extension_unit1(unit: &audio::ExtensionUnit1, indent: usize, width: usize) {
-    dump_value(unit.unit_id, "bUnitID", indent, width);
-    dump_value(unit.extension_code, "wExtensionCode", indent, width);
-    dump_value(unit.nr_in_pins, "bNrInPins", indent, width);
-    dump_array(&unit.source_ids, "baSourceID", indent, width);
-    dump_value(unit.nr_channels, "bNrChannels", indent, width);
-    dump_hex(unit.channel_config, "wChannelConfig", indent, width);
+fn dump_audio_extension_unit1(
+    out: &mut DumpWriter,
+    unit: &audio::ExtensionUnit1,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, unit.unit_id, "bUnitID", indent, width)?;
+    dump_value(out, unit.extension_code, "wExtensionCode", indent, width)?;
+    dump_value(out, unit.nr_in_pins, "bNrInPins", indent, width)?;
+    dump_array(out, &unit.source_ids, "baSourceID", indent, width)?;
+    dump_value(out, unit.nr_channels, "bNrChannels", indent, width)?;
+    dump_hex(out, unit.channel_config, "wChannelConfig", indent, width)?;
     let channel_names = audio::UacInterfaceDescriptor::get_channel_name_strings(
         &audio::UacProtocol::Uac1,
         unit.channel_config as u32,
     );
     for name in channel_names.iter() {
-        println!("{:indent$}{}", "", name, indent = indent + 2);
+        writeln!(out, "{:indent$}{}", "", name, indent = indent + 2)?;
     }
-    dump_value(unit.channel_names_index, "iChannelNames", indent, width);
     dump_value_string(
+        out,
         unit.channel_names_index,
         "iChannelNames",
         unit.channel_names.as_ref().unwrap_or(&"".into()),
         indent,
         width,
-    );
-    dump_value(unit.control_size, "bControlSize", indent, width);
-    dump_bitmap_array(&unit.controls, "bmControls", indent, width);
+    )?;
+    dump_value(out, unit.control_size, "bControlSize", indent, width)?;
+    dump_bitmap_array(out, &unit.controls, "bmControls", indent, width)?;
     dump_value_string(
+        out,
         unit.extension_index,
         "iExtension",
         unit.extension.as_ref().unwrap_or(&"".into()),
         indent,
         width,
-    );
+    )?;
+
+    Ok(())
 }
 
 /// Dumps the contents of a UAC2 Extension Unit Descriptor
-fn dump_audio_extension_unit2(unit: &audio::ExtensionUnit2, indent: usize, width: usize) {
-    dump_value(unit.unit_id, "bUnitID", indent, width);
-    dump_value(unit.extension_code, "wExtensionCode", indent, width);
-    dump_value(unit.nr_in_pins, "bNrInPins", indent, width);
-    dump_array(&unit.source_ids, "baSourceID", indent, width);
-    dump_value(unit.nr_channels, "bNrChannels", indent, width);
-    dump_hex(unit.channel_config, "bmChannelConfig", indent, width);
+fn dump_audio_extension_unit2(
+    out: &mut DumpWriter,
+    unit: &audio::ExtensionUnit2,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, unit.unit_id, "bUnitID", indent, width)?;
+    dump_value(out, unit.extension_code, "wExtensionCode", indent, width)?;
+    dump_value(out, unit.nr_in_pins, "bNrInPins", indent, width)?;
+    dump_array(out, &unit.source_ids, "baSourceID", indent, width)?;
+    dump_value(out, unit.nr_channels, "bNrChannels", indent, width)?;
+    dump_hex(out, unit.channel_config, "bmChannelConfig", indent, width)?;
     let channel_names = audio::UacInterfaceDescriptor::get_channel_name_strings(
         &audio::UacProtocol::Uac2,
         unit.channel_config,
     );
     for name in channel_names.iter() {
-        println!("{:indent$}{}", "", name, indent = indent + 2);
+        writeln!(out, "{:indent$}{}", "", name, indent = indent + 2)?;
     }
     dump_value_string(
+        out,
         unit.channel_names_index,
         "iChannelNames",
         unit.channel_names.as_ref().unwrap_or(&"".into()),
         indent,
         width,
-    );
-    dump_hex(unit.controls, "bmControls", indent, width);
+    )?;
+    dump_hex(out, unit.controls, "bmControls", indent, width)?;
     dump_bitmap_controls(
+        out,
         unit.controls,
         &UAC2_EXTENSION_UNIT_BMCONTROLS,
         &audio::ControlType::BmControl2,
         indent + 2,
-    );
+    )?;
     dump_value_string(
+        out,
         unit.extension_index,
         "iExtension",
         unit.extension.as_ref().unwrap_or(&"".into()),
         indent,
         width,
-    );
+    )?;
+
+    Ok(())
 }
 
 /// Dumps the contents of a UAC3 Extension Unit Descriptor
-fn dump_audio_extension_unit3(unit: &audio::ExtensionUnit3, indent: usize, width: usize) {
-    dump_value(unit.unit_id, "bUnitID", indent, width);
-    dump_value(unit.extension_code, "wExtensionCode", indent, width);
-    dump_value(unit.nr_in_pins, "bNrInPins", indent, width);
-    dump_array(&unit.source_ids, "baSourceID", indent, width);
+fn dump_audio_extension_unit3(
+    out: &mut DumpWriter,
+    unit: &audio::ExtensionUnit3,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, unit.unit_id, "bUnitID", indent, width)?;
+    dump_value(out, unit.extension_code, "wExtensionCode", indent, width)?;
+    dump_value(out, unit.nr_in_pins, "bNrInPins", indent, width)?;
+    dump_array(out, &unit.source_ids, "baSourceID", indent, width)?;
     dump_value(
+        out,
         unit.extension_descr_str,
         "wExtensionDescrStr",
         indent,
         width,
-    );
-    dump_hex(unit.controls, "bmControls", indent, width);
+    )?;
+    dump_hex(out, unit.controls, "bmControls", indent, width)?;
     dump_bitmap_controls(
+        out,
         unit.controls,
         &UAC3_EXTENSION_UNIT_BMCONTROLS,
         &audio::ControlType::BmControl2,
         indent + 2,
-    );
-    dump_value(unit.cluster_descr_id, "wClusterDescrID", indent, width);
+    )?;
+    dump_value(out, unit.cluster_descr_id, "wClusterDescrID", indent, width)?;
+
+    Ok(())
 }
 
 /// Dumps the contents of a UAC2 Clock Source Descriptor
-fn dump_audio_clock_source2(source: &audio::ClockSource2, indent: usize, width: usize) {
+fn dump_audio_clock_source2(
+    out: &mut DumpWriter,
+    source: &audio::ClockSource2,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
     let uac2_clk_src_bmattr = |index: usize| -> Option<&'static str> {
         match index {
             0 => Some("External"),
@@ -607,28 +891,37 @@ fn dump_audio_clock_source2(source: &audio::ClockSource2, indent: usize, width:
         }
     };
 
-    dump_value(source.clock_id, "bClockID", indent, width);
-    dump_hex(source.attributes, "bmAttributes", indent, width);
-    dump_bitmap_strings(source.attributes, uac2_clk_src_bmattr, indent + 2);
-    dump_hex(source.controls, "bmControls", indent, width);
+    dump_value(out, source.clock_id, "bClockID", indent, width)?;
+    dump_hex(out, source.attributes, "bmAttributes", indent, width)?;
+    dump_bitmap_strings(out, source.attributes, uac2_clk_src_bmattr, indent + 2)?;
+    dump_hex(out, source.controls, "bmControls", indent, width)?;
     dump_bitmap_controls(
+        out,
         source.controls,
         &UAC2_CLOCK_SOURCE_BMCONTROLS,
         &audio::ControlType::BmControl2,
         indent + 2,
-    );
-    dump_value(source.assoc_terminal, "bAssocTerminal", indent, width);
+    )?;
+    dump_value(out, source.assoc_terminal, "bAssocTerminal", indent, width)?;
     dump_value_string(
+        out,
         source.clock_source_index,
         "iClockSource",
         source.clock_source.as_ref().unwrap_or(&"".into()),
         indent,
         width,
-    );
+    )?;
+
+    Ok(())
 }
 
 /// Dumps the contents of a UAC3 Clock Source Descriptor
-fn dump_audio_clock_source3(source: &audio::ClockSource3, indent: usize, width: usize) {
+fn dump_audio_clock_source3(
+    out: &mut DumpWriter,
+    source: &audio::ClockSource3,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
     let uac3_clk_src_bmattr = |index: usize| -> Option<&'static str> {
         match index {
             0 => Some("External"),
@@ -639,404 +932,623 @@ fn dump_audio_clock_source3(source: &audio::ClockSource3, indent: usize, width:
         }
     };
 
-    dump_value(source.clock_id, "bClockID", indent, width);
-    dump_hex(source.attributes, "bmAttributes", indent, width);
-    dump_bitmap_strings(source.attributes, uac3_clk_src_bmattr, indent + 2);
-    dump_hex(source.controls, "bmControls", indent, width);
+    dump_value(out, source.clock_id, "bClockID", indent, width)?;
+    dump_hex(out, source.attributes, "bmAttributes", indent, width)?;
+    dump_bitmap_strings(out, source.attributes, uac3_clk_src_bmattr, indent + 2)?;
+    dump_hex(out, source.controls, "bmControls", indent, width)?;
     dump_bitmap_controls(
+        out,
         source.controls,
         &UAC2_CLOCK_SOURCE_BMCONTROLS,
         &audio::ControlType::BmControl2,
         indent + 2,
-    );
+    )?;
     dump_value(
+        out,
         source.reference_terminal,
         "bReferenceTerminal",
         indent,
         width,
-    );
-    dump_value(source.clock_source_str, "wClockSourceStr", indent, width);
+    )?;
+    dump_value(
+        out,
+        source.clock_source_str,
+        "wClockSourceStr",
+        indent,
+        width,
+    )?;
+
+    Ok(())
 }
 
 /// Dumps the contents of a UAC2 Clock Selector Descriptor
-fn dump_audio_clock_selector2(selector: &audio::ClockSelector2, indent: usize, width: usize) {
-    dump_value(selector.clock_id, "bClockID", indent, width);
-    dump_value(selector.nr_in_pins, "bNrInPins", indent, width);
-    dump_array(&selector.csource_ids, "baCSourceID", indent, width);
-    dump_hex(selector.controls, "bmControls", indent, width);
+fn dump_audio_clock_selector2(
+    out: &mut DumpWriter,
+    selector: &audio::ClockSelector2,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, selector.clock_id, "bClockID", indent, width)?;
+    dump_value(out, selector.nr_in_pins, "bNrInPins", indent, width)?;
+    dump_array(out, &selector.csource_ids, "baCSourceID", indent, width)?;
+    dump_hex(out, selector.controls, "bmControls", indent, width)?;
     dump_bitmap_controls(
+        out,
         selector.controls,
         &UAC2_CLOCK_SELECTOR_BMCONTROLS,
         &audio::ControlType::BmControl2,
         indent + 2,
-    );
+    )?;
     dump_value_string(
+        out,
         selector.clock_selector_index,
         "iClockSelector",
         selector.clock_selector.as_ref().unwrap_or(&"".into()),
         indent,
         width,
-    );
+    )?;
+
+    Ok(())
 }
 
 /// Dumps the contents of a UAC3 Clock Selector Descriptor
-fn dump_audio_clock_selector3(selector: &audio::ClockSelector3, indent: usize, width: usize) {
-    dump_value(selector.clock_id, "bClockID", indent, width);
-    dump_value(selector.nr_in_pins, "bNrInPins", indent, width);
-    dump_array(&selector.csource_ids, "baCSourceID", indent, width);
-    dump_hex(selector.controls, "bmControls", indent, width);
+fn dump_audio_clock_selector3(
+    out: &mut DumpWriter,
+    selector: &audio::ClockSelector3,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, selector.clock_id, "bClockID", indent, width)?;
+    dump_value(out, selector.nr_in_pins, "bNrInPins", indent, width)?;
+    dump_array(out, &selector.csource_ids, "baCSourceID", indent, width)?;
+    dump_hex(out, selector.controls, "bmControls", indent, width)?;
     dump_bitmap_controls(
+        out,
         selector.controls,
         &UAC2_CLOCK_SELECTOR_BMCONTROLS,
         &audio::ControlType::BmControl2,
         indent + 2,
-    );
+    )?;
     dump_value(
+        out,
         selector.cselector_descr_str,
         "wCSelectorDescrStr",
         indent,
         width,
-    );
+    )?;
+
+    Ok(())
 }
 
 /// Dumps the contents of a UAC2 Clock Multiplier Descriptor
-fn dump_audio_clock_multiplier2(multiplier: &audio::ClockMultiplier2, indent: usize, width: usize) {
-    dump_value(multiplier.clock_id, "bClockID", indent, width);
-    dump_value(multiplier.csource_id, "bCSourceID", indent, width);
-    dump_hex(multiplier.controls, "bmControls", indent, width);
+fn dump_audio_clock_multiplier2(
+    out: &mut DumpWriter,
+    multiplier: &audio::ClockMultiplier2,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, multiplier.clock_id, "bClockID", indent, width)?;
+    dump_value(out, multiplier.csource_id, "bCSourceID", indent, width)?;
+    dump_hex(out, multiplier.controls, "bmControls", indent, width)?;
     dump_bitmap_controls(
+        out,
         multiplier.controls,
         &UAC2_CLOCK_MULTIPLIER_BMCONTROLS,
         &audio::ControlType::BmControl2,
         indent + 2,
-    );
+    )?;
     dump_value_string(
+        out,
         multiplier.clock_multiplier_index,
         "iClockMultiplier",
         multiplier.clock_multiplier.as_ref().unwrap_or(&"".into()),
         indent,
         width,
-    );
+    )?;
+
+    Ok(())
 }
 
 /// Dumps the contents of a UAC3 Clock Multiplier Descriptor
-fn dump_audio_clock_multiplier3(multiplier: &audio::ClockMultiplier3, indent: usize, width: usize) {
-    dump_value(multiplier.clock_id, "bClockID", indent, width);
-    dump_value(multiplier.csource_id, "bCSourceID", indent, width);
-    dump_hex(multiplier.controls, "bmControls", indent, width);
+fn dump_audio_clock_multiplier3(
+    out: &mut DumpWriter,
+    multiplier: &audio::ClockMultiplier3,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, multiplier.clock_id, "bClockID", indent, width)?;
+    dump_value(out, multiplier.csource_id, "bCSourceID", indent, width)?;
+    dump_hex(out, multiplier.controls, "bmControls", indent, width)?;
     dump_bitmap_controls(
+        out,
         multiplier.controls,
         &UAC2_CLOCK_MULTIPLIER_BMCONTROLS,
         &audio::ControlType::BmControl2,
         indent + 2,
-    );
+    )?;
     dump_value(
+        out,
         multiplier.cmultiplier_descr_str,
         "wCMultiplierDescrStr",
         indent,
         width,
-    );
+    )?;
+
+    Ok(())
 }
 
 fn dump_audio_sample_rate_converter2(
+    out: &mut DumpWriter,
     converter: &audio::SampleRateConverter2,
     indent: usize,
     width: usize,
-) {
-    dump_value(converter.unit_id, "bUnitID", indent, width);
-    dump_value(converter.source_id, "bSourceID", indent, width);
-    dump_value(converter.csource_in_id, "bCSourceInID", indent, width);
-    dump_value(converter.csource_out_id, "bCSourceOutID", indent, width);
+) -> fmt::Result {
+    dump_value(out, converter.unit_id, "bUnitID", indent, width)?;
+    dump_value(out, converter.source_id, "bSourceID", indent, width)?;
+    dump_value(out, converter.csource_in_id, "bCSourceInID", indent, width)?;
+    dump_value(
+        out,
+        converter.csource_out_id,
+        "bCSourceOutID",
+        indent,
+        width,
+    )?;
     dump_value_string(
+        out,
         converter.src_index,
         "iSRC",
         converter.src.as_ref().unwrap_or(&"".into()),
         indent,
         width,
-    );
+    )?;
+
+    Ok(())
 }
 
 fn dump_audio_sample_rate_converter3(
+    out: &mut DumpWriter,
     converter: &audio::SampleRateConverter3,
     indent: usize,
     width: usize,
-) {
-    dump_value(converter.unit_id, "bUnitID", indent, width);
-    dump_value(converter.source_id, "bSourceID", indent, width);
-    dump_value(converter.csource_in_id, "bCSourceInID", indent, width);
-    dump_value(converter.csource_out_id, "bCSourceOutID", indent, width);
-    dump_value(converter.src_descr_str, "wSRCDescrStr", indent, width);
+) -> fmt::Result {
+    dump_value(out, converter.unit_id, "bUnitID", indent, width)?;
+    dump_value(out, converter.source_id, "bSourceID", indent, width)?;
+    dump_value(out, converter.csource_in_id, "bCSourceInID", indent, width)?;
+    dump_value(
+        out,
+        converter.csource_out_id,
+        "bCSourceOutID",
+        indent,
+        width,
+    )?;
+    dump_value(out, converter.src_descr_str, "wSRCDescrStr", indent, width)?;
+
+    Ok(())
 }
 
-fn dump_audio_header1(header: &audio::Header1, indent: usize, width: usize) {
-    dump_value(header.version, "bcdADC", indent, width);
-    dump_value(header.total_length, "wTotalLength", indent, width);
-    dump_value(header.collection_bytes, "bInCollection", indent, width);
-    dump_array(&header.interfaces, "baInterfaceNr", indent, width);
+fn dump_audio_header1(
+    out: &mut DumpWriter,
+    header: &audio::Header1,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, header.version, "bcdADC", indent, width)?;
+    dump_value(out, header.total_length, "wTotalLength", indent, width)?;
+    dump_value(out, header.collection_bytes, "bInCollection", indent, width)?;
+    dump_array(out, &header.interfaces, "baInterfaceNr", indent, width)?;
+
+    Ok(())
 }
 
-fn dump_audio_header2(header: &audio::Header2, indent: usize, width: usize) {
-    dump_value(header.version, "bcdADC", indent, width);
-    dump_value(header.total_length, "wTotalLength", indent, width);
-    dump_hex(header.controls, "bmControls", indent, width);
+fn dump_audio_header2(
+    out: &mut DumpWriter,
+    header: &audio::Header2,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, header.version, "bcdADC", indent, width)?;
+    dump_value(out, header.total_length, "wTotalLength", indent, width)?;
+    dump_hex(out, header.controls, "bmControls", indent, width)?;
     dump_bitmap_controls(
+        out,
         header.controls as u32,
         &UAC2_INTERFACE_HEADER_BMCONTROLS,
         &audio::ControlType::BmControl2,
         indent + 2,
-    );
+    )?;
+
+    Ok(())
 }
 
-fn dump_audio_header3(header: &audio::Header3, indent: usize, width: usize) {
-    dump_value(header.category, "bCategory", indent, width);
-    dump_value(header.total_length, "wTotalLength", indent, width);
-    dump_hex(header.controls, "bmControls", indent, width);
+fn dump_audio_header3(
+    out: &mut DumpWriter,
+    header: &audio::Header3,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, header.category, "bCategory", indent, width)?;
+    dump_value(out, header.total_length, "wTotalLength", indent, width)?;
+    dump_hex(out, header.controls, "bmControls", indent, width)?;
     dump_bitmap_controls(
+        out,
         header.controls,
         &UAC2_INTERFACE_HEADER_BMCONTROLS,
         &audio::ControlType::BmControl2,
         indent + 2,
-    );
+    )?;
+
+    Ok(())
 }
 
-fn dump_audio_input_terminal1(ait: &audio::InputTerminal1, indent: usize, width: usize) {
-    dump_value(ait.terminal_id, "bTerminalID", indent, width);
-    println!(
+fn dump_audio_input_terminal1(
+    out: &mut DumpWriter,
+    ait: &audio::InputTerminal1,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, ait.terminal_id, "bTerminalID", indent, width)?;
+    writeln!(
+        out,
         "{:indent$}wTerminalType      {:5} {}",
         "",
         ait.terminal_type,
-        names::videoterminal(ait.terminal_type).unwrap_or_default(),
+        name_or_unknown(
+            names::terminal(ait.terminal_type, names::TerminalDomain::Audio),
+            "terminal",
+            ait.terminal_type
+        ),
         indent = indent
-    );
-    dump_value(ait.assoc_terminal, "bAssocTerminal", indent, width);
-    dump_value(ait.nr_channels, "bNrChannels", indent, width);
-    dump_hex(ait.channel_config, "wChannelConfig", indent, width);
+    )?;
+    dump_value(out, ait.assoc_terminal, "bAssocTerminal", indent, width)?;
+    dump_value(out, ait.nr_channels, "bNrChannels", indent, width)?;
+    dump_hex(out, ait.channel_config, "wChannelConfig", indent, width)?;
     let channel_names = audio::UacInterfaceDescriptor::get_channel_name_strings(
         &audio::UacProtocol::Uac1,
         ait.channel_config as u32,
     );
     for name in channel_names.iter() {
-        println!("{:indent$}{}", "", name, indent = indent + 2);
+        writeln!(out, "{:indent$}{}", "", name, indent = indent + 2)?;
     }
     dump_value_string(
+        out,
         ait.channel_names_index,
         "iChannelNames",
         ait.channel_names.as_ref().unwrap_or(&"".into()),
         indent,
         width,
-    );
+    )?;
     dump_value_string(
+        out,
         ait.terminal_index,
         "iTerminal",
         ait.terminal.as_ref().unwrap_or(&"".into()),
         indent,
         width,
-    );
+    )?;
+
+    Ok(())
 }
 
-fn dump_audio_input_terminal2(ait: &audio::InputTerminal2, indent: usize, width: usize) {
-    dump_value(ait.terminal_id, "bTerminalID", indent, width);
+fn dump_audio_input_terminal2(
+    out: &mut DumpWriter,
+    ait: &audio::InputTerminal2,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, ait.terminal_id, "bTerminalID", indent, width)?;
     dump_name(
+        out,
         ait.terminal_type,
-        names::videoterminal,
+        |t| names::terminal(t, names::TerminalDomain::Audio),
+        "terminal",
         "wTerminalType",
         indent,
         width,
-    );
-    dump_value(ait.assoc_terminal, "bAssocTerminal", indent, width);
-    dump_value(ait.nr_channels, "bNrChannels", indent, width);
-    dump_hex(ait.channel_config, "wChannelConfig", indent, width);
+    )?;
+    dump_value(out, ait.assoc_terminal, "bAssocTerminal", indent, width)?;
+    dump_value(out, ait.csource_id, "bCSourceID", indent, width)?;
+    dump_value(out, ait.nr_channels, "bNrChannels", indent, width)?;
+    dump_hex(out, ait.channel_config, "bmChannelConfig", indent, width)?;
     let channel_names = audio::UacInterfaceDescriptor::get_channel_name_strings(
         &audio::UacProtocol::Uac2,
         ait.channel_config,
     );
     for name in channel_names.iter() {
-        println!("{:indent$}{}", "", name, indent = indent + 2);
+        writeln!(out, "{:indent$}{}", "", name, indent = indent + 2)?;
     }
     dump_value_string(
+        out,
         ait.channel_names_index,
         "iChannelNames",
         ait.channel_names.as_ref().unwrap_or(&"".into()),
         indent,
         width,
-    );
-    dump_hex(ait.controls, "bmControls", indent, width);
+    )?;
+    dump_hex(out, ait.controls, "bmControls", indent, width)?;
     dump_bitmap_controls(
+        out,
         ait.controls,
         &UAC2_INPUT_TERMINAL_BMCONTROLS,
         &audio::ControlType::BmControl2,
         indent + 2,
-    );
-    dump_value(ait.terminal_index, "iTerminal", indent, width);
+    )?;
     dump_value_string(
+        out,
         ait.terminal_index,
         "iTerminal",
         ait.terminal.as_ref().unwrap_or(&"".into()),
         indent,
         width,
-    );
+    )?;
+
+    Ok(())
 }
 
-fn dump_audio_input_terminal3(ait: &audio::InputTerminal3, indent: usize, width: usize) {
-    dump_value(ait.terminal_id, "bTerminalID", indent, width);
+fn dump_audio_input_terminal3(
+    out: &mut DumpWriter,
+    ait: &audio::InputTerminal3,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, ait.terminal_id, "bTerminalID", indent, width)?;
     dump_name(
+        out,
         ait.terminal_type,
-        names::videoterminal,
+        |t| names::terminal(t, names::TerminalDomain::Audio),
+        "terminal",
         "wTerminalType",
         indent,
         width,
-    );
-    dump_value(ait.assoc_terminal, "bAssocTerminal", indent, width);
-    dump_value(ait.csource_id, "bCSourceID", indent, width);
-    dump_hex(ait.controls, "bmControls", indent, width);
+    )?;
+    dump_value(out, ait.assoc_terminal, "bAssocTerminal", indent, width)?;
+    dump_value(out, ait.csource_id, "bCSourceID", indent, width)?;
+    dump_hex(out, ait.controls, "bmControls", indent, width)?;
     dump_bitmap_controls(
+        out,
         ait.controls,
         &UAC3_INPUT_TERMINAL_BMCONTROLS,
         &audio::ControlType::BmControl2,
         indent + 2,
-    );
-    dump_value(ait.cluster_descr_id, "wClusterDescrID", indent, width);
+    )?;
+    dump_value(out, ait.cluster_descr_id, "wClusterDescrID", indent, width)?;
     dump_value(
+        out,
         ait.ex_terminal_descr_id,
         "wExTerminalDescrID",
         indent,
         width,
-    );
-    dump_value(ait.connectors_descr_id, "wConnectorDescrId", indent, width);
-    dump_value(ait.terminal_descr_str, "wTerminalDescrStr", indent, width);
+    )?;
+    dump_value(
+        out,
+        ait.connectors_descr_id,
+        "wConnectorDescrId",
+        indent,
+        width,
+    )?;
+    dump_value(
+        out,
+        ait.terminal_descr_str,
+        "wTerminalDescrStr",
+        indent,
+        width,
+    )?;
+
+    Ok(())
 }
 
-pub(crate) fn dump_audio_output_terminal1(a: &audio::OutputTerminal1, indent: usize, width: usize) {
-    dump_value(a.terminal_id, "bTerminalID", indent, width);
+pub(crate) fn dump_audio_output_terminal1(
+    out: &mut DumpWriter,
+    a: &audio::OutputTerminal1,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, a.terminal_id, "bTerminalID", indent, width)?;
     dump_name(
+        out,
         a.terminal_type,
-        names::videoterminal,
+        |t| names::terminal(t, names::TerminalDomain::Audio),
+        "terminal",
         "wTerminalType",
         indent,
         width,
-    );
-    dump_value(a.assoc_terminal, "bAssocTerminal", indent, width);
-    dump_value(a.source_id, "bSourceID", indent, width);
+    )?;
+    dump_value(out, a.assoc_terminal, "bAssocTerminal", indent, width)?;
+    dump_value(out, a.source_id, "bSourceID", indent, width)?;
     dump_value_string(
+        out,
         a.terminal_index,
         "iTerminal",
         a.terminal.as_ref().unwrap_or(&"".into()),
         indent,
         width,
-    );
+    )?;
+
+    Ok(())
 }
 
-fn dump_audio_output_terminal2(a: &audio::OutputTerminal2, indent: usize, width: usize) {
-    dump_value(a.terminal_id, "bTerminalID", indent, width);
+fn dump_audio_output_terminal2(
+    out: &mut DumpWriter,
+    a: &audio::OutputTerminal2,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, a.terminal_id, "bTerminalID", indent, width)?;
     dump_name(
+        out,
         a.terminal_type,
-        names::videoterminal,
+        |t| names::terminal(t, names::TerminalDomain::Audio),
+        "terminal",
         "wTerminalType",
         indent,
         width,
-    );
-    dump_value(a.assoc_terminal, "bAssocTerminal", indent, width);
-    dump_value(a.source_id, "bSourceID", indent, width);
-    dump_hex(a.controls, "bmControls", indent, width);
+    )?;
+    dump_value(out, a.assoc_terminal, "bAssocTerminal", indent, width)?;
+    dump_value(out, a.source_id, "bSourceID", indent, width)?;
+    dump_value(out, a.c_source_id, "bCSourceID", indent, width)?;
+    dump_hex(out, a.controls, "bmControls", indent, width)?;
     dump_bitmap_controls(
+        out,
         a.controls,
         &UAC2_OUTPUT_TERMINAL_BMCONTROLS,
         &audio::ControlType::BmControl2,
         indent + 2,
-    );
+    )?;
     dump_value_string(
+        out,
         a.terminal_index,
         "iTerminal",
         a.terminal.as_ref().unwrap_or(&"".into()),
         indent,
         width,
-    );
+    )?;
+
+    Ok(())
 }
 
-fn dump_audio_output_terminal3(a: &audio::OutputTerminal3, indent: usize, width: usize) {
-    dump_value(a.terminal_id, "bTerminalID", indent, width);
+fn dump_audio_output_terminal3(
+    out: &mut DumpWriter,
+    a: &audio::OutputTerminal3,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, a.terminal_id, "bTerminalID", indent, width)?;
     dump_name(
+        out,
         a.terminal_type,
-        names::videoterminal,
+        |t| names::terminal(t, names::TerminalDomain::Audio),
+        "terminal",
         "wTerminalType",
         indent,
         width,
-    );
-    dump_value(a.assoc_terminal, "bAssocTerminal", indent, width);
-    dump_value(a.c_source_id, "bCSourceID", indent, width);
-    dump_hex(a.controls, "bmControls", indent, width);
+    )?;
+    dump_value(out, a.assoc_terminal, "bAssocTerminal", indent, width)?;
+    dump_value(out, a.c_source_id, "bCSourceID", indent, width)?;
+    dump_hex(out, a.controls, "bmControls", indent, width)?;
     dump_bitmap_controls(
+        out,
         a.controls,
         &UAC3_OUTPUT_TERMINAL_BMCONTROLS,
         &audio::ControlType::BmControl2,
         indent + 2,
-    );
-    dump_value(a.ex_terminal_descr_id, "wExTerminalDescrID", indent, width);
-    dump_value(a.connectors_descr_id, "wConnectorDescrId", indent, width);
-    dump_value(a.terminal_descr_str, "wTerminalDescrStr", indent, width);
+    )?;
+    dump_value(
+        out,
+        a.ex_terminal_descr_id,
+        "wExTerminalDescrID",
+        indent,
+        width,
+    )?;
+    dump_value(
+        out,
+        a.connectors_descr_id,
+        "wConnectorDescrId",
+        indent,
+        width,
+    )?;
+    dump_value(
+        out,
+        a.terminal_descr_str,
+        "wTerminalDescrStr",
+        indent,
+        width,
+    )?;
+
+    Ok(())
 }
 
-fn dump_extended_terminal_header(d: &audio::ExtendedTerminalHeader, indent: usize, width: usize) {
-    dump_value(d.descriptor_id, "wDescriptorID", indent, width);
-    dump_value(d.nr_channels, "bNrChannels", indent, width);
+fn dump_extended_terminal_header(
+    out: &mut DumpWriter,
+    d: &audio::ExtendedTerminalHeader,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, d.descriptor_id, "wDescriptorID", indent, width)?;
+    dump_value(out, d.nr_channels, "bNrChannels", indent, width)?;
+
+    Ok(())
 }
 
-fn dump_audio_streaming_interface1(asi: &audio::StreamingInterface1, indent: usize, width: usize) {
-    dump_value(asi.terminal_link, "bTerminalLink", indent, width);
-    dump_value(asi.delay, "bDelay", indent, width);
-    dump_value(asi.format_tag, "wFormatTag", indent, width);
+fn dump_audio_streaming_interface1(
+    out: &mut DumpWriter,
+    asi: &audio::StreamingInterface1,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, asi.terminal_link, "bTerminalLink", indent, width)?;
+    dump_value(out, asi.delay, "bDelay", indent, width)?;
+    dump_value(out, asi.format_tag, "wFormatTag", indent, width)?;
+
+    Ok(())
 }
 
-fn dump_audio_streaming_interface2(asi: &audio::StreamingInterface2, indent: usize, width: usize) {
-    dump_value(asi.terminal_link, "bTerminalLink", indent, width);
-    dump_hex(asi.controls, "bmControls", indent, width);
+fn dump_audio_streaming_interface2(
+    out: &mut DumpWriter,
+    asi: &audio::StreamingInterface2,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, asi.terminal_link, "bTerminalLink", indent, width)?;
+    dump_hex(out, asi.controls, "bmControls", indent, width)?;
     dump_bitmap_controls(
+        out,
         asi.controls,
         &UAC2_AS_INTERFACE_BMCONTROLS,
         &audio::ControlType::BmControl2,
         indent + 2,
-    );
-    dump_value(asi.format_type, "bFormatType", indent, width);
-    dump_value(asi.nr_channels, "bNrChannels", indent, width);
-    dump_hex(asi.channel_config, "bmChannelConfig", indent, width);
+    )?;
+    dump_value(out, asi.format_type, "bFormatType", indent, width)?;
+    dump_value(out, asi.nr_channels, "bNrChannels", indent, width)?;
+    dump_hex(out, asi.channel_config, "bmChannelConfig", indent, width)?;
     let channel_names = audio::UacInterfaceDescriptor::get_channel_name_strings(
         &audio::UacProtocol::Uac2,
         asi.channel_config,
     );
     for name in channel_names.iter() {
-        println!("{:indent$}{}", "", name, indent = indent + 2);
+        writeln!(out, "{:indent$}{}", "", name, indent = indent + 2)?;
     }
     dump_value_string(
+        out,
         asi.channel_names_index,
         "iChannelNames",
         asi.channel_names.as_ref().unwrap_or(&"".into()),
         indent,
         width,
-    );
+    )?;
+
+    Ok(())
 }
 
-fn dump_audio_streaming_interface3(asi: &audio::StreamingInterface3, indent: usize, width: usize) {
-    dump_value(asi.terminal_link, "bTerminalLink", indent, width);
-    dump_hex(asi.controls, "bmControls", indent, width);
+fn dump_audio_streaming_interface3(
+    out: &mut DumpWriter,
+    asi: &audio::StreamingInterface3,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, asi.terminal_link, "bTerminalLink", indent, width)?;
+    dump_hex(out, asi.controls, "bmControls", indent, width)?;
     dump_bitmap_controls(
+        out,
         asi.controls,
         &UAC3_AS_INTERFACE_BMCONTROLS,
         &audio::ControlType::BmControl2,
         indent + 2,
-    );
-    dump_value(asi.cluster_descr_id, "wClusterDescrID", indent, width);
-    dump_hex(asi.formats, "bmFormats", indent, width);
-    dump_value(asi.sub_slot_size, "bSubslotSize", indent, width);
-    dump_value(asi.bit_resolution, "bBitResolution", indent, width);
-    dump_hex(asi.aux_protocols, "bmAuxProtocols", indent, width);
-    dump_value(asi.control_size, "bControlSize", indent, width);
+    )?;
+    dump_value(out, asi.cluster_descr_id, "wClusterDescrID", indent, width)?;
+    dump_hex(out, asi.formats, "bmFormats", indent, width)?;
+    dump_value(out, asi.sub_slot_size, "bSubslotSize", indent, width)?;
+    dump_value(out, asi.bit_resolution, "bBitResolution", indent, width)?;
+    dump_hex(out, asi.aux_protocols, "bmAuxProtocols", indent, width)?;
+    dump_value(out, asi.control_size, "bControlSize", indent, width)?;
+
+    Ok(())
 }
 
 fn dump_audio_data_streaming_endpoint1(
+    out: &mut DumpWriter,
     ads: &audio::DataStreamingEndpoint1,
     indent: usize,
     width: usize,
-) {
+) -> fmt::Result {
     let uac1_attrs = |a: usize| match a {
         0 => Some("Sampling Frequency"),
         1 => Some("Pitch"),
@@ -1044,81 +1556,98 @@ fn dump_audio_data_streaming_endpoint1(
         7 => Some("MaxPacketsOnly"),
         _ => None,
     };
-    dump_hex(ads.attributes, "bmAttributes", indent, width);
-    dump_bitmap_strings(ads.attributes, uac1_attrs, indent + 2);
-    dump_value(ads.lock_delay_units, "bLockDelayUnits", indent, width);
-    dump_value(ads.lock_delay, "wLockDelay", indent, width);
+    dump_hex(out, ads.attributes, "bmAttributes", indent, width)?;
+    dump_bitmap_strings(out, ads.attributes, uac1_attrs, indent + 2)?;
+    dump_value(out, ads.lock_delay_units, "bLockDelayUnits", indent, width)?;
+    dump_value(out, ads.lock_delay, "wLockDelay", indent, width)?;
+
+    Ok(())
 }
 
 fn dump_audio_data_streaming_endpoint2(
+    out: &mut DumpWriter,
     ads: &audio::DataStreamingEndpoint2,
     indent: usize,
     width: usize,
-) {
+) -> fmt::Result {
     let uac2_attrs = |attr: usize| match attr {
         0x07 => Some("MaxPacketsOnly"),
         _ => None,
     };
-    dump_hex(ads.attributes, "bmAttributes", indent, width);
-    dump_bitmap_strings(ads.attributes, uac2_attrs, indent + 2);
-    dump_hex(ads.controls, "bmControls", indent, width);
+    dump_hex(out, ads.attributes, "bmAttributes", indent, width)?;
+    dump_bitmap_strings(out, ads.attributes, uac2_attrs, indent + 2)?;
+    dump_hex(out, ads.controls, "bmControls", indent, width)?;
     dump_bitmap_controls(
+        out,
         ads.controls,
         &UAC2_AS_ISO_ENDPOINT_BMCONTROLS,
         &audio::ControlType::BmControl2,
         indent + 2,
-    );
-    dump_value(ads.lock_delay_units, "bLockDelayUnits", indent, width);
-    dump_value(ads.lock_delay, "wLockDelay", indent, width);
+    )?;
+    dump_value(out, ads.lock_delay_units, "bLockDelayUnits", indent, width)?;
+    dump_value(out, ads.lock_delay, "wLockDelay", indent, width)?;
+
+    Ok(())
 }
 
 fn dump_audio_data_streaming_endpoint3(
+    out: &mut DumpWriter,
     ads: &audio::DataStreamingEndpoint3,
     indent: usize,
     width: usize,
-) {
-    dump_hex(ads.controls, "bmControls", indent, width);
+) -> fmt::Result {
+    dump_hex(out, ads.controls, "bmControls", indent, width)?;
     dump_bitmap_controls(
+        out,
         ads.controls,
         &UAC2_AS_ISO_ENDPOINT_BMCONTROLS,
         &audio::ControlType::BmControl2,
         indent + 2,
-    );
-    dump_value(ads.lock_delay_units, "bLockDelayUnits", indent, width);
-    dump_value(ads.lock_delay, "wLockDelay", indent, width);
+    )?;
+    dump_value(out, ads.lock_delay_units, "bLockDelayUnits", indent, width)?;
+    dump_value(out, ads.lock_delay, "wLockDelay", indent, width)?;
+
+    Ok(())
 }
 
-fn dump_audio_streaming_format(af: &audio::StreamingFormat, indent: usize, width: usize) {
+fn dump_audio_streaming_format(
+    out: &mut DumpWriter,
+    af: &audio::StreamingFormat,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
     dump_value_string(
+        out,
         u8::from(af.format_type.to_owned()),
         "bFormatType",
         format!("({:#})", af.format_type),
         indent,
         width,
-    );
+    )?;
 
     match &af.interface {
         audio::StreamingFormatInterface::FormatTypeI1(asi) => {
-            dump_format_type_i(asi, indent, width);
+            dump_format_type_i(out, asi, indent, width)?;
         }
         audio::StreamingFormatInterface::FormatTypeII1(asi) => {
-            dump_format_type_ii(asi, indent, width);
+            dump_format_type_ii(out, asi, indent, width)?;
         }
         audio::StreamingFormatInterface::FormatTypeIII1(asi) => {
-            dump_format_type_iii(asi, indent, width);
+            dump_format_type_iii(out, asi, indent, width)?;
         }
         audio::StreamingFormatInterface::FormatTypeI2(asi) => {
-            dump_format_type_i_uac2(asi, indent, width);
+            dump_format_type_i_uac2(out, asi, indent, width)?;
         }
         audio::StreamingFormatInterface::FormatTypeII2(asi) => {
-            dump_format_type_ii_uac2(asi, indent, width);
+            dump_format_type_ii_uac2(out, asi, indent, width)?;
         }
         audio::StreamingFormatInterface::FormatTypeIII2(asi) => {
-            dump_format_type_iii_uac2(asi, indent, width);
+            dump_format_type_iii_uac2(out, asi, indent, width)?;
         }
         i => {
             let data: Vec<u8> = i.to_owned().into();
-            println!(
+            writeln!(
+                out,
                 "{:indent$}invalid desc format type: {}",
                 "",
                 data.iter()
@@ -1126,29 +1655,33 @@ fn dump_audio_streaming_format(af: &audio::StreamingFormat, indent: usize, width
                     .collect::<Vec<String>>()
                     .join(""),
                 indent = indent
-            )
+            )?
         }
     }
+
+    Ok(())
 }
 
 fn dump_audio_streaming_format_specific(
+    out: &mut DumpWriter,
     af: &audio::StreamingFormatSpecific,
     indent: usize,
     width: usize,
-) {
+) -> fmt::Result {
     let fmtptr = get_format_specific_string(af.format_tag);
-    dump_value_string(af.format_tag, "wFormatTag", fmtptr, indent, width);
+    dump_value_string(out, af.format_tag, "wFormatTag", fmtptr, indent, width)?;
 
     match &af.interface {
         audio::StreamingFormatInterface::FormatSpecificAc3(fs) => {
-            dump_format_specific_ac3(fs, indent, width);
+            dump_format_specific_ac3(out, fs, indent, width)?;
         }
         audio::StreamingFormatInterface::FormatSpecificMpeg(fs) => {
-            dump_format_specific_mpeg(fs, indent, width);
+            dump_format_specific_mpeg(out, fs, indent, width)?;
         }
         i => {
             let data: Vec<u8> = i.to_owned().into();
-            println!(
+            writeln!(
+                out,
                 "{:indent$}Invalid desc format type: {}",
                 "",
                 data.iter()
@@ -1156,196 +1689,217 @@ fn dump_audio_streaming_format_specific(
                     .collect::<Vec<String>>()
                     .join(""),
                 indent = indent
-            )
+            )?
         }
     }
+
+    Ok(())
 }
 
-fn dump_audio_subtype(uacid: &audio::UacInterfaceDescriptor, indent: usize) {
+fn dump_audio_subtype(
+    out: &mut DumpWriter,
+    uacid: &audio::UacInterfaceDescriptor,
+    indent: usize,
+) -> fmt::Result {
     match uacid {
         audio::UacInterfaceDescriptor::Header1(a) => {
-            dump_audio_header1(a, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_header1(out, a, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::Header2(ach) => {
-            dump_audio_header2(ach, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_header2(out, ach, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::Header3(ach) => {
-            dump_audio_header3(ach, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_header3(out, ach, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::InputTerminal1(ait) => {
-            dump_audio_input_terminal1(ait, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_input_terminal1(out, ait, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::InputTerminal2(ait) => {
-            dump_audio_input_terminal2(ait, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_input_terminal2(out, ait, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::InputTerminal3(ait) => {
-            dump_audio_input_terminal3(ait, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_input_terminal3(out, ait, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::OutputTerminal1(a) => {
-            dump_audio_output_terminal1(a, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_output_terminal1(out, a, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::OutputTerminal2(a) => {
-            dump_audio_output_terminal2(a, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_output_terminal2(out, a, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::OutputTerminal3(a) => {
-            dump_audio_output_terminal3(a, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_output_terminal3(out, a, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::ExtendedTerminalHeader(d) => {
-            dump_extended_terminal_header(d, indent, LSUSB_DUMP_WIDTH);
+            dump_extended_terminal_header(out, d, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::PowerDomain(power_domain) => {
-            dump_audio_power_domain(power_domain, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_power_domain(out, power_domain, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::MixerUnit1(mixer_unit) => {
-            dump_audio_mixer_unit1(mixer_unit, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_mixer_unit1(out, mixer_unit, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::MixerUnit2(mixer_unit) => {
-            dump_audio_mixer_unit2(mixer_unit, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_mixer_unit2(out, mixer_unit, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::MixerUnit3(mixer_unit) => {
-            dump_audio_mixer_unit3(mixer_unit, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_mixer_unit3(out, mixer_unit, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::SelectorUnit1(selector_unit) => {
-            dump_audio_selector_unit1(selector_unit, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_selector_unit1(out, selector_unit, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::SelectorUnit2(selector_unit) => {
-            dump_audio_selector_unit2(selector_unit, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_selector_unit2(out, selector_unit, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::SelectorUnit3(selector_unit) => {
-            dump_audio_selector_unit3(selector_unit, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_selector_unit3(out, selector_unit, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::ProcessingUnit1(unit) => {
-            dump_audio_processing_unit1(unit, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_processing_unit1(out, unit, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::ProcessingUnit2(unit) => {
-            dump_audio_processing_unit2(unit, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_processing_unit2(out, unit, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::ProcessingUnit3(unit) => {
-            dump_audio_processing_unit3(unit, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_processing_unit3(out, unit, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::EffectUnit2(unit) => {
-            dump_audio_effect_unit2(unit, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_effect_unit2(out, unit, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::EffectUnit3(unit) => {
-            dump_audio_effect_unit3(unit, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_effect_unit3(out, unit, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::FeatureUnit1(unit) => {
-            dump_audio_feature_unit1(unit, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_feature_unit1(out, unit, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::FeatureUnit2(unit) => {
-            dump_audio_feature_unit2(unit, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_feature_unit2(out, unit, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::FeatureUnit3(unit) => {
-            dump_audio_feature_unit3(unit, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_feature_unit3(out, unit, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::ExtensionUnit1(unit) => {
-            dump_audio_extension_unit1(unit, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_extension_unit1(out, unit, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::ExtensionUnit2(unit) => {
-            dump_audio_extension_unit2(unit, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_extension_unit2(out, unit, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::ExtensionUnit3(unit) => {
-            dump_audio_extension_unit3(unit, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_extension_unit3(out, unit, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::ClockSource2(source) => {
-            dump_audio_clock_source2(source, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_clock_source2(out, source, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::ClockSource3(source) => {
-            dump_audio_clock_source3(source, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_clock_source3(out, source, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::ClockSelector2(selector) => {
-            dump_audio_clock_selector2(selector, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_clock_selector2(out, selector, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::ClockSelector3(selector) => {
-            dump_audio_clock_selector3(selector, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_clock_selector3(out, selector, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::ClockMultiplier2(multiplier) => {
-            dump_audio_clock_multiplier2(multiplier, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_clock_multiplier2(out, multiplier, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::ClockMultiplier3(multiplier) => {
-            dump_audio_clock_multiplier3(multiplier, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_clock_multiplier3(out, multiplier, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::SampleRateConverter2(converter) => {
-            dump_audio_sample_rate_converter2(converter, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_sample_rate_converter2(out, converter, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::SampleRateConverter3(converter) => {
-            dump_audio_sample_rate_converter3(converter, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_sample_rate_converter3(out, converter, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::StreamingInterface1(asi) => {
-            dump_audio_streaming_interface1(asi, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_streaming_interface1(out, asi, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::StreamingInterface2(asi) => {
-            dump_audio_streaming_interface2(asi, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_streaming_interface2(out, asi, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::StreamingInterface3(asi) => {
-            dump_audio_streaming_interface3(asi, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_streaming_interface3(out, asi, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::StreamingFormat(fmt) => {
-            dump_audio_streaming_format(fmt, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_streaming_format(out, fmt, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::StreamingFormatSpecific(fmt) => {
-            dump_audio_streaming_format_specific(fmt, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_streaming_format_specific(out, fmt, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::DataStreamingEndpoint1(ads) => {
-            dump_audio_data_streaming_endpoint1(ads, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_data_streaming_endpoint1(out, ads, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::DatastreamingEndpoint2(ads) => {
-            dump_audio_data_streaming_endpoint2(ads, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_data_streaming_endpoint2(out, ads, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::DataStreamingEndpoint3(ads) => {
-            dump_audio_data_streaming_endpoint3(ads, indent, LSUSB_DUMP_WIDTH);
+            dump_audio_data_streaming_endpoint3(out, ads, indent, LSUSB_DUMP_WIDTH)?;
         }
         audio::UacInterfaceDescriptor::Undefined(data)
         | audio::UacInterfaceDescriptor::Invalid(data) => {
-            println!(
+            writeln!(
+                out,
                 "{:indent$}Invalid desc subtype: {}",
                 "",
                 data.iter()
                     .map(|b| format!("{:02x}", b))
                     .collect::<Vec<String>>()
                     .join(" "),
-            );
+            )?;
         }
         _ => {
             log::warn!("Unsupported UAC interface descriptor: {:?}", uacid);
         }
     }
+
+    Ok(())
 }
 
 pub(crate) fn dump_audiocontrol_interface(
+    out: &mut DumpWriter,
     uacd: &audio::UacDescriptor,
     uaci: &audio::ControlSubtype,
     protocol: &audio::UacProtocol,
     indent: usize,
-) {
-    dump_string("AudioControl Interface Descriptor", indent);
-    dump_value(uacd.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
+) -> fmt::Result {
+    dump_string(out, "AudioControl Interface Descriptor:", indent)?;
+    dump_value(out, uacd.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         uacd.descriptor_type,
         "bDescriptorType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value_string(
-        uaci.to_owned() as u8,
+        out,
+        uaci.to_uac_subtype(protocol),
         "bDescriptorSubtype",
         format!("({:#})", uaci),
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     match &uacd.interface {
         audio::UacInterfaceDescriptor::Invalid(_) => {
-            println!(
-                "{:indent$}Warning: {:#} descriptors are illegal for {}",
-                "",
-                uacd.descriptor_subtype,
-                u8::from(protocol.to_owned()),
-                indent = indent
-            );
+            dump_warning(
+                out,
+                DumpWarning {
+                    kind: DumpWarningKind::Illegal,
+                    descriptor: "AudioControl Interface".into(),
+                    offset: 2,
+                    message: format!(
+                        "{:#} descriptors are illegal for {}",
+                        uacd.descriptor_subtype,
+                        u8::from(protocol.to_owned())
+                    ),
+                },
+                indent,
+            )?;
         }
-        uacid => dump_audio_subtype(uacid, indent + 2),
+        uacid => dump_audio_subtype(out, uacid, indent + 2)?,
     }
+
+    Ok(())
 }
 
 fn get_format_specific_string(fmttag: u16) -> &'static str {
@@ -1376,140 +1930,206 @@ fn get_format_specific_string(fmttag: u16) -> &'static str {
     }
 }
 
-fn dump_format_type_i(ft: &audio::FormatTypeI1, indent: usize, width: usize) {
-    dump_value(ft.num_channels, "bNrChannels", indent, width);
-    dump_value(ft.subframe_size, "bSubframeSize", indent, width);
-    dump_value(ft.bit_resolution, "bBitResolution", indent, width);
+fn dump_format_type_i(
+    out: &mut DumpWriter,
+    ft: &audio::FormatTypeI1,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, ft.num_channels, "bNrChannels", indent, width)?;
+    dump_value(out, ft.subframe_size, "bSubframeSize", indent, width)?;
+    dump_value(out, ft.bit_resolution, "bBitResolution", indent, width)?;
     dump_value_string(
+        out,
         u8::from(ft.sample_frequency_type.to_owned()),
         "bSamFreqType",
         ft.sample_frequency_type.to_string(),
         indent,
         width,
-    );
+    )?;
     match ft.sample_frequency_type {
         audio::SampleFrequencyType::Continuous => {
             dump_value(
+                out,
                 ft.sample_frequencies.first().unwrap_or(&0),
                 "tLowerSamFreq",
                 indent,
                 width,
-            );
+            )?;
             dump_value(
+                out,
                 ft.sample_frequencies.get(1).unwrap_or(&0),
                 "tUpperSamFreq",
                 indent,
                 width,
-            );
+            )?;
         }
         audio::SampleFrequencyType::Discrete(n) => {
             for i in 0..n {
                 dump_value(
+                    out,
                     ft.sample_frequencies[i as usize],
                     &format!("tSamFreq[{}]", i),
                     indent,
                     width,
-                );
+                )?;
             }
         }
     }
+
+    Ok(())
 }
 
-fn dump_format_type_ii(ft: &audio::FormatTypeII1, indent: usize, width: usize) {
-    dump_value(ft.max_bit_rate, "wMaxBitRate", indent, width);
-    dump_value(ft.samples_per_frame, "wSamplesPerFrame", indent, width);
+fn dump_format_type_ii(
+    out: &mut DumpWriter,
+    ft: &audio::FormatTypeII1,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, ft.max_bit_rate, "wMaxBitRate", indent, width)?;
+    dump_value(out, ft.samples_per_frame, "wSamplesPerFrame", indent, width)?;
     dump_value_string(
+        out,
         u8::from(ft.sample_frequency_type.to_owned()),
         "bSamFreqType",
         ft.sample_frequency_type.to_string(),
         indent,
         width,
-    );
+    )?;
     match ft.sample_frequency_type {
         audio::SampleFrequencyType::Continuous => {
             dump_value(
+                out,
                 ft.sample_frequencies.first().unwrap_or(&0),
                 "tLowerSamFreq",
                 indent,
                 width,
-            );
+            )?;
             dump_value(
+                out,
                 ft.sample_frequencies.get(1).unwrap_or(&0),
                 "tUpperSamFreq",
                 indent,
                 width,
-            );
+            )?;
         }
         audio::SampleFrequencyType::Discrete(n) => {
             for i in 0..n {
                 dump_value(
+                    out,
                     ft.sample_frequencies[i as usize],
                     &format!("tSamFreq[{}]", i),
                     indent,
                     width,
-                );
+                )?;
             }
         }
     }
+
+    Ok(())
 }
 
-fn dump_format_type_iii(ft: &audio::FormatTypeIII1, indent: usize, width: usize) {
-    dump_value(ft.num_channels, "bNrChannels", indent, width);
-    dump_value(ft.subframe_size, "bSubframeSize", indent, width);
-    dump_value(ft.bit_resolution, "bBitResolution", indent, width);
+fn dump_format_type_iii(
+    out: &mut DumpWriter,
+    ft: &audio::FormatTypeIII1,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, ft.num_channels, "bNrChannels", indent, width)?;
+    dump_value(out, ft.subframe_size, "bSubframeSize", indent, width)?;
+    dump_value(out, ft.bit_resolution, "bBitResolution", indent, width)?;
     dump_value_string(
+        out,
         u8::from(ft.sample_frequency_type.to_owned()),
         "bSamFreqType",
         ft.sample_frequency_type.to_string(),
         indent,
         width,
-    );
+    )?;
     match ft.sample_frequency_type {
         audio::SampleFrequencyType::Continuous => {
             dump_value(
+                out,
                 ft.sample_frequencies.first().unwrap_or(&0),
                 "tLowerSamFreq",
                 indent,
                 width,
-            );
+            )?;
             dump_value(
+                out,
                 ft.sample_frequencies.get(1).unwrap_or(&0),
                 "tUpperSamFreq",
                 indent,
                 width,
-            );
+            )?;
         }
         audio::SampleFrequencyType::Discrete(n) => {
             for i in 0..n {
                 dump_value(
+                    out,
                     ft.sample_frequencies[i as usize],
                     &format!("tSamFreq[{}]", i),
                     indent,
                     width,
-                );
+                )?;
             }
         }
     }
+
+    Ok(())
 }
 
-fn dump_format_type_i_uac2(ft: &audio::FormatTypeI2, indent: usize, width: usize) {
-    dump_value(ft.sub_slot_size, "bSubslotSize", indent, width);
-    dump_value(ft.bit_resolution, "bBitResolution", indent, width);
+fn dump_format_type_i_uac2(
+    out: &mut DumpWriter,
+    ft: &audio::FormatTypeI2,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, ft.sub_slot_size, "bSubslotSize", indent, width)?;
+    dump_value(out, ft.bit_resolution, "bBitResolution", indent, width)?;
+
+    Ok(())
 }
 
-fn dump_format_type_ii_uac2(ft: &audio::FormatTypeII2, indent: usize, width: usize) {
-    dump_value(ft.max_bit_rate, "wMaxBitRate", indent, width);
-    dump_value(ft.slots_per_frame, "wSlotsPerFrame", indent, width);
+fn dump_format_type_ii_uac2(
+    out: &mut DumpWriter,
+    ft: &audio::FormatTypeII2,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, ft.max_bit_rate, "wMaxBitRate", indent, width)?;
+    dump_value(out, ft.slots_per_frame, "wSlotsPerFrame", indent, width)?;
+
+    Ok(())
 }
 
-fn dump_format_type_iii_uac2(ft: &audio::FormatTypeIII2, indent: usize, width: usize) {
-    dump_value(ft.sub_slot_size, "bSubslotSize", indent, width);
-    dump_value(ft.bit_resolution, "bBitResolution", indent, width);
+fn dump_format_type_iii_uac2(
+    out: &mut DumpWriter,
+    ft: &audio::FormatTypeIII2,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, ft.sub_slot_size, "bSubslotSize", indent, width)?;
+    dump_value(out, ft.bit_resolution, "bBitResolution", indent, width)?;
+
+    Ok(())
 }
 
-fn dump_format_specific_mpeg(fs: &audio::FormatSpecificMpeg, indent: usize, width: usize) {
-    dump_hex(fs.mpeg_capabilities, "bmMPEGCapabilities", indent, width);
+fn dump_format_specific_mpeg(
+    out: &mut DumpWriter,
+    fs: &audio::FormatSpecificMpeg,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_hex(
+        out,
+        fs.mpeg_capabilities,
+        "bmMPEGCapabilities",
+        indent,
+        width,
+    )?;
     dump_bitmap_strings(
+        out,
         fs.mpeg_capabilities as u8,
         |b| match b {
             0 => Some("Layer I"),
@@ -1523,8 +2143,9 @@ fn dump_format_specific_mpeg(fs: &audio::FormatSpecificMpeg, indent: usize, widt
             _ => None,
         },
         indent + 2,
-    );
-    println!(
+    )?;
+    writeln!(
+        out,
         "{:indent$}MPEG-2 multilingual support: {}",
         "",
         match (fs.mpeg_capabilities >> 8) & 3 {
@@ -1534,9 +2155,10 @@ fn dump_format_specific_mpeg(fs: &audio::FormatSpecificMpeg, indent: usize, widt
             _ => "Supported at Fs and 1/2Fs",
         },
         indent = indent + 2
-    );
-    dump_hex(fs.mpeg_features, "bmMPEGFeatures", indent, width);
-    println!(
+    )?;
+    dump_hex(out, fs.mpeg_features, "bmMPEGFeatures", indent, width)?;
+    writeln!(
+        out,
         "{:indent$}Internal Dynamic Range Control: {}",
         "",
         match (fs.mpeg_features >> 4) & 3 {
@@ -1546,13 +2168,21 @@ fn dump_format_specific_mpeg(fs: &audio::FormatSpecificMpeg, indent: usize, widt
             _ => "scalable, separate boost and cut scaling value",
         },
         indent = indent + 2
-    );
+    )?;
+
+    Ok(())
 }
 
-fn dump_format_specific_ac3(fs: &audio::FormatSpecificAc3, indent: usize, width: usize) {
-    dump_hex(fs.bsid, "bmBSID", indent, width);
-    dump_hex(fs.ac3_features, "bmAC3Features", indent, width);
+fn dump_format_specific_ac3(
+    out: &mut DumpWriter,
+    fs: &audio::FormatSpecificAc3,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_hex(out, fs.bsid, "bmBSID", indent, width)?;
+    dump_hex(out, fs.ac3_features, "bmAC3Features", indent, width)?;
     dump_bitmap_strings(
+        out,
         fs.ac3_features,
         |b| match b {
             0 => Some("RF mode"),
@@ -1562,8 +2192,9 @@ fn dump_format_specific_ac3(fs: &audio::FormatSpecificAc3, indent: usize, width:
             _ => None,
         },
         indent + 2,
-    );
-    println!(
+    )?;
+    writeln!(
+        out,
         "{:indent$}Internal Dynamic Range Control: {}",
         "",
         match (fs.ac3_features >> 4) & 3 {
@@ -1573,77 +2204,284 @@ fn dump_format_specific_ac3(fs: &audio::FormatSpecificAc3, indent: usize, width:
             _ => "scalable, separate boost and cut scaling value",
         },
         indent = indent + 2
-    );
+    )?;
+
+    Ok(())
 }
 
 pub(crate) fn dump_audiostreaming_interface(
+    out: &mut DumpWriter,
     uacd: &audio::UacDescriptor,
     uasi: &audio::StreamingSubtype,
     protocol: &audio::UacProtocol,
     indent: usize,
-) {
-    dump_string("AudioStreaming Interface Descriptor:", indent);
-    dump_value(uacd.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
+) -> fmt::Result {
+    dump_string(out, "AudioStreaming Interface Descriptor:", indent)?;
+    dump_value(out, uacd.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         uacd.descriptor_type,
         "bDescriptorType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value_string(
+        out,
         uasi.to_owned() as u8,
         "bDescriptorSubtype",
         format!("({:#})", uasi),
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     match &uacd.interface {
         audio::UacInterfaceDescriptor::Invalid(_) => {
-            println!(
-                "{:indent$}Warning: {:#} descriptors are illegal for {}",
-                "",
-                uacd.descriptor_subtype,
-                u8::from(protocol.to_owned()),
-                indent = indent + 2
-            );
+            dump_warning(
+                out,
+                DumpWarning {
+                    kind: DumpWarningKind::Illegal,
+                    descriptor: "AudioStreaming Interface".into(),
+                    offset: 2,
+                    message: format!(
+                        "{:#} descriptors are illegal for {}",
+                        uacd.descriptor_subtype,
+                        u8::from(protocol.to_owned())
+                    ),
+                },
+                indent + 2,
+            )?;
         }
-        uacid => dump_audio_subtype(uacid, indent + 2),
+        uacid => dump_audio_subtype(out, uacid, indent + 2)?,
     }
+
+    Ok(())
 }
 
-pub(crate) fn dump_audiostreaming_endpoint(ad: &audio::UacDescriptor, indent: usize) {
+pub(crate) fn dump_audiostreaming_endpoint(
+    out: &mut DumpWriter,
+    ad: &audio::UacDescriptor,
+    indent: usize,
+) -> fmt::Result {
     // audio streaming endpoint is only EP_GENERAL
     let subtype_string = match ad.descriptor_subtype {
         audio::UacType::Streaming(audio::StreamingSubtype::General) => "EP_GENERAL",
         // lowercase in lsusb
         _ => "invalid",
     };
-    dump_string("AudioStreaming Endpoint Descriptor:", indent);
-    dump_value(ad.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
+    dump_string(out, "AudioStreaming Endpoint Descriptor:", indent)?;
+    dump_value(out, ad.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         ad.descriptor_type,
         "bDescriptorType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value_string(
+        out,
         u8::from(ad.descriptor_subtype.to_owned()),
         "bDescriptorSubtype",
         format!("({:#})", subtype_string),
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     if matches!(
         ad.descriptor_subtype,
         audio::UacType::Streaming(audio::StreamingSubtype::General)
     ) {
-        dump_audio_subtype(&ad.interface, indent + 2);
+        dump_audio_subtype(out, &ad.interface, indent + 2)?;
     }
+
+    Ok(())
 }
 
-pub(crate) fn dump_midistreaming_interface(md: &audio::MidiDescriptor, indent: usize) {
+/// Dump the UAC3 High Capability Cluster descriptor resolving wClusterDescrID `id`, or note it was not found
+pub(crate) fn dump_uac3_cluster(
+    out: &mut DumpWriter,
+    id: u16,
+    cluster: Option<&audio::ClusterDescriptor3>,
+    indent: usize,
+) -> fmt::Result {
+    let cluster = match cluster {
+        Some(c) => c,
+        None => {
+            dump_value_string(
+                out,
+                id,
+                "wClusterDescrID",
+                "(cluster not found)",
+                indent,
+                LSUSB_DUMP_WIDTH,
+            )?;
+            return Ok(());
+        }
+    };
+
+    dump_string(
+        out,
+        "AudioControl Interface High Capability Cluster Descriptor:",
+        indent,
+    )?;
+    dump_value(out, cluster.length, "wLength", indent + 2, LSUSB_DUMP_WIDTH)?;
+    dump_value(
+        out,
+        cluster.descriptor_type,
+        "bDescriptorType",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    dump_value(
+        out,
+        cluster.descriptor_subtype,
+        "bDescriptorSubtype",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    dump_value(
+        out,
+        cluster.descriptor_id,
+        "wDescriptorID",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    dump_value(
+        out,
+        cluster.nr_channels,
+        "bNrChannels",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    for relationship in cluster.channel_relationships() {
+        dump_value_string(
+            out,
+            format!("0x{:02x}", relationship),
+            "bChRelationship",
+            audio::uac3_channel_relationship(relationship),
+            indent + 2,
+            LSUSB_DUMP_WIDTH,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Dump the UAC3 High Capability Connectors descriptor resolving wConnectorsDescrID `id`, or note it was not found
+pub(crate) fn dump_uac3_connectors(
+    out: &mut DumpWriter,
+    id: u16,
+    connectors: Option<&audio::ConnectorsDescriptor3>,
+    indent: usize,
+) -> fmt::Result {
+    let connectors = match connectors {
+        Some(c) => c,
+        None => {
+            dump_value_string(
+                out,
+                id,
+                "wConnectorsDescrID",
+                "(connectors not found)",
+                indent,
+                LSUSB_DUMP_WIDTH,
+            )?;
+            return Ok(());
+        }
+    };
+
+    dump_string(
+        out,
+        "AudioControl Interface High Capability Connectors Descriptor:",
+        indent,
+    )?;
+    dump_value(
+        out,
+        connectors.length,
+        "wLength",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    dump_value(
+        out,
+        connectors.descriptor_type,
+        "bDescriptorType",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    dump_value(
+        out,
+        connectors.descriptor_subtype,
+        "bDescriptorSubtype",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    dump_value(
+        out,
+        connectors.descriptor_id,
+        "wDescriptorID",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    dump_value(
+        out,
+        connectors.nr_connectors,
+        "bNrConnectors",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    for connector in &connectors.connectors {
+        dump_value(
+            out,
+            connector.con_id,
+            "baConID",
+            indent + 2,
+            LSUSB_DUMP_WIDTH,
+        )?;
+        dump_value(
+            out,
+            connector.cluster_descr_id,
+            "wClusterDescrID",
+            indent + 4,
+            LSUSB_DUMP_WIDTH,
+        )?;
+        dump_value(
+            out,
+            connector.con_type,
+            "bConType",
+            indent + 4,
+            LSUSB_DUMP_WIDTH,
+        )?;
+        dump_hex(
+            out,
+            connector.con_attributes,
+            "bmConAttributes",
+            indent + 4,
+            LSUSB_DUMP_WIDTH,
+        )?;
+        dump_value(
+            out,
+            connector.con_descr_str,
+            "wConDescrStr",
+            indent + 4,
+            LSUSB_DUMP_WIDTH,
+        )?;
+        dump_value_string(
+            out,
+            format!("0x{:08x}", connector.con_color),
+            "dwConColor",
+            audio::connector_color_string(connector.con_color),
+            indent + 4,
+            LSUSB_DUMP_WIDTH,
+        )?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn dump_midistreaming_interface(
+    out: &mut DumpWriter,
+    md: &audio::MidiDescriptor,
+    indent: usize,
+) -> fmt::Result {
     let jack_types = |t: u8| match t {
         0x00 => "Undefined",
         0x01 => "Embedded",
@@ -1651,163 +2489,183 @@ pub(crate) fn dump_midistreaming_interface(md: &audio::MidiDescriptor, indent: u
         _ => "Invalid",
     };
 
-    dump_string("MIDIStreaming Interface Descriptor:", indent);
-    dump_value(md.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
+    dump_string(out, "MIDIStreaming Interface Descriptor:", indent)?;
+    dump_value(out, md.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         md.descriptor_type,
         "bDescriptorType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value_string(
+        out,
         md.descriptor_subtype.to_owned() as u8,
         "bDescriptorSubtype",
         format!("({:#})", md.descriptor_subtype),
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     match &md.interface {
         audio::MidiInterfaceDescriptor::Header(d) => {
-            dump_value(d.version, "bcdADC", indent + 2, LSUSB_DUMP_WIDTH);
-            dump_hex(d.total_length, "wTotalLength", indent + 2, LSUSB_DUMP_WIDTH);
+            dump_value(out, d.version, "bcdADC", indent + 2, LSUSB_DUMP_WIDTH)?;
+            dump_hex(
+                out,
+                d.total_length,
+                "wTotalLength",
+                indent + 2,
+                LSUSB_DUMP_WIDTH,
+            )?;
         }
         audio::MidiInterfaceDescriptor::InputJack(d) => {
             dump_value_string(
+                out,
                 d.jack_type,
                 "bJackType",
                 jack_types(d.jack_type),
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
-            dump_value(d.jack_id, "bJackID", indent + 2, LSUSB_DUMP_WIDTH);
+            )?;
+            dump_value(out, d.jack_id, "bJackID", indent + 2, LSUSB_DUMP_WIDTH)?;
             dump_value_string(
+                out,
                 d.jack_string_index,
                 "iJack",
                 d.jack_string.as_ref().unwrap_or(&"".into()),
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
         }
         audio::MidiInterfaceDescriptor::OutputJack(d) => {
             dump_value_string(
+                out,
                 d.jack_type,
                 "bJackType",
                 jack_types(d.jack_type),
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
-            dump_value(d.jack_id, "bJackID", indent + 2, LSUSB_DUMP_WIDTH);
+            )?;
+            dump_value(out, d.jack_id, "bJackID", indent + 2, LSUSB_DUMP_WIDTH)?;
             dump_value(
+                out,
                 d.num_input_pins,
                 "bNrInputPins",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
 
             for (i, p) in d.source_ids.iter() {
                 dump_value(
+                    out,
                     i,
                     &format!("baSourceID({:2})", i),
                     indent + 2,
                     LSUSB_DUMP_WIDTH,
-                );
+                )?;
                 dump_value(
+                    out,
                     p,
                     &format!("baSourcePin({:2})", i),
                     indent + 2,
                     LSUSB_DUMP_WIDTH,
-                );
+                )?;
             }
             dump_value_string(
+                out,
                 d.jack_string_index,
                 "iJack",
                 d.jack_string.as_ref().unwrap_or(&"".into()),
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
         }
         audio::MidiInterfaceDescriptor::Element(d) => {
-            dump_value(d.element_id, "bElementID", indent + 2, LSUSB_DUMP_WIDTH);
             dump_value(
+                out,
+                d.element_id,
+                "bElementID",
+                indent + 2,
+                LSUSB_DUMP_WIDTH,
+            )?;
+            dump_value(
+                out,
                 d.num_input_pins,
                 "bNrInputPins",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
 
             for (i, p) in d.source_ids.iter() {
                 dump_value(
+                    out,
                     i,
                     &format!("baSourceID({:2})", i),
                     indent + 2,
                     LSUSB_DUMP_WIDTH,
-                );
+                )?;
                 dump_value(
+                    out,
                     p,
                     &format!("baSourcePin({:2})", i),
                     indent + 2,
                     LSUSB_DUMP_WIDTH,
-                );
+                )?;
             }
 
             dump_value(
+                out,
                 d.num_output_pins,
                 "bNrOutputPins",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
             dump_value(
+                out,
                 d.in_terminal_link,
                 "bInTerminalLink",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
             dump_value(
+                out,
                 d.out_terminal_link,
                 "bOutTerminalLink",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
-            dump_value(d.el_caps_size, "bElCapsSize", indent + 2, LSUSB_DUMP_WIDTH);
+            )?;
+            dump_value(
+                out,
+                d.el_caps_size,
+                "bElCapsSize",
+                indent + 2,
+                LSUSB_DUMP_WIDTH,
+            )?;
             dump_hex(
+                out,
                 d.element_caps,
                 "bmElementCaps",
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
-            dump_bitmap_strings(
-                d.element_caps,
-                |b| match b {
-                    0 => Some("Undefined"),
-                    1 => Some("MIDI Clock"),
-                    2 => Some("MTC (MIDI Time Code)"),
-                    3 => Some("MMC (MIDI Machine Control)"),
-                    4 => Some("GM1 (General MIDI v.1)"),
-                    5 => Some("GM2 (General MIDI v.2)"),
-                    6 => Some("GS MIDI Extension"),
-                    7 => Some("XG MIDI Extension"),
-                    8 => Some("EFX"),
-                    9 => Some("MIDI Patch Bay"),
-                    10 => Some("DLS1 (Downloadable Sounds Level 1)"),
-                    11 => Some("DLS2 (Downloadable Sounds Level 2)"),
-                    _ => None,
-                },
-                indent + 2,
-            );
+            )?;
+            for capability in d.capabilities() {
+                dump_string(out, capability, indent + 2)?;
+            }
 
             dump_value_string(
+                out,
                 d.element_string_index,
                 "iElement",
                 d.element_string.as_ref().unwrap_or(&String::new()),
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
         }
         audio::MidiInterfaceDescriptor::Undefined(_) => (),
         invalid => {
             let data: Vec<u8> = invalid.to_owned().into();
-            println!(
+            writeln!(
+                out,
                 "{:indent$}Invalid desc subtype: {}",
                 "",
                 data.iter()
@@ -1815,40 +2673,57 @@ pub(crate) fn dump_midistreaming_interface(md: &audio::MidiDescriptor, indent: u
                     .collect::<Vec<String>>()
                     .join(" "),
                 indent = indent + 2,
-            );
+            )?;
         }
     }
+
+    Ok(())
 }
 
-pub(crate) fn dump_midistreaming_endpoint(md: &audio::MidiDescriptor, indent: usize) {
+pub(crate) fn dump_midistreaming_endpoint(
+    out: &mut DumpWriter,
+    md: &audio::MidiDescriptor,
+    indent: usize,
+) -> fmt::Result {
     let subtype_string = match u8::from(md.descriptor_subtype.to_owned()) {
         1 | 2 => "GENERAL",
         _ => "Invalid",
     };
 
-    dump_string("MIDIStreaming Endpoint Descriptor:", indent);
-    dump_value(md.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
+    dump_string(out, "MIDIStreaming Endpoint Descriptor:", indent)?;
+    dump_value(out, md.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         md.descriptor_type,
         "bDescriptorType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value_string(
+        out,
         u8::from(md.descriptor_subtype.to_owned()),
         "bDescriptorSubtype",
         subtype_string,
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     if let audio::MidiInterfaceDescriptor::Endpoint(ep) = &md.interface {
         dump_value(
+            out,
             ep.num_jacks,
             "bNumEmbMIDIJack",
             indent + 2,
             LSUSB_DUMP_WIDTH,
-        );
-        dump_array(&ep.jacks, "baAssocJackID", indent + 2, LSUSB_DUMP_WIDTH);
+        )?;
+        dump_array(
+            out,
+            &ep.jacks,
+            "baAssocJackID",
+            indent + 2,
+            LSUSB_DUMP_WIDTH,
+        )?;
     }
+
+    Ok(())
 }
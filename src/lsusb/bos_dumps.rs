@@ -2,63 +2,113 @@ use crate::usb::descriptors::bos;
 
 use super::*;
 
-fn dump_extension_capability(d: &bos::ExtensionCapability, indent: usize) {
-    dump_string("USB 2.0 Extension Device Capability:", indent);
-    dump_value(d.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
+fn dump_extension_capability(
+    out: &mut DumpWriter,
+    d: &bos::ExtensionCapability,
+    indent: usize,
+) -> fmt::Result {
+    dump_string(out, "USB 2.0 Extension Device Capability:", indent)?;
+    dump_value(out, d.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         d.descriptor_type,
         "bDescriptorType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         u8::from(d.capability_type.to_owned()),
         "bDevCapabilityType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
-    dump_hex(d.attributes, "bmAttributes", indent + 2, LSUSB_DUMP_WIDTH);
+    )?;
+    dump_hex(
+        out,
+        d.attributes,
+        "bmAttributes",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
 
     if d.attributes & 0x02 == 0 {
-        dump_string("(Missing must-be-set LPM bit!)", indent + 4);
+        dump_string(out, "(Missing must-be-set LPM bit!)", indent + 4)?;
     } else if d.attributes & 0x04 == 0 {
-        dump_string("HIRD Link Power Management (LPM) Supported", indent + 4);
+        dump_string(
+            out,
+            "HIRD Link Power Management (LPM) Supported",
+            indent + 4,
+        )?;
     } else {
-        dump_string("BESL Link Power Management (LPM) Supported", indent + 4);
+        dump_string(
+            out,
+            "BESL Link Power Management (LPM) Supported",
+            indent + 4,
+        )?;
     }
     if d.attributes & 0x08 != 0 {
         let val = d.attributes & 0xf00;
-        dump_value_string(val, "BESL value", "us", indent + 4, LSUSB_DUMP_WIDTH);
+        dump_value_string(out, val, "BESL value", "us", indent + 4, LSUSB_DUMP_WIDTH)?;
     }
     if d.attributes & 0x10 != 0 {
         let val = d.attributes & 0xf000;
-        dump_value_string(val, "Deep BESL value", "us", indent + 4, LSUSB_DUMP_WIDTH);
+        dump_value_string(
+            out,
+            val,
+            "Deep BESL value",
+            "us",
+            indent + 4,
+            LSUSB_DUMP_WIDTH,
+        )?;
     }
+
+    Ok(())
 }
 
-fn dump_ss_capability(d: &bos::SuperSpeedCapability, indent: usize) {
-    dump_string("SuperSpeed USB Device Capability:", indent);
-    dump_value(d.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
+fn dump_ss_capability(
+    out: &mut DumpWriter,
+    d: &bos::SuperSpeedCapability,
+    indent: usize,
+) -> fmt::Result {
+    dump_string(out, "SuperSpeed USB Device Capability:", indent)?;
+    dump_value(out, d.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         d.descriptor_type,
         "bDescriptorType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         u8::from(d.capability_type.to_owned()),
         "bDevCapabilityType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
-    dump_hex(d.attributes, "bmAttributes", indent + 2, LSUSB_DUMP_WIDTH);
+    )?;
     dump_hex(
+        out,
+        d.attributes,
+        "bmAttributes",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    if d.ltm_supported() {
+        dump_string(
+            out,
+            "Latency Tolerance Messages (LTM) Supported",
+            indent + 4,
+        )?;
+    }
+    dump_hex(
+        out,
         d.speed_supported,
         "wSpeedsSupported",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_bitmap_strings(
+        out,
         d.speed_supported,
         |b| match b {
             0 => Some("Device can operate at Low Speed (1Mbps)"),
@@ -68,14 +118,16 @@ fn dump_ss_capability(d: &bos::SuperSpeedCapability, indent: usize) {
             _ => None,
         },
         indent + 4,
-    );
+    )?;
     dump_value(
+        out,
         d.functionality_supported,
         "bFunctionalitySupport",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_bitmap_strings(
+        out,
         d.functionality_supported,
         |b| match b {
             0 => Some("Lowest fully-functional device speed is Low Speed (1Mbps)"),
@@ -85,82 +137,106 @@ fn dump_ss_capability(d: &bos::SuperSpeedCapability, indent: usize) {
             _ => Some("Lowest fully-functional device speed is at an unknown speed!"),
         },
         indent + 4,
-    );
+    )?;
     dump_value_string(
+        out,
         d.u1_device_exit_latency,
         "bU1DevExitLat",
         "micro seconds",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value_string(
+        out,
         d.u2_device_exit_latency,
         "bU2DevExitLat",
         "micro seconds",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
+
+    Ok(())
 }
 
-fn dump_ss_plus_capability(d: &bos::SuperSpeedPlusCapability, indent: usize) {
-    dump_string("SuperSpeedPlus USB Device Capability:", indent);
-    dump_value(d.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
+fn dump_ss_plus_capability(
+    out: &mut DumpWriter,
+    d: &bos::SuperSpeedPlusCapability,
+    indent: usize,
+) -> fmt::Result {
+    dump_string(out, "SuperSpeedPlus USB Device Capability:", indent)?;
+    dump_value(out, d.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         d.descriptor_type,
         "bDescriptorType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         u8::from(d.capability_type.to_owned()),
         "bDevCapabilityType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
-    dump_hex(d.attributes, "bmAttributes", indent + 2, LSUSB_DUMP_WIDTH);
+    )?;
+    dump_hex(
+        out,
+        d.attributes,
+        "bmAttributes",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
     dump_string(
+        out,
         &format!(
             "Sublink Speed Attribute count {}",
             d.sublink_speed_attribute_count()
         ),
         indent + 4,
-    );
+    )?;
     dump_string(
+        out,
         &format!("Sublink Speed ID count {}", d.sublink_speed_id_count()),
         indent + 4,
-    );
+    )?;
     dump_hex(
+        out,
         d.functionality_supported,
         "wFunctionalitySupport",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_string(
+        out,
         &format!(
             "Min functional Speed Attribute ID: {}",
             d.functional_speed_attribute_id()
         ),
         indent + 4,
-    );
+    )?;
     dump_string(
+        out,
         &format!("Min functional RX lanes: {}", d.functional_rx_lanes()),
         indent + 4,
-    );
+    )?;
     dump_string(
+        out,
         &format!("Min functional TX lanes: {}", d.functional_tx_lanes()),
         indent + 4,
-    );
+    )?;
 
     let bitrate_prefix = [' ', 'K', 'M', 'G'];
 
     for (i, &ss_attr) in d.sublink_attributes.iter().enumerate() {
         dump_hex(
+            out,
             ss_attr,
             &format!("bmSublinkSpeedAttr[{}]", i),
             indent,
             LSUSB_DUMP_WIDTH,
-        );
+        )?;
         dump_string(
+            out,
             &format!(
                 "Speed Attribute ID: {} {}{}b/s {} {} SuperSpeed{}",
                 ss_attr & 0x0f,
@@ -175,8 +251,10 @@ fn dump_ss_plus_capability(d: &bos::SuperSpeedPlusCapability, indent: usize) {
                 if (ss_attr & 0x4000) != 0 { "Plus" } else { "" },
             ),
             indent + 4,
-        );
+        )?;
     }
+
+    Ok(())
 }
 
 const VCONN_POWER_STRINGS: [&str; 8] = ["1W", "1.5W", "2W", "3W", "4W", "5W", "6W", "reserved"];
@@ -188,7 +266,11 @@ const ALT_MODE_STATE: [&str; 4] = [
     "Alternate Mode configuration successful",
 ];
 
-fn dump_billboard_capability(d: &bos::BillboardCapability, indent: usize) {
+fn dump_billboard_capability(
+    out: &mut DumpWriter,
+    d: &bos::BillboardCapability,
+    indent: usize,
+) -> fmt::Result {
     let vconn = if d.vconn_power & (1 << 15) != 0 {
         "VCONN power not required"
     } else if (d.vconn_power & 0x7) < 7 {
@@ -197,46 +279,52 @@ fn dump_billboard_capability(d: &bos::BillboardCapability, indent: usize) {
         "reserved"
     };
 
-    dump_string("Billboard Capability:", indent);
-    dump_value(d.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
+    dump_string(out, "Billboard Capability:", indent)?;
+    dump_value(out, d.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         d.descriptor_type,
         "bDescriptorType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         u8::from(d.capability_type.to_owned()),
         "bDevCapabilityType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value_string(
+        out,
         d.additional_info_url_index,
         "iAdditionalInfoURL",
         d.additional_info_url.as_ref().unwrap_or(&String::new()),
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         d.number_of_alternate_modes,
         "bNumberOfAlternateModes",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         d.preferred_alternate_mode,
         "bPreferredAlternateMode",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value_string(
+        out,
         d.vconn_power,
         "VCONN Power",
         vconn,
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     let bytes_string = d
         .configured
@@ -244,240 +332,344 @@ fn dump_billboard_capability(d: &bos::BillboardCapability, indent: usize) {
         .map(|b| format!("{:02x}", b))
         .collect::<Vec<String>>()
         .join(" ");
-    dump_value(bytes_string, "bmConfigured", indent + 2, LSUSB_DUMP_WIDTH);
+    dump_value(
+        out,
+        bytes_string,
+        "bmConfigured",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
 
-    dump_value(d.version, "bcdVersion", indent + 2, LSUSB_DUMP_WIDTH);
+    dump_value(out, d.version, "bcdVersion", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         d.additional_failure_info,
         "bAdditionalFailureInfo",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
-    dump_value(d.reserved, "bReserved", indent + 2, LSUSB_DUMP_WIDTH);
+    )?;
+    dump_value(out, d.reserved, "bReserved", indent + 2, LSUSB_DUMP_WIDTH)?;
 
-    dump_string("Alternate Modes supported by Device Container:", indent + 2);
+    dump_string(
+        out,
+        "Alternate Modes supported by Device Container:",
+        indent + 2,
+    )?;
     for (alt_mode, am) in d.alternate_modes.iter().enumerate() {
         let state = ((d.configured[alt_mode >> 2] >> ((alt_mode & 0x3) << 1)) & 0x3) as usize;
         dump_string(
+            out,
             &format!("Alternate Mode {} : {}", alt_mode, ALT_MODE_STATE[state]),
             indent + 2,
-        );
+        )?;
         dump_hex(
+            out,
             am.svid,
             &format!("wSVID[{}]", alt_mode),
             indent + 4,
             LSUSB_DUMP_WIDTH,
-        );
+        )?;
         dump_value(
+            out,
             am.alternate_mode,
             &format!("bAlternateMode[{}]", alt_mode),
             indent + 4,
             LSUSB_DUMP_WIDTH,
-        );
+        )?;
         dump_value_string(
+            out,
             am.alternate_mode_string_index,
             &format!("iAlternateModeString[{}]", alt_mode),
             am.alternate_mode_string.as_ref().unwrap_or(&String::new()),
             indent + 4,
             LSUSB_DUMP_WIDTH,
-        );
+        )?;
     }
+
+    Ok(())
 }
 
-fn dump_billboard_alt_mode_capability(d: &bos::BillboardAltModeCapability, indent: usize) {
-    dump_string("Billboard Alternate Mode Capability:", indent);
-    dump_value(d.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
+fn dump_billboard_alt_mode_capability(
+    out: &mut DumpWriter,
+    d: &bos::BillboardAltModeCapability,
+    indent: usize,
+) -> fmt::Result {
+    dump_string(out, "Billboard Alternate Mode Capability:", indent)?;
+    dump_value(out, d.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         d.descriptor_type,
         "bDescriptorType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         u8::from(d.capability_type.to_owned()),
         "bDevCapabilityType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
-    dump_value(d.index, "bIndex", indent + 2, LSUSB_DUMP_WIDTH);
+    )?;
+    dump_value(out, d.index, "bIndex", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_hex(
+        out,
         d.alternate_mode_vdo,
         "dwAlternateModeVdo",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
+
+    Ok(())
 }
 
 fn dump_platform_device_capability(
+    out: &mut DumpWriter,
     d: &bos::PlatformDeviceCompatibility,
     data: bool,
     indent: usize,
-) {
-    dump_string("Platform Device Capability:", indent);
-    dump_value(d.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
+) -> fmt::Result {
+    dump_string(out, "Platform Device Capability:", indent)?;
+    dump_value(out, d.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         d.descriptor_type,
         "bDescriptorType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         d.compatibility_type.to_owned(),
         "bDevCapabilityType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
-    dump_value(d.reserved, "bReserved", indent + 2, LSUSB_DUMP_WIDTH);
+    )?;
+    dump_value(out, d.reserved, "bReserved", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_guid(
+        out,
         &d.guid,
         "PlatformCapabilityUUID",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     // Dump the data if requested
     if data {
         for (i, b) in d.data.iter().enumerate() {
             dump_hex(
+                out,
                 *b,
                 &format!("CapabilityData[{}]", i),
                 indent + 2,
                 LSUSB_DUMP_WIDTH,
-            );
+            )?;
         }
     }
+
+    Ok(())
 }
 
-fn dump_webusb_platform_capability(d: &bos::WebUsbPlatformCapability, indent: usize) {
-    dump_platform_device_capability(&d.platform, false, indent);
-    dump_string("WebUSB", indent + 4);
-    dump_value(d.version, "bcdVersion", indent + 6, LSUSB_DUMP_WIDTH);
-    dump_value(d.vendor_code, "bVendorCode", indent + 6, LSUSB_DUMP_WIDTH);
+fn dump_webusb_platform_capability(
+    out: &mut DumpWriter,
+    d: &bos::WebUsbPlatformCapability,
+    indent: usize,
+) -> fmt::Result {
+    dump_platform_device_capability(out, &d.platform, false, indent)?;
+    dump_string(out, "WebUSB", indent + 4)?;
+    dump_value(out, d.version, "bcdVersion", indent + 6, LSUSB_DUMP_WIDTH)?;
+    dump_value(
+        out,
+        d.vendor_code,
+        "bVendorCode",
+        indent + 6,
+        LSUSB_DUMP_WIDTH,
+    )?;
     dump_value_string(
+        out,
         d.landing_page_index,
         "iLandingPage",
         d.url.as_ref().unwrap_or(&String::new()),
         indent + 6,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     for (i, b) in d.platform.data.iter().enumerate() {
         dump_hex(
+            out,
             *b,
             &format!("CapabilityData[{}]", i),
             indent + 2,
             LSUSB_DUMP_WIDTH,
-        );
+        )?;
     }
+
+    Ok(())
 }
 
-pub fn dump_container_id_capability(d: &bos::ContainerIdCapability, indent: usize) {
-    dump_string("Container ID Device Capability:", indent);
-    dump_value(d.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
+pub fn dump_container_id_capability(
+    out: &mut DumpWriter,
+    d: &bos::ContainerIdCapability,
+    indent: usize,
+) -> fmt::Result {
+    dump_string(out, "Container ID Device Capability:", indent)?;
+    dump_value(out, d.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         d.descriptor_type,
         "bDescriptorType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         u8::from(d.capability_type.to_owned()),
         "bDevCapabilityType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
-    dump_value(d.reserved, "bReserved", indent + 2, LSUSB_DUMP_WIDTH);
-    dump_guid(&d.container_id, "ContainerID", indent + 2, LSUSB_DUMP_WIDTH);
+    )?;
+    dump_value(out, d.reserved, "bReserved", indent + 2, LSUSB_DUMP_WIDTH)?;
+    dump_guid(
+        out,
+        &d.container_id,
+        "ContainerID",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+
+    Ok(())
 }
 
-fn dump_usb3_dc_configuration_summary(d: &bos::ConfigurationSummaryCapability, indent: usize) {
-    dump_string("Configuration Summary Device Capability:", indent);
-    dump_value(d.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
+fn dump_usb3_dc_configuration_summary(
+    out: &mut DumpWriter,
+    d: &bos::ConfigurationSummaryCapability,
+    indent: usize,
+) -> fmt::Result {
+    dump_string(out, "Configuration Summary Device Capability:", indent)?;
+    dump_value(out, d.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         d.descriptor_type,
         "bDescriptorType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         u8::from(d.capability_type.to_owned()),
         "bDevCapabilityType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
-    dump_value(d.version, "bcdVersion", indent + 2, LSUSB_DUMP_WIDTH);
-    dump_value(d.class, "bClass", indent + 2, LSUSB_DUMP_WIDTH);
-    dump_value(d.sub_class, "bSubClass", indent + 2, LSUSB_DUMP_WIDTH);
-    dump_value(d.protocol, "bProtocol", indent + 2, LSUSB_DUMP_WIDTH);
+    )?;
+    dump_value(out, d.version, "bcdVersion", indent + 2, LSUSB_DUMP_WIDTH)?;
+    dump_value(out, d.class, "bClass", indent + 2, LSUSB_DUMP_WIDTH)?;
+    dump_value(out, d.sub_class, "bSubClass", indent + 2, LSUSB_DUMP_WIDTH)?;
+    dump_value(out, d.protocol, "bProtocol", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         d.configuration_count,
         "bConfigurationCount",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_array(
+        out,
         &d.configured,
         "bConfigurationIndex",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
+
+    Ok(())
+}
+
+fn dump_unknown_capability(
+    out: &mut DumpWriter,
+    capability_type: u8,
+    data: &[u8],
+    indent: usize,
+) -> fmt::Result {
+    dump_string(out, "Unknown Device Capability:", indent)?;
+    dump_value(out, data.len() + 3, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
+    dump_value(out, 0x10, "bDescriptorType", indent + 2, LSUSB_DUMP_WIDTH)?;
+    dump_hex(
+        out,
+        capability_type,
+        "bDevCapabilityType",
+        indent + 2,
+        LSUSB_DUMP_WIDTH,
+    )?;
+    dump_unrecognised(out, data, indent + 2)?;
+
+    Ok(())
 }
 
-pub(crate) fn dump_bos_descriptor(bosd: &bos::BinaryObjectStoreDescriptor, indent: usize) {
-    dump_string("Binary Object Store Descriptor:", indent);
-    dump_value(bosd.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH);
+pub(crate) fn dump_bos_descriptor(
+    out: &mut DumpWriter,
+    bosd: &bos::BinaryObjectStoreDescriptor,
+    indent: usize,
+) -> fmt::Result {
+    dump_string(out, "Binary Object Store Descriptor:", indent)?;
+    dump_value(out, bosd.length, "bLength", indent + 2, LSUSB_DUMP_WIDTH)?;
     dump_value(
+        out,
         bosd.descriptor_type,
         "bDescriptorType",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         bosd.total_length,
         "wTotalLength",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
     dump_value(
+        out,
         bosd.num_device_capabilities,
         "bNumDeviceCaps",
         indent + 2,
         LSUSB_DUMP_WIDTH,
-    );
+    )?;
 
     for cap in &bosd.capabilities {
         match cap {
             bos::BosCapability::Usb2Extension(d) => {
-                dump_extension_capability(d, indent + 2);
+                dump_extension_capability(out, d, indent + 2)?;
             }
             bos::BosCapability::SuperSpeed(d) => {
-                dump_ss_capability(d, indent + 2);
+                dump_ss_capability(out, d, indent + 2)?;
             }
             bos::BosCapability::SuperSpeedPlus(d) => {
-                dump_ss_plus_capability(d, indent + 2);
+                dump_ss_plus_capability(out, d, indent + 2)?;
             }
             bos::BosCapability::Billboard(d) => {
-                dump_billboard_capability(d, indent + 2);
+                dump_billboard_capability(out, d, indent + 2)?;
             }
             bos::BosCapability::BillboardAltMode(d) => {
-                dump_billboard_alt_mode_capability(d, indent + 2);
+                dump_billboard_alt_mode_capability(out, d, indent + 2)?;
             }
             bos::BosCapability::ContainerId(d) => {
-                dump_container_id_capability(d, indent + 2);
+                dump_container_id_capability(out, d, indent + 2)?;
             }
             bos::BosCapability::ConfigurationSummary(d) => {
-                dump_usb3_dc_configuration_summary(d, indent + 2);
+                dump_usb3_dc_configuration_summary(out, d, indent + 2)?;
             }
             bos::BosCapability::Platform(d) => {
-                dump_platform_device_capability(d, true, indent + 2);
+                dump_platform_device_capability(out, d, true, indent + 2)?;
             }
             bos::BosCapability::WebUsbPlatform(d) => {
-                dump_webusb_platform_capability(d, indent + 2);
+                dump_webusb_platform_capability(out, d, indent + 2)?;
+            }
+            bos::BosCapability::Unknown(t, data) => {
+                dump_unknown_capability(out, *t, data, indent + 2)?;
             }
             _ => {
                 let data: Vec<u8> = cap.to_owned().into();
-                dump_unrecognised(data.as_slice(), indent + 2);
+                dump_unrecognised(out, data.as_slice(), indent + 2)?;
             }
         }
     }
+
+    Ok(())
 }
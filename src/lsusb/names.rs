@@ -5,6 +5,7 @@
 //! The function names match those found in the lsusb source code.
 #[allow(unused_imports)]
 use crate::error::{Error, ErrorKind};
+use std::collections::HashMap;
 use usb_ids::{self, FromId};
 
 /// Get name of vendor from [`usb_ids::Vendor`] or [`hwdb_get`] if feature is enabled
@@ -74,6 +75,34 @@ pub fn protocol(cid: u8, scid: u8, pid: u8) -> Option<String> {
     .unwrap_or(usb_ids::Protocol::from_cid_scid_pid(cid, scid, pid).map(|v| v.name().to_owned()))
 }
 
+/// Names of the class, sub class and protocol joined by " > " like "Human Interface Device > Boot Interface Subclass > Keyboard", using [`class`], [`subclass`] and [`protocol`]
+///
+/// Levels without a name fall back to their value in hex; the chain stops at the first level without a name since lower levels are defined relative to it.
+///
+/// ```
+/// use cyme::lsusb::names;
+/// assert_eq!(
+///     names::class_chain(0x02, 0x02, 0x05),
+///     "Communications > Abstract (modem) > AT-commands (3G)"
+/// );
+/// assert_eq!(names::class_chain(0x03, 0x01, 0x7f), "Human Interface Device > Boot Interface Subclass > 0x7f");
+/// assert_eq!(names::class_chain(0x42, 0x01, 0x01), "0x42 > 0x01 > 0x01");
+/// ```
+pub fn class_chain(cid: u8, scid: u8, pid: u8) -> String {
+    let class_name = class(cid);
+    let subclass_name = class_name.as_ref().and_then(|_| subclass(cid, scid));
+    let protocol_name = subclass_name
+        .as_ref()
+        .and_then(|_| protocol(cid, scid, pid));
+
+    [
+        class_name.unwrap_or(format!("{:#04x}", cid)),
+        subclass_name.unwrap_or(format!("{:#04x}", scid)),
+        protocol_name.unwrap_or(format!("{:#04x}", pid)),
+    ]
+    .join(" > ")
+}
+
 /// Get HID descriptor type name from [`usb_ids::Hid`]
 pub fn hid(id: u8) -> Option<String> {
     usb_ids::Hid::from_id(id).map(|v| v.name().to_owned())
@@ -94,9 +123,36 @@ pub fn hutus(page_id: u8, id: u16) -> Option<String> {
     usb_ids::HidUsage::from_pageid_uid(page_id, id).map(|v| v.name().to_owned())
 }
 
-/// Get name of [`usb_ids::Language`] from id
-pub fn langid(id: u16) -> Option<String> {
-    usb_ids::Language::from_id(id).map(|v| v.name().to_owned())
+lazy_static! {
+    /// "Language (Dialect)" names of [`usb_ids::Language`] keyed by LANGID; the language alone for a dialect of the same name or the primary id without a dialect
+    static ref LANGID_NAMES: HashMap<u16, String> = {
+        let mut names = HashMap::new();
+        for language in usb_ids::Languages::iter() {
+            names.insert(language.id(), language.name().to_owned());
+            for dialect in language.dialects() {
+                let name = if dialect.name() == language.name() {
+                    language.name().to_owned()
+                } else {
+                    format!("{} ({})", language.name(), dialect.name())
+                };
+                names.insert(language.id() | ((dialect.id() as u16) << 10), name);
+            }
+        }
+        names
+    };
+}
+
+/// Get name of a LANGID from the [`usb_ids::Language`] of the primary language (bits 0..9) and [`usb_ids::Dialect`] of the sub-language (bits 10..15)
+///
+/// ```
+/// use cyme::lsusb::names;
+/// assert_eq!(names::langid(0x0409), Some("English (US)"));
+/// assert_eq!(names::langid(0x0809), Some("English (UK)"));
+/// assert_eq!(names::langid(0x0407), Some("German"));
+/// assert_eq!(names::langid(0x03ff), None);
+/// ```
+pub fn langid(id: u16) -> Option<&'static str> {
+    LANGID_NAMES.get(&id).map(|n| n.as_str())
 }
 
 /// Get name of [`usb_ids::Phy`] from id
@@ -109,16 +165,74 @@ pub fn bias(id: u8) -> Option<String> {
     usb_ids::Bias::from_id(id).map(|v| v.name().to_owned())
 }
 
-/// Get name of [`usb_ids::HidCountryCode`] from id
+/// Get name of HID country code from id
+///
+/// Uses a table from the HID specification rather than [`usb_ids::HidCountryCode`], which reads the decimal codes in usb.ids as hex
+///
+/// ```
+/// use cyme::lsusb::names;
+/// assert_eq!(names::countrycode(33), Some("US".to_owned()));
+/// assert_eq!(names::countrycode(36), None);
+/// ```
 pub fn countrycode(id: u8) -> Option<String> {
-    usb_ids::HidCountryCode::from_id(id).map(|v| v.name().to_owned())
+    crate::usb::descriptors::HID_COUNTRY_CODES
+        .get(id as usize)
+        .map(|n| n.to_string())
 }
 
-/// Get name of [`usb_ids::VideoControl`] from id
+/// Get name of [`usb_ids::VideoTerminal`] from id
 pub fn videoterminal(id: u16) -> Option<String> {
     usb_ids::VideoTerminal::from_id(id).map(|v| v.name().to_owned())
 }
 
+/// Get name of [`usb_ids::AudioTerminal`] from id
+pub fn audioterminal(id: u16) -> Option<String> {
+    usb_ids::AudioTerminal::from_id(id).map(|v| v.name().to_owned())
+}
+
+/// Class domain a terminal type belongs to
+///
+/// UAC and UVC share the USB terminal types (0x01xx) but assign different meanings to the rest; 0x0201 is a Microphone in audio but a Camera Sensor in video.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminalDomain {
+    /// USB Audio Class terminal types
+    Audio,
+    /// USB Video Class terminal types
+    Video,
+}
+
+/// USB terminal types common to both audio and video terminal tables
+///
+/// UAC names 0x0100 'USB Undefined' with 0x01ff vendor specific, whilst UVC uses 0x0100 as vendor specific.
+fn usb_terminal(id: u16, domain: TerminalDomain) -> Option<&'static str> {
+    match (id, domain) {
+        (0x0101, _) => Some("USB Streaming"),
+        (0x0100, TerminalDomain::Audio) => Some("USB Undefined"),
+        (0x0100, TerminalDomain::Video) | (0x01ff, TerminalDomain::Audio) => {
+            Some("USB Vendor Specific")
+        }
+        _ => None,
+    }
+}
+
+/// Get name of terminal type `id` within `domain`, checking the common USB terminal types before [`audioterminal`] or [`videoterminal`]
+///
+/// ```
+/// use cyme::lsusb::names::{self, TerminalDomain};
+/// assert_eq!(names::terminal(0x0101, TerminalDomain::Audio), Some("USB Streaming".to_owned()));
+/// assert_eq!(names::terminal(0x0101, TerminalDomain::Video), Some("USB Streaming".to_owned()));
+/// assert_eq!(names::terminal(0x0201, TerminalDomain::Audio), Some("Microphone".to_owned()));
+/// assert_eq!(names::terminal(0x0201, TerminalDomain::Video), Some("Camera Sensor".to_owned()));
+/// ```
+pub fn terminal(id: u16, domain: TerminalDomain) -> Option<String> {
+    usb_terminal(id, domain)
+        .map(|s| s.to_owned())
+        .or_else(|| match domain {
+            TerminalDomain::Audio => audioterminal(id),
+            TerminalDomain::Video => videoterminal(id),
+        })
+}
+
 /// Wrapper around [`crate::udev::hwdb_get`] so that it can be 'used' without feature
 ///
 /// Returns `Err` not `None` if feature is not enabled so that with unwrap_or hwdb can still return `None` if no match in db
@@ -84,187 +84,284 @@ const STD_NAMES: [&str; 6] = [
     "PAL - 525/60",
 ];
 
-fn dump_processing_unit(pu: &video::ProcessingUnit, protocol: u8, indent: usize, width: usize) {
-    dump_value(pu.unit_id, "bUnitID", indent + 2, width);
-    dump_value(pu.source_id, "bSourceID", indent + 2, width);
-    dump_value(pu.max_multiplier, "wMaxMultiplier", indent + 2, width);
-    dump_value(pu.control_size, "bControlSize", indent + 2, width);
+fn dump_processing_unit(
+    out: &mut DumpWriter,
+    pu: &video::ProcessingUnit,
+    protocol: u8,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, pu.unit_id, "bUnitID", indent + 2, width)?;
+    dump_value(out, pu.source_id, "bSourceID", indent + 2, width)?;
+    dump_value(out, pu.max_multiplier, "wMaxMultiplier", indent + 2, width)?;
+    dump_value(out, pu.control_size, "bControlSize", indent + 2, width)?;
 
-    dump_hex(pu.controls, "bmControls", indent + 2, width);
+    dump_hex(out, pu.controls, "bmControls", indent + 2, width)?;
     if protocol == 0x01 {
         for (i, n) in CTRL_NAMES.iter().enumerate().take(19) {
             if (pu.controls >> i) & 1 != 0 {
-                dump_string(n, indent + 4);
+                dump_string(out, n, indent + 4)?;
             }
         }
     } else {
         for (i, n) in CTRL_NAMES.iter().enumerate().take(18) {
             if (pu.controls >> i) & 1 != 0 {
-                dump_string(n, indent + 4);
+                dump_string(out, n, indent + 4)?;
             }
         }
     }
 
     dump_value_string(
+        out,
         pu.processing_index,
         "iProcessing",
         pu.processing_index.to_string(),
         indent + 2,
         width,
-    );
-    dump_hex(pu.video_standards, "bmVideoStandards", indent + 2, width);
+    )?;
+    dump_hex(
+        out,
+        pu.video_standards,
+        "bmVideoStandards",
+        indent + 2,
+        width,
+    )?;
     for (i, n) in STD_NAMES.iter().enumerate().take(6) {
         if (pu.video_standards >> i) & 1 != 0 {
-            dump_string(n, indent + 4);
+            dump_string(out, n, indent + 4)?;
         }
     }
+
+    Ok(())
 }
 
-fn dump_extension_unit(eu: &video::ExtensionUnit, indent: usize, width: usize) {
-    dump_value(eu.unit_id, "bUnitID", indent + 2, width);
+fn dump_extension_unit(
+    out: &mut DumpWriter,
+    eu: &video::ExtensionUnit,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, eu.unit_id, "bUnitID", indent + 2, width)?;
     dump_guid(
+        out,
         &eu.guid_extension_code,
         "guidExtensionCode",
         indent + 2,
         width,
-    );
-    dump_value(eu.num_controls, "bNumControls", indent + 2, width);
-    dump_value(eu.num_input_pins, "bNrInPins", indent + 2, width);
+    )?;
+    dump_value(out, eu.num_controls, "bNumControls", indent + 2, width)?;
+    dump_value(out, eu.num_input_pins, "bNrInPins", indent + 2, width)?;
 
     for (i, source_id) in eu.source_ids.iter().enumerate() {
         dump_value(
+            out,
             *source_id,
             &format!("baSourceID({:2})", i),
             indent + 2,
             width,
-        );
+        )?;
     }
 
-    dump_value(eu.control_size, "bControlSize", indent + 2, width);
+    dump_value(out, eu.control_size, "bControlSize", indent + 2, width)?;
 
     for (i, control) in eu.controls.iter().enumerate() {
-        dump_hex(*control, &format!("bmControls({:2})", i), indent + 2, width);
+        dump_hex(
+            out,
+            *control,
+            &format!("bmControls({:2})", i),
+            indent + 2,
+            width,
+        )?;
     }
 
     dump_value_string(
+        out,
         eu.extension_index,
         "iExtension",
         eu.extension.as_ref().unwrap_or(&String::new()),
         indent + 2,
         width,
-    );
+    )?;
+
+    Ok(())
 }
 
-fn dump_encoding_unit(eu: &video::EncodingUnit, indent: usize, width: usize) {
-    dump_value(eu.unit_id, "bUnitID", indent + 2, width);
-    dump_value(eu.source_id, "bSourceID", indent + 2, width);
+fn dump_encoding_unit(
+    out: &mut DumpWriter,
+    eu: &video::EncodingUnit,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, eu.unit_id, "bUnitID", indent + 2, width)?;
+    dump_value(out, eu.source_id, "bSourceID", indent + 2, width)?;
     dump_value_string(
+        out,
         eu.encoding_index,
         "iEncoding",
         eu.encoding.as_ref().unwrap_or(&String::new()),
         indent + 2,
         width,
-    );
-    dump_value(eu.control_size, "bControlSize", indent + 2, width);
+    )?;
+    dump_value(out, eu.control_size, "bControlSize", indent + 2, width)?;
 
-    dump_hex(eu.controls, "bmControls", indent + 2, width);
+    dump_hex(out, eu.controls, "bmControls", indent + 2, width)?;
     for (i, n) in EN_CTRL_NAMES.iter().enumerate().take(20) {
         if (eu.controls >> i) & 1 != 0 {
-            dump_string(n, indent + 4);
+            dump_string(out, n, indent + 4)?;
         }
     }
 
-    dump_hex(eu.controls_runtime, "bmControlsRuntime", indent + 2, width);
+    dump_hex(
+        out,
+        eu.controls_runtime,
+        "bmControlsRuntime",
+        indent + 2,
+        width,
+    )?;
     for (i, n) in EN_CTRL_NAMES.iter().enumerate().take(20) {
         if (eu.controls_runtime >> i) & 1 != 0 {
-            dump_string(n, indent + 4);
+            dump_string(out, n, indent + 4)?;
         }
     }
+
+    Ok(())
 }
 
 pub(crate) fn dump_videocontrol_interface(
+    out: &mut DumpWriter,
     vcd: &video::UvcDescriptor,
     vct: &video::ControlSubtype,
     protocol: u8,
     indent: usize,
-) {
+) -> fmt::Result {
     const DUMP_WIDTH: usize = 36; // wider in lsusb for long numbers
-    dump_string("VideoControl Interface Descriptor:", indent);
-    dump_value(vcd.length, "bLength", indent + 2, DUMP_WIDTH);
+    dump_string(out, "VideoControl Interface Descriptor:", indent)?;
+    dump_value(out, vcd.length, "bLength", indent + 2, DUMP_WIDTH)?;
     dump_value(
+        out,
         vcd.descriptor_type,
         "bDescriptorType",
         indent + 2,
         DUMP_WIDTH,
-    );
+    )?;
     dump_value_string(
+        out,
         vct.to_owned() as u8,
         "bDescriptorSubtype",
         format!("({:#})", vct),
         indent + 2,
         DUMP_WIDTH,
-    );
+    )?;
 
     match &vcd.interface {
         video::UvcInterfaceDescriptor::Invalid(_) => {
-            println!(
-                "{:indent$}Warning: {:#} descriptors are illegal for {}",
-                "",
-                vct,
-                protocol.to_owned(),
-                indent = indent
-            );
+            dump_warning(
+                out,
+                DumpWarning {
+                    kind: DumpWarningKind::Illegal,
+                    descriptor: "VideoControl Interface".into(),
+                    offset: 2,
+                    message: format!(
+                        "{:#} descriptors are illegal for {}",
+                        vct,
+                        protocol.to_owned()
+                    ),
+                },
+                indent,
+            )?;
         }
-        uvcid => dump_video_subtype(uvcid, protocol, indent, DUMP_WIDTH),
+        uvcid => dump_video_subtype(out, uvcid, protocol, indent, DUMP_WIDTH)?,
     }
+
+    Ok(())
 }
 
-fn dump_video_input_header(ih: &video::InputHeader, indent: usize, width: usize) {
-    dump_value(ih.num_formats, "bNumFormats", indent + 2, width);
-    dump_hex(ih.total_length, "wTotalLength", indent + 2, width);
+fn dump_video_input_header(
+    out: &mut DumpWriter,
+    ih: &video::InputHeader,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, ih.num_formats, "bNumFormats", indent + 2, width)?;
+    dump_hex(out, ih.total_length, "wTotalLength", indent + 2, width)?;
     dump_value_string(
+        out,
         format!("0x{:02x}", ih.endpoint_address.address),
         "bEndpointAddress",
         ih.endpoint_address.to_string(),
         indent + 2,
         width,
-    );
-    dump_value(ih.info, "bmInfo", indent + 2, width);
-    dump_value(ih.terminal_link, "bTerminalLink", indent + 2, width);
+    )?;
+    dump_value(out, ih.info, "bmInfo", indent + 2, width)?;
+    dump_value(out, ih.terminal_link, "bTerminalLink", indent + 2, width)?;
     dump_value(
+        out,
         ih.still_capture_method,
         "bStillCaptureMethod",
         indent + 2,
         width,
-    );
-    dump_value(ih.trigger_support, "bTriggerSupport", indent + 2, width);
-    dump_value(ih.trigger_usage, "bTriggerUsage", indent + 2, width);
-    dump_value(ih.control_size, "bControlSize", indent + 2, width);
+    )?;
+    dump_value(
+        out,
+        ih.trigger_support,
+        "bTriggerSupport",
+        indent + 2,
+        width,
+    )?;
+    dump_value(out, ih.trigger_usage, "bTriggerUsage", indent + 2, width)?;
+    dump_value(out, ih.control_size, "bControlSize", indent + 2, width)?;
 
     for (i, b) in ih.controls.chunks(ih.control_size as usize).enumerate() {
-        dump_value(b[0], &format!("bmaControls({:2})", i), indent + 2, width);
+        dump_value(
+            out,
+            b[0],
+            &format!("bmaControls({:2})", i),
+            indent + 2,
+            width,
+        )?;
     }
+
+    Ok(())
 }
 
-fn dump_video_output_header(oh: &video::OutputHeader, indent: usize, width: usize) {
-    dump_value(oh.num_formats, "bNumFormats", indent + 2, width);
-    dump_hex(oh.total_length, "wTotalLength", indent + 2, width);
+fn dump_video_output_header(
+    out: &mut DumpWriter,
+    oh: &video::OutputHeader,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, oh.num_formats, "bNumFormats", indent + 2, width)?;
+    dump_hex(out, oh.total_length, "wTotalLength", indent + 2, width)?;
     dump_value_string(
+        out,
         format!("0x{:02x}", oh.endpoint_address.address),
         "bEndpointAddress",
         oh.endpoint_address.to_string(),
         indent + 2,
         width,
-    );
-    dump_value(oh.terminal_link, "bTerminalLink", indent + 2, width);
-    dump_value(oh.control_size, "bControlSize", indent + 2, width);
+    )?;
+    dump_value(out, oh.terminal_link, "bTerminalLink", indent + 2, width)?;
+    dump_value(out, oh.control_size, "bControlSize", indent + 2, width)?;
 
     for (i, b) in oh.controls.chunks(oh.control_size as usize).enumerate() {
-        dump_value(b[0], &format!("bmaControls({:2})", i), indent + 2, width);
+        dump_value(
+            out,
+            b[0],
+            &format!("bmaControls({:2})", i),
+            indent + 2,
+            width,
+        )?;
     }
+
+    Ok(())
 }
 
-fn dump_video_color_format(cf: &video::ColorFormat, indent: usize, width: usize) {
+fn dump_video_color_format(
+    out: &mut DumpWriter,
+    cf: &video::ColorFormat,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
     let color_primatives = |c: u8| match c {
         1 => "BT.709,sRGB",
         2 => "BT.470-2 (M)",
@@ -295,13 +392,15 @@ fn dump_video_color_format(cf: &video::ColorFormat, indent: usize, width: usize)
     };
 
     dump_value_string(
+        out,
         cf.color_primaries,
         "bColorPrimaries",
         format!("({})", color_primatives(cf.color_primaries)),
         indent + 2,
         width,
-    );
+    )?;
     dump_value_string(
+        out,
         cf.transfer_characteristics,
         "bTransferCharacteristics",
         format!(
@@ -310,33 +409,55 @@ fn dump_video_color_format(cf: &video::ColorFormat, indent: usize, width: usize)
         ),
         indent + 2,
         width,
-    );
+    )?;
     dump_value_string(
+        out,
         cf.matrix_coefficients,
         "bMatrixCoefficients",
         format!("({})", matrix_coefficients(cf.matrix_coefficients)),
         indent + 2,
         width,
-    );
+    )?;
+
+    Ok(())
 }
 
-fn dump_format_stream_based(fs: &video::FormatStreamBased, indent: usize, width: usize) {
-    dump_value(fs.format_index, "bFormatIndex", indent + 2, width);
-    dump_guid(&fs.guid_format, "guidFormat", indent + 2, width);
-    dump_value(fs.packet_length, "dwPacketLength", indent + 2, width);
+fn dump_format_stream_based(
+    out: &mut DumpWriter,
+    fs: &video::FormatStreamBased,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, fs.format_index, "bFormatIndex", indent + 2, width)?;
+    dump_guid(out, &fs.guid_format, "guidFormat", indent + 2, width)?;
+    dump_value(out, fs.packet_length, "dwPacketLength", indent + 2, width)?;
+
+    Ok(())
 }
 
-fn dump_format_mpeg2ts(fmts: &video::FormatMPEG2TS, indent: usize, width: usize) {
-    dump_value(fmts.format_index, "bFormatIndex", indent + 2, width);
-    dump_value(fmts.data_offset, "bDataOffset", indent + 2, width);
-    dump_value(fmts.packet_length, "bPacketLength", indent + 2, width);
-    dump_value(fmts.stride_length, "bStrideLength", indent + 2, width);
+fn dump_format_mpeg2ts(
+    out: &mut DumpWriter,
+    fmts: &video::FormatMPEG2TS,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, fmts.format_index, "bFormatIndex", indent + 2, width)?;
+    dump_value(out, fmts.data_offset, "bDataOffset", indent + 2, width)?;
+    dump_value(out, fmts.packet_length, "bPacketLength", indent + 2, width)?;
+    dump_value(out, fmts.stride_length, "bStrideLength", indent + 2, width)?;
     if let Some(guid) = &fmts.guid_stride_format {
-        dump_guid(guid, "guidStrideFormat", indent + 2, width);
+        dump_guid(out, guid, "guidStrideFormat", indent + 2, width)?;
     }
+
+    Ok(())
 }
 
-fn dump_interlace_flags(interlace_flags: u8, indent: usize, width: usize) {
+fn dump_interlace_flags(
+    out: &mut DumpWriter,
+    interlace_flags: u8,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
     let field_pattern = |f: u8| -> &'static str {
         match f {
             0 => "Field 1 only",
@@ -347,8 +468,9 @@ fn dump_interlace_flags(interlace_flags: u8, indent: usize, width: usize) {
         }
     };
 
-    dump_hex(interlace_flags, "bmInterlaceFlags", indent, width);
+    dump_hex(out, interlace_flags, "bmInterlaceFlags", indent, width)?;
     dump_string(
+        out,
         &format!(
             "Interlaced stream or variable: {}",
             if interlace_flags & 0x01 != 0 {
@@ -358,8 +480,9 @@ fn dump_interlace_flags(interlace_flags: u8, indent: usize, width: usize) {
             }
         ),
         indent + 2,
-    );
+    )?;
     dump_string(
+        out,
         &format!(
             "Fields per frame: {}",
             if interlace_flags & 0x02 != 0 {
@@ -369,8 +492,9 @@ fn dump_interlace_flags(interlace_flags: u8, indent: usize, width: usize) {
             }
         ),
         indent + 2,
-    );
+    )?;
     dump_string(
+        out,
         &format!(
             "Field 1 first: {}",
             if interlace_flags & 0x04 != 0 {
@@ -380,26 +504,36 @@ fn dump_interlace_flags(interlace_flags: u8, indent: usize, width: usize) {
             }
         ),
         indent + 2,
-    );
+    )?;
     dump_string(
+        out,
         &format!(
             "Field pattern: {}",
             field_pattern((interlace_flags >> 4) & 0x03)
         ),
         indent + 2,
-    );
+    )?;
+
+    Ok(())
 }
 
-fn dump_format_mjpeg(fmjpeg: &video::FormatMJPEG, indent: usize, width: usize) {
-    dump_value(fmjpeg.format_index, "bFormatIndex", indent + 2, width);
+fn dump_format_mjpeg(
+    out: &mut DumpWriter,
+    fmjpeg: &video::FormatMJPEG,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, fmjpeg.format_index, "bFormatIndex", indent + 2, width)?;
     dump_value(
+        out,
         fmjpeg.num_frame_descriptors,
         "bNumFrameDescriptors",
         indent + 2,
         width,
-    );
-    dump_value(fmjpeg.flags, "bmFlags", indent + 2, width);
+    )?;
+    dump_value(out, fmjpeg.flags, "bmFlags", indent + 2, width)?;
     dump_string(
+        out,
         &format!(
             "Fixed-size samples: {}",
             if fmjpeg.flags & 0x01 != 0 {
@@ -409,207 +543,314 @@ fn dump_format_mjpeg(fmjpeg: &video::FormatMJPEG, indent: usize, width: usize) {
             }
         ),
         indent + 2,
-    );
+    )?;
     dump_value(
+        out,
         fmjpeg.default_frame_index,
         "bDefaultFrameIndex",
         indent + 2,
         width,
-    );
-    dump_value(fmjpeg.aspect_ratio_x, "bAspectRatioX", indent + 2, width);
-    dump_value(fmjpeg.aspect_ratio_y, "bAspectRatioY", indent + 2, width);
-    dump_interlace_flags(fmjpeg.interlace_flags, indent + 2, width);
-    dump_value(fmjpeg.copy_protect, "bCopyProtect", indent + 2, width);
+    )?;
+    dump_value(
+        out,
+        fmjpeg.aspect_ratio_x,
+        "bAspectRatioX",
+        indent + 2,
+        width,
+    )?;
+    dump_value(
+        out,
+        fmjpeg.aspect_ratio_y,
+        "bAspectRatioY",
+        indent + 2,
+        width,
+    )?;
+    dump_interlace_flags(out, fmjpeg.interlace_flags, indent + 2, width)?;
+    dump_value(out, fmjpeg.copy_protect, "bCopyProtect", indent + 2, width)?;
+
+    Ok(())
 }
 
-fn dump_still_image_frame(sif: &video::StillImageFrame, indent: usize, width: usize) {
+fn dump_still_image_frame(
+    out: &mut DumpWriter,
+    sif: &video::StillImageFrame,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
     dump_value_string(
+        out,
         sif.endpoint_address.address,
         "bEndpointAddress",
         sif.endpoint_address.to_string(),
         indent + 2,
         width,
-    );
+    )?;
     dump_value(
+        out,
         sif.num_image_size_patterns,
         "bNumImageSizePatterns",
         indent + 2,
         width,
-    );
+    )?;
 
     for (i, (w, h)) in sif.image_size_patterns.iter().enumerate() {
-        dump_value(*w, &format!("wWidth({:2})", i), indent + 2, width);
-        dump_value(*h, &format!("wHeight({:2})", i), indent + 2, width);
+        dump_value(out, *w, &format!("wWidth({:2})", i), indent + 2, width)?;
+        dump_value(out, *h, &format!("wHeight({:2})", i), indent + 2, width)?;
     }
 
     dump_value(
+        out,
         sif.num_compression_patterns,
         "bNumCompressionPatterns",
         indent + 2,
         width,
-    );
+    )?;
 
     for (i, b) in sif.compression_patterns.iter().enumerate() {
-        dump_value(*b, &format!("bCompression({:2})", i), indent + 2, width);
+        dump_value(
+            out,
+            *b,
+            &format!("bCompression({:2})", i),
+            indent + 2,
+            width,
+        )?;
     }
+
+    Ok(())
 }
 
-fn dump_format_frame(fufb: &video::FormatFrame, indent: usize, width: usize) {
-    dump_value(fufb.format_index, "bFormatIndex", indent + 2, width);
+fn dump_format_frame(
+    out: &mut DumpWriter,
+    fufb: &video::FormatFrame,
+    indent: usize,
+    width: usize,
+) -> fmt::Result {
+    dump_value(out, fufb.format_index, "bFormatIndex", indent + 2, width)?;
     dump_value(
+        out,
         fufb.num_frame_descriptors,
         "bNumFrameDescriptors",
         indent + 2,
         width,
-    );
-    dump_guid(&fufb.guid_format, "guidFormat", indent + 2, width);
-    dump_value(fufb.bits_per_pixel, "bBitsPerPixel", indent + 2, width);
+    )?;
+    dump_guid(out, &fufb.guid_format, "guidFormat", indent + 2, width)?;
+    dump_value(out, fufb.bits_per_pixel, "bBitsPerPixel", indent + 2, width)?;
     dump_value(
+        out,
         fufb.default_frame_index,
         "bDefaultFrameIndex",
         indent + 2,
         width,
-    );
-    dump_value(fufb.aspect_ratio_x, "bAspectRatioX", indent + 2, width);
-    dump_value(fufb.aspect_ratio_y, "bAspectRatioY", indent + 2, width);
-    dump_hex(fufb.interlace_flags, "bmInterlaceFlags", indent + 2, width);
-    dump_value(fufb.copy_protect, "bCopyProtect", indent + 2, width);
-    dump_interlace_flags(fufb.interlace_flags, indent + 2, width);
+    )?;
+    dump_value(out, fufb.aspect_ratio_x, "bAspectRatioX", indent + 2, width)?;
+    dump_value(out, fufb.aspect_ratio_y, "bAspectRatioY", indent + 2, width)?;
+    dump_hex(
+        out,
+        fufb.interlace_flags,
+        "bmInterlaceFlags",
+        indent + 2,
+        width,
+    )?;
+    dump_value(out, fufb.copy_protect, "bCopyProtect", indent + 2, width)?;
+    dump_interlace_flags(out, fufb.interlace_flags, indent + 2, width)?;
     if let Some(variable_size) = fufb.variable_size {
-        dump_value(variable_size, "bVariableSize", indent + 2, width);
+        dump_value(out, variable_size, "bVariableSize", indent + 2, width)?;
     }
+
+    Ok(())
 }
 
-fn dump_frame(frame: &video::FrameCommon, indent: usize, dump_width: usize) {
-    dump_value(frame.frame_index, "bFrameIndex", indent + 2, dump_width);
-    dump_hex(frame.capabilities, "bmCapabilities", indent + 2, dump_width);
+fn dump_frame(
+    out: &mut DumpWriter,
+    frame: &video::FrameCommon,
+    indent: usize,
+    dump_width: usize,
+) -> fmt::Result {
+    dump_value(
+        out,
+        frame.frame_index,
+        "bFrameIndex",
+        indent + 2,
+        dump_width,
+    )?;
+    dump_hex(
+        out,
+        frame.capabilities,
+        "bmCapabilities",
+        indent + 2,
+        dump_width,
+    )?;
     if frame.capabilities & 0x01 != 0 {
-        dump_string("Still image supported", indent + 4);
+        dump_string(out, "Still image supported", indent + 4)?;
     } else {
-        dump_string("Still image unsupported", indent + 4);
+        dump_string(out, "Still image unsupported", indent + 4)?;
     }
     if frame.capabilities & 0x02 != 0 {
-        dump_string("Fixed frame-rate", indent + 4);
+        dump_string(out, "Fixed frame-rate", indent + 4)?;
     }
-    dump_value(frame.width, "wWidth", indent + 2, dump_width);
-    dump_value(frame.height, "wHeight", indent + 2, dump_width);
-    dump_value(frame.min_bit_rate, "dwMinBitRate", indent + 2, dump_width);
-    dump_value(frame.max_bit_rate, "dwMaxBitRate", indent + 2, dump_width);
+    dump_value(out, frame.width, "wWidth", indent + 2, dump_width)?;
+    dump_value(out, frame.height, "wHeight", indent + 2, dump_width)?;
+    dump_value(
+        out,
+        frame.min_bit_rate,
+        "dwMinBitRate",
+        indent + 2,
+        dump_width,
+    )?;
+    dump_value(
+        out,
+        frame.max_bit_rate,
+        "dwMaxBitRate",
+        indent + 2,
+        dump_width,
+    )?;
+
+    Ok(())
 }
 
-fn dump_frame_uncompressed(frame: &video::FrameUncompressed, indent: usize, dump_width: usize) {
-    dump_frame(&frame.common, indent, dump_width);
+fn dump_frame_uncompressed(
+    out: &mut DumpWriter,
+    frame: &video::FrameUncompressed,
+    indent: usize,
+    dump_width: usize,
+) -> fmt::Result {
+    dump_frame(out, &frame.common, indent, dump_width)?;
     dump_value(
+        out,
         frame.max_video_frame_buffer_size,
         "dwMaxVideoFrameBufferSize",
         indent + 2,
         dump_width,
-    );
+    )?;
     dump_value(
+        out,
         frame.default_frame_interval,
         "dwDefaultFrameInterval",
         indent + 2,
         dump_width,
-    );
+    )?;
     dump_value(
+        out,
         frame.frame_interval_type,
         "bFrameIntervalType",
         indent + 2,
         dump_width,
-    );
+    )?;
     if frame.frame_interval_type == 0 {
         dump_value(
+            out,
             frame.frame_intervals[0],
             "dwMinFrameInterval",
             indent + 2,
             dump_width,
-        );
+        )?;
         dump_value(
+            out,
             frame.frame_intervals[1],
             "dwMaxFrameInterval",
             indent + 2,
             dump_width,
-        );
+        )?;
         dump_value(
+            out,
             frame.frame_intervals[2],
             "dwFrameIntervalStep",
             indent + 2,
             dump_width,
-        );
+        )?;
     } else {
         for (i, interval) in frame.frame_intervals.iter().enumerate() {
             dump_value(
+                out,
                 *interval,
                 &format!("dwFrameInterval({:2})", i),
                 indent + 2,
                 dump_width,
-            );
+            )?;
         }
     }
+
+    Ok(())
 }
 
-fn dump_frame_framebased(frame: &video::FrameFrameBased, indent: usize, dump_width: usize) {
-    dump_frame(&frame.common, indent, dump_width);
+fn dump_frame_framebased(
+    out: &mut DumpWriter,
+    frame: &video::FrameFrameBased,
+    indent: usize,
+    dump_width: usize,
+) -> fmt::Result {
+    dump_frame(out, &frame.common, indent, dump_width)?;
     dump_value(
+        out,
         frame.default_frame_interval,
         "dwDefaultFrameInterval",
         indent + 2,
         dump_width,
-    );
+    )?;
     dump_value(
+        out,
         frame.frame_interval_type,
         "bFrameIntervalType",
         indent + 2,
         dump_width,
-    );
+    )?;
     dump_value(
+        out,
         frame.bytes_per_line,
         "dwBytesPerLine",
         indent + 2,
         dump_width,
-    );
+    )?;
     if frame.frame_interval_type == 0 {
         dump_value(
+            out,
             frame.frame_intervals[0],
             "dwMinFrameInterval",
             indent + 2,
             dump_width,
-        );
+        )?;
         dump_value(
+            out,
             frame.frame_intervals[1],
             "dwMaxFrameInterval",
             indent + 2,
             dump_width,
-        );
+        )?;
         dump_value(
+            out,
             frame.frame_intervals[2],
             "dwFrameIntervalStep",
             indent + 2,
             dump_width,
-        );
+        )?;
     } else {
         for (i, interval) in frame.frame_intervals.iter().enumerate() {
             dump_value(
+                out,
                 *interval,
                 &format!("dwFrameInterval({:2})", i),
                 indent + 2,
                 dump_width,
-            );
+            )?;
         }
     }
+
+    Ok(())
 }
 
 fn dump_video_subtype(
+    out: &mut DumpWriter,
     uvcid: &video::UvcInterfaceDescriptor,
     protocol: u8,
     indent: usize,
     width: usize,
-) {
+) -> fmt::Result {
     match uvcid {
         video::UvcInterfaceDescriptor::Header(h) => {
-            dump_value(h.version, "bcdUVC", indent + 2, width);
-            dump_hex(h.total_length, "wTotalLength", indent + 2, width);
+            dump_value(out, h.version, "bcdUVC", indent + 2, width)?;
+            dump_hex(out, h.total_length, "wTotalLength", indent + 2, width)?;
             dump_value(
+                out,
                 format!(
                     "{:5}.{:06}MHz",
                     h.clock_frequency / 1000000,
@@ -618,115 +859,131 @@ fn dump_video_subtype(
                 "dwClockFrequency",
                 indent + 2,
                 width + 10,
-            );
-            dump_value(h.collection_bytes, "bInCollection", indent + 2, width);
-            dump_array(&h.interfaces, "baInterfaceNr", indent + 2, width);
+            )?;
+            dump_value(out, h.collection_bytes, "bInCollection", indent + 2, width)?;
+            dump_array(out, &h.interfaces, "baInterfaceNr", indent + 2, width)?;
         }
         video::UvcInterfaceDescriptor::InputTerminal(d) => {
-            dump_value(d.terminal_id, "bTerminalID", indent + 2, width);
+            dump_value(out, d.terminal_id, "bTerminalID", indent + 2, width)?;
             dump_value_string(
+                out,
                 format!("0x{:04x}", d.terminal_type),
                 "wTerminalType",
-                names::videoterminal(d.terminal_type).unwrap_or_default(),
+                name_or_unknown(
+                    names::terminal(d.terminal_type, names::TerminalDomain::Video),
+                    "terminal",
+                    d.terminal_type,
+                ),
                 indent + 2,
                 width,
-            );
-            dump_value(d.associated_terminal, "bAssocTerminal", indent + 2, width);
+            )?;
+            dump_value(
+                out,
+                d.associated_terminal,
+                "bAssocTerminal",
+                indent + 2,
+                width,
+            )?;
             dump_value_string(
+                out,
                 d.terminal_index,
                 "iTerminal",
                 d.terminal.as_ref().unwrap_or(&String::new()),
                 indent + 2,
                 width,
-            );
+            )?;
 
             if let Some(extra) = &d.extra {
                 dump_value(
+                    out,
                     extra.objective_focal_length_min,
                     "wObjectiveFocalLengthMin",
                     indent + 2,
                     width,
-                );
+                )?;
                 dump_value(
+                    out,
                     extra.objective_focal_length_max,
                     "wObjectiveFocalLengthMax",
                     indent + 2,
                     width,
-                );
+                )?;
                 dump_value(
+                    out,
                     extra.ocular_focal_length,
                     "wOcularFocalLength",
                     indent + 2,
                     width,
-                );
-                dump_value(extra.control_size, "bControlSize", indent + 2, width);
-                dump_hex(extra.controls, "bmControls", indent + 2, width);
+                )?;
+                dump_value(out, extra.control_size, "bControlSize", indent + 2, width)?;
+                dump_hex(out, extra.controls, "bmControls", indent + 2, width)?;
 
                 if protocol == 0x01 {
                     for (i, n) in CAM_CTRL_NAMES.iter().enumerate().take(22) {
                         if (extra.controls >> i) & 1 != 0 {
-                            dump_string(n, indent + 4);
+                            dump_string(out, n, indent + 4)?;
                         }
                     }
                 } else {
                     for (i, n) in CAM_CTRL_NAMES.iter().enumerate().take(19) {
                         if (extra.controls >> i) & 1 != 0 {
-                            dump_string(n, indent + 4);
+                            dump_string(out, n, indent + 4)?;
                         }
                     }
                 }
             }
         }
         video::UvcInterfaceDescriptor::OutputTerminal(ot) => {
-            dump_audio_output_terminal1(ot, indent, width);
+            dump_audio_output_terminal1(out, ot, indent, width)?;
         }
         video::UvcInterfaceDescriptor::SelectorUnit(su) => {
-            dump_audio_selector_unit1(su, indent, width);
+            dump_audio_selector_unit1(out, su, indent, width)?;
         }
         video::UvcInterfaceDescriptor::ProcessingUnit(pu) => {
-            dump_processing_unit(pu, protocol, indent, width);
+            dump_processing_unit(out, pu, protocol, indent, width)?;
         }
         video::UvcInterfaceDescriptor::ExtensionUnit(eu) => {
-            dump_extension_unit(eu, indent, width);
+            dump_extension_unit(out, eu, indent, width)?;
         }
         video::UvcInterfaceDescriptor::EncodingUnit(eu) => {
-            dump_encoding_unit(eu, indent, width);
+            dump_encoding_unit(out, eu, indent, width)?;
         }
         video::UvcInterfaceDescriptor::InputHeader(d) => {
-            dump_video_input_header(d, indent, width);
+            dump_video_input_header(out, d, indent, width)?;
         }
         video::UvcInterfaceDescriptor::OutputHeader(d) => {
-            dump_video_output_header(d, indent, width);
+            dump_video_output_header(out, d, indent, width)?;
         }
         video::UvcInterfaceDescriptor::StillImageFrame(d) => {
-            dump_still_image_frame(d, indent, width);
+            dump_still_image_frame(out, d, indent, width)?;
         }
         video::UvcInterfaceDescriptor::FormatFrameBased(d)
         | video::UvcInterfaceDescriptor::FormatUncompressed(d) => {
-            dump_format_frame(d, indent, width);
+            dump_format_frame(out, d, indent, width)?;
         }
         video::UvcInterfaceDescriptor::FrameUncompressed(d)
         | video::UvcInterfaceDescriptor::FrameMJPEG(d) => {
-            dump_frame_uncompressed(d, indent, width);
+            dump_frame_uncompressed(out, d, indent, width)?;
         }
         video::UvcInterfaceDescriptor::FrameFrameBased(d) => {
-            dump_frame_framebased(d, indent, width);
+            dump_frame_framebased(out, d, indent, width)?;
         }
         video::UvcInterfaceDescriptor::FormatMJPEG(d) => {
-            dump_format_mjpeg(d, indent, width);
+            dump_format_mjpeg(out, d, indent, width)?;
         }
         video::UvcInterfaceDescriptor::FormatMPEG2TS(d) => {
-            dump_format_mpeg2ts(d, indent, width);
+            dump_format_mpeg2ts(out, d, indent, width)?;
         }
         video::UvcInterfaceDescriptor::ColorFormat(d) => {
-            dump_video_color_format(d, indent, width);
+            dump_video_color_format(out, d, indent, width)?;
         }
         video::UvcInterfaceDescriptor::FormatStreamBased(d) => {
-            dump_format_stream_based(d, indent, width);
+            dump_format_stream_based(out, d, indent, width)?;
         }
         video::UvcInterfaceDescriptor::Undefined(data)
         | video::UvcInterfaceDescriptor::Invalid(data) => {
-            println!(
+            writeln!(
+                out,
                 "{:indent$}Invalid desc subtype: {}",
                 "",
                 data.iter()
@@ -734,47 +991,61 @@ fn dump_video_subtype(
                     .collect::<Vec<String>>()
                     .join(" "),
                 indent = indent + 2,
-            );
+            )?;
         }
         _ => {
             log::warn!("Unhandled UVC interface descriptor: {:?}", uvcid);
         }
     }
+
+    Ok(())
 }
 
 pub(crate) fn dump_videostreaming_interface(
+    out: &mut DumpWriter,
     vsd: &video::UvcDescriptor,
     vst: &video::StreamingSubtype,
     protocol: u8,
     indent: usize,
-) {
+) -> fmt::Result {
     const DUMP_WIDTH: usize = 36; // wider in lsusb for long numbers
-    dump_string("VideoStreaming Interface Descriptor:", indent);
-    dump_value(vsd.length, "bLength", indent + 2, DUMP_WIDTH);
+    dump_string(out, "VideoStreaming Interface Descriptor:", indent)?;
+    dump_value(out, vsd.length, "bLength", indent + 2, DUMP_WIDTH)?;
     dump_value(
+        out,
         vsd.descriptor_type,
         "bDescriptorType",
         indent + 2,
         DUMP_WIDTH,
-    );
+    )?;
     dump_value_string(
+        out,
         vst.to_owned() as u8,
         "bDescriptorSubtype",
         format!("({:#})", vst),
         indent + 2,
         DUMP_WIDTH,
-    );
+    )?;
 
     match &vsd.interface {
         video::UvcInterfaceDescriptor::Invalid(_) => {
-            println!(
-                "{:indent$}Warning: {:#} descriptors are illegal for {}",
-                "",
-                vst,
-                protocol.to_owned(),
-                indent = indent
-            );
+            dump_warning(
+                out,
+                DumpWarning {
+                    kind: DumpWarningKind::Illegal,
+                    descriptor: "VideoStreaming Interface".into(),
+                    offset: 2,
+                    message: format!(
+                        "{:#} descriptors are illegal for {}",
+                        vst,
+                        protocol.to_owned()
+                    ),
+                },
+                indent,
+            )?;
         }
-        uvcid => dump_video_subtype(uvcid, protocol, indent, DUMP_WIDTH),
+        uvcid => dump_video_subtype(out, uvcid, protocol, indent, DUMP_WIDTH)?,
     }
+
+    Ok(())
 }
@@ -6,13 +6,14 @@ use serde_with::skip_serializing_none;
 use std::env;
 use terminal_size::terminal_size;
 
-use cyme::config::Config;
-use cyme::display;
+use cyme::config::{Config, DisplayConfig};
+use cyme::display::{self, Block};
 use cyme::error::{Error, ErrorKind, Result};
 use cyme::lsusb;
 use cyme::system_profiler;
 use cyme::usb;
 use cyme::usb::ClassCode;
+use cyme::verify;
 
 #[derive(Parser, Debug, Default, Serialize, Deserialize)]
 #[skip_serializing_none]
@@ -34,7 +35,11 @@ struct Args {
     #[arg(short, long)]
     show: Option<String>,
 
-    /// Selects which device lsusb will examine - supplied as Linux /dev/bus/usb/BBB/DDD style path
+    /// Only profile and show devices on this bus number (in decimal); devices on other buses are not opened
+    #[arg(long, conflicts_with = "show")]
+    bus: Option<u8>,
+
+    /// Selects which device to examine: port path '2-1.4' (or trailing ports '1.4'), Linux /dev/bus/usb/BBB/DDD style path (or 'BBB/DDD') or VID:PID
     #[arg(short = 'D', long)]
     device: Option<String>,
 
@@ -50,6 +55,10 @@ struct Args {
     #[arg(long)]
     filter_class: Option<ClassCode>,
 
+    /// Filter on devices with an AudioControl or AudioStreaming interface of UAC version: uac1, uac2 or uac3
+    #[arg(long)]
+    filter_uac: Option<usb::descriptors::audio::UacProtocol>,
+
     /// Verbosity level: 1 prints device configurations; 2 prints interfaces; 3 prints interface endpoints; 4 prints everything and all blocks
     #[arg(short = 'v', long, default_value_t = 0, action = clap::ArgAction::Count)]
     verbose: u8,
@@ -87,7 +96,7 @@ struct Args {
     sort_buses: bool,
 
     /// Group devices by value when listing
-    #[arg(long, value_enum, default_value_t = Default::default())]
+    #[arg(long, visible_alias = "group-by", value_enum, default_value_t = Default::default())]
     group_devices: display::Group,
 
     /// Hide empty buses when printing tree; those with no devices. When listing will hide Linux root_hubs
@@ -99,6 +108,10 @@ struct Args {
     #[arg(long, default_value_t = false)]
     hide_hubs: bool,
 
+    /// Output only leaf devices, excluding all hubs from the list. Has no effect on the tree, where hubs provide structure
+    #[arg(long, default_value_t = false)]
+    no_hubs: bool,
+
     /// Show base16 values as base10 decimal instead
     #[arg(long, default_value_t = false)]
     decimal: bool,
@@ -111,6 +124,10 @@ struct Args {
     #[arg(long, value_enum, default_value_t = display::ColorWhen::Always, aliases = &["colour"])]
     color: display::ColorWhen,
 
+    /// Colour each device line by its speed or class rather than colouring each block; makes devices which fell back to USB 2.0 stand out
+    #[arg(long, value_enum, aliases = &["colour-by"])]
+    color_by: Option<display::ColorBy>,
+
     /// Disable coloured output, can also use NO_COLOR environment variable
     #[arg(long, default_value_t = false, hide = true, aliases = &["no_colour"])]
     no_color: bool,
@@ -139,18 +156,46 @@ struct Args {
     #[arg(long, default_value_t = false, overrides_with = "lsusb")]
     json: bool,
 
+    /// Output --json on a single line without whitespace for piping rather than pretty printed; implies --json
+    #[arg(long, default_value_t = false, overrides_with = "lsusb")]
+    json_compact: bool,
+
+    /// Omit raw descriptor bytes from --json output, leaving only decoded fields; implies --json
+    #[arg(long, default_value_t = false, overrides_with = "lsusb")]
+    json_omit_raw: bool,
+
     /// Read from json output rather than profiling system
     #[arg(long)]
     from_json: Option<String>,
 
+    /// Decode a raw descriptor blob, such as a copy of sysfs 'descriptors', as a single device on a phony bus rather than profiling system
+    #[arg(long, conflicts_with = "from_json")]
+    parse_file: Option<String>,
+
     /// Force libusb profiler on macOS rather than using/combining system_profiler output
     #[arg(short = 'F', long, default_value_t = false)]
     force_libusb: bool,
 
+    /// Number of threads used to profile devices with libusb; devices are independent so can be profiled in parallel, which is faster with many devices
+    #[arg(short = 'j', long, default_value_t = 1)]
+    jobs: usize,
+
+    /// Linux only: read descriptors cached by the kernel in sysfs rather than requesting them from devices; more complete without elevated permissions but status, BOS, hub and HID report descriptors are not available
+    #[arg(long, default_value_t = false)]
+    sysfs_descriptors: bool,
+
+    /// Do not request string descriptors from devices, which is much faster when only the topology is wanted; strings will come from the sysfs cache on Linux, otherwise names from the USB IDs database only and serial numbers will be missing
+    #[arg(long, default_value_t = false)]
+    no_strings: bool,
+
     /// Path to user config file to use for custom icons, colours and default settings
     #[arg(short = 'c', long)]
     config: Option<String>,
 
+    /// Path to a saved display config of blocks, theme, icons, sort and filter; arguments take precedence and its filter applies in addition to any filter arguments
+    #[arg(long)]
+    display_config: Option<String>,
+
     /// Turn debugging information on. Alternatively can use RUST_LOG env: INFO, DEBUG, TRACE
     #[arg(short = 'z', long, action = clap::ArgAction::Count)]
     // short -d taken by lsusb compat vid:pid
@@ -160,11 +205,75 @@ struct Args {
     #[arg(long)]
     mask_serials: Option<display::MaskSerial>,
 
+    /// Print a one line summary above each device in --lsusb --verbose dumps
+    #[arg(long, default_value_t = false)]
+    device_summary: bool,
+
+    /// Limit --lsusb --verbose dumps to the device, configuration, interface and endpoint descriptors; omits class-specific descriptors
+    #[arg(long, default_value_t = false, requires = "lsusb")]
+    lsusb_brief: bool,
+
+    /// Append the interface and endpoint counts of the active configuration to each device like [2 if, 4 ep]
+    #[arg(long, default_value_t = false)]
+    show_counts: bool,
+
+    /// Mark devices drawing more power than their bus-powered parent hub can supply per port with [over-budget]
+    #[arg(long, default_value_t = false)]
+    power_budget: bool,
+
+    /// Nest the interfaces of the active configuration under each device in the tree with their class, driver and an endpoint summary; a compact alternative to --verbose
+    #[arg(long, default_value_t = false, requires = "tree")]
+    show_interfaces: bool,
+
+    /// Show all configurations of devices rather than only the active one; the active configuration is marked. Always the case with --lsusb
+    #[arg(long, default_value_t = false)]
+    all_configs: bool,
+
+    /// Only show devices which declare manufacturer, product or serial strings that could not be read from them, due to firmware bugs or permissions. Adds the missing-strings block showing where any fallback came from unless blocks are specified
+    #[arg(long, default_value_t = false)]
+    missing_strings: bool,
+
+    /// Print counts of devices by interface class across all buses rather than listing devices, followed by the total number of buses, devices and peripherals; devices which are not hubs
+    #[arg(long, default_value_t = false)]
+    summary: bool,
+
+    /// Check device descriptors for common firmware mistakes such as big-endian fields and print any found rather than listing devices
+    #[arg(long, default_value_t = false)]
+    verify: bool,
+
+    /// Print only the value of this device block for each device, one per line, for scripting; a blank line is printed for devices without a value
+    #[arg(long, value_name = "BLOCK")]
+    select: Option<display::DeviceBlocks>,
+
+    /// With --select, skip devices without a value rather than printing a blank line
+    #[arg(long, default_value_t = false, requires = "select")]
+    skip_empty: bool,
+
+    /// Print an example of the --json output with every descriptor type populated
+    #[arg(long, exclusive = true)]
+    print_json_example: bool,
+
     /// Generate cli completions and man page
     #[arg(long, hide = true, exclusive = true)]
     gen: bool,
 }
 
+impl Args {
+    /// Whether the arguments need descriptor data beyond what the system profiler provides, so libusb must be used on macOS and the profiler must get extra
+    fn needs_extra(&self) -> bool {
+        self.verbose > 0
+            || self.more
+            || self.device.is_some() // device path requires extra
+            || self.filter_uac.is_some()
+            || self.summary
+            || self.verify // descriptors require extra
+            || self.show_counts
+            || self.power_budget
+            || self.show_interfaces // interfaces require extra
+            || self.missing_strings // string indexes require extra
+    }
+}
+
 /// Print in bold red and exit with error
 macro_rules! eprintexit {
     ($error:expr) => {
@@ -195,6 +304,7 @@ fn merge_config(c: &Config, a: &mut Args) {
     a.more |= c.more;
     a.hide_buses |= c.hide_buses;
     a.hide_hubs |= c.hide_hubs;
+    a.no_hubs |= c.no_hubs;
     a.decimal |= c.decimal;
     a.no_padding |= c.no_padding;
     a.ascii |= c.ascii;
@@ -206,6 +316,24 @@ fn merge_config(c: &Config, a: &mut Args) {
     }
 }
 
+/// Merge a [`DisplayConfig`] into the user config and args; args which are set take precedence
+fn merge_display_config(d: &DisplayConfig, c: &mut Config, a: &mut Args) {
+    a.tree |= d.mode == Some(display::DisplayMode::Tree);
+    a.blocks = a.blocks.take().or(d.blocks.clone());
+    a.bus_blocks = a.bus_blocks.take().or(d.bus_blocks.clone());
+    a.config_blocks = a.config_blocks.take().or(d.config_blocks.clone());
+    a.interface_blocks = a.interface_blocks.take().or(d.interface_blocks.clone());
+    a.endpoint_blocks = a.endpoint_blocks.take().or(d.endpoint_blocks.clone());
+    a.sort_devices = a.sort_devices.take().or(d.sort_devices.clone());
+    a.sort_buses |= d.sort_buses;
+    if let Some(colours) = d.colours.as_ref() {
+        c.colours = colours.clone();
+    }
+    if let Some(icons) = d.icons.as_ref() {
+        c.icons = icons.clone();
+    }
+}
+
 /// Parse the vidpid filter lsusb format: vid:Option<pid>
 fn parse_vidpid(s: &str) -> Result<(Option<u16>, Option<u16>)> {
     if s.contains(':') {
@@ -272,32 +400,29 @@ fn parse_show(s: &str) -> Result<(Option<u8>, Option<u8>)> {
     }
 }
 
-/// Parse devpath supplied by --device into a show format
-///
-/// Could be a regex match r"^[\/|\w+\/]+(?'bus'\d{3})\/(?'devno'\d{3})$" but this saves another crate
-fn parse_devpath(s: &str) -> Result<(Option<u8>, Option<u8>)> {
-    if s.contains('/') {
-        let split: Vec<&str> = s.split('/').collect();
-        // second to last
-        let bus: Option<u8> = split.get(split.len() - 2).map_or(Ok(None), |v| {
-            v.parse::<u8>()
-                .map(Some)
-                .map_err(|e| Error::new(ErrorKind::Parsing, &e.to_string()))
-        })?;
-        // last
-        let device = split.last().map_or(Ok(None), |v| {
-            v.parse::<u8>()
-                .map(Some)
-                .map_err(|e| Error::new(ErrorKind::Parsing, &e.to_string()))
-        })?;
+/// Abort with exit code before trying to call libusb feature if not present
+#[cfg(not(feature = "libusb"))]
+fn get_parse_file_spusb(_file_path: &str) -> Result<system_profiler::SPUSBDataType> {
+    Err(Error::new(
+        ErrorKind::Unsupported,
+        "libusb feature is required to do this, install with `cargo install --features libusb`",
+    ))
+}
 
-        Ok((bus, device))
-    } else {
-        Err(Error::new(
-            ErrorKind::InvalidArg,
-            &format!("Invalid device path {}", s),
-        ))
-    }
+#[cfg(feature = "libusb")]
+fn get_parse_file_spusb(file_path: &str) -> Result<system_profiler::SPUSBDataType> {
+    let device = usb::profiler::parse_descriptor_file(file_path)?;
+    let bus = system_profiler::USBBus {
+        name: String::from("Phony Descriptor File Import"),
+        host_controller: String::from("Phony Host Controller"),
+        pci_device: None,
+        pci_vendor: None,
+        pci_revision: None,
+        usb_bus_number: Some(device.location_id.bus),
+        devices: Some(vec![device]),
+    };
+
+    Ok(system_profiler::SPUSBDataType { buses: vec![bus] })
 }
 
 /// Abort with exit code before trying to call libusb feature if not present
@@ -311,32 +436,35 @@ fn get_libusb_spusb(_args: &Args) -> Result<system_profiler::SPUSBDataType> {
 
 #[cfg(feature = "libusb")]
 fn get_libusb_spusb(args: &Args, print_stderr: bool) -> Result<system_profiler::SPUSBDataType> {
-    if args.verbose > 0
-        || args.tree
-        || args.device.is_some()
-        || args.lsusb
-        || args.json
-        || args.more
-        || args.filter_class.is_none()
-    // class filter requires extra
-    {
-        usb::profiler::get_spusb_with_extra(print_stderr).map_err(|e| {
-            Error::new(
-                ErrorKind::LibUSB,
-                &format!(
-                    "Failed to gather system USB data with extra from libusb, Error({})",
-                    e
-                ),
-            )
-        })
-    } else {
-        usb::profiler::get_spusb(print_stderr).map_err(|e| {
-            Error::new(
-                ErrorKind::LibUSB,
-                &format!("Failed to gather system USB data from libusb, Error({})", e),
-            )
-        })
-    }
+    let settings = usb::profiler::ProfilerSettings {
+        // class filter requires extra
+        with_extra: args.needs_extra()
+            || args.tree
+            || args.lsusb
+            || args.json
+            || args.filter_class.is_none(),
+        print_stderr,
+        jobs: args.jobs,
+        sysfs_descriptors: args.sysfs_descriptors,
+        bus: args.bus,
+        fetch_strings: !args.no_strings,
+    };
+
+    usb::profiler::get_spusb_with_settings(&settings).map_err(|e| {
+        Error::new(
+            e.kind(),
+            &format!(
+                "Failed to gather system USB data{} from libusb, Error({})",
+                if settings.with_extra {
+                    " with extra"
+                } else {
+                    ""
+                },
+                e
+            ),
+        )
+        .with_source(e)
+    })
 }
 
 fn print_lsusb(
@@ -360,12 +488,34 @@ fn print_lsusb(
         }
 
         let devices = sp_usb.flatten_devices();
+        // descriptor warnings go to stderr after the dump so that stdout is just the dump
+        let mut warnings = Vec::new();
         // even though we filtered using filter.show and using prepare, keep this here because it will match the exact Linux dev path and exit error if it doesn't match like lsusb
         if let Some(dev_path) = &device {
-            lsusb::dump_one_device(&devices, dev_path)?
+            lsusb::dump_one_device(
+                &devices,
+                dev_path,
+                lsusb::DumpContext {
+                    style: lsusb::DumpStyle::from_settings(settings),
+                    warnings: Some(&mut warnings),
+                    ..Default::default()
+                },
+            )?
         } else {
             let sorted = settings.sort_devices.sort_devices_ref(&devices);
-            lsusb::print(&sorted, settings.verbosity > 0);
+            lsusb::print_styled(
+                &sorted,
+                lsusb::Verbosity::from_settings(settings),
+                settings.device_summary,
+                lsusb::DumpContext {
+                    style: lsusb::DumpStyle::from_settings(settings),
+                    warnings: Some(&mut warnings),
+                    ..Default::default()
+                },
+            );
+        }
+        for warning in warnings {
+            eprintln!("Warning: {}", warning);
         }
     };
 
@@ -423,11 +573,23 @@ fn cyme() -> Result<()> {
         std::process::exit(0);
     }
 
+    if args.print_json_example {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&system_profiler::SPUSBDataType::example())
+                .map_err(|e| Error::new(ErrorKind::Parsing, &e.to_string()))?
+        );
+        std::process::exit(0);
+    }
+
     // set the module debug level, will also check env if args.debug == 0
     cyme::set_log_level(args.debug)?;
 
+    // initialises the libusb context so only when profiling; decoding dumps should work without USB access
     #[cfg(feature = "libusb")]
-    usb::profiler::set_log_level(args.debug);
+    if args.from_json.is_none() && args.parse_file.is_none() {
+        usb::profiler::set_log_level(args.debug);
+    }
 
     let mut config = if let Some(path) = args.config.as_ref() {
         let config = Config::from_file(path)?;
@@ -444,6 +606,20 @@ fn cyme() -> Result<()> {
 
     merge_config(&config, &mut args);
 
+    let display_config = match args.display_config.as_ref() {
+        Some(path) => {
+            let display_config = DisplayConfig::from_file(path)?;
+            log::info!("Using display config {:?}", display_config);
+            merge_display_config(&display_config, &mut config, &mut args);
+            Some(display_config)
+        }
+        None => None,
+    };
+
+    if args.json_compact || args.json_omit_raw {
+        args.json = true;
+    }
+
     // legacy arg, hidden but still suport with new format
     if args.no_color {
         args.color = display::ColorWhen::Never;
@@ -452,8 +628,10 @@ fn cyme() -> Result<()> {
     // set the output colouring
     let colours = match args.color {
         display::ColorWhen::Auto => {
-            // colored crate manages coloring
-            Some(config.colours)
+            // resolved by the colored crate from the terminal and environment so that settings.colours alone says whether to colour
+            colored::control::SHOULD_COLORIZE
+                .should_colorize()
+                .then_some(config.colours)
         }
         display::ColorWhen::Always => {
             env::set_var("NO_COLOR", "0");
@@ -493,11 +671,13 @@ fn cyme() -> Result<()> {
                 system_profiler::read_flat_json_to_phony_bus(file_path.as_str())?
             }
         }
+    } else if let Some(file_path) = args.parse_file.as_ref() {
+        get_parse_file_spusb(file_path)?
     } else if cfg!(target_os = "macos") 
         && !args.force_libusb
-        && args.device.is_none() // device path requires extra
         && args.filter_class.is_none() // class filter requires extra
-        && !((args.tree && args.lsusb) || args.verbose > 0 || args.more)
+        && !args.needs_extra()
+        && !(args.tree && args.lsusb)
     {
         system_profiler::get_spusb()
             .map_or_else(|e| {
@@ -530,13 +710,31 @@ fn cyme() -> Result<()> {
 
     log::trace!("Returned system_profiler data\n\r{:#?}", spusb);
 
+    // resolve --device now so the error lists devices if it is ambiguous
+    let device = match &args.device {
+        Some(d) => {
+            let selector: system_profiler::DeviceSelector = d.parse().map_err(|e| {
+                Error::new(
+                    ErrorKind::InvalidArg,
+                    &format!("Failed to parse device '{}'; Error({:#})", d, e),
+                )
+            })?;
+            Some(selector.resolve(&spusb)?.to_owned())
+        }
+        None => None,
+    };
+
     let filter = if args.hide_hubs
+        || (args.no_hubs && !args.tree)
+        || args.missing_strings
         || args.vidpid.is_some()
         || args.show.is_some()
         || args.device.is_some()
         || args.filter_name.is_some()
         || args.filter_serial.is_some()
         || args.filter_class.is_some()
+        || args.filter_uac.is_some()
+        || args.bus.is_some()
     {
         let mut f = system_profiler::USBFilter::new();
 
@@ -551,19 +749,10 @@ fn cyme() -> Result<()> {
             f.pid = pid;
         }
 
-        // decode device devpath into the show filter since that is what it essentially will do
-        if let Some(devpath) = &args.device {
-            let (bus, number) = parse_devpath(devpath.as_str()).map_err(|e| {
-                Error::new(
-                    ErrorKind::InvalidArg,
-                    &format!(
-                        "Failed to parse devpath '{}', should end with 'BUS/DEVNO'; Error({})",
-                        devpath, e
-                    ),
-                )
-            })?;
-            f.bus = bus;
-            f.number = number;
+        // resolve the device selector into the show filter since that is what it essentially will do
+        if let Some(device) = &device {
+            f.bus = Some(device.location_id.bus);
+            f.number = Some(device.location_id.number);
         } else if let Some(show) = &args.show {
             let (bus, number) = parse_show(show.as_str()).map_err(|e| {
                 Error::new(
@@ -573,13 +762,20 @@ fn cyme() -> Result<()> {
             })?;
             f.bus = bus;
             f.number = number;
+        } else if let Some(bus) = args.bus {
+            // system_profiler and json dumps are not limited when profiling so filter too
+            f.bus = Some(bus);
         }
 
         // no need to unwrap as these are Option
         f.name = args.filter_name;
         f.serial = args.filter_serial;
         f.class = args.filter_class;
+        f.uac = args.filter_uac;
         f.exclude_empty_hub = args.hide_hubs;
+        // hubs are the structure of the tree so only exclude them from lists
+        f.exclude_hubs = args.no_hubs && !args.tree;
+        f.missing_strings = args.missing_strings;
         // exclude root hubs unless dumping a list or json
         f.no_exclude_root_hub =
             args.lsusb || args.json || !(args.tree || args.group_devices == display::Group::Bus);
@@ -614,13 +810,24 @@ fn cyme() -> Result<()> {
         }
     };
 
-    let group_devices = if args.group_devices == display::Group::Bus && args.tree {
+    let group_devices = if args.group_devices != display::Group::NoGroup && args.tree {
         eprintln!("--group-devices with --tree is ignored; will print as tree");
         display::Group::NoGroup
     } else {
         args.group_devices
     };
 
+    let mut device_blocks = args.blocks.map_or(config.blocks, Some);
+    if args.missing_strings && device_blocks.is_none() {
+        let mut blocks = if args.tree {
+            display::DeviceBlocks::default_device_tree_blocks()
+        } else {
+            display::DeviceBlocks::default_blocks(args.more)
+        };
+        blocks.push(display::DeviceBlocks::MissingStrings);
+        device_blocks = Some(blocks);
+    }
+
     let settings = display::PrintSettings {
         no_padding: args.no_padding,
         decimal: args.decimal,
@@ -630,12 +837,14 @@ fn cyme() -> Result<()> {
         sort_buses: args.sort_buses,
         group_devices,
         json: args.json,
+        json_compact: args.json_compact,
+        json_omit_raw_bytes: args.json_omit_raw,
         headings: args.headings,
         verbosity: args.verbose,
         more: args.more,
         encoding: args.encoding,
         mask_serials: args.mask_serials.map_or(config.mask_serials, Some),
-        device_blocks: args.blocks.map_or(config.blocks, Some),
+        device_blocks,
         bus_blocks: args.bus_blocks.map_or(config.bus_blocks, Some),
         config_blocks: args.config_blocks.map_or(config.config_blocks, Some),
         interface_blocks: args.interface_blocks.map_or(config.interface_blocks, Some),
@@ -646,18 +855,69 @@ fn cyme() -> Result<()> {
         auto_width: !config.no_auto_width,
         terminal_size: terminal_size(),
         icon_when: args.icon,
+        device_summary: args.device_summary,
+        lsusb_brief: args.lsusb_brief,
+        show_counts: args.show_counts,
+        power_budget: args.power_budget,
+        // lsusb dumps and verify need every configuration
+        all_configurations: args.all_configs || args.lsusb || args.verify,
+        show_interfaces: args.show_interfaces,
+        color_by: args.color_by,
     };
 
+    // the filter of a display config is in addition to the args so retain root_hubs, which the args filter decides on
+    if let Some(mut f) = display_config.and_then(|d| d.filter) {
+        f.no_exclude_root_hub = true;
+        f.retain_buses(&mut spusb.buses);
+    }
+
     display::prepare(&mut spusb, filter, &settings);
 
-    if args.lsusb {
-        print_lsusb(&spusb, &args.device, &settings)?;
+    if args.summary {
+        let histogram = spusb.class_histogram();
+        if settings.json {
+            println!(
+                "{}",
+                display::json_string(&histogram, settings.json_compact)?
+            );
+        } else {
+            let mut counts: Vec<_> = histogram.iter().collect();
+            counts.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+            println!(
+                "{}",
+                counts
+                    .iter()
+                    .map(|(c, n)| format!("{}: {}", c.to_title_case(), n))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            );
+            println!(
+                "{} buses, {} devices ({} peripherals)",
+                spusb.bus_count(),
+                spusb.device_count(),
+                spusb.device_count_excluding_hubs()
+            );
+        }
+    } else if args.verify {
+        let violations = verify::verify(&spusb);
+        if settings.json {
+            println!(
+                "{}",
+                display::json_string(&violations, settings.json_compact)?
+            );
+        } else {
+            violations.iter().for_each(|v| println!("{}", v));
+        }
+    } else if let Some(block) = args.select.as_ref() {
+        display::print_selected(&spusb, block, args.skip_empty, &settings);
+    } else if args.lsusb {
+        print_lsusb(&spusb, &device.map(|d| d.dev_path()), &settings)?;
     } else {
-        // check and report if was looking for args.device
-        if args.device.is_some() && !spusb.buses.iter().any(|b| b.has_devices()) {
+        // check and report if was looking for device
+        if let Some(device) = device.filter(|_| !spusb.buses.iter().any(|b| b.has_devices())) {
             return Err(Error::new(
                 ErrorKind::NotFound,
-                &format!("Unable to find device at {:?}", args.device.unwrap()),
+                &format!("Unable to find device at {}", device.port_path()),
             ));
         }
         display::print(&spusb, &settings);
@@ -709,21 +969,22 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_devpath() {
-        assert_eq!(
-            parse_devpath("/dev/bus/usb/001/003").unwrap(),
-            (Some(1), Some(3))
-        );
-        assert_eq!(
-            parse_devpath("/dev/bus/usb/004/003").unwrap(),
-            (Some(4), Some(3))
-        );
-        assert_eq!(
-            parse_devpath("/dev/bus/usb/004/3").unwrap(),
-            (Some(4), Some(3))
+    fn test_needs_extra() {
+        let args = Args::try_parse_from(["cyme", "--tree"]).unwrap();
+        assert!(!args.needs_extra());
+        let args = Args::try_parse_from(["cyme", "--tree", "--show-interfaces"]).unwrap();
+        assert!(args.needs_extra());
+        assert!(Args::try_parse_from(["cyme", "--show-interfaces"]).is_err());
+        assert!(Args::try_parse_from(["cyme", "--skip-empty"]).is_err());
+    }
+
+    #[test]
+    fn test_lsusb_brief_requires_lsusb() {
+        assert!(Args::try_parse_from(["cyme", "--lsusb-brief"]).is_err());
+        assert!(
+            Args::try_parse_from(["cyme", "--lsusb", "-v", "--lsusb-brief"])
+                .unwrap()
+                .lsusb_brief
         );
-        assert_eq!(parse_devpath("004/3").unwrap(), (Some(4), Some(3)));
-        assert!(parse_devpath("004/").is_err());
-        assert!(parse_devpath("sas/ssas").is_err());
     }
 }
@@ -18,22 +18,33 @@
 //! let spusb = system_profiler::get_spusb_with_extra().unwrap();
 //! ```
 use colored::*;
+use itertools::Itertools;
 use serde::de::{self, MapAccess, SeqAccess, Visitor};
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_with::{skip_serializing_none, DeserializeFromStr, SerializeDisplay};
 use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 use std::fs;
 use std::io::Read;
 use std::process::Command;
 use std::str::FromStr;
+use usb_ids::FromId;
 
 use crate::error::{Error, ErrorKind};
 use crate::types::NumericalUnit;
+use crate::usb::descriptors::{audio, ClassDescriptor, Descriptor, DescriptorType, HubDescriptor};
 use crate::usb::*;
 
+/// Current a bus-powered hub can supply to each downstream port in mA; USB 2.0 11.13
+pub const BUS_POWERED_HUB_PORT_MA: u32 = 100;
+/// Current a bus-powered SuperSpeed hub can supply to each downstream port in mA; one SuperSpeed unit load
+pub const BUS_POWERED_SUPERSPEED_HUB_PORT_MA: u32 = 150;
+
+mod example;
+
 /// Root JSON returned from system_profiler and used as holder for all static USB bus data
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SPUSBDataType {
     /// system buses
     #[serde(rename(deserialize = "SPUSBDataType"), alias = "buses")]
@@ -74,6 +85,104 @@ impl SPUSBDataType {
         self.buses.iter_mut().find(|b| b.get_bus_number() == number)
     }
 
+    /// Count of devices by [`ClassCode`] across all buses
+    ///
+    /// Counted at the interface level so a composite device contributes once to each of its interface classes. Devices without interface data (profiled without extra) are counted by their device class unless it is [`ClassCode::UseInterfaceDescriptor`].
+    ///
+    /// ```
+    /// use cyme::system_profiler::SPUSBDataType;
+    /// use cyme::usb::ClassCode;
+    ///
+    /// let histogram = SPUSBDataType::example().class_histogram();
+    /// assert_eq!(histogram.get(&ClassCode::Hub), Some(&1));
+    /// assert_eq!(histogram.get(&ClassCode::HID), Some(&1));
+    /// ```
+    pub fn class_histogram(&self) -> BTreeMap<ClassCode, usize> {
+        let mut ret = BTreeMap::new();
+        for device in self.flatten_devices() {
+            let mut classes: Vec<ClassCode> = match device.extra.as_ref() {
+                Some(extra) if !extra.configurations.is_empty() => extra
+                    .configurations
+                    .iter()
+                    .flat_map(|c| c.interfaces.iter().map(|i| i.class))
+                    .collect(),
+                _ => device
+                    .class
+                    .filter(|c| *c != ClassCode::UseInterfaceDescriptor)
+                    .into_iter()
+                    .collect(),
+            };
+            classes.sort();
+            classes.dedup();
+            for class in classes {
+                *ret.entry(class).or_insert(0) += 1;
+            }
+        }
+        ret
+    }
+
+    /// Number of buses
+    pub fn bus_count(&self) -> usize {
+        self.buses.len()
+    }
+
+    /// Total number of devices on all buses, including hubs
+    ///
+    /// ```
+    /// use cyme::system_profiler::SPUSBDataType;
+    ///
+    /// let sp_usb = SPUSBDataType::example();
+    /// assert_eq!(sp_usb.bus_count(), 1);
+    /// assert_eq!(sp_usb.device_count(), 2);
+    /// assert_eq!(sp_usb.device_count_excluding_hubs(), 1);
+    /// ```
+    pub fn device_count(&self) -> usize {
+        self.flatten_devices().len()
+    }
+
+    /// Total number of devices on all buses which are not hubs; the peripherals
+    pub fn device_count_excluding_hubs(&self) -> usize {
+        self.flatten_devices()
+            .iter()
+            .filter(|d| !d.is_hub())
+            .count()
+    }
+
+    /// Unique (VID, PID) pairs of the devices on all buses, including hubs; each model of device attached once
+    ///
+    /// Devices without a VID or PID are skipped.
+    ///
+    /// ```
+    /// use cyme::system_profiler::SPUSBDataType;
+    ///
+    /// let mut sp_usb = SPUSBDataType::example();
+    /// assert_eq!(sp_usb.distinct_vid_pids().len(), 2);
+    /// assert_eq!(sp_usb.distinct_vid_pids_excluding_hubs().len(), 1);
+    ///
+    /// // a second of the same model does not add a pair
+    /// let hub = sp_usb.get_node_mut("1-1").unwrap();
+    /// let mut twin = hub.devices.as_ref().unwrap()[0].clone();
+    /// twin.location_id.tree_positions = vec![1, 3];
+    /// hub.devices.as_mut().unwrap().push(twin);
+    /// assert_eq!(sp_usb.device_count(), 3);
+    /// assert_eq!(sp_usb.distinct_vid_pids().len(), 2);
+    /// ```
+    pub fn distinct_vid_pids(&self) -> BTreeSet<(u16, u16)> {
+        self.flatten_devices()
+            .into_iter()
+            .filter_map(|d| d.vendor_id.zip(d.product_id))
+            .collect()
+    }
+
+    /// Unique (VID, PID) pairs of the devices on all buses which are not hubs; see [`SPUSBDataType::distinct_vid_pids`]
+    pub fn distinct_vid_pids_excluding_hubs(&self) -> BTreeSet<(u16, u16)> {
+        self.flatten_devices()
+            .into_iter()
+            .filter(|d| !d.is_hub())
+            .filter_map(|d| d.vendor_id.zip(d.product_id))
+            .collect()
+    }
+
     /// Search for reference to [`USBDevice`] at `port_path` in all buses
     pub fn get_node(&self, port_path: &str) -> Option<&USBDevice> {
         for bus in self.buses.iter() {
@@ -84,6 +193,49 @@ impl SPUSBDataType {
         None
     }
 
+    /// The hub chain of the [`USBDevice`] at `port_path`, from its parent up to the trunk device; the bus root is not included
+    ///
+    /// The tree is owned top-down so devices do not store a reference to their parent; this walks down from the bus instead. Only valid for a tree which has not been flattened. Empty if the device is a trunk device or is not found.
+    ///
+    /// ```
+    /// use cyme::system_profiler::SPUSBDataType;
+    ///
+    /// let sp_usb = SPUSBDataType::example();
+    /// let ancestors = sp_usb.ancestors("1-1.2");
+    /// assert_eq!(ancestors.len(), 1);
+    /// assert_eq!(ancestors[0].port_path(), "1-1");
+    /// assert!(sp_usb.ancestors("1-1").is_empty());
+    /// ```
+    pub fn ancestors(&self, port_path: &str) -> Vec<&USBDevice> {
+        let location = match self.get_node(port_path) {
+            Some(d) => &d.location_id,
+            None => return Vec::new(),
+        };
+
+        (1..location.tree_positions.len())
+            .rev()
+            .filter_map(|depth| {
+                self.get_node(&get_port_path(
+                    location.bus,
+                    &location.tree_positions[..depth],
+                ))
+            })
+            .collect()
+    }
+
+    /// The parent hub of the [`USBDevice`] at `port_path`; None for trunk devices, which are attached to the bus root
+    ///
+    /// ```
+    /// use cyme::system_profiler::SPUSBDataType;
+    ///
+    /// let sp_usb = SPUSBDataType::example();
+    /// assert_eq!(sp_usb.parent("1-1.2").unwrap().name, "USB2.0 Hub");
+    /// assert!(sp_usb.parent("1-1").is_none());
+    /// ```
+    pub fn parent(&self, port_path: &str) -> Option<&USBDevice> {
+        self.ancestors(port_path).into_iter().next()
+    }
+
     /// Search for mutable reference to [`USBDevice`] at `port_path` in all buses
     pub fn get_node_mut(&mut self, port_path: &str) -> Option<&mut USBDevice> {
         for bus in self.buses.iter_mut() {
@@ -95,6 +247,26 @@ impl SPUSBDataType {
     }
 }
 
+/// Shallow iteration over the [`USBBus`]es; use [`SPUSBDataType::flatten_devices`] for every device on every bus
+///
+/// ```
+/// use cyme::system_profiler::SPUSBDataType;
+///
+/// let profile = SPUSBDataType::example();
+/// for bus in &profile {
+///     assert!(bus.has_devices());
+/// }
+/// assert_eq!((&profile).into_iter().count(), profile.buses.len());
+/// ```
+impl<'a> IntoIterator for &'a SPUSBDataType {
+    type Item = &'a USBBus;
+    type IntoIter = std::slice::Iter<'a, USBBus>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.buses.iter()
+    }
+}
+
 impl fmt::Display for SPUSBDataType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         for v in &self.buses {
@@ -224,6 +396,61 @@ impl USBBus {
         self.get_node_mut(&self.interface())
     }
 
+    /// Theoretical bandwidth in bytes per second reserved by the periodic endpoints of all devices on the bus, excluding the root hub; see [`USBDevice::periodic_bandwidth`]
+    pub fn periodic_bandwidth_used(&self) -> u64 {
+        self.flattened_devices()
+            .into_iter()
+            .filter(|d| !d.is_root_hub())
+            .map(|d| d.periodic_bandwidth())
+            .sum()
+    }
+
+    /// Theoretical bandwidth in bytes per second the bus can reserve for periodic transfers; see [`Speed::periodic_budget`]
+    ///
+    /// The speed of the bus is taken from the root hub, or the fastest device directly attached if there is no root hub. None if neither speed is known
+    pub fn periodic_bandwidth_budget(&self) -> Option<u64> {
+        let speed_budget = |d: &USBDevice| match &d.device_speed {
+            Some(DeviceSpeed::SpeedValue(s)) => s.periodic_budget(),
+            _ => None,
+        };
+        match self.get_root_hub_device() {
+            Some(root_hub) => speed_budget(root_hub),
+            None => self.devices.iter().flatten().filter_map(speed_budget).max(),
+        }
+    }
+
+    /// Returns `true` if the periodic endpoints on the bus reserve more bandwidth than the bus can schedule; the reason another isochronous device such as a webcam can fail to start
+    ///
+    /// ```
+    /// use cyme::system_profiler::{DeviceSpeed, SPUSBDataType};
+    /// use cyme::usb::Speed;
+    ///
+    /// let mut sp_usb = SPUSBDataType::example();
+    /// let bus = &sp_usb.buses[0];
+    /// assert!(bus.periodic_bandwidth_used() > 0);
+    /// assert!(!bus.exceeds_periodic_budget());
+    ///
+    /// // behind the USB 2.0 hub the composite runs at High Speed; stream on its isochronous interface
+    /// // with three 1024 byte transactions every microframe, 24.6 MB/s
+    /// let device = sp_usb.get_node_mut("1-1.2").unwrap();
+    /// device.device_speed = Some(DeviceSpeed::SpeedValue(Speed::HighSpeed));
+    /// let config = &mut device.extra.as_mut().unwrap().configurations[0];
+    /// let streaming = config.interfaces.iter_mut().find(|i| i.number == 4).unwrap();
+    /// streaming.active_alt_setting = Some(1);
+    /// streaming.endpoints[0].max_packet_size = (2 << 11) | 1024;
+    /// assert!(!sp_usb.buses[0].exceeds_periodic_budget());
+    ///
+    /// // a second such device streaming on the hub is more than the 48 MB/s High Speed periodic budget
+    /// let mut second = sp_usb.get_node("1-1.2").unwrap().clone();
+    /// second.location_id.tree_positions = vec![1, 3];
+    /// sp_usb.get_node_mut("1-1").unwrap().devices.as_mut().unwrap().push(second);
+    /// assert!(sp_usb.buses[0].exceeds_periodic_budget());
+    /// ```
+    pub fn exceeds_periodic_budget(&self) -> bool {
+        self.periodic_bandwidth_budget()
+            .is_some_and(|budget| self.periodic_bandwidth_used() > budget)
+    }
+
     /// Search for [`USBDevice`] in branches of bus and return reference
     pub fn get_node(&self, port_path: &str) -> Option<&USBDevice> {
         if let Some(devices) = self.devices.as_ref() {
@@ -255,12 +482,14 @@ impl USBBus {
     /// Generate a String from self like lsusb default list device
     pub fn to_lsusb_string(&self) -> String {
         format!(
-            "Bus {:03} Device 000: ID {:04x}:{:04x} {} {}",
+            "Bus {:03} Device 000: ID {}",
             self.get_bus_number(),
-            self.pci_vendor.unwrap_or(0xffff),
-            self.pci_device.unwrap_or(0xffff),
-            self.name,
-            self.host_controller,
+            format_id(
+                self.pci_vendor,
+                self.pci_device,
+                Some(&self.name),
+                Some(&self.host_controller)
+            ),
         )
     }
 
@@ -301,11 +530,13 @@ impl USBBus {
                     speed
                 ),
                 format!(
-                    "ID {:04x}:{:04x} {} {}",
-                    self.pci_vendor.unwrap_or(0xFFFF),
-                    self.pci_device.unwrap_or(0xFFFF),
-                    vendor,
-                    product,
+                    "ID {}",
+                    format_id(
+                        self.pci_vendor,
+                        self.pci_device,
+                        Some(&vendor),
+                        Some(&product)
+                    )
                 ),
                 format!(
                     "/sys/bus/usb/devices/usb{}  {}",
@@ -321,11 +552,13 @@ impl USBBus {
                     self.get_bus_number(),
                 ),
                 format!(
-                    "ID {:04x}:{:04x} {} {}",
-                    self.pci_vendor.unwrap_or(0xFFFF),
-                    self.pci_device.unwrap_or(0xFFFF),
-                    self.host_controller,
-                    self.name,
+                    "ID {}",
+                    format_id(
+                        self.pci_vendor,
+                        self.pci_device,
+                        Some(&self.host_controller),
+                        Some(&self.name)
+                    )
                 ),
                 format!(
                     "/sys/bus/usb/devices/usb{}  {}",
@@ -381,6 +614,27 @@ pub fn write_devices_recursive(f: &mut fmt::Formatter, devices: &Vec<USBDevice>)
     Ok(())
 }
 
+/// Shallow iteration over the devices attached directly to the bus (not the devices of hubs); use [`USBBus::flattened_devices`] for every device on the bus
+///
+/// ```
+/// use cyme::system_profiler::SPUSBDataType;
+///
+/// let profile = SPUSBDataType::example();
+/// let bus = &profile.buses[0];
+/// assert!(bus.into_iter().count() < bus.flattened_devices().len());
+/// for device in bus {
+///     assert_eq!(device.get_depth(), 1);
+/// }
+/// ```
+impl<'a> IntoIterator for &'a USBBus {
+    type Item = &'a USBDevice;
+    type IntoIter = std::iter::Flatten<std::option::Iter<'a, Vec<USBDevice>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.devices.iter().flatten()
+    }
+}
+
 impl fmt::Display for USBBus {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         // use plus formatter to add tree
@@ -482,9 +736,9 @@ impl FromStr for DeviceLocation {
 impl DeviceLocation {
     /// Linux style port path where it can be found on system device path - normaly /sys/bus/usb/devices
     ///
-    /// A wrapper for [`get_port_path`]
+    /// The [`PortPath`] as a string
     pub fn port_path(&self) -> String {
-        get_port_path(self.bus, &self.tree_positions)
+        self.to_port_path().to_string()
     }
 
     /// Port path of parent
@@ -494,6 +748,11 @@ impl DeviceLocation {
         get_parent_path(self.bus, &self.tree_positions)
     }
 
+    /// Parsed [`PortPath`] of the location
+    pub fn to_port_path(&self) -> PortPath {
+        PortPath::new(self.bus, self.tree_positions.clone())
+    }
+
     /// Port path of trunk
     ///
     /// A wrapper for [`get_trunk_path`]
@@ -503,7 +762,7 @@ impl DeviceLocation {
 
     /// Linux sysfs name of [`USBDevice`] similar to `port_path` but root_hubs use the USB controller name instead of port
     pub fn sysfs_name(&self) -> String {
-        get_sysfs_name(self.bus, &self.tree_positions)
+        self.to_port_path().sysfs_name()
     }
 }
 
@@ -651,6 +910,122 @@ impl FromStr for DeviceSpeed {
     }
 }
 
+/// Where a string field of a [`USBDevice`] was obtained from
+///
+/// Reading string descriptors requires opening the device, which generally needs elevated permissions, so the profiler falls back to other sources when it cannot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InfoSource {
+    /// Read from the device string descriptor
+    Device,
+    /// Read from the kernel sysfs cache of the string descriptor
+    Sysfs,
+    /// Looked up by VID:PID in the udev hwdb or usb-ids database
+    Database,
+}
+
+impl fmt::Display for InfoSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InfoSource::Device => write!(f, "device"),
+            InfoSource::Sysfs => write!(f, "sysfs"),
+            InfoSource::Database => write!(f, "database"),
+        }
+    }
+}
+
+/// [`InfoSource`] for each of the string fields of a [`USBDevice`]; None if the field is None
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeviceInfoSource {
+    /// Source of [`USBDevice::manufacturer`]
+    pub manufacturer: Option<InfoSource>,
+    /// Source of [`USBDevice::name`]
+    pub name: Option<InfoSource>,
+    /// Source of [`USBDevice::serial_num`]
+    pub serial_num: Option<InfoSource>,
+}
+
+impl DeviceInfoSource {
+    /// Whether any string with a non-zero index in `string_indexes` was not read from the device, so may differ from or be missing information the device would report
+    ///
+    /// `string_indexes` are (iProduct, iManufacturer, iSerialNumber) like [`USBDeviceExtra::string_indexes`]. A string with index 0 is not provided by the device so any fallback is not limited.
+    pub fn is_limited(&self, string_indexes: (u8, u8, u8)) -> bool {
+        let (i_product, i_manufacturer, i_serial) = string_indexes;
+        [
+            (i_manufacturer, self.manufacturer),
+            (i_product, self.name),
+            (i_serial, self.serial_num),
+        ]
+        .iter()
+        .any(|(index, s)| *index != 0 && *s != Some(InfoSource::Device))
+    }
+}
+
+/// Dominant role of a [`USBDevice`], see [`USBDevice::primary_function`]
+///
+/// Variants are in order of precedence when choosing between the functions of a composite device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Function {
+    /// Hub class device
+    Hub,
+    /// Video or audio/video class
+    Video,
+    /// Audio class
+    Audio,
+    /// CDC networking such as ECM, NCM and MBIM, or RNDIS
+    Network,
+    /// Mass storage class
+    Storage,
+    /// HID class; keyboards, mice and the buttons of other devices
+    Input,
+    /// More than one function without one grouped by an Interface Association Descriptor
+    Composite,
+    /// No function which can be classified
+    Unknown,
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Function {
+    /// [`Function`] of an interface or device with `class` and `sub_class`; None for classes which are not a classified function or are auxiliary, like CDC Data
+    pub fn from_class(class: ClassCode, sub_class: u8, protocol: u8) -> Option<Function> {
+        match (class, sub_class, protocol) {
+            (ClassCode::Hub, _, _) => Some(Function::Hub),
+            (ClassCode::Video, _, _) | (ClassCode::AudioVideo, _, _) => Some(Function::Video),
+            (ClassCode::Audio, _, _) => Some(Function::Audio),
+            // ECM, ATM, EEM, NCM and MBIM
+            (ClassCode::CDCCommunications, 0x06 | 0x07 | 0x0c | 0x0d | 0x0e, _) => {
+                Some(Function::Network)
+            }
+            // RNDIS
+            (ClassCode::WirelessController, 0x01, 0x03)
+            | (ClassCode::Miscellaneous, 0x04, 0x01) => Some(Function::Network),
+            (ClassCode::MassStorage, _, _) => Some(Function::Storage),
+            (ClassCode::HID, _, _) => Some(Function::Input),
+            _ => None,
+        }
+    }
+
+    /// Base [`ClassCode`] representing the function, used for the icon; None for [`Function::Composite`] and [`Function::Unknown`]
+    pub fn class_code(&self) -> Option<ClassCode> {
+        match self {
+            Function::Hub => Some(ClassCode::Hub),
+            Function::Video => Some(ClassCode::Video),
+            Function::Audio => Some(ClassCode::Audio),
+            Function::Network => Some(ClassCode::CDCCommunications),
+            Function::Storage => Some(ClassCode::MassStorage),
+            Function::Input => Some(ClassCode::HID),
+            Function::Composite | Function::Unknown => None,
+        }
+    }
+}
+
 /// USB device data based on JSON object output from system_profiler but now used for other platforms
 ///
 /// Desgined to hold static data for the device, obtained from system_profiler Deserializer or cyme::lsusb. Fields should probably be non-pub with getters/setters but treat them as read-only.
@@ -710,12 +1085,196 @@ pub struct USBDevice {
     /// Extra data obtained by libusb/udev exploration
     #[serde(default)]
     pub extra: Option<USBDeviceExtra>,
-    /// Internal to store any non-critical errors captured whilst profiling, unable to open for example
+    /// Where the string fields were obtained from when profiled with libusb
+    #[serde(default)]
+    pub info_source: Option<DeviceInfoSource>,
+    /// Internal to store any non-critical errors captured whilst profiling, unable to open for example. The [`crate::error::ErrorKind`] can be used to determine the cause, such as [`crate::error::ErrorKind::Permission`]
     #[serde(skip)]
-    pub profiler_error: Option<String>,
+    pub profiler_error: Option<Error>,
 }
 
 impl USBDevice {
+    /// Resolves string indexes referenced by class descriptors in all configurations of the device from its string table with `lookup`
+    ///
+    /// Fills fields such as `function_string`, `terminal` and `feature`. String indexes of 0 are not looked up. Does not walk child `devices` since each has its own string table.
+    pub fn resolve_strings<F: FnMut(u8) -> Option<String>>(&mut self, mut lookup: F) {
+        if let Some(extra) = self.extra.as_mut() {
+            for config in extra.configurations.iter_mut() {
+                config.resolve_strings(&mut lookup);
+            }
+        }
+    }
+
+    /// Classified [`Function`]s of the device in order of precedence: of the device class if it has one, otherwise of the interfaces of the active configuration
+    pub fn functions(&self) -> Vec<Function> {
+        self.classified_functions().0
+    }
+
+    /// [`USBDevice::functions`] and those of the functions grouped by Interface Association Descriptors
+    fn classified_functions(&self) -> (Vec<Function>, Vec<Function>) {
+        if let Some(f) = self.class.and_then(|c| {
+            Function::from_class(c, self.sub_class.unwrap_or(0), self.protocol.unwrap_or(0))
+        }) {
+            return (vec![f], Vec::new());
+        }
+
+        let config = match self.extra.as_ref().and_then(|e| e.active_configuration()) {
+            Some(c) => c,
+            None => return (Vec::new(), Vec::new()),
+        };
+        let iads = config.interface_associations();
+        let mut grouped: Vec<Function> = iads
+            .iter()
+            .filter_map(|iad| {
+                Function::from_class(
+                    ClassCode::from(iad.function_class),
+                    iad.function_sub_class,
+                    iad.function_protocol,
+                )
+            })
+            .collect();
+        let mut functions: Vec<Function> = config
+            .interfaces
+            .iter()
+            .filter_map(|i| Function::from_class(i.class, i.sub_class, i.protocol))
+            .chain(grouped.iter().copied())
+            .collect();
+
+        for fs in [&mut functions, &mut grouped] {
+            fs.sort();
+            fs.dedup();
+        }
+        (functions, grouped)
+    }
+
+    /// Dominant role of the device for display, icons and grouping
+    ///
+    /// The function of the device class is used if it has one. Otherwise from the interfaces of the active configuration:
+    ///
+    /// 1. a single function is the primary function; HID alongside other functions is ignored since it is typically buttons or controls of the main function
+    /// 2. of more than one, the function grouped by an Interface Association Descriptor with the highest [`Function`] precedence; so a UVC+UAC webcam is always Video
+    /// 3. otherwise [`Function::Composite`]
+    ///
+    /// [`Function::Unknown`] if there are no classified functions.
+    ///
+    /// ```
+    /// use cyme::system_profiler::{Function, SPUSBDataType};
+    ///
+    /// let sp_usb = SPUSBDataType::example();
+    /// assert_eq!(sp_usb.get_node("1-1").unwrap().primary_function(), Function::Hub);
+    /// let device = sp_usb.get_node("1-1.2").unwrap();
+    /// assert_eq!(device.functions(), vec![Function::Video, Function::Audio, Function::Input]);
+    /// // audio is grouped by an IAD but video is not
+    /// assert_eq!(device.primary_function(), Function::Audio);
+    /// ```
+    pub fn primary_function(&self) -> Function {
+        let (functions, grouped) = self.classified_functions();
+        let main: Vec<Function> = functions
+            .iter()
+            .copied()
+            .filter(|f| *f != Function::Input)
+            .collect();
+
+        match (functions.as_slice(), main.as_slice()) {
+            ([], _) => Function::Unknown,
+            ([f], _) | (_, [f]) => *f,
+            _ => grouped.first().copied().unwrap_or(Function::Composite),
+        }
+    }
+
+    /// Strings with a non-zero index in the device descriptor which could not be read from the device, paired with the [`InfoSource`] of any fallback used instead
+    ///
+    /// Strings from the kernel sysfs cache were read from the device at enumeration so are not missing. Missing strings are firmware bugs or, if all are missing, usually a lack of permission to open the device. Empty without [`USBDeviceExtra`] since the string indexes are not known.
+    ///
+    /// ```
+    /// use cyme::system_profiler::{SPUSBDataType, InfoSource};
+    ///
+    /// let sp_usb = SPUSBDataType::example();
+    /// let mut device = sp_usb.get_node("1-1.2").unwrap().clone();
+    /// assert!(device.missing_strings().is_empty());
+    ///
+    /// let source = device.info_source.as_mut().unwrap();
+    /// source.manufacturer = Some(InfoSource::Database);
+    /// source.serial_num = None;
+    /// assert_eq!(
+    ///     device.missing_strings(),
+    ///     vec![("iManufacturer", Some(InfoSource::Database)), ("iSerialNumber", None)]
+    /// );
+    /// ```
+    pub fn missing_strings(&self) -> Vec<(&'static str, Option<InfoSource>)> {
+        let (i_product, i_manufacturer, i_serial) = match self.extra.as_ref() {
+            Some(extra) => extra.string_indexes,
+            None => return Vec::new(),
+        };
+        let sources = self.info_source.as_ref();
+
+        [
+            (
+                "iManufacturer",
+                i_manufacturer,
+                sources.map(|s| s.manufacturer),
+                self.manufacturer.is_some(),
+            ),
+            (
+                "iProduct",
+                i_product,
+                sources.map(|s| s.name),
+                !self.name.is_empty(),
+            ),
+            (
+                "iSerialNumber",
+                i_serial,
+                sources.map(|s| s.serial_num),
+                self.serial_num.is_some(),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(field, index, source, has_value)| {
+            // json without sources; can only tell if there is no value at all
+            let read = source.map_or(has_value, |s| {
+                matches!(s, Some(InfoSource::Device | InfoSource::Sysfs))
+            });
+            (index != 0 && !read).then_some((field, source.flatten()))
+        })
+        .collect()
+    }
+
+    /// Whether `other` is the same device with the same descriptors, such as the device re-enumerated
+    ///
+    /// Compares the identity of the device - bus, port path, VID:PID and serial - and its descriptors, not `devices` attached to it. Ignored are the device number, which changes each enumeration, strings that can be resolved in a different language and fields which are profiler or runtime state rather than descriptors. Descriptors are compared as raw bytes in the order the device returns them, which is stable, so a device whose descriptors are reordered is not the same.
+    ///
+    /// ```
+    /// use cyme::system_profiler::SPUSBDataType;
+    ///
+    /// let sp_usb = SPUSBDataType::example();
+    /// let device = sp_usb.get_node("1-1.2").unwrap();
+    /// let mut reenumerated = device.clone();
+    /// reenumerated.location_id.number += 1;
+    /// reenumerated.name = String::from("Beispiel Verbundgerät");
+    /// reenumerated.extra.as_mut().unwrap().configurations[0].name = String::from("Standard");
+    /// assert!(device.semantically_eq(&reenumerated));
+    ///
+    /// reenumerated.extra.as_mut().unwrap().configurations[0].total_length += 1;
+    /// assert!(!device.semantically_eq(&reenumerated));
+    /// ```
+    pub fn semantically_eq(&self, other: &USBDevice) -> bool {
+        self.location_id.to_port_path() == other.location_id.to_port_path()
+            && self.vendor_id == other.vendor_id
+            && self.product_id == other.product_id
+            && self.serial_num == other.serial_num
+            && self.bcd_device == other.bcd_device
+            && self.bcd_usb == other.bcd_usb
+            && self.device_speed == other.device_speed
+            && self.class == other.class
+            && self.sub_class == other.sub_class
+            && self.protocol == other.protocol
+            && match (&self.extra, &other.extra) {
+                (Some(a), Some(b)) => a.semantically_eq(b),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+
     /// Does the device have child devices; `devices` is Some and > 0
     pub fn has_devices(&self) -> bool {
         match &self.devices {
@@ -736,6 +1295,75 @@ impl USBDevice {
         }
     }
 
+    /// Does the device have an AudioControl or AudioStreaming interface of UAC version `uac` in any configuration; see [`USBInterface::uac_version`]
+    ///
+    /// All configurations are checked since a device can offer both a UAC1 and a UAC2 configuration
+    pub fn has_uac_version(&self, uac: &audio::UacProtocol) -> bool {
+        self.extra.as_ref().is_some_and(|extra| {
+            extra
+                .configurations
+                .iter()
+                .flat_map(|conf| conf.interfaces.iter())
+                .any(|i| i.uac_version().as_ref() == Some(uac))
+        })
+    }
+
+    /// All extra descriptors of every configuration; see [`USBConfiguration::descriptors`]
+    pub fn descriptors(&self) -> impl Iterator<Item = &Descriptor> {
+        self.extra
+            .iter()
+            .flat_map(|e| e.configurations.iter())
+            .flat_map(|c| c.descriptors())
+    }
+
+    /// Extra descriptors of every configuration with bDescriptorType `t`
+    ///
+    /// Class-specific types are masked as the profiler stores them, so CS_INTERFACE 0x24 descriptors are [`DescriptorType::Interface`] and CS_ENDPOINT 0x25 are [`DescriptorType::Endpoint`]; the raw or masked value can be passed.
+    ///
+    /// ```
+    /// use cyme::system_profiler::SPUSBDataType;
+    /// use cyme::usb::descriptors::DescriptorType;
+    ///
+    /// let sp_usb = SPUSBDataType::example();
+    /// let device = sp_usb.get_node("1-1.2").unwrap();
+    /// assert_eq!(device.descriptors_of_type(0x0b).count(), 2);
+    /// assert_eq!(
+    ///     device.descriptors_of_type(0x24).count(),
+    ///     device.descriptors_of_type(DescriptorType::Interface).count()
+    /// );
+    /// ```
+    pub fn descriptors_of_type(
+        &self,
+        t: impl Into<DescriptorType>,
+    ) -> impl Iterator<Item = &Descriptor> {
+        let t = match t.into() {
+            DescriptorType::Pipe => DescriptorType::Interface,
+            t => t,
+        };
+        // compare values so that unparsed descriptors match too
+        let t = u8::from(t);
+        self.descriptors()
+            .filter(move |d| u8::from(d.descriptor_type()) == t)
+    }
+
+    /// Class-specific descriptors of every configuration with bDescriptorSubtype `subtype`, such as all UAC feature units
+    ///
+    /// See [`ClassDescriptor::descriptor_subtype`]; descriptors of all classes are included so filter on the variant if the subtype is ambiguous.
+    pub fn class_descriptors_of_subtype(
+        &self,
+        subtype: u8,
+    ) -> impl Iterator<Item = &ClassDescriptor> {
+        self.descriptors()
+            .filter_map(|d| match d {
+                Descriptor::Device(cd)
+                | Descriptor::Config(cd)
+                | Descriptor::Interface(cd)
+                | Descriptor::Endpoint(cd) => Some(cd),
+                _ => None,
+            })
+            .filter(move |cd| cd.descriptor_subtype() == Some(subtype))
+    }
+
     /// Gets root_hub [`USBDevice`] if it is one
     ///
     /// root_hub returns `Some(Self)`
@@ -759,18 +1387,20 @@ impl USBDevice {
 
     /// Recursively walk all [`USBDevice`] from self, looking for the one with `port_path` and returning reference
     ///
-    /// Will panic if `port_path` is not a child device or if it sits shallower than self
+    /// None if `port_path` is not a valid [`PortPath`] (logged as a warning) or is not self or a child device
     pub fn get_node(&self, port_path: &str) -> Option<&USBDevice> {
-        // special case for root_hub, it ends with :1.0
-        if port_path.ends_with(":1.0") {
+        let node_depth = match PortPath::from_str(port_path) {
+            Ok(p) => p.depth(),
+            // special case for root_hub, it ends with :1.0
+            Err(_) if port_path.ends_with(":1.0") => 0,
+            Err(e) => {
+                log::warn!("{}", e);
+                return None;
+            }
+        };
+        if node_depth == 0 {
             return self.get_root_hub();
         }
-        let node_depth = port_path
-            .split('-')
-            .last()
-            .expect("Invalid port path")
-            .split('.')
-            .count();
         let current_depth = self.get_depth();
         log::debug!(
             "Get node at {} with {} ({}); depth {}/{}",
@@ -781,12 +1411,9 @@ impl USBDevice {
             node_depth
         );
 
-        // should not be looking for nodes below us unless root
+        // nodes shallower than us cannot be children
         match current_depth.cmp(&node_depth) {
-            Ordering::Greater => panic!(
-                "Trying to find node at {}/{} shallower than current position {}!",
-                &port_path, node_depth, current_depth
-            ),
+            Ordering::Greater => return None,
             Ordering::Equal => {
                 if self.port_path() == port_path {
                     return Some(self);
@@ -811,21 +1438,23 @@ impl USBDevice {
 
     /// Recursively walk all [`USBDevice`] from self, looking for the one with `port_path` and returning mutable
     ///
-    /// Will panic if `port_path` is not a child device or if it sits shallower than self
+    /// None if `port_path` is not a valid [`PortPath`] (logged as a warning) or is not self or a child device
     pub fn get_node_mut(&mut self, port_path: &str) -> Option<&mut USBDevice> {
-        if port_path.ends_with(":1.0") {
+        let node_depth = match PortPath::from_str(port_path) {
+            Ok(p) => p.depth(),
+            Err(_) if port_path.ends_with(":1.0") => 0,
+            Err(e) => {
+                log::warn!("{}", e);
+                return None;
+            }
+        };
+        if node_depth == 0 {
             if self.is_root_hub() {
                 return Some(self);
             } else {
                 return None;
             }
         }
-        let node_depth = port_path
-            .split('-')
-            .last()
-            .expect("Invalid port path")
-            .split('.')
-            .count();
         let current_depth = self.get_depth();
         log::debug!(
             "Get node at {} with {} ({}); depth {}/{}",
@@ -836,12 +1465,9 @@ impl USBDevice {
             node_depth
         );
 
-        // should not be looking for nodes below us
+        // nodes shallower than us cannot be children
         match current_depth.cmp(&node_depth) {
-            Ordering::Greater => panic!(
-                "Trying to find node at {}/{} shallower than current position {}!",
-                &port_path, node_depth, current_depth
-            ),
+            Ordering::Greater => return None,
             Ordering::Equal => {
                 if self.port_path() == port_path {
                     return Some(self);
@@ -894,6 +1520,167 @@ impl USBDevice {
             || self.class.as_ref().map_or(false, |c| *c == ClassCode::Hub)
     }
 
+    /// Whether the device is powered by its own supply; the Self Powered bit of GET_STATUS if read, otherwise the bmAttributes of the active configuration. None without extra data
+    pub fn is_self_powered(&self) -> Option<bool> {
+        let extra = self.extra.as_ref()?;
+        match extra.status {
+            Some(status) => Some(status & 0x01 != 0),
+            None => extra
+                .active_configuration()
+                .map(|c| c.attributes.contains(&ConfigAttributes::SelfPowered)),
+        }
+    }
+
+    /// Returns `true` if the device is a hub drawing its power from the upstream port, so can only supply [`USBDevice::bus_powered_hub_port_ma`] to each of its ports
+    pub fn is_bus_powered_hub(&self) -> bool {
+        self.is_hub() && self.is_self_powered() == Some(false)
+    }
+
+    /// Current in mA the device can supply to each downstream port as a bus-powered hub: [`BUS_POWERED_SUPERSPEED_HUB_PORT_MA`] at SuperSpeed or faster, otherwise [`BUS_POWERED_HUB_PORT_MA`]
+    ///
+    /// If the speed is unknown, a bcdUSB of 3.0 or above is taken as SuperSpeed
+    pub fn bus_powered_hub_port_ma(&self) -> u32 {
+        let superspeed = match &self.device_speed {
+            Some(DeviceSpeed::SpeedValue(speed)) => {
+                matches!(speed, Speed::SuperSpeed | Speed::SuperSpeedPlus)
+            }
+            _ => self.bcd_usb.is_some_and(|v| v.0 >= 3),
+        };
+        if superspeed {
+            BUS_POWERED_SUPERSPEED_HUB_PORT_MA
+        } else {
+            BUS_POWERED_HUB_PORT_MA
+        }
+    }
+
+    /// Maximum power draw of the active configuration in mA at the operating speed of the device
+    pub fn max_power_ma(&self) -> Option<u32> {
+        let config = self.extra.as_ref()?.active_configuration()?;
+        Some(match &self.device_speed {
+            Some(DeviceSpeed::SpeedValue(speed)) => config.max_power_ma(*speed),
+            _ => config.max_power.value,
+        })
+    }
+
+    /// Theoretical bandwidth in bytes per second reserved by the periodic endpoints of the active alternate settings of the active configuration at the operating speed of the device; see [`USBEndpoint::periodic_bandwidth`]
+    ///
+    /// If the active alternate setting of an interface is unknown, alternate setting 0 is assumed since it is selected when the configuration is set. 0 if the speed is unknown
+    pub fn periodic_bandwidth(&self) -> u64 {
+        let speed = match &self.device_speed {
+            Some(DeviceSpeed::SpeedValue(s)) => *s,
+            _ => return 0,
+        };
+        self.extra
+            .as_ref()
+            .and_then(|e| e.active_configuration())
+            .map_or(0, |config| {
+                config
+                    .interfaces
+                    .iter()
+                    .filter(|i| i.is_active_alt_setting().unwrap_or(i.alt_setting == 0))
+                    .flat_map(|i| i.endpoints.iter())
+                    .filter_map(|ep| ep.periodic_bandwidth(speed))
+                    .sum()
+            })
+    }
+
+    /// Maximum packet size of the default control endpoint in bytes at the operating speed of the device; see [`USBDeviceExtra::control_max_packet_size`]
+    ///
+    /// If the speed is unknown, bMaxPacketSize0 is taken as an exponent if bcdUSB is 3.0 or above and it is 9, since 9 is not a valid size in bytes. None without extra data
+    pub fn control_max_packet_size(&self) -> Option<u32> {
+        let extra = self.extra.as_ref()?;
+        Some(match &self.device_speed {
+            Some(DeviceSpeed::SpeedValue(speed)) => extra.control_max_packet_size(*speed),
+            _ if extra.max_packet_size == 9 && self.bcd_usb.is_some_and(|v| v.0 >= 3) => {
+                extra.control_max_packet_size(Speed::SuperSpeed)
+            }
+            _ => extra.max_packet_size as u32,
+        })
+    }
+
+    /// Returns `true` if the device is bus-powered and its active configuration draws more than `parent` can supply as a bus-powered hub
+    ///
+    /// ```
+    /// use cyme::system_profiler::SPUSBDataType;
+    ///
+    /// let mut sp_usb = SPUSBDataType::example();
+    /// let device = sp_usb.get_node("1-1.2").unwrap();
+    /// // the example hub is self-powered
+    /// assert!(!device.exceeds_hub_budget(sp_usb.parent("1-1.2").unwrap()));
+    ///
+    /// let hub = sp_usb.get_node_mut("1-1").unwrap();
+    /// hub.extra.as_mut().unwrap().status = Some(0);
+    /// let device = sp_usb.get_node("1-1.2").unwrap();
+    /// assert!(device.exceeds_hub_budget(sp_usb.parent("1-1.2").unwrap()));
+    /// ```
+    pub fn exceeds_hub_budget(&self, parent: &USBDevice) -> bool {
+        parent.is_bus_powered_hub() && self.exceeds_power(parent.bus_powered_hub_port_ma())
+    }
+
+    /// Returns `true` if the device is not self-powered and its active configuration draws more than `available_ma`
+    pub fn exceeds_power(&self, available_ma: u32) -> bool {
+        self.is_self_powered() != Some(true)
+            && self.max_power_ma().is_some_and(|ma| ma > available_ma)
+    }
+
+    /// Children attached to a port the hub descriptor does not report; port 0 or beyond bNbrPorts. Empty if the device has no [`HubDescriptor`]
+    ///
+    /// ```
+    /// use cyme::system_profiler::SPUSBDataType;
+    /// let mut sp_usb = SPUSBDataType::example();
+    /// assert!(sp_usb.get_node("1-1").unwrap().children_outside_ports().is_empty());
+    ///
+    /// sp_usb.get_node_mut("1-1").unwrap().extra.as_mut().unwrap().hub.as_mut().unwrap().num_ports = 1;
+    /// let hub = sp_usb.get_node("1-1").unwrap();
+    /// assert_eq!(hub.children_outside_ports()[0].port_path(), "1-1.2");
+    /// assert_eq!(hub.hub_port_count(), Some(2));
+    /// ```
+    pub fn children_outside_ports(&self) -> Vec<&USBDevice> {
+        let Some(num_ports) = self.hub_descriptor().map(|h| h.num_ports) else {
+            return Vec::new();
+        };
+        self.devices
+            .iter()
+            .flatten()
+            .filter(|d| {
+                let port = d.get_branch_position();
+                port == 0 || port > num_ports
+            })
+            .collect()
+    }
+
+    /// Number of downstream ports of a hub; bNbrPorts of the [`HubDescriptor`], extended to cover any child on a higher port so that a misreporting hub does not hide devices
+    ///
+    /// None if the device has no [`HubDescriptor`]
+    pub fn hub_port_count(&self) -> Option<u8> {
+        let num_ports = self.hub_descriptor()?.num_ports;
+        Some(
+            self.children_outside_ports()
+                .iter()
+                .map(|d| d.get_branch_position())
+                .fold(num_ports, u8::max),
+        )
+    }
+
+    /// Log a warning for each of the [`USBDevice::children_outside_ports`] of a hub
+    pub fn warn_children_outside_ports(&self) {
+        let Some(num_ports) = self.hub_descriptor().map(|h| h.num_ports) else {
+            return;
+        };
+        for child in self.children_outside_ports() {
+            log::warn!(
+                "Hub {} reports {} ports but has a child on port {}",
+                self.port_path(),
+                num_ports,
+                child.get_branch_position()
+            );
+        }
+    }
+
+    pub(crate) fn hub_descriptor(&self) -> Option<&HubDescriptor> {
+        self.extra.as_ref().and_then(|e| e.hub.as_ref())
+    }
+
     /// Linux style port path where it can be found on system device path - normaly /sys/bus/usb/devices
     ///
     /// Normal device
@@ -989,28 +1776,89 @@ impl USBDevice {
         self.location_id.tree_positions.is_empty()
     }
 
-    /// From lsusb.c: Attempt to get friendly vendor and product names from the udev hwdb. If either or both are not present, instead populate those from the device's own string descriptors
-    pub fn get_vendor_product_with_fallback(&self) -> (String, String) {
-        match &self.extra {
-            Some(v) => (
-                v.vendor.to_owned().unwrap_or(
-                    self.manufacturer
-                        .as_ref()
-                        .unwrap_or(&String::new())
-                        .to_owned(),
-                ),
-                v.product_name
-                    .to_owned()
-                    .unwrap_or(self.name.trim().to_string()),
-            ),
-            None => (
-                self.manufacturer
-                    .as_ref()
-                    .unwrap_or(&String::new())
-                    .to_owned(),
-                self.name.trim().to_string(),
-            ),
-        }
+    /// bNumConfigurations from the device descriptor; None if the descriptor was not read
+    ///
+    /// The configurations retrieved are in [`USBDeviceExtra::configurations`], which can be fewer if some could not be read.
+    pub fn num_configurations(&self) -> Option<u8> {
+        self.extra.as_ref().and_then(|e| e.num_configurations)
+    }
+
+    /// Name to display for the device, used by every renderer so naming is consistent
+    ///
+    /// In order of precedence: the product string then the product name from the ids database. None if neither is known; the VID:PID is not repeated as a name since it is shown alongside.
+    ///
+    /// ```
+    /// use cyme::system_profiler::USBDevice;
+    ///
+    /// let mut d = USBDevice {
+    ///     name: String::from(" Black Magic Probe "),
+    ///     vendor_id: Some(0x1d50),
+    ///     product_id: Some(0x6018),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(d.display_name(), Some(String::from("Black Magic Probe")));
+    /// d.name = String::new();
+    /// assert_eq!(d.display_name(), Some(String::from("Black Magic Debug Probe (Application)")));
+    /// d.product_id = Some(0xfffe);
+    /// assert_eq!(d.display_name(), None);
+    /// ```
+    pub fn display_name(&self) -> Option<String> {
+        Some(self.name.trim())
+            .filter(|n| !n.is_empty())
+            .map(|n| n.to_string())
+            .or_else(|| self.extra.as_ref().and_then(|e| e.product_name.to_owned()))
+            .or_else(|| {
+                self.vendor_id.zip(self.product_id).and_then(|(vid, pid)| {
+                    usb_ids::Device::from_vid_pid(vid, pid).map(|d| d.name().to_owned())
+                })
+            })
+    }
+
+    /// Manufacturer to display for the device, used by every renderer so naming is consistent
+    ///
+    /// In order of precedence: the manufacturer string then the vendor name from the ids database. None if neither is known.
+    ///
+    /// ```
+    /// use cyme::system_profiler::USBDevice;
+    ///
+    /// let mut d = USBDevice {
+    ///     vendor_id: Some(0x1d50),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(d.display_manufacturer(), Some(String::from("OpenMoko, Inc.")));
+    /// d.manufacturer = Some(String::from("Black Magic Debug"));
+    /// assert_eq!(d.display_manufacturer(), Some(String::from("Black Magic Debug")));
+    /// ```
+    pub fn display_manufacturer(&self) -> Option<String> {
+        self.manufacturer
+            .as_ref()
+            .map(|m| m.trim().to_string())
+            .filter(|m| !m.is_empty())
+            .or_else(|| self.extra.as_ref().and_then(|e| e.vendor.to_owned()))
+            .or_else(|| {
+                self.vendor_id
+                    .and_then(|vid| usb_ids::Vendor::from_id(vid).map(|v| v.name().to_owned()))
+            })
+    }
+
+    /// From lsusb.c: Attempt to get friendly vendor and product names from the udev hwdb. If either or both are not present, instead populate those from [`USBDevice::display_manufacturer`] and [`USBDevice::display_name`]
+    ///
+    /// The database is preferred over the device strings to match the lsusb list output.
+    pub fn get_vendor_product_with_fallback(&self) -> (String, String) {
+        let vendor = self
+            .extra
+            .as_ref()
+            .and_then(|v| v.vendor.to_owned())
+            .or_else(|| self.display_manufacturer())
+            .unwrap_or_default();
+        let product = self
+            .extra
+            .as_ref()
+            .and_then(|v| v.product_name.to_owned())
+            .or_else(|| self.display_name())
+            .unwrap_or_default();
+
+        (vendor, product)
     }
 
     /// Generate a String from self like lsusb default list device
@@ -1028,16 +1876,74 @@ impl USBDevice {
     pub fn to_lsusb_string(&self) -> String {
         let (vendor, product) = self.get_vendor_product_with_fallback();
         format!(
-            "Bus {:03} Device {:03}: ID {:04x}:{:04x} {} {}",
+            "Bus {:03} Device {:03}: ID {}",
             self.location_id.bus,
             self.location_id.number,
-            self.vendor_id.unwrap_or(0xffff),
-            self.product_id.unwrap_or(0xffff),
-            vendor,
-            product,
+            format_id(
+                self.vendor_id,
+                self.product_id,
+                Some(&vendor),
+                Some(&product)
+            ),
         )
     }
 
+    /// One line summary of the key facts about the device: VID:PID, name, bcdDevice, speed, power source and driver. Fields which are not known are omitted
+    ///
+    /// ```
+    /// use cyme::system_profiler::{DeviceLocation, DeviceSpeed, USBDevice};
+    /// use cyme::usb::Speed;
+    /// use cyme::usb::Version;
+    ///
+    /// let d = USBDevice {
+    ///     name: String::from("Foo Widget"),
+    ///     vendor_id: Some(0x1234),
+    ///     product_id: Some(0x5678),
+    ///     bcd_device: Some(Version(2, 1, 0)),
+    ///     device_speed: Some(DeviceSpeed::SpeedValue(Speed::SuperSpeed)),
+    ///     location_id: DeviceLocation { bus: 1, number: 4, tree_positions: vec![1] },
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(d.summary(), "1234:5678 Foo Widget (rev 2.10, 5000 Mbps)");
+    /// ```
+    pub fn summary(&self) -> String {
+        let (_, product) = self.get_vendor_product_with_fallback();
+        let mut facts: Vec<String> = Vec::new();
+
+        if let Some(v) = self.bcd_device {
+            facts.push(format!("rev {}", v));
+        }
+        if let Some(DeviceSpeed::SpeedValue(v)) = &self.device_speed {
+            if *v != Speed::Unknown {
+                let dv = NumericalUnit::<f32>::from(v);
+                let mbps = if dv.unit.starts_with('G') {
+                    dv.value * 1000.0
+                } else {
+                    dv.value
+                };
+                facts.push(format!("{} Mbps", mbps));
+            }
+        }
+        if let Some(extra) = self.extra.as_ref() {
+            if let Some(config) = extra.active_configuration() {
+                if config.attributes.contains(&ConfigAttributes::SelfPowered) {
+                    facts.push(String::from("self-powered"));
+                } else {
+                    facts.push(String::from("bus-powered"));
+                }
+            }
+            if let Some(driver) = extra.driver.as_ref() {
+                facts.push(format!("driver={}", driver));
+            }
+        }
+
+        let mut ret = format_id(self.vendor_id, self.product_id, None, Some(&product));
+        if !facts.is_empty() {
+            ret.push_str(&format!(" ({})", facts.join(", ")));
+        }
+        ret
+    }
+
     /// Generate a tuple (String, String, String) of the lsusb tree output at all three verbosity levels
     pub fn to_lsusb_tree_string(&self) -> Vec<(String, String, String)> {
         let mut format_strs = Vec::new();
@@ -1080,11 +1986,13 @@ impl USBDevice {
                             speed
                         ),
                         format!(
-                            "ID {:04x}:{:04x} {} {}",
-                            self.vendor_id.unwrap_or(0xFFFF),
-                            self.product_id.unwrap_or(0xFFFF),
-                            vendor,
-                            product,
+                            "ID {}",
+                            format_id(
+                                self.vendor_id,
+                                self.product_id,
+                                Some(&vendor),
+                                Some(&product)
+                            )
                         ),
                         format!(
                             "{}/{}  {}",
@@ -1110,14 +2018,17 @@ impl USBDevice {
                     speed
                 ),
                 format!(
-                    "ID {:04x}:{:04x} {} {}",
-                    self.vendor_id.unwrap_or(0xFFFF),
-                    self.product_id.unwrap_or(0xFFFF),
-                    // these are actually usb_ids vendor/product but don't have those without extra
-                    self.manufacturer
-                        .as_ref()
-                        .unwrap_or(&String::from("[unknown]")),
-                    self.name,
+                    "ID {}",
+                    format_id(
+                        self.vendor_id,
+                        self.product_id,
+                        Some(
+                            &self
+                                .display_manufacturer()
+                                .unwrap_or(String::from("[unknown]"))
+                        ),
+                        Some(&self.display_name().unwrap_or_default())
+                    )
                 ),
                 format!(
                     "{}/{}  {}",
@@ -1208,7 +2119,7 @@ impl fmt::Display for USBDevice {
                     .yellow()
                     .bold(),
                 format!("0x{:04x}", self.product_id.unwrap_or(0)).yellow(),
-                self.name.trim().bold().blue(),
+                self.display_name().unwrap_or_default().bold().blue(),
                 self.serial_num
                     .as_ref()
                     .unwrap_or(&String::from("None"))
@@ -1236,10 +2147,174 @@ impl fmt::Display for USBDevice {
     }
 }
 
+/// Selects a single [`USBDevice`] from a [`SPUSBDataType`] using one of the forms users commonly have to hand
+///
+/// Parsed from a string with [`FromStr`]:
+/// * Port path: '2-1.4', or the root hub interface path '2-0:1.0' for the root hub
+/// * Trailing ports of a port path: '1.4', which must only match one device
+/// * Bus and device number: '002/005' or a full '/dev/bus/usb/002/005' path
+/// * Vendor and product ID in hex: '1d50:6018'
+///
+/// ```
+/// use cyme::system_profiler::{DeviceSelector, SPUSBDataType};
+///
+/// let sp_usb = SPUSBDataType::example();
+/// let by_path: DeviceSelector = "1-1.2".parse().unwrap();
+/// let by_vidpid: DeviceSelector = "1d50:6018".parse().unwrap();
+/// assert_eq!("/dev/bus/usb/001/002".parse::<DeviceSelector>().unwrap(), "001/002".parse().unwrap());
+/// assert!("/dev/blah/001/002".parse::<DeviceSelector>().is_err());
+/// assert!("/dev/bus/usb/001".parse::<DeviceSelector>().is_err());
+/// assert_eq!(
+///     by_path.resolve(&sp_usb).unwrap().port_path(),
+///     by_vidpid.resolve(&sp_usb).unwrap().port_path()
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeviceSelector {
+    /// Full port path
+    PortPath(PortPath),
+    /// Trailing ports of a port path, matching any device whose path ends with them
+    TrailingPorts(Vec<u8>),
+    /// Bus and device number as in /dev/bus/usb/BBB/DDD
+    BusNumber {
+        /// Bus number
+        bus: u8,
+        /// Device number on the bus
+        number: u8,
+    },
+    /// Vendor and product ID
+    VidPid {
+        /// Vendor ID
+        vid: u16,
+        /// Product ID
+        pid: u16,
+    },
+}
+
+impl FromStr for DeviceSelector {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let parse_u8 = |v: &str| {
+            v.parse::<u8>()
+                .map_err(|e| Error::new(ErrorKind::Parsing, &format!("{} '{}'", e, v)))
+        };
+        let parse_hex = |v: &str| {
+            u16::from_str_radix(v.trim_start_matches("0x"), 16)
+                .map_err(|e| Error::new(ErrorKind::Parsing, &format!("{} '{}'", e, v)))
+        };
+
+        // port paths contain '-' and root hubs also a ':' so check them first
+        if s.contains('-') {
+            // root hub interface path selects the root hub
+            let s = match s.split_once('-') {
+                Some((bus, "0:1.0")) => &s[..bus.len() + 2],
+                _ => s,
+            };
+            Ok(DeviceSelector::PortPath(s.parse()?))
+        } else if !s.is_empty() && s.chars().all(|c| c.is_ascii_digit() || c == '.') {
+            let ports = s
+                .split('.')
+                .map(|p| match parse_u8(p)? {
+                    0 => Err(Error::new(
+                        ErrorKind::Parsing,
+                        &format!("Invalid trailing ports '{}': port numbers start at 1", s),
+                    )),
+                    p => Ok(p),
+                })
+                .collect::<Result<Vec<u8>, Error>>()?;
+            Ok(DeviceSelector::TrailingPorts(ports))
+        } else if let Some((bus, number)) = s
+            .strip_prefix("/dev/bus/usb/")
+            .unwrap_or(s)
+            .split_once('/')
+            .filter(|(bus, _)| !bus.is_empty() && bus.chars().all(|c| c.is_ascii_digit()))
+        {
+            // only /dev/bus/usb/BBB/DDD or bare BBB/DDD
+            Ok(DeviceSelector::BusNumber {
+                bus: parse_u8(bus)?,
+                number: parse_u8(number)?,
+            })
+        } else if let Some((vid, pid)) = s.split_once(':') {
+            Ok(DeviceSelector::VidPid {
+                vid: parse_hex(vid)?,
+                pid: parse_hex(pid)?,
+            })
+        } else {
+            Err(Error::new(
+                ErrorKind::InvalidArg,
+                &format!(
+                    "Invalid device selector '{}', expected a port path '2-1.4', bus/device number '002/005' or VID:PID '1d50:6018'",
+                    s
+                ),
+            ))
+        }
+    }
+}
+
+impl fmt::Display for DeviceSelector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DeviceSelector::PortPath(p) => write!(f, "{}", p),
+            DeviceSelector::TrailingPorts(ports) => write!(f, "{}", ports.iter().format(".")),
+            DeviceSelector::BusNumber { bus, number } => write!(f, "{:03}/{:03}", bus, number),
+            DeviceSelector::VidPid { vid, pid } => write!(f, "{:04x}:{:04x}", vid, pid),
+        }
+    }
+}
+
+impl DeviceSelector {
+    /// Does `device` match the selector
+    pub fn is_match(&self, device: &USBDevice) -> bool {
+        match self {
+            DeviceSelector::PortPath(p) => device.location_id.to_port_path() == *p,
+            DeviceSelector::TrailingPorts(ports) => {
+                device.location_id.tree_positions.ends_with(ports)
+            }
+            DeviceSelector::BusNumber { bus, number } => {
+                device.location_id.bus == *bus && device.location_id.number == *number
+            }
+            DeviceSelector::VidPid { vid, pid } => {
+                device.vendor_id == Some(*vid) && device.product_id == Some(*pid)
+            }
+        }
+    }
+
+    /// Find the single [`USBDevice`] in `sp_usb` matching the selector
+    ///
+    /// Errors with [`ErrorKind::NotFound`] if there is no match or [`ErrorKind::InvalidArg`] listing the devices if there is more than one.
+    pub fn resolve<'a>(&self, sp_usb: &'a SPUSBDataType) -> Result<&'a USBDevice, Error> {
+        let devices = sp_usb.flatten_devices();
+        let matches: Vec<&USBDevice> = devices.into_iter().filter(|d| self.is_match(d)).collect();
+        match matches.as_slice() {
+            [] => Err(Error::new(
+                ErrorKind::NotFound,
+                &format!("Unable to find device matching {}", self),
+            )),
+            [d] => Ok(d),
+            _ => Err(Error::new(
+                ErrorKind::InvalidArg,
+                &format!(
+                    "{} matches {} devices, select one by port path:\n{}",
+                    self,
+                    matches.len(),
+                    matches
+                        .iter()
+                        .map(|d| format!("  {} {}", d.port_path(), d.to_lsusb_string()))
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                ),
+            )),
+        }
+    }
+}
+
 /// Used to filter devices within buses
 ///
 /// The tree to a [`USBDevice`] is kept even if parent branches are not matches. To avoid this, one must flatten the devices first.
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct USBFilter {
     /// Retain only devices with vendor id matching this
     pub vid: Option<u16>,
@@ -1255,8 +2330,14 @@ pub struct USBFilter {
     pub serial: Option<String>,
     /// retain only device of ClassCode class
     pub class: Option<ClassCode>,
+    /// retain only devices with an AudioControl or AudioStreaming interface of this UAC version
+    pub uac: Option<audio::UacProtocol>,
     /// Exlcude empty hubs in the tree
     pub exclude_empty_hub: bool,
+    /// Retain only devices with declared strings which could not be read from the device; see [`USBDevice::missing_strings`]
+    pub missing_strings: bool,
+    /// Exclude all hubs, empty or not, leaving only leaf devices. Intended for flattened devices since retaining a tree will keep hubs with matching devices below them
+    pub exclude_hubs: bool,
     /// Don't exclude Linux root_hub devices - this is inverse because they are pseudo [`USBBus`]'s in the tree
     pub no_exclude_root_hub: bool,
 }
@@ -1359,7 +2440,13 @@ impl USBFilter {
             && (self.class.as_ref().map_or(true, |fc| {
                 device.class.as_ref().map_or(false, |c| c == fc) || device.has_interface_class(fc)
             }))
+            && (self
+                .uac
+                .as_ref()
+                .map_or(true, |u| device.has_uac_version(u)))
             && !(self.exclude_empty_hub && device.is_hub() && !device.has_devices())
+            && !(self.exclude_hubs && device.is_hub())
+            && (!self.missing_strings || !device.missing_strings().is_empty())
             && (!device.is_root_hub() || self.no_exclude_root_hub)
     }
 
@@ -1402,7 +2489,7 @@ impl USBFilter {
 
     /// Retains only `&USBDevice` in `devices` which match filter
     ///
-    /// Does not check down tree so should be used to flattened devices only (`get_all_devices`). Will remove hubs if `hide_hubs` since when flattened they will have no devices
+    /// Does not check down tree so should be used to flattened devices only (`get_all_devices`). Will remove hubs if `hide_hubs` since when flattened they will have no devices and all hubs if `exclude_hubs`
     pub fn retain_flattened_devices_ref(&self, devices: &mut Vec<&USBDevice>) {
         devices.retain(|d| self.is_match(d))
     }
@@ -1422,6 +2509,7 @@ pub fn read_json_dump(file_path: &str) -> Result<SPUSBDataType, Error> {
             ErrorKind::Parsing,
             &format!("Failed to parse dump at {:?}; Error({})", file_path, e),
         )
+        .with_source(e)
     })?;
 
     Ok(json_dump)
@@ -1439,6 +2527,7 @@ pub fn read_flat_json_dump(file_path: &str) -> Result<Vec<USBDevice>, Error> {
             ErrorKind::Parsing,
             &format!("Failed to parse dump at {:?}; Error({})", file_path, e),
         )
+        .with_source(e)
     })?;
 
     Ok(json_dump)
@@ -1486,6 +2575,7 @@ pub fn get_spusb() -> Result<SPUSBDataType, Error> {
                     e
                 ),
             )
+            .with_source(e)
         })
     } else {
         log::error!(
@@ -1518,6 +2608,44 @@ pub fn get_spusb_with_extra() -> Result<SPUSBDataType, Error> {
     ))
 }
 
+/// Format a device identifier as `VID:PID vendor product` like `1234:5678 Foo Corp Widget`
+///
+/// Missing or empty names are left out so the string never has trailing spaces and an unknown id is shown as `ffff`.
+///
+/// ```
+/// use cyme::system_profiler::format_id;
+///
+/// assert_eq!(
+///     format_id(Some(0x1234), Some(0x5678), Some("Foo Corp"), Some("Widget")),
+///     "1234:5678 Foo Corp Widget"
+/// );
+/// assert_eq!(format_id(Some(0x1234), Some(0x5678), None, Some(" ")), "1234:5678");
+/// assert_eq!(format_id(None, Some(0x5678), None, Some("Widget")), "ffff:5678 Widget");
+/// ```
+pub fn format_id(
+    vid: Option<u16>,
+    pid: Option<u16>,
+    vendor_name: Option<&str>,
+    product_name: Option<&str>,
+) -> String {
+    let mut ret = format!(
+        "{:04x}:{:04x}",
+        vid.unwrap_or(0xffff),
+        pid.unwrap_or(0xffff)
+    );
+    for name in [vendor_name, product_name]
+        .into_iter()
+        .flatten()
+        .map(str::trim)
+        .filter(|n| !n.is_empty())
+    {
+        ret.push(' ');
+        ret.push_str(name);
+    }
+
+    ret
+}
+
 /// Deserializes an option number from String (base10 or base16 encoding) or a number
 ///
 /// Modified from https://github.com/vityafx/serde-aux/blob/master/src/field_attributes.rs with addition of base16 encoding
@@ -1631,6 +2759,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::usb::descriptors::{audio, ClassDescriptor, Descriptor};
 
     #[test]
     fn test_deserialize_device() {
@@ -1697,4 +2826,587 @@ mod tests {
     fn test_json_dump_read_not_panic() {
         read_json_dump("./tests/data/system_profiler_dump.json").unwrap();
     }
+
+    #[test]
+    fn test_example_round_trip() {
+        let example = SPUSBDataType::example();
+        let json = serde_json::to_string_pretty(&example).unwrap();
+        let read: SPUSBDataType = serde_json::from_str(&json).unwrap();
+        assert_eq!(json, serde_json::to_string_pretty(&read).unwrap());
+    }
+
+    #[test]
+    fn test_example_synch_endpoint() {
+        let example = SPUSBDataType::example();
+        let device = example.get_node("1-1.2").unwrap();
+        let interface = device.extra.as_ref().unwrap().configurations[0]
+            .interfaces
+            .iter()
+            .find(|i| i.name == "Audio Streaming")
+            .unwrap();
+        let pairs = interface.synch_endpoint_pairs();
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0].0.address.address, 0x05);
+        assert_eq!(pairs[0].1.address.address, 0x85);
+        assert!(pairs[0].1.is_feedback());
+        // bSynchAddress of 0 is no synch endpoint
+        assert_eq!(pairs[0].1.synch_address(), None);
+    }
+
+    #[test]
+    fn test_parse_device_selector() {
+        let bus_number = |bus, number| DeviceSelector::BusNumber { bus, number };
+        assert_eq!(
+            DeviceSelector::from_str("/dev/bus/usb/001/003").unwrap(),
+            bus_number(1, 3)
+        );
+        assert_eq!(
+            DeviceSelector::from_str("/dev/bus/usb/004/3").unwrap(),
+            bus_number(4, 3)
+        );
+        assert_eq!(DeviceSelector::from_str("004/3").unwrap(), bus_number(4, 3));
+        assert!(DeviceSelector::from_str("004/").is_err());
+        assert!(DeviceSelector::from_str("sas/ssas").is_err());
+        assert_eq!(
+            DeviceSelector::from_str("2-1.4").unwrap(),
+            DeviceSelector::PortPath(PortPath::new(2, vec![1, 4]))
+        );
+        assert_eq!(
+            DeviceSelector::from_str("1-0:1.0").unwrap(),
+            DeviceSelector::PortPath(PortPath::new(1, vec![]))
+        );
+        assert!(DeviceSelector::from_str("1-1.4:1.0").is_err());
+        assert!(DeviceSelector::from_str("1-x").is_err());
+        assert_eq!(
+            DeviceSelector::from_str("1.4").unwrap(),
+            DeviceSelector::TrailingPorts(vec![1, 4])
+        );
+        assert!(DeviceSelector::from_str("1.0").is_err());
+        assert_eq!(
+            DeviceSelector::from_str("1d50:6018").unwrap(),
+            DeviceSelector::VidPid {
+                vid: 0x1d50,
+                pid: 0x6018
+            }
+        );
+        assert!(DeviceSelector::from_str("1d50:zzzz").is_err());
+        assert!(DeviceSelector::from_str("widget").is_err());
+    }
+
+    #[test]
+    fn test_resolve_device_selector() {
+        let mut example = SPUSBDataType::example();
+        let resolve = |sp: &SPUSBDataType, s: &str| {
+            DeviceSelector::from_str(s)
+                .unwrap()
+                .resolve(sp)
+                .map(|d| d.port_path())
+        };
+        assert_eq!(resolve(&example, "1-1.2").unwrap(), "1-1.2");
+        assert_eq!(resolve(&example, "1.2").unwrap(), "1-1.2");
+        assert_eq!(resolve(&example, "2").unwrap(), "1-1.2");
+        // a full port path only matches exactly, not 1-1.2
+        assert_eq!(resolve(&example, "1-1").unwrap(), "1-1");
+        assert_eq!(resolve(&example, "001/003").unwrap(), "1-1.2");
+        assert_eq!(resolve(&example, "1d50:6018").unwrap(), "1-1.2");
+        assert_eq!(
+            resolve(&example, "1-4").unwrap_err().kind(),
+            ErrorKind::NotFound
+        );
+
+        // same VID:PID on another bus is ambiguous
+        let mut bus = example.buses[0].clone();
+        bus.usb_bus_number = Some(2);
+        for d in bus.devices.iter_mut().flatten() {
+            d.location_id.bus = 2;
+            for c in d.devices.iter_mut().flatten() {
+                c.location_id.bus = 2;
+            }
+        }
+        example.buses.push(bus);
+        let err = resolve(&example, "1d50:6018").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidArg);
+        assert!(err.message().contains("1-1.2") && err.message().contains("2-1.2"));
+    }
+
+    #[test]
+    fn test_get_node_shallower_is_none() {
+        let example = SPUSBDataType::example();
+        let device = example.get_node("1-1.2").unwrap();
+        assert!(device.get_node("1-1").is_none());
+        assert_eq!(device.get_node("1-1.2").unwrap().port_path(), "1-1.2");
+    }
+
+    #[test]
+    fn test_info_source_limited() {
+        let mut source = DeviceInfoSource::default();
+        assert!(!source.is_limited((0, 0, 0)));
+        // declared but not read
+        assert!(source.is_limited((0, 0, 3)));
+        source.manufacturer = Some(InfoSource::Device);
+        source.serial_num = Some(InfoSource::Device);
+        assert!(!source.is_limited((0, 1, 3)));
+        source.name = Some(InfoSource::Database);
+        // no iProduct so the database name is not limited
+        assert!(!source.is_limited((0, 1, 3)));
+        assert!(source.is_limited((2, 1, 3)));
+        assert_eq!(
+            serde_json::to_string(&source).unwrap(),
+            r#"{"manufacturer":"device","name":"database","serial_num":"device"}"#
+        );
+    }
+
+    #[test]
+    fn test_example_has_all_class_descriptors() {
+        let example = SPUSBDataType::example();
+        let device = example.get_node("1-1.2").unwrap();
+        let config = &device.extra.as_ref().unwrap().configurations[0];
+        let class_descriptors: Vec<&ClassDescriptor> = config
+            .interfaces
+            .iter()
+            .flat_map(|i| {
+                i.extra
+                    .iter()
+                    .flatten()
+                    .chain(i.endpoints.iter().flat_map(|e| e.extra.iter().flatten()))
+            })
+            .filter_map(|d| match d {
+                Descriptor::Interface(c) | Descriptor::Endpoint(c) => Some(c),
+                _ => None,
+            })
+            .collect();
+
+        assert!(class_descriptors
+            .iter()
+            .any(|c| matches!(c, ClassDescriptor::Hid(_))));
+        assert!(class_descriptors
+            .iter()
+            .any(|c| matches!(c, ClassDescriptor::Communication(_))));
+        assert!(class_descriptors
+            .iter()
+            .any(|c| matches!(c, ClassDescriptor::Ccid(_))));
+        assert!(class_descriptors
+            .iter()
+            .any(|c| matches!(c, ClassDescriptor::Printer(_))));
+        assert!(class_descriptors
+            .iter()
+            .any(|c| matches!(c, ClassDescriptor::Midi(_, _))));
+        assert!(class_descriptors
+            .iter()
+            .any(|c| matches!(c, ClassDescriptor::Audio(_, _))));
+        assert!(class_descriptors
+            .iter()
+            .any(|c| matches!(c, ClassDescriptor::Video(_, _))));
+        assert!(class_descriptors
+            .iter()
+            .any(|c| matches!(c, ClassDescriptor::Dfu(_))));
+        assert!(class_descriptors
+            .iter()
+            .any(|c| matches!(c, ClassDescriptor::Generic(Some(_), _))));
+        // one for each descriptor in the raw example
+        assert_eq!(config.extra.as_ref().unwrap().len(), 6);
+        assert!(device.extra.as_ref().unwrap().binary_object_store.is_some());
+    }
+
+    #[test]
+    fn test_example_interface_associations() {
+        let example = SPUSBDataType::example();
+        let device = example.get_node("1-1.2").unwrap();
+        let config = &device.extra.as_ref().unwrap().configurations[0];
+        let functions = config.interface_associations();
+
+        assert_eq!(functions.len(), 2);
+        assert_eq!(
+            ClassCode::from(functions[0].function_class),
+            ClassCode::CDCCommunications
+        );
+        assert!(functions[0].contains(1));
+        assert!(!functions[0].contains(2));
+        assert_eq!(
+            ClassCode::from(functions[1].function_class),
+            ClassCode::Audio
+        );
+        assert_eq!(
+            config
+                .interfaces
+                .iter()
+                .filter(|i| functions[1].contains(i.number))
+                .count(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_json_deterministic() {
+        let pretty = crate::display::json_string(&SPUSBDataType::example(), false).unwrap();
+        let compact = crate::display::json_string(&SPUSBDataType::example(), true).unwrap();
+
+        // separately built profiles and a round trip through the json produce identical bytes
+        assert_eq!(
+            pretty,
+            crate::display::json_string(&SPUSBDataType::example(), false).unwrap()
+        );
+        let read: SPUSBDataType = serde_json::from_str(&compact).unwrap();
+        assert_eq!(compact, crate::display::json_string(&read, true).unwrap());
+
+        assert!(!compact.contains('\n'));
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&pretty).unwrap(),
+            serde_json::from_str::<serde_json::Value>(&compact).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_example_ss_isoc_companion() {
+        let example = SPUSBDataType::example();
+        let device = example.get_node("1-1.2").unwrap();
+        let endpoints: Vec<&USBEndpoint> = device.extra.as_ref().unwrap().configurations[0]
+            .interfaces
+            .iter()
+            .flat_map(|i| i.endpoints.iter())
+            .filter(|ep| ep.ss_isoc_companion().is_some())
+            .collect();
+
+        assert_eq!(endpoints.len(), 1);
+        assert!(endpoints[0].ss_companion().unwrap().has_isoc_companion());
+        assert_eq!(
+            endpoints[0].ss_isoc_companion().unwrap().bytes_per_interval,
+            96 * 1024
+        );
+    }
+
+    #[test]
+    fn test_class_descriptors_of_subtype() {
+        let example = SPUSBDataType::example();
+        let device = example.get_node("1-1.2").unwrap();
+        // 0x02 is an input terminal or jack in audio, MIDI and video; ACM in CDC
+        let descriptors: Vec<&ClassDescriptor> =
+            device.class_descriptors_of_subtype(0x02).collect();
+        assert_eq!(descriptors.len(), 5);
+        assert!(matches!(descriptors[0], ClassDescriptor::Communication(_)));
+        assert!(matches!(
+            descriptors[1],
+            ClassDescriptor::Audio(
+                audio::UacDescriptor {
+                    descriptor_subtype: audio::UacType::Control(
+                        audio::ControlSubtype::InputTerminal
+                    ),
+                    ..
+                },
+                _
+            )
+        ));
+        assert!(matches!(descriptors[3], ClassDescriptor::Midi(_, _)));
+        assert!(matches!(descriptors[4], ClassDescriptor::Video(_, _)));
+
+        assert!(device.descriptors().all(|d| device
+            .descriptors_of_type(u8::from(d.descriptor_type()))
+            .any(|f| f == d)));
+        assert_eq!(device.class_descriptors_of_subtype(0xfe).count(), 0);
+    }
+
+    #[test]
+    fn test_resolve_strings() {
+        let example = SPUSBDataType::example();
+        let mut device = example.get_node("1-1.2").unwrap().to_owned();
+        let iad = Descriptor::try_from([0x08, 0x0b, 0x00, 0x02, 0x02, 0x02, 0x01, 0x05].as_slice())
+            .unwrap();
+        let config = &mut device.extra.as_mut().unwrap().configurations[0];
+        config.extra.get_or_insert_with(Vec::new).push(iad);
+
+        let mut looked_up = Vec::new();
+        device.resolve_strings(|i| {
+            looked_up.push(i);
+            Some(format!("String {}", i))
+        });
+
+        // example descriptors all have index 0 so only the added IAD string should be fetched
+        assert_eq!(looked_up, vec![5]);
+        let config = &device.extra.as_ref().unwrap().configurations[0];
+        let function = config
+            .interface_associations()
+            .into_iter()
+            .find(|iad| iad.function_string_index == 5)
+            .unwrap();
+        assert_eq!(function.function_string, Some("String 5".to_string()));
+    }
+
+    #[test]
+    fn test_semantically_eq_ignores_resolved_strings() {
+        let example = SPUSBDataType::example();
+        let mut device = example.get_node("1-1.2").unwrap().to_owned();
+        let iad = Descriptor::try_from([0x08, 0x0b, 0x00, 0x02, 0x02, 0x02, 0x01, 0x05].as_slice())
+            .unwrap();
+        let config = &mut device.extra.as_mut().unwrap().configurations[0];
+        config.extra.get_or_insert_with(Vec::new).push(iad);
+
+        let mut english = device.clone();
+        english.resolve_strings(|_| Some(String::from("Audio")));
+        let mut german = device.clone();
+        german.resolve_strings(|_| Some(String::from("Ton")));
+        assert!(english.semantically_eq(&german));
+
+        // a different string index is a descriptor change
+        let config = &mut german.extra.as_mut().unwrap().configurations[0];
+        config.extra.as_mut().unwrap().pop();
+        config.extra.as_mut().unwrap().push(
+            Descriptor::try_from([0x08, 0x0b, 0x00, 0x02, 0x02, 0x02, 0x01, 0x06].as_slice())
+                .unwrap(),
+        );
+        assert!(!english.semantically_eq(&german));
+    }
+
+    #[test]
+    fn test_semantically_eq_ignores_hub_port_status() {
+        let example = SPUSBDataType::example();
+        let mut device = example.get_node("1-1.2").unwrap().to_owned();
+        let hub = crate::usb::descriptors::HubDescriptor::try_from(
+            [0x09, 0x29, 0x04, 0x00, 0x00, 0x32, 0x64, 0x00, 0xff].as_slice(),
+        )
+        .unwrap();
+        device.extra.as_mut().unwrap().hub = Some(hub.clone());
+
+        let mut connected = device.clone();
+        let mut status = hub.clone();
+        status.port_statuses = Some(vec![[0x03, 0x01, 0x01, 0x00, 0, 0, 0, 0]; 4]);
+        connected.extra.as_mut().unwrap().hub = Some(status);
+        assert!(device.semantically_eq(&connected));
+
+        let mut ports = hub;
+        ports.num_ports = 7;
+        connected.extra.as_mut().unwrap().hub = Some(ports);
+        assert!(!device.semantically_eq(&connected));
+    }
+
+    #[test]
+    fn test_example_audio_subclass() {
+        let example = SPUSBDataType::example();
+        let device = example.get_node("1-1.2").unwrap();
+        let config = &device.extra.as_ref().unwrap().configurations[0];
+        let audio_interfaces: Vec<&USBInterface> = config
+            .interfaces
+            .iter()
+            .filter(|i| i.class == ClassCode::Audio)
+            .collect();
+
+        assert_eq!(audio_interfaces.len(), 3);
+        for interface in audio_interfaces {
+            for d in interface.extra.iter().flatten() {
+                if let Descriptor::Interface(cd) = d {
+                    assert_eq!(
+                        cd.audio_subclass(),
+                        Some(audio::AudioSubclass::from(interface.sub_class))
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_filter_uac_version() {
+        assert_eq!(
+            "UAC2".parse::<audio::UacProtocol>(),
+            Ok(audio::UacProtocol::Uac2)
+        );
+        assert!("uac4".parse::<audio::UacProtocol>().is_err());
+
+        let mut example = SPUSBDataType::example();
+        let filter = USBFilter {
+            uac: Some(audio::UacProtocol::Uac1),
+            ..Default::default()
+        };
+        filter.retain_buses(&mut example.buses);
+        assert!(example.get_node("1-1.2").is_some());
+
+        let mut example = SPUSBDataType::example();
+        let filter = USBFilter {
+            uac: Some(audio::UacProtocol::Uac2),
+            ..Default::default()
+        };
+        filter.retain_buses(&mut example.buses);
+        assert!(example.get_node("1-1.2").is_none());
+
+        // MIDIStreaming interfaces have protocol 0 but are not UAC1
+        let mut example = SPUSBDataType::example();
+        let device = example.get_node_mut("1-1.2").unwrap();
+        for config in device.extra.as_mut().unwrap().configurations.iter_mut() {
+            config.interfaces.retain(|i| {
+                i.class != ClassCode::Audio
+                    || audio::AudioSubclass::from(i.sub_class)
+                        == audio::AudioSubclass::MidiStreaming
+            });
+        }
+        let filter = USBFilter {
+            uac: Some(audio::UacProtocol::Uac1),
+            ..Default::default()
+        };
+        filter.retain_buses(&mut example.buses);
+        assert!(example.get_node("1-1.2").is_none());
+    }
+
+    #[test]
+    fn test_filter_exclude_hubs() {
+        let filter = USBFilter {
+            exclude_hubs: true,
+            ..Default::default()
+        };
+
+        let mut example = SPUSBDataType::example();
+        example.flatten();
+        filter.retain_buses(&mut example.buses);
+        let paths: Vec<String> = example
+            .flatten_devices()
+            .iter()
+            .map(|d| d.port_path())
+            .collect();
+        assert_eq!(paths, vec!["1-1.2"]);
+
+        // tree retains the hub as parent of a matching device
+        let mut example = SPUSBDataType::example();
+        filter.retain_buses(&mut example.buses);
+        assert!(example.get_node("1-1").is_some());
+    }
+
+    #[test]
+    fn test_hub_port_mismatch() {
+        let mut example = SPUSBDataType::example();
+        let hub = example.get_node_mut("1-1").unwrap();
+        hub.extra.as_mut().unwrap().hub.as_mut().unwrap().num_ports = 1;
+        let mut port_zero = hub.devices.as_ref().unwrap()[0].clone();
+        port_zero.location_id.tree_positions = vec![1, 0];
+        hub.devices.as_mut().unwrap().push(port_zero);
+
+        let hub = example.get_node("1-1").unwrap();
+        assert_eq!(hub.children_outside_ports().len(), 2);
+        assert_eq!(hub.hub_port_count(), Some(2));
+
+        let violations: Vec<String> = crate::verify::verify(&example)
+            .into_iter()
+            .filter(|v| v.kind == crate::verify::ViolationKind::HubPortOutOfRange)
+            .map(|v| v.message)
+            .collect();
+        assert_eq!(
+            violations,
+            vec![
+                "child on port 2 exceeds hub's 1 ports",
+                "child on port 0 but hub ports start at 1"
+            ]
+        );
+
+        // neither tree nor lsusb output should panic with the malformed hub
+        crate::display::print(&example, &crate::display::PrintSettings::default());
+        crate::display::print(
+            &example,
+            &crate::display::PrintSettings {
+                tree: true,
+                ..Default::default()
+            },
+        );
+        crate::lsusb::print(&example.flatten_devices(), true);
+    }
+
+    #[test]
+    fn test_bus_over_periodic_budget() {
+        let mut example = SPUSBDataType::example();
+        let hub = example.get_node_mut("1-1").unwrap();
+        hub.device_speed = Some(DeviceSpeed::SpeedValue(Speed::FullSpeed));
+        let device = example.get_node_mut("1-1.2").unwrap();
+        device.device_speed = Some(DeviceSpeed::SpeedValue(Speed::FullSpeed));
+        // two isochronous endpoints with 1023 byte packets every frame exceed Full Speed alone
+        let config = &mut device.extra.as_mut().unwrap().configurations[0];
+        for interface in config.interfaces.iter_mut().filter(|i| i.number == 4) {
+            interface.active_alt_setting = Some(1);
+            for ep in interface.endpoints.iter_mut() {
+                ep.max_packet_size = 1023;
+            }
+        }
+
+        let bus = &example.buses[0];
+        assert_eq!(bus.periodic_bandwidth_budget(), Some(1_350_000));
+        assert!(bus.periodic_bandwidth_used() >= 2_046_000);
+        assert!(bus.exceeds_periodic_budget());
+
+        let violations: Vec<crate::verify::Violation> = crate::verify::verify(&example)
+            .into_iter()
+            .filter(|v| v.kind == crate::verify::ViolationKind::OverBandwidthBudget)
+            .collect();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].path, bus.path());
+    }
+
+    #[test]
+    fn test_filter_missing_strings() {
+        let filter = USBFilter {
+            missing_strings: true,
+            ..Default::default()
+        };
+
+        // all strings read from the device or sysfs
+        let mut example = SPUSBDataType::example();
+        filter.retain_buses(&mut example.buses);
+        assert!(example.get_node("1-1.2").is_none());
+
+        // serial declared but permissions prevented reading it
+        let mut example = SPUSBDataType::example();
+        let device = example.get_node_mut("1-1.2").unwrap();
+        device.serial_num = None;
+        device.info_source.as_mut().unwrap().serial_num = None;
+        filter.retain_buses(&mut example.buses);
+        let device = example.get_node("1-1.2").unwrap();
+        assert_eq!(device.missing_strings(), vec![("iSerialNumber", None)]);
+
+        // json without info sources falls back to whether there is a value
+        let mut device = device.clone();
+        device.info_source = None;
+        assert_eq!(device.missing_strings(), vec![("iSerialNumber", None)]);
+        device.serial_num = Some(String::from("1234"));
+        assert!(device.missing_strings().is_empty());
+    }
+
+    #[test]
+    fn test_primary_function_webcam() {
+        let example = SPUSBDataType::example();
+        let mut device = example.get_node("1-1.2").unwrap().to_owned();
+        let config = &mut device.extra.as_mut().unwrap().configurations[0];
+        // group the video control interface 6 with an IAD like a UVC+UAC webcam
+        let video_iad =
+            Descriptor::try_from([0x08, 0x0b, 0x06, 0x01, 0x0e, 0x03, 0x00, 0x00].as_slice())
+                .unwrap();
+        config.extra.as_mut().unwrap().push(video_iad.clone());
+        assert_eq!(device.primary_function(), Function::Video);
+
+        // regardless of IAD order
+        let config = &mut device.extra.as_mut().unwrap().configurations[0];
+        config.extra.as_mut().unwrap().pop();
+        config.extra.as_mut().unwrap().insert(0, video_iad);
+        assert_eq!(device.primary_function(), Function::Video);
+
+        // without IADs the functions are equal
+        let config = &mut device.extra.as_mut().unwrap().configurations[0];
+        config.extra = None;
+        assert_eq!(device.primary_function(), Function::Composite);
+
+        // device class takes precedence
+        device.class = Some(ClassCode::MassStorage);
+        assert_eq!(device.functions(), vec![Function::Storage]);
+        device.class = Some(ClassCode::VendorSpecificClass);
+        device.extra = None;
+        assert_eq!(device.primary_function(), Function::Unknown);
+    }
+
+    #[test]
+    fn test_summary_active_configuration() {
+        let example = SPUSBDataType::example();
+        let mut device = example.get_node("1-1.2").unwrap().to_owned();
+        let extra = device.extra.as_mut().unwrap();
+        extra.configurations[0].attributes = vec![ConfigAttributes::SelfPowered];
+        let mut second = extra.configurations[0].clone();
+        second.number = extra.configurations[0].number + 1;
+        second.attributes = vec![];
+        extra.configurations.push(second.clone());
+        assert!(device.summary().contains("self-powered"));
+
+        device.extra.as_mut().unwrap().active_config = Some(second.number);
+        assert!(device.summary().contains("bus-powered"));
+    }
 }
@@ -0,0 +1,540 @@
+//! Representative [`SPUSBDataType`] with every [`Descriptor`] type populated; used to document the `--json` output and as a contract test of the Serialize/Deserialize impls.
+//!
+//! Descriptors are parsed from raw bytes with the same `TryFrom` impls the profiler uses, so the output stays in sync with what a real device would produce.
+use super::*;
+use crate::usb::descriptors::*;
+
+const EXAMPLE_BUS: u8 = 1;
+const EXAMPLE_PORTS: [u8; 2] = [1, 2];
+
+/// Parse the example descriptor `bytes`, applying the class context if passed; the example is fixed so a descriptor that fails to parse is a bug in it
+fn parse_example(bytes: &[u8], class: Option<(u8, u8, u8)>) -> Descriptor {
+    let mut d = Descriptor::try_from(bytes)
+        .unwrap_or_else(|e| panic!("Example descriptor {:02x?} failed to parse: {}", bytes, e));
+    if let Some(class) = class {
+        d.update_with_class_context(class).unwrap_or_else(|e| {
+            panic!(
+                "Example descriptor {:02x?} failed to parse with class {:?}: {}",
+                bytes, class, e
+            )
+        });
+    }
+    d
+}
+
+/// Parse descriptors following an interface descriptor like the profiler; class specific types are forced to interface and the class context applied
+fn interface_extra(class: (u8, u8, u8), raw: &[&[u8]]) -> Vec<Descriptor> {
+    raw.iter()
+        .map(|r| {
+            let mut bytes = r.to_vec();
+            bytes[1] = 0x04;
+            parse_example(&bytes, Some(class))
+        })
+        .collect()
+}
+
+/// Parse descriptors following an endpoint descriptor like the profiler; class specific endpoint type is masked to endpoint
+fn endpoint_extra(class: (u8, u8, u8), raw: &[&[u8]]) -> Vec<Descriptor> {
+    raw.iter()
+        .map(|r| {
+            let mut bytes = r.to_vec();
+            if bytes[1] == 0x25 {
+                bytes[1] = 0x05;
+            }
+            parse_example(&bytes, Some(class))
+        })
+        .collect()
+}
+
+fn raw_extra(raw: &[&[u8]]) -> Vec<Descriptor> {
+    raw.iter().map(|r| parse_example(r, None)).collect()
+}
+
+fn endpoint(
+    address: u8,
+    attributes: u8,
+    max_packet_size: u16,
+    interval: u8,
+    extra: Option<Vec<Descriptor>>,
+) -> USBEndpoint {
+    USBEndpoint {
+        length: 7,
+        address: EndpointAddress::from(address),
+        transfer_type: TransferType::from(attributes),
+        sync_type: SyncType::from(attributes),
+        usage_type: UsageType::from(attributes),
+        max_packet_size,
+        interval,
+        extra,
+        refresh: None,
+        synch_address: None,
+    }
+}
+
+fn interface(
+    name: &str,
+    number: u8,
+    alt_setting: u8,
+    class: (u8, u8, u8),
+    driver: &str,
+    endpoints: Vec<USBEndpoint>,
+    extra: Vec<Descriptor>,
+) -> USBInterface {
+    USBInterface {
+        name: name.to_string(),
+        string_index: 0,
+        number,
+        path: get_interface_path(EXAMPLE_BUS, &EXAMPLE_PORTS, 1, number),
+        class: ClassCode::from(class.0),
+        sub_class: class.1,
+        protocol: class.2,
+        alt_setting,
+        driver: Some(driver.to_string()),
+        syspath: Some(format!(
+            "/sys/devices/pci0000:00/0000:00:14.0/usb1/{}",
+            get_interface_path(EXAMPLE_BUS, &EXAMPLE_PORTS, 1, number)
+        )),
+        endpoints,
+        length: 9,
+        extra: Some(extra),
+        uac3_clusters: None,
+        uac3_connectors: None,
+        active_alt_setting: None,
+    }
+}
+
+fn example_interfaces() -> Vec<USBInterface> {
+    const CDC: (u8, u8, u8) = (0x02, 0x02, 0x01);
+    const CDC_DATA: (u8, u8, u8) = (0x0a, 0x00, 0x00);
+    const HID: (u8, u8, u8) = (0x03, 0x01, 0x01);
+    const AUDIO_CONTROL: (u8, u8, u8) = (0x01, 0x01, 0x00);
+    const AUDIO_STREAMING: (u8, u8, u8) = (0x01, 0x02, 0x00);
+    const MIDI: (u8, u8, u8) = (0x01, 0x03, 0x00);
+    const VIDEO_CONTROL: (u8, u8, u8) = (0x0e, 0x01, 0x00);
+    const CCID: (u8, u8, u8) = (0x0b, 0x00, 0x00);
+    const PRINTER: (u8, u8, u8) = (0x07, 0x01, 0x04);
+    const DFU: (u8, u8, u8) = (0xfe, 0x01, 0x02);
+    const VENDOR: (u8, u8, u8) = (0xff, 0xff, 0xff);
+
+    vec![
+        interface(
+            "CDC ACM",
+            0,
+            0,
+            CDC,
+            "cdc_acm",
+            vec![endpoint(0x83, 0x03, 8, 16, None)],
+            interface_extra(
+                CDC,
+                &[
+                    &[0x05, 0x24, 0x00, 0x10, 0x01],
+                    &[0x05, 0x24, 0x01, 0x00, 0x01],
+                    &[0x04, 0x24, 0x02, 0x02],
+                    &[0x05, 0x24, 0x06, 0x00, 0x01],
+                ],
+            ),
+        ),
+        interface(
+            "CDC Data",
+            1,
+            0,
+            CDC_DATA,
+            "cdc_acm",
+            vec![
+                endpoint(
+                    0x01,
+                    0x02,
+                    1024,
+                    0,
+                    Some(raw_extra(&[&[0x06, 0x30, 0x0f, 0x00, 0x00, 0x00]])),
+                ),
+                endpoint(
+                    0x82,
+                    0x02,
+                    1024,
+                    0,
+                    Some(raw_extra(&[&[0x06, 0x30, 0x0f, 0x00, 0x00, 0x00]])),
+                ),
+            ],
+            vec![],
+        ),
+        interface(
+            "Keyboard",
+            2,
+            0,
+            HID,
+            "usbhid",
+            vec![endpoint(0x84, 0x03, 8, 10, None)],
+            interface_extra(
+                HID,
+                &[&[0x09, 0x21, 0x11, 0x01, 0x00, 0x01, 0x22, 0x3f, 0x00]],
+            ),
+        ),
+        interface(
+            "Audio Control",
+            3,
+            0,
+            AUDIO_CONTROL,
+            "snd-usb-audio",
+            vec![],
+            interface_extra(
+                AUDIO_CONTROL,
+                &[
+                    &[0x09, 0x24, 0x01, 0x00, 0x01, 0x1e, 0x00, 0x01, 0x04],
+                    &[
+                        0x0c, 0x24, 0x02, 0x01, 0x01, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+                    ],
+                    &[0x09, 0x24, 0x03, 0x02, 0x01, 0x01, 0x00, 0x01, 0x00],
+                ],
+            ),
+        ),
+        interface(
+            "Audio Streaming",
+            4,
+            1,
+            AUDIO_STREAMING,
+            "snd-usb-audio",
+            vec![
+                // asynchronous data endpoint synchronised by the feedback endpoint
+                USBEndpoint {
+                    length: 9,
+                    refresh: Some(0),
+                    synch_address: Some(0x85),
+                    ..endpoint(
+                        0x05,
+                        0x05,
+                        96,
+                        1,
+                        Some(endpoint_extra(
+                            AUDIO_STREAMING,
+                            &[
+                                &[0x07, 0x25, 0x01, 0x01, 0x00, 0x00, 0x00],
+                                // SuperSpeedPlus isochronous companions reserving 96 KB per interval
+                                &[0x06, 0x30, 0x00, 0x80, 0x00, 0x00],
+                                &[0x08, 0x31, 0x00, 0x00, 0x00, 0x80, 0x01, 0x00],
+                            ],
+                        )),
+                    )
+                },
+                USBEndpoint {
+                    length: 9,
+                    refresh: Some(0),
+                    synch_address: Some(0),
+                    ..endpoint(0x85, 0x11, 3, 1, None)
+                },
+            ],
+            interface_extra(
+                AUDIO_STREAMING,
+                &[
+                    &[0x07, 0x24, 0x01, 0x02, 0x01, 0x01, 0x00],
+                    &[
+                        0x0b, 0x24, 0x02, 0x01, 0x01, 0x02, 0x10, 0x01, 0x80, 0xbb, 0x00,
+                    ],
+                ],
+            ),
+        ),
+        interface(
+            "MIDI",
+            5,
+            0,
+            MIDI,
+            "snd-usb-audio",
+            vec![USBEndpoint {
+                length: 9,
+                ..endpoint(
+                    0x06,
+                    0x02,
+                    512,
+                    0,
+                    Some(endpoint_extra(MIDI, &[&[0x05, 0x25, 0x01, 0x01, 0x01]])),
+                )
+            }],
+            interface_extra(
+                MIDI,
+                &[
+                    &[0x07, 0x24, 0x01, 0x00, 0x01, 0x41, 0x00],
+                    &[0x06, 0x24, 0x02, 0x01, 0x01, 0x00],
+                    &[0x09, 0x24, 0x03, 0x01, 0x02, 0x01, 0x01, 0x01, 0x00],
+                ],
+            ),
+        ),
+        interface(
+            "Video Control",
+            6,
+            0,
+            VIDEO_CONTROL,
+            "uvcvideo",
+            vec![endpoint(0x87, 0x03, 16, 6, None)],
+            interface_extra(
+                VIDEO_CONTROL,
+                &[
+                    &[
+                        0x0d, 0x24, 0x01, 0x00, 0x01, 0x33, 0x00, 0x80, 0x8d, 0x5b, 0x00, 0x01,
+                        0x07,
+                    ],
+                    &[
+                        0x12, 0x24, 0x02, 0x01, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                        0x00, 0x00, 0x03, 0x0e, 0x00, 0x00,
+                    ],
+                    &[
+                        0x0b, 0x24, 0x05, 0x02, 0x01, 0x00, 0x00, 0x02, 0x7f, 0x14, 0x00,
+                    ],
+                    &[0x09, 0x24, 0x03, 0x03, 0x01, 0x01, 0x00, 0x02, 0x00],
+                ],
+            ),
+        ),
+        interface(
+            "Smart Card",
+            8,
+            0,
+            CCID,
+            "usbfs",
+            vec![
+                endpoint(0x09, 0x02, 64, 0, None),
+                endpoint(0x89, 0x02, 64, 0, None),
+            ],
+            interface_extra(
+                CCID,
+                &[&[
+                    0x36, 0x21, 0x10, 0x01, 0x00, 0x07, 0x03, 0x00, 0x00, 0x00, 0xa0, 0x0f, 0x00,
+                    0x00, 0xa0, 0x0f, 0x00, 0x00, 0x00, 0x80, 0x25, 0x00, 0x00, 0x80, 0x25, 0x00,
+                    0x00, 0x00, 0xfe, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                    0x00, 0xba, 0x04, 0x02, 0x00, 0x0f, 0x01, 0x00, 0x00, 0xff, 0xff, 0x00, 0x00,
+                    0x00, 0x01,
+                ]],
+            ),
+        ),
+        interface(
+            "Printer",
+            9,
+            0,
+            PRINTER,
+            "usblp",
+            vec![
+                endpoint(0x0a, 0x02, 512, 0, None),
+                endpoint(0x8a, 0x02, 512, 0, None),
+            ],
+            interface_extra(
+                PRINTER,
+                &[&[0x0a, 0x21, 0x01, 0x01, 0x00, 0x06, 0x01, 0x00, 0x01, 0x00]],
+            ),
+        ),
+        interface(
+            "DFU",
+            10,
+            0,
+            DFU,
+            "usbfs",
+            vec![],
+            interface_extra(
+                DFU,
+                &[&[0x09, 0x21, 0x0b, 0xff, 0x00, 0x00, 0x04, 0x1a, 0x01]],
+            ),
+        ),
+        interface(
+            "Vendor",
+            11,
+            0,
+            VENDOR,
+            "usbfs",
+            vec![endpoint(0x8b, 0x03, 64, 1, None)],
+            interface_extra(VENDOR, &[&[0x05, 0x24, 0xff, 0x01, 0x02]]),
+        ),
+    ]
+}
+
+fn example_device() -> USBDevice {
+    USBDevice {
+        name: String::from("Example Composite"),
+        vendor_id: Some(0x1d50),
+        product_id: Some(0x6018),
+        location_id: DeviceLocation {
+            bus: EXAMPLE_BUS,
+            tree_positions: EXAMPLE_PORTS.to_vec(),
+            number: 3,
+        },
+        serial_num: Some(String::from("0123456789")),
+        manufacturer: Some(String::from("cyme")),
+        bcd_device: Some(Version(1, 0, 0)),
+        bcd_usb: Some(Version(3, 2, 0)),
+        device_speed: Some(DeviceSpeed::SpeedValue(Speed::SuperSpeed)),
+        class: Some(ClassCode::Miscellaneous),
+        sub_class: Some(0x02),
+        protocol: Some(0x01),
+        extra: Some(USBDeviceExtra {
+            max_packet_size: 9,
+            driver: Some(String::from("usb")),
+            syspath: Some(String::from(
+                "/sys/devices/pci0000:00/0000:00:14.0/usb1/1-1.2",
+            )),
+            vendor: Some(String::from("OpenMoko, Inc.")),
+            product_name: None,
+            string_indexes: (2, 1, 3),
+            configurations: vec![USBConfiguration {
+                name: String::from("Default"),
+                string_index: 4,
+                number: 1,
+                interfaces: example_interfaces(),
+                attributes: vec![ConfigAttributes::RemoteWakeup],
+                max_power: NumericalUnit {
+                    value: 500,
+                    unit: String::from("mA"),
+                    description: None,
+                },
+                max_power_units: Some(250),
+                length: 9,
+                total_length: 512,
+                extra: Some(raw_extra(&[
+                    &[0x08, 0x0b, 0x00, 0x02, 0x02, 0x02, 0x01, 0x00],
+                    &[0x08, 0x0b, 0x03, 0x03, 0x01, 0x00, 0x00, 0x00],
+                    &[0x03, 0x09, 0x03],
+                    &[0x05, 0x0c, 0x05, 0x00, 0x01],
+                    &[0x05, 0x0e, 0x01, 0x01, 0x00],
+                    &[0x04, 0x0d, 0x00, 0x00],
+                ])),
+            }],
+            status: Some(0x0002),
+            debug: Some(
+                DebugDescriptor::try_from([0x04, 0x0a, 0x01, 0x82].as_slice())
+                    .expect("Example debug descriptor failed to parse"),
+            ),
+            binary_object_store: bos::BinaryObjectStoreDescriptor::try_from(
+                [
+                    0x05, 0x0f, 0x16, 0x00, 0x02, 0x07, 0x10, 0x02, 0x02, 0x00, 0x00, 0x00, 0x0a,
+                    0x10, 0x03, 0x00, 0x0e, 0x00, 0x01, 0x0a, 0xff, 0x07,
+                ]
+                .as_slice(),
+            )
+            .map(Some)
+            .expect("Example BOS descriptor failed to parse"),
+            other_speed_configurations: Vec::new(),
+            qualifier: DeviceQualifierDescriptor::try_from(
+                [0x0a, 0x06, 0x00, 0x02, 0xef, 0x02, 0x01, 0x40, 0x01, 0x00].as_slice(),
+            )
+            .map(Some)
+            .expect("Example device qualifier failed to parse"),
+            hub: None,
+            power_state: Some(PowerState::Active),
+            active_config: Some(1),
+            num_configurations: Some(1),
+        }),
+        info_source: Some(DeviceInfoSource {
+            manufacturer: Some(InfoSource::Device),
+            name: Some(InfoSource::Sysfs),
+            serial_num: Some(InfoSource::Device),
+        }),
+        ..Default::default()
+    }
+}
+
+fn example_hub(devices: Vec<USBDevice>) -> USBDevice {
+    USBDevice {
+        name: String::from("USB2.0 Hub"),
+        vendor_id: Some(0x05e3),
+        product_id: Some(0x0610),
+        location_id: DeviceLocation {
+            bus: EXAMPLE_BUS,
+            tree_positions: vec![EXAMPLE_PORTS[0]],
+            number: 2,
+        },
+        manufacturer: Some(String::from("GenesysLogic")),
+        bcd_device: Some(Version(6, 0, 4)),
+        bcd_usb: Some(Version(2, 1, 0)),
+        device_speed: Some(DeviceSpeed::SpeedValue(Speed::HighSpeed)),
+        class: Some(ClassCode::Hub),
+        sub_class: Some(0x00),
+        protocol: Some(0x02),
+        devices: Some(devices),
+        extra: Some(USBDeviceExtra {
+            max_packet_size: 64,
+            driver: Some(String::from("hub")),
+            syspath: Some(String::from(
+                "/sys/devices/pci0000:00/0000:00:14.0/usb1/1-1",
+            )),
+            vendor: Some(String::from("Genesys Logic, Inc.")),
+            product_name: Some(String::from("Hub")),
+            string_indexes: (2, 1, 0),
+            configurations: vec![USBConfiguration {
+                name: String::new(),
+                string_index: 0,
+                number: 1,
+                interfaces: vec![USBInterface {
+                    name: String::new(),
+                    string_index: 0,
+                    number: 0,
+                    path: get_interface_path(EXAMPLE_BUS, &[EXAMPLE_PORTS[0]], 1, 0),
+                    class: ClassCode::Hub,
+                    sub_class: 0,
+                    protocol: 1,
+                    alt_setting: 0,
+                    driver: Some(String::from("hub")),
+                    syspath: None,
+                    endpoints: vec![endpoint(0x81, 0x03, 1, 12, None)],
+                    length: 9,
+                    extra: Some(vec![]),
+                    uac3_clusters: None,
+                    uac3_connectors: None,
+                    active_alt_setting: None,
+                }],
+                attributes: vec![
+                    ConfigAttributes::SelfPowered,
+                    ConfigAttributes::RemoteWakeup,
+                ],
+                max_power: NumericalUnit {
+                    value: 100,
+                    unit: String::from("mA"),
+                    description: None,
+                },
+                max_power_units: Some(50),
+                length: 9,
+                total_length: 25,
+                extra: Some(vec![]),
+            }],
+            status: Some(0x0001),
+            debug: None,
+            binary_object_store: None,
+            qualifier: None,
+            other_speed_configurations: Vec::new(),
+            hub: HubDescriptor::try_from(
+                [0x09, 0x29, 0x04, 0xe0, 0x00, 0x32, 0x64, 0x00, 0xff].as_slice(),
+            )
+            .map(|mut h| {
+                h.port_statuses =
+                    Some(vec![[0x03, 0x05, 0, 0, 0, 0, 0, 0], [0; 8], [0; 8], [0; 8]]);
+                Some(h)
+            })
+            .expect("Example hub descriptor failed to parse"),
+            power_state: Some(PowerState::Suspended),
+            active_config: Some(1),
+            num_configurations: Some(1),
+        }),
+        ..Default::default()
+    }
+}
+
+impl SPUSBDataType {
+    /// Get an example [`SPUSBDataType`] with a single bus, a hub and a composite device that has each [`Descriptor`] and [`ClassDescriptor`] type populated
+    ///
+    /// Useful for documenting the shape of the `--json` output:
+    ///
+    /// ```
+    /// use cyme::system_profiler::SPUSBDataType;
+    ///
+    /// let example = SPUSBDataType::example();
+    /// let json = serde_json::to_string_pretty(&example).unwrap();
+    /// assert!(json.contains("\"interface-association\""));
+    /// ```
+    pub fn example() -> SPUSBDataType {
+        SPUSBDataType {
+            buses: vec![USBBus {
+                name: String::from("xHCI Host Controller"),
+                host_controller: String::from("Linux Foundation"),
+                pci_device: Some(0x0003),
+                pci_revision: None,
+                pci_vendor: Some(0x1d6b),
+                usb_bus_number: Some(EXAMPLE_BUS),
+                devices: Some(vec![example_hub(vec![example_device()])]),
+            }],
+        }
+    }
+}
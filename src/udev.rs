@@ -2,6 +2,19 @@
 use udevrs::{udev_new, UdevDevice, UdevHwdb};
 
 use crate::error::{Error, ErrorKind};
+use crate::usb::PowerState;
+
+/// Wraps [`udevrs::Error`], which does not impl [`std::error::Error`], so it can be kept as the source of a crate [`Error`] and downcast by callers
+#[derive(Debug)]
+pub struct UdevError(pub udevrs::Error);
+
+impl std::fmt::Display for UdevError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for UdevError {}
 
 /// Contains data returned by [`get_udev_info()`].
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -31,6 +44,7 @@ pub fn get_udev_info(port_path: &str) -> Result<UdevInfo, Error> {
                 path, e
             ),
         )
+        .with_source(UdevError(e))
     })?;
 
     Ok({
@@ -58,6 +72,7 @@ pub fn get_udev_driver_name(port_path: &str) -> Result<Option<String>, Error> {
                 path, e
             ),
         )
+        .with_source(UdevError(e))
     })?;
 
     Ok(device.get_driver().map(|s| s.trim().to_string()))
@@ -80,6 +95,7 @@ pub fn get_udev_syspath(port_path: &str) -> Result<Option<String>, Error> {
                 path, e
             ),
         )
+        .with_source(UdevError(e))
     })?;
 
     Ok(Some(device.syspath().trim().to_string()))
@@ -112,6 +128,7 @@ pub fn get_udev_attribute<T: AsRef<std::ffi::OsStr> + std::fmt::Display + Into<S
                 attribute, path, e
             ),
         )
+        .with_source(UdevError(e))
     })?;
 
     Ok(device
@@ -119,6 +136,57 @@ pub fn get_udev_attribute<T: AsRef<std::ffi::OsStr> + std::fmt::Display + Into<S
         .map(|s| s.trim().to_string()))
 }
 
+/// Lookup the runtime power management state for a device given the `port_path`.
+///
+/// Reads the `power/runtime_status` attribute; returns [`ErrorKind::NotFound`] if the device does not report it.
+///
+/// ```no_run
+/// use cyme::udev::get_power_state;
+/// use cyme::usb::PowerState;
+///
+/// let state = get_power_state("1-0:1.0").unwrap();
+/// assert_eq!(state, PowerState::Active);
+/// ```
+pub fn get_power_state(port_path: &str) -> Result<PowerState, Error> {
+    get_udev_attribute(port_path, "power/runtime_status")?
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::NotFound,
+                &format!("No runtime power status for device at {}", port_path),
+            )
+        })?
+        .parse()
+}
+
+/// Lookup the active alternate setting of the interface at `intf_path`, like "1-1:1.0"
+///
+/// Reads the `bAlternateSetting` attribute, which the kernel only reports for the alternate setting in use; returns [`ErrorKind::NotFound`] if the interface does not report it.
+///
+/// ```no_run
+/// use cyme::udev::get_active_alt_setting;
+///
+/// let alt = get_active_alt_setting("1-0:1.0").unwrap();
+/// assert_eq!(alt, 0);
+/// ```
+pub fn get_active_alt_setting(intf_path: &str) -> Result<u8, Error> {
+    let value = get_udev_attribute(intf_path, "bAlternateSetting")?.ok_or_else(|| {
+        Error::new(
+            ErrorKind::NotFound,
+            &format!("No bAlternateSetting for interface at {}", intf_path),
+        )
+    })?;
+    value.parse::<u8>().map_err(|e| {
+        Error::new(
+            ErrorKind::Parsing,
+            &format!(
+                "Invalid bAlternateSetting '{}' for interface at {}",
+                value, intf_path
+            ),
+        )
+        .with_source(e)
+    })
+}
+
 /// Utilities to get device information using udev hwdb - only supported on Linux. Requires 'udev' feature.
 pub mod hwdb {
     use super::*;
@@ -146,6 +214,7 @@ pub mod hwdb {
                 ErrorKind::Udev,
                 &format!("Failed to get hwdb: Error({})", e),
             )
+            .with_source(UdevError(e))
         })?;
 
         Ok(udevrs::udev_hwdb_query_one(&mut hwdb, modalias, key).map(|s| s.trim().to_string()))
@@ -3,6 +3,7 @@ use std::path::Path;
 use udevlib;
 
 use crate::error::{Error, ErrorKind};
+use crate::usb::PowerState;
 
 /// Contains data returned by [`get_udev_info()`].
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -32,6 +33,7 @@ pub fn get_udev_info(port_path: &str) -> Result<UdevInfo, Error> {
                 path, e
             ),
         )
+        .with_source(e)
     })?;
 
     Ok({
@@ -61,6 +63,7 @@ pub fn get_udev_driver_name(port_path: &str) -> Result<Option<String>, Error> {
                 path, e
             ),
         )
+        .with_source(e)
     })?;
 
     Ok(device
@@ -85,6 +88,7 @@ pub fn get_udev_syspath(port_path: &str) -> Result<Option<String>, Error> {
                 path, e
             ),
         )
+        .with_source(e)
     })?;
 
     Ok(device.syspath().to_str().map(|s| s.to_string()))
@@ -117,6 +121,7 @@ pub fn get_udev_attribute<T: AsRef<std::ffi::OsStr> + std::fmt::Display>(
                 attribute, path, e
             ),
         )
+        .with_source(e)
     })?;
 
     Ok(device
@@ -124,6 +129,28 @@ pub fn get_udev_attribute<T: AsRef<std::ffi::OsStr> + std::fmt::Display>(
         .map(|s| s.to_str().unwrap_or("").to_string()))
 }
 
+/// Lookup the runtime power management state for a device given the `port_path`.
+///
+/// Reads the `power/runtime_status` attribute; returns [`ErrorKind::NotFound`] if the device does not report it.
+///
+/// ```no_run
+/// use cyme::udev::get_power_state;
+/// use cyme::usb::PowerState;
+///
+/// let state = get_power_state("1-0:1.0").unwrap();
+/// assert_eq!(state, PowerState::Active);
+/// ```
+pub fn get_power_state(port_path: &str) -> Result<PowerState, Error> {
+    get_udev_attribute(port_path, "power/runtime_status")?
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::NotFound,
+                &format!("No runtime power status for device at {}", port_path),
+            )
+        })?
+        .parse()
+}
+
 /// udev hwdb lookup functions
 ///
 /// Protected by the `udev_hwdb` feature because 'libudev-sys' excludes hwdb ffi bindings if native udev does not support hwdb
@@ -153,6 +180,7 @@ pub mod hwdb {
                 ErrorKind::Udev,
                 &format!("Failed to get hwdb: Error({})", e),
             )
+            .with_source(e)
         })?;
 
         Ok(hwdb
@@ -3,6 +3,8 @@
 //! Also refering to [beyondlogic](https://beyondlogic.org/usbnutshell/usb5.shtml)
 //!
 //! There are some repeated/copied Enum defines from rusb in order to control Serialize/Deserialize and add impl
+//!
+//! JSON field names of the structs here are snake_case to match those used by macOS `system_profiler` for [`crate::system_profiler::USBDevice`], rather than the USB descriptor `bField` names. They are set explicitly with `rename_all` and locked by tests so that consumers of `--json` are not broken by refactors.
 use clap::ValueEnum;
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
@@ -193,7 +195,20 @@ pub enum DescriptorUsage {
 /// USB class code defines [ref](https://www.usb.org/defined-class-codes)
 ///
 /// Technically this is the 'Base Class' - the 'Class Code' is the full triplet of (Base Class, Sub Class, Protocol). TODO rename in 2.0 release
-#[derive(Debug, ValueEnum, Default, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(
+    Debug,
+    ValueEnum,
+    Default,
+    Clone,
+    Copy,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+)]
 #[serde(rename_all = "kebab-case")]
 #[non_exhaustive]
 #[repr(u8)]
@@ -304,6 +319,8 @@ impl From<Class> for ClassCode {
             Class::FullSpeedHub => ClassCode::Hub,
             Class::HighSpeedHubSingleTT => ClassCode::Hub,
             Class::HighSpeedHubMultiTT => ClassCode::Hub,
+            Class::HidBootKeyboard => ClassCode::HID,
+            Class::HidBootMouse => ClassCode::HID,
             Class::AudioVideoAVControlInterface => ClassCode::Audio,
             Class::AudioVideoAVDataVideo => ClassCode::Audio,
             Class::AudioVideoAVDataAudio => ClassCode::Audio,
@@ -423,6 +440,10 @@ pub enum Class {
     HighSpeedHubSingleTT,
     /// Hi-speed hub with multiple TTs
     HighSpeedHubMultiTT,
+    /// HID Boot Interface Subclass keyboard; usable by the BIOS boot protocol without parsing the report descriptor
+    HidBootKeyboard,
+    /// HID Boot Interface Subclass mouse; usable by the BIOS boot protocol without parsing the report descriptor
+    HidBootMouse,
     /// Audio/Video Device – AVControl Interface
     AudioVideoAVControlInterface,
     /// Audio/Video Device – AVData Video Streaming Interface
@@ -551,6 +572,8 @@ where
             (ClassCode::Hub, 0x00, 0x00) => Class::FullSpeedHub,
             (ClassCode::Hub, 0x00, 0x01) => Class::HighSpeedHubSingleTT,
             (ClassCode::Hub, 0x00, 0x02) => Class::HighSpeedHubMultiTT,
+            (ClassCode::HID, 0x01, 0x01) => Class::HidBootKeyboard,
+            (ClassCode::HID, 0x01, 0x02) => Class::HidBootMouse,
             (ClassCode::Audio, 0x01, 0x00) => Class::AudioVideoAVControlInterface,
             (ClassCode::Audio, 0x02, 0x00) => Class::AudioVideoAVDataVideo,
             (ClassCode::Audio, 0x03, 0x00) => Class::AudioVideoAVDataAudio,
@@ -623,10 +646,34 @@ impl Class {
             _ => DescriptorUsage::Interface,
         }
     }
+
+    /// Human readable description of what the protocol makes the class, where it says more than the base class name
+    ///
+    /// ```
+    /// use cyme::usb::{Class, ClassCode};
+    ///
+    /// assert_eq!(Class::from((ClassCode::HID, 1, 1)).protocol_description(), Some("HID Boot Keyboard"));
+    /// assert_eq!(Class::from((ClassCode::HID, 1, 2)).protocol_description(), Some("HID Boot Mouse"));
+    /// assert_eq!(Class::from((ClassCode::HID, 0, 0)).protocol_description(), None);
+    /// ```
+    pub fn protocol_description(&self) -> Option<&'static str> {
+        match self {
+            Class::HidBootKeyboard => Some("HID Boot Keyboard"),
+            Class::HidBootMouse => Some("HID Boot Mouse"),
+            _ => None,
+        }
+    }
+
+    /// [`Class::protocol_description`] or the class name if there is no description
+    pub fn description(&self) -> String {
+        self.protocol_description()
+            .map(String::from)
+            .unwrap_or(self.to_string())
+    }
 }
 
 /// USB Speed is also defined in libusb but this one allows us to provide updates and custom impl
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 #[serde(untagged, rename_all = "snake_case")]
 #[allow(missing_docs)]
@@ -687,6 +734,62 @@ impl fmt::Display for Speed {
     }
 }
 
+/// Runtime power management state of a device as reported by Linux sysfs `power/runtime_status`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(missing_docs)]
+pub enum PowerState {
+    Active,
+    Suspended,
+    Suspending,
+    Resuming,
+    Error,
+    Unsupported,
+}
+
+impl PowerState {
+    /// Whether the device is suspended or in the process of suspending
+    pub fn is_suspended(&self) -> bool {
+        matches!(self, PowerState::Suspended | PowerState::Suspending)
+    }
+}
+
+impl FromStr for PowerState {
+    type Err = Error;
+
+    fn from_str(s: &str) -> error::Result<Self> {
+        match s.trim() {
+            "active" => Ok(PowerState::Active),
+            "suspended" => Ok(PowerState::Suspended),
+            "suspending" => Ok(PowerState::Suspending),
+            "resuming" => Ok(PowerState::Resuming),
+            "error" => Ok(PowerState::Error),
+            "unsupported" => Ok(PowerState::Unsupported),
+            _ => Err(Error::new(
+                ErrorKind::Parsing,
+                &format!("Unknown runtime power state: {}", s.trim()),
+            )),
+        }
+    }
+}
+
+impl fmt::Display for PowerState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                PowerState::Active => "active",
+                PowerState::Suspended => "suspended",
+                PowerState::Suspending => "suspending",
+                PowerState::Resuming => "resuming",
+                PowerState::Error => "error",
+                PowerState::Unsupported => "unsupported",
+            }
+        )
+    }
+}
+
 impl From<&Speed> for NumericalUnit<f32> {
     fn from(speed: &Speed) -> NumericalUnit<f32> {
         match speed {
@@ -725,6 +828,25 @@ impl From<&Speed> for NumericalUnit<f32> {
 }
 
 impl Speed {
+    /// Name of the speed as written in the USB specification, rather than the snake_case [`fmt::Display`] used for serialization
+    ///
+    /// ```
+    /// # use cyme::usb::Speed;
+    ///
+    /// assert_eq!(Speed::SuperSpeed.name(), "SuperSpeed");
+    /// assert_eq!(Speed::FullSpeed.name(), "Full Speed");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        match self {
+            Speed::SuperSpeedPlus => "SuperSpeedPlus",
+            Speed::SuperSpeed => "SuperSpeed",
+            Speed::HighSpeed | Speed::HighBandwidth => "High Speed",
+            Speed::FullSpeed => "Full Speed",
+            Speed::LowSpeed => "Low Speed",
+            Speed::Unknown => "Unknown",
+        }
+    }
+
     /// lsusb speed is always in Mb/s and shown just a M prefix
     ///
     /// ```
@@ -742,6 +864,26 @@ impl Speed {
             _ => format!("{:.0}{}", dv.value, prefix),
         }
     }
+
+    /// Theoretical bandwidth in bytes per second a bus at this speed can reserve for periodic transfers: 90% of the frame time for Low, Full and SuperSpeed, 80% of the microframe time for High Speed. None if the speed is unknown
+    ///
+    /// ```
+    /// # use cyme::usb::Speed;
+    ///
+    /// assert_eq!(Speed::HighSpeed.periodic_budget(), Some(48_000_000));
+    /// assert_eq!(Speed::Unknown.periodic_budget(), None);
+    /// ```
+    pub fn periodic_budget(&self) -> Option<u64> {
+        match self {
+            Speed::LowSpeed => Some(187_500 * 9 / 10),
+            Speed::FullSpeed => Some(1_500_000 * 9 / 10),
+            Speed::HighSpeed | Speed::HighBandwidth => Some(60_000_000 * 8 / 10),
+            // after 8b/10b and 128b/132b line encoding
+            Speed::SuperSpeed => Some(500_000_000 * 9 / 10),
+            Speed::SuperSpeedPlus => Some(1_212_121_212 * 9 / 10),
+            Speed::Unknown => None,
+        }
+    }
 }
 
 /// Transfer and [`USBEndpoint`] direction
@@ -767,7 +909,7 @@ impl fmt::Display for Direction {
 }
 
 /// Transfer type  for [`USBEndpoint`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[repr(u8)]
 pub enum TransferType {
     /// Control endpoint.
@@ -780,14 +922,57 @@ pub enum TransferType {
     Interrupt,
 }
 
+impl From<u8> for TransferType {
+    /// From bmAttributes bits 0..1
+    fn from(b: u8) -> Self {
+        match b & 0x03 {
+            0 => TransferType::Control,
+            1 => TransferType::Isochronous,
+            2 => TransferType::Bulk,
+            _ => TransferType::Interrupt,
+        }
+    }
+}
+
 impl fmt::Display for TransferType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{:?}", self)
     }
 }
 
+impl TransferType {
+    /// Service interval in microseconds of a periodic endpoint with bInterval `interval` when the device operates at `speed`. None for bulk and control endpoints, where bInterval is at most a NAK rate
+    ///
+    /// At Low and Full Speed bInterval is the number of 1 ms frames for interrupt endpoints and 2^(bInterval-1) frames for isochronous ones. From High Speed it is 2^(bInterval-1) 125 us microframes for both. An unknown speed is treated as Full Speed.
+    ///
+    /// ```
+    /// # use cyme::usb::{Speed, TransferType};
+    /// assert_eq!(TransferType::Interrupt.service_interval_us(10, Speed::FullSpeed), Some(10_000));
+    /// assert_eq!(TransferType::Interrupt.service_interval_us(10, Speed::HighSpeed), Some(64_000));
+    /// assert_eq!(TransferType::Isochronous.service_interval_us(4, Speed::FullSpeed), Some(8_000));
+    /// assert_eq!(TransferType::Isochronous.service_interval_us(1, Speed::SuperSpeed), Some(125));
+    /// assert_eq!(TransferType::Bulk.service_interval_us(1, Speed::HighSpeed), None);
+    /// ```
+    pub fn service_interval_us(&self, interval: u8, speed: Speed) -> Option<u64> {
+        let isochronous = match self {
+            TransferType::Isochronous => true,
+            TransferType::Interrupt => false,
+            _ => return None,
+        };
+        let exponential = 2u64.pow(interval.clamp(1, 16) as u32 - 1);
+
+        Some(match speed {
+            Speed::HighSpeed | Speed::HighBandwidth | Speed::SuperSpeed | Speed::SuperSpeedPlus => {
+                exponential * 125
+            }
+            _ if isochronous => exponential * 1000,
+            _ => interval.max(1) as u64 * 1000,
+        })
+    }
+}
+
 /// Isochronous synchronization mode for [`USBEndpoint`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[repr(u8)]
 pub enum SyncType {
     /// No synchronisation.
@@ -800,6 +985,18 @@ pub enum SyncType {
     Synchronous,
 }
 
+impl From<u8> for SyncType {
+    /// From bmAttributes bits 2..3
+    fn from(b: u8) -> Self {
+        match (b >> 2) & 0x03 {
+            0 => SyncType::None,
+            1 => SyncType::Asynchronous,
+            2 => SyncType::Adaptive,
+            _ => SyncType::Synchronous,
+        }
+    }
+}
+
 impl fmt::Display for SyncType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{:?}", self)
@@ -807,7 +1004,7 @@ impl fmt::Display for SyncType {
 }
 
 /// Isochronous usage type for [`USBEndpoint`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[repr(u8)]
 #[non_exhaustive]
 pub enum UsageType {
@@ -821,6 +1018,18 @@ pub enum UsageType {
     Reserved,
 }
 
+impl From<u8> for UsageType {
+    /// From bmAttributes bits 4..5
+    fn from(b: u8) -> Self {
+        match (b >> 4) & 0x03 {
+            0 => UsageType::Data,
+            1 => UsageType::Feedback,
+            2 => UsageType::FeedbackData,
+            _ => UsageType::Reserved,
+        }
+    }
+}
+
 impl fmt::Display for UsageType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{:?}", self)
@@ -851,6 +1060,7 @@ fn default_endpoint_desc_length() -> u8 {
 /// Address information for a [`USBEndpoint`]
 // This struct could be one byte with getters using mask but this saves a custom Serialize impl for system_profiler
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub struct EndpointAddress {
     /// Endpoint address byte
     pub address: u8,
@@ -881,14 +1091,23 @@ impl From<EndpointAddress> for u8 {
     }
 }
 
+/// lsusb style 'EP 1 IN'
+///
+/// ```
+/// use cyme::usb::EndpointAddress;
+///
+/// assert_eq!(EndpointAddress::from(0x81).to_string(), "EP 1 IN");
+/// assert_eq!(EndpointAddress::from(0x02).to_string(), "EP 2 OUT");
+/// ```
 impl fmt::Display for EndpointAddress {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "EP {} {}", self.number, self.direction)
+        write!(f, "EP {} {:#}", self.number, self.direction)
     }
 }
 
 /// Endpoint for a [`USBInterface`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub struct USBEndpoint {
     /// Endpoint length in bytes
     #[serde(default = "default_endpoint_desc_length")] // for backwards compatible json
@@ -908,9 +1127,36 @@ pub struct USBEndpoint {
     /// Extra descriptors data based on type
     #[serde(default)] // default for legacy json
     pub extra: Option<Vec<Descriptor>>,
+    /// bRefresh of audio endpoint descriptors, which are 9 bytes rather than 7. Rate at which a synchronisation endpoint provides feedback as a power of 2 ms; 0 for data endpoints
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh: Option<u8>,
+    /// bSynchAddress of audio endpoint descriptors, which are 9 bytes rather than 7. Raw address of the endpoint used to synchronise this one, 0 if none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub synch_address: Option<u8>,
 }
 
 impl USBEndpoint {
+    /// Resolves string indexes of class descriptors in `extra` using `lookup`; see [`Descriptor::resolve_strings`]
+    pub fn resolve_strings<F: FnMut(u8) -> Option<String>>(&mut self, lookup: &mut F) {
+        for d in self.extra.iter_mut().flatten() {
+            d.resolve_strings(lookup);
+        }
+    }
+
+    /// Whether the endpoint descriptor and its class descriptors are the same as `other`; see [`crate::system_profiler::USBDevice::semantically_eq`]
+    pub fn semantically_eq(&self, other: &USBEndpoint) -> bool {
+        self.length == other.length
+            && self.address == other.address
+            && self.transfer_type == other.transfer_type
+            && self.sync_type == other.sync_type
+            && self.usage_type == other.usage_type
+            && self.max_packet_size == other.max_packet_size
+            && self.interval == other.interval
+            && self.refresh == other.refresh
+            && self.synch_address == other.synch_address
+            && descriptor_bytes(&self.extra) == descriptor_bytes(&other.extra)
+    }
+
     /// Decodes the max packet value into a multipler and number of bytes like lsusb
     ///
     /// ```
@@ -929,6 +1175,8 @@ impl USBEndpoint {
     ///     max_packet_size: 0xfff1,
     ///     interval: 3,
     ///     extra: None,
+    ///     refresh: None,
+    ///     synch_address: None,
     /// };
     /// assert_eq!(ep.max_packet_string(), "4x 2033");
     /// ep.max_packet_size = 0x0064;
@@ -942,16 +1190,132 @@ impl USBEndpoint {
         )
     }
 
+    /// Endpoint number, the low 4 bits of bEndpointAddress
+    pub fn endpoint_number(&self) -> u8 {
+        self.address.number
+    }
+
+    /// Transfer [`Direction`] from bit 7 of bEndpointAddress
+    pub fn direction(&self) -> Direction {
+        self.address.direction
+    }
+
+    /// SuperSpeed Endpoint Companion descriptor of the endpoint if present
+    pub fn ss_companion(&self) -> Option<&SsEndpointCompanionDescriptor> {
+        self.extra.iter().flatten().find_map(|d| match d {
+            Descriptor::SsEndpointCompanion(c) => Some(c),
+            _ => None,
+        })
+    }
+
+    /// SuperSpeedPlus Isochronous Endpoint Companion descriptor of the endpoint if present; defines the bandwidth reserved for high-bandwidth isochronous endpoints
+    pub fn ss_isoc_companion(&self) -> Option<&SsIsocEndpointCompanionDescriptor> {
+        self.extra.iter().flatten().find_map(|d| match d {
+            Descriptor::SsIsocEndpointCompanion(c) => Some(c),
+            _ => None,
+        })
+    }
+
     /// Returns the attributes byte for the endpoint
     pub fn attributes(&self) -> u8 {
         self.transfer_type.to_owned() as u8
             | (self.sync_type.to_owned() as u8) << 2
             | (self.usage_type.to_owned() as u8) << 4
     }
+    /// bRefresh feedback rate exponent of a synchronisation endpoint; the endpoint reports every 2^bRefresh ms. None if not an audio endpoint or bRefresh is 0, as it is for data endpoints and always with UAC2
+    pub fn refresh(&self) -> Option<u8> {
+        self.refresh.filter(|&r| r != 0)
+    }
+
+    /// Address of the endpoint which provides synchronisation for this one, such as the feedback endpoint for an asynchronous isochronous audio data endpoint. None if not an audio endpoint or bSynchAddress is 0
+    pub fn synch_address(&self) -> Option<u8> {
+        self.synch_address.filter(|&a| a != 0)
+    }
+
+    /// Theoretical bandwidth in bytes per second reserved by a periodic (interrupt or isochronous) endpoint when the device operates at `speed`. None for bulk and control endpoints or if the speed is unknown
+    ///
+    /// The bytes per service interval are wMaxPacketSize times the additional transactions for High Speed, or wBytesPerInterval of the [`SsEndpointCompanionDescriptor`] for SuperSpeed, falling back to its burst and Mult if the descriptor is truncated; dwBytesPerInterval of a [`SsIsocEndpointCompanionDescriptor`] takes precedence at SuperSpeedPlus. The service interval is [`TransferType::service_interval_us`].
+    ///
+    /// ```
+    /// # use cyme::usb::*;
+    /// let mut ep = USBEndpoint {
+    ///     length: 7,
+    ///     address: EndpointAddress::from(0x81),
+    ///     transfer_type: TransferType::Isochronous,
+    ///     sync_type: SyncType::Asynchronous,
+    ///     usage_type: UsageType::Data,
+    ///     max_packet_size: 0x1400, // 3x 1024
+    ///     interval: 1,
+    ///     extra: None,
+    ///     refresh: None,
+    ///     synch_address: None,
+    /// };
+    /// // 3072 bytes every 125 us microframe
+    /// assert_eq!(ep.periodic_bandwidth(Speed::HighSpeed), Some(24_576_000));
+    /// ep.transfer_type = TransferType::Interrupt;
+    /// ep.max_packet_size = 8;
+    /// ep.interval = 10;
+    /// assert_eq!(ep.periodic_bandwidth(Speed::FullSpeed), Some(800));
+    /// // SuperSpeed companion reserving 1024 of a possible 2048 bytes per interval
+    /// ep.max_packet_size = 1024;
+    /// ep.interval = 4;
+    /// let companion = [0x06, 0x30, 0x01, 0x00, 0x00, 0x04];
+    /// ep.extra = Some(vec![descriptors::Descriptor::try_from(companion.as_slice()).unwrap()]);
+    /// assert_eq!(ep.periodic_bandwidth(Speed::SuperSpeed), Some(1_024_000));
+    /// ep.transfer_type = TransferType::Bulk;
+    /// assert_eq!(ep.periodic_bandwidth(Speed::FullSpeed), None);
+    /// ```
+    pub fn periodic_bandwidth(&self, speed: Speed) -> Option<u64> {
+        if speed == Speed::Unknown {
+            return None;
+        }
+        let interval_us = self
+            .transfer_type
+            .service_interval_us(self.interval, speed)?;
+        let isochronous = self.transfer_type == TransferType::Isochronous;
+        let packet_size = (self.max_packet_size & 0x7ff) as u64;
+
+        let bytes = match speed {
+            Speed::HighSpeed | Speed::HighBandwidth => {
+                let transactions = ((self.max_packet_size >> 11) & 3) as u64 + 1;
+                packet_size * transactions
+            }
+            Speed::SuperSpeed | Speed::SuperSpeedPlus => {
+                let isoc_companion = self
+                    .ss_isoc_companion()
+                    .filter(|_| speed == Speed::SuperSpeedPlus);
+                match (isoc_companion, self.ss_companion()) {
+                    (Some(sic), _) => sic.bytes_per_interval as u64,
+                    (None, Some(c)) => c.bytes_per_interval.map_or_else(
+                        || {
+                            let mult = if isochronous {
+                                (c.attributes & 0x03) as u64 + 1
+                            } else {
+                                1
+                            };
+                            packet_size * (c.max_burst as u64 + 1) * mult
+                        },
+                        u64::from,
+                    ),
+                    (None, None) => packet_size,
+                }
+            }
+            _ => packet_size,
+        };
+
+        Some(bytes * 1_000_000 / interval_us)
+    }
+
+    /// Is this an explicit isochronous feedback endpoint, which would be the [`USBEndpoint::synch_address`] of a data endpoint
+    pub fn is_feedback(&self) -> bool {
+        matches!(self.transfer_type, TransferType::Isochronous)
+            && matches!(self.usage_type, UsageType::Feedback)
+    }
 }
 
 /// Interface within a [`USBConfiguration`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub struct USBInterface {
     /// Name from descriptor
     pub name: String,
@@ -982,9 +1346,111 @@ pub struct USBInterface {
     /// Extra descriptors for interface based on type
     #[serde(default)] // default for legacy json
     pub extra: Option<Vec<Descriptor>>,
+    /// UAC3 High Capability Cluster descriptors referenced by the interface descriptors, requested from the device
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uac3_clusters: Option<Vec<audio::ClusterDescriptor3>>,
+    /// UAC3 High Capability Connectors descriptors referenced by the terminal descriptors, requested from the device
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uac3_connectors: Option<Vec<audio::ConnectorsDescriptor3>>,
+    /// Active alternate setting of the interface number as reported by the kernel on Linux only; the same for each alternate setting
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_alt_setting: Option<u8>,
 }
 
 impl USBInterface {
+    /// Whether this is the alternate setting in use; None if the active alternate setting is unknown
+    ///
+    /// ```
+    /// use cyme::system_profiler::SPUSBDataType;
+    ///
+    /// let sp_usb = SPUSBDataType::example();
+    /// let device = sp_usb.get_node("1-1.2").unwrap();
+    /// let mut interface = device.extra.as_ref().unwrap().configurations[0].interfaces[0].clone();
+    /// assert_eq!(interface.is_active_alt_setting(), None);
+    /// interface.active_alt_setting = Some(1);
+    /// assert_eq!(interface.is_active_alt_setting(), Some(interface.alt_setting == 1));
+    /// ```
+    pub fn is_active_alt_setting(&self) -> Option<bool> {
+        self.active_alt_setting.map(|a| a == self.alt_setting)
+    }
+
+    /// Resolves string indexes of class descriptors of the interface and its endpoints using `lookup`
+    pub fn resolve_strings<F: FnMut(u8) -> Option<String>>(&mut self, lookup: &mut F) {
+        for d in self.extra.iter_mut().flatten() {
+            d.resolve_strings(lookup);
+        }
+        for ep in self.endpoints.iter_mut() {
+            ep.resolve_strings(lookup);
+        }
+    }
+
+    /// Whether the interface descriptor, its endpoints and class descriptors are the same as `other`, ignoring the interface string, driver and paths; see [`crate::system_profiler::USBDevice::semantically_eq`]
+    pub fn semantically_eq(&self, other: &USBInterface) -> bool {
+        self.length == other.length
+            && self.number == other.number
+            && self.alt_setting == other.alt_setting
+            && self.string_index == other.string_index
+            && self.class == other.class
+            && self.sub_class == other.sub_class
+            && self.protocol == other.protocol
+            && self.uac3_clusters == other.uac3_clusters
+            && self.uac3_connectors == other.uac3_connectors
+            && pairwise_eq(
+                &self.endpoints,
+                &other.endpoints,
+                USBEndpoint::semantically_eq,
+            )
+            && descriptor_bytes(&self.extra) == descriptor_bytes(&other.extra)
+    }
+
+    /// wClusterDescrID referenced by UAC3 class descriptors of the interface
+    pub fn uac3_cluster_ids(&self) -> Vec<u16> {
+        self.extra
+            .iter()
+            .flatten()
+            .filter_map(|d| match d {
+                Descriptor::Interface(ClassDescriptor::Audio(uacd, audio::UacProtocol::Uac3)) => {
+                    Some(uacd.interface.cluster_descr_ids())
+                }
+                _ => None,
+            })
+            .flatten()
+            .unique()
+            .collect()
+    }
+
+    /// Resolve a wClusterDescrID to the [`audio::ClusterDescriptor3`] requested from the device
+    pub fn uac3_cluster(&self, id: u16) -> Option<&audio::ClusterDescriptor3> {
+        self.uac3_clusters
+            .iter()
+            .flatten()
+            .find(|c| c.descriptor_id == id)
+    }
+
+    /// wConnectorsDescrID referenced by UAC3 terminal descriptors of the interface
+    pub fn uac3_connectors_ids(&self) -> Vec<u16> {
+        self.extra
+            .iter()
+            .flatten()
+            .filter_map(|d| match d {
+                Descriptor::Interface(ClassDescriptor::Audio(uacd, audio::UacProtocol::Uac3)) => {
+                    Some(uacd.interface.connectors_descr_ids())
+                }
+                _ => None,
+            })
+            .flatten()
+            .unique()
+            .collect()
+    }
+
+    /// Resolve a wConnectorsDescrID to the [`audio::ConnectorsDescriptor3`] requested from the device
+    pub fn uac3_connectors(&self, id: u16) -> Option<&audio::ConnectorsDescriptor3> {
+        self.uac3_connectors
+            .iter()
+            .flatten()
+            .find(|c| c.descriptor_id == id)
+    }
+
     /// Linux syspath to interface
     pub fn path(&self, bus: u8, ports: &[u8], config: u8) -> String {
         get_interface_path(bus, ports, config, self.number)
@@ -1008,14 +1474,72 @@ impl USBInterface {
             .map(|p| p.name())
     }
 
+    /// Class, sub class and protocol names as [`crate::lsusb::names::class_chain`]
+    pub fn class_chain(&self) -> String {
+        crate::lsusb::names::class_chain(u8::from(self.class), self.sub_class, self.protocol)
+    }
+
     /// Returns fully defined USB [`Class`] based on base_class, sub_class and protocol triplet
     pub fn fully_defined_class(&self) -> Class {
         (self.class, self.sub_class, self.protocol).into()
     }
+
+    /// USB Audio Class version from bInterfaceProtocol if this is an AudioControl or AudioStreaming interface
+    ///
+    /// MIDIStreaming interfaces are excluded since their protocol is always 0, which would otherwise read as UAC1
+    pub fn uac_version(&self) -> Option<audio::UacProtocol> {
+        match (self.class, audio::AudioSubclass::from(self.sub_class)) {
+            (ClassCode::Audio, audio::AudioSubclass::Control | audio::AudioSubclass::Streaming) => {
+                Some(self.protocol.into())
+            }
+            _ => None,
+        }
+    }
+
+    /// Formats and frames of a UVC VideoStreaming interface; None for other interfaces or if it advertises no formats
+    pub fn uvc_streaming(&self) -> Option<video::UvcStreamingInterface> {
+        let streaming = video::UvcStreamingInterface::from_descriptors(
+            self.extra.iter().flatten().filter_map(|d| match d {
+                Descriptor::Interface(ClassDescriptor::Video(vd, _)) => Some(&vd.interface),
+                _ => None,
+            }),
+        );
+        (!streaming.formats.is_empty()).then_some(streaming)
+    }
+
+    /// Audio data [`audio::StreamingFormatType`] from the FORMAT_TYPE descriptor if this is an Audio Streaming interface
+    pub fn audio_format_type(&self) -> Option<audio::StreamingFormatType> {
+        self.extra.iter().flatten().find_map(|d| match d {
+            Descriptor::Interface(ClassDescriptor::Audio(
+                audio::UacDescriptor {
+                    interface: audio::UacInterfaceDescriptor::StreamingFormat(sf),
+                    ..
+                },
+                _,
+            )) => Some(sf.format_type.to_owned()),
+            _ => None,
+        })
+    }
+
+    /// The endpoint on this interface which synchronises `endpoint`, based on [`USBEndpoint::synch_address`]
+    pub fn synch_endpoint(&self, endpoint: &USBEndpoint) -> Option<&USBEndpoint> {
+        endpoint
+            .synch_address()
+            .and_then(|a| self.endpoints.iter().find(|e| e.address.address == a))
+    }
+
+    /// Pairs of (data, synch) endpoints on this interface, such as an isochronous audio data endpoint and its feedback endpoint
+    pub fn synch_endpoint_pairs(&self) -> Vec<(&USBEndpoint, &USBEndpoint)> {
+        self.endpoints
+            .iter()
+            .filter_map(|e| self.synch_endpoint(e).map(|s| (e, s)))
+            .collect()
+    }
 }
 
 /// Devices can have multiple configurations, each with different attributes and interfaces
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub struct USBConfiguration {
     /// Name from string descriptor
     pub name: String,
@@ -1030,6 +1554,9 @@ pub struct USBConfiguration {
     pub attributes: Vec<ConfigAttributes>,
     /// Maximum power consumption in mA
     pub max_power: NumericalUnit<u32>,
+    /// bMaxPower as reported, in units that depend on operating speed; see [`USBConfiguration::max_power_ma`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_power_units: Option<u8>,
     /// Size of configuration descriptor in bytes
     #[serde(default = "default_configuration_desc_length")]
     pub length: u8,
@@ -1042,6 +1569,152 @@ pub struct USBConfiguration {
 }
 
 impl USBConfiguration {
+    /// Maximum power consumption in mA of bMaxPower when operating at `speed`: 8 mA units at SuperSpeed and above, otherwise 2 mA units
+    ///
+    /// Falls back to [`USBConfiguration::max_power`] if bMaxPower is not known, for json from older versions.
+    ///
+    /// ```
+    /// use cyme::system_profiler::SPUSBDataType;
+    /// use cyme::usb::Speed;
+    ///
+    /// let sp_usb = SPUSBDataType::example();
+    /// let device = sp_usb.get_node("1-1.2").unwrap();
+    /// let mut config = device.extra.as_ref().unwrap().configurations[0].clone();
+    /// config.max_power_units = Some(225);
+    /// assert_eq!(config.max_power_ma(Speed::HighSpeed), 450);
+    /// assert_eq!(config.max_power_ma(Speed::SuperSpeed), 1800);
+    ///
+    /// config.max_power_units = None;
+    /// assert_eq!(config.max_power_ma(Speed::SuperSpeed), config.max_power.value);
+    /// ```
+    pub fn max_power_ma(&self, speed: Speed) -> u32 {
+        match self.max_power_units {
+            Some(units) => match speed {
+                Speed::SuperSpeed | Speed::SuperSpeedPlus => units as u32 * 8,
+                _ => units as u32 * 2,
+            },
+            None => self.max_power.value,
+        }
+    }
+
+    /// Configuration string at iConfiguration, such as "High Speed" or "Composite"; None if the device does not name the configuration
+    ///
+    /// ```
+    /// use cyme::system_profiler::SPUSBDataType;
+    ///
+    /// let sp_usb = SPUSBDataType::example();
+    /// let device = sp_usb.get_node("1-1.2").unwrap();
+    /// let config = &device.extra.as_ref().unwrap().configurations[0];
+    /// assert_eq!(config.configuration_string(), Some("Default"));
+    ///
+    /// let mut config = config.clone();
+    /// config.name = String::from(" ");
+    /// assert_eq!(config.configuration_string(), None);
+    /// ```
+    pub fn configuration_string(&self) -> Option<&str> {
+        let name = self.name.trim();
+        (!name.is_empty()).then_some(name)
+    }
+
+    /// Whether the configuration descriptor, its interfaces and class descriptors are the same as `other`, ignoring the configuration string; see [`crate::system_profiler::USBDevice::semantically_eq`]
+    pub fn semantically_eq(&self, other: &USBConfiguration) -> bool {
+        self.length == other.length
+            && self.total_length == other.total_length
+            && self.number == other.number
+            && self.string_index == other.string_index
+            && self.attributes == other.attributes
+            && self.max_power == other.max_power
+            && self.max_power_units == other.max_power_units
+            && pairwise_eq(
+                &self.interfaces,
+                &other.interfaces,
+                USBInterface::semantically_eq,
+            )
+            && descriptor_bytes(&self.extra) == descriptor_bytes(&other.extra)
+    }
+
+    /// Resolves the configuration string if not already read, and string indexes of class descriptors of the configuration and all its interfaces using `lookup`
+    pub fn resolve_strings<F: FnMut(u8) -> Option<String>>(&mut self, lookup: &mut F) {
+        if self.configuration_string().is_none() && self.string_index != 0 {
+            if let Some(name) = lookup(self.string_index) {
+                self.name = name;
+            }
+        }
+        for d in self.extra.iter_mut().flatten() {
+            d.resolve_strings(lookup);
+        }
+        for interface in self.interfaces.iter_mut() {
+            interface.resolve_strings(lookup);
+        }
+    }
+
+    /// Summarise the interfaces of the configuration and how many alternate settings each has
+    ///
+    /// ```
+    /// use cyme::system_profiler::SPUSBDataType;
+    ///
+    /// let example = SPUSBDataType::example();
+    /// let extra = example.get_node("1-1.2").unwrap().extra.as_ref().unwrap();
+    /// let mut config = extra.active_configuration().unwrap().to_owned();
+    /// assert!(config.interface_summary().with_multiple_alt_settings().is_empty());
+    ///
+    /// // zero bandwidth alt setting 0 of the audio streaming interface
+    /// let mut alt = config.interfaces.iter().find(|i| i.number == 4).unwrap().to_owned();
+    /// alt.alt_setting = 0;
+    /// alt.endpoints.clear();
+    /// config.interfaces.push(alt);
+    ///
+    /// let summary = config.interface_summary();
+    /// assert_eq!(summary.interface_count(), 11);
+    /// assert_eq!(summary.with_multiple_alt_settings(), vec![(4, 2)]);
+    /// assert_eq!(
+    ///     summary.to_string(),
+    ///     "11 interfaces, 1 with multiple alt settings (interface 4 has 2)"
+    /// );
+    /// ```
+    pub fn interface_summary(&self) -> InterfaceSummary {
+        let mut alt_settings: Vec<(u8, usize)> = Vec::new();
+        for interface in self.interfaces.iter() {
+            match alt_settings
+                .iter_mut()
+                .find(|(n, _)| *n == interface.number)
+            {
+                Some((_, count)) => *count += 1,
+                None => alt_settings.push((interface.number, 1)),
+            }
+        }
+
+        InterfaceSummary { alt_settings }
+    }
+
+    /// Number of interfaces and total endpoints of their default alternate settings; alternate setting 0 or the first present
+    ///
+    /// ```
+    /// use cyme::system_profiler::SPUSBDataType;
+    ///
+    /// let example = SPUSBDataType::example();
+    /// let extra = example.get_node("1-1.2").unwrap().extra.as_ref().unwrap();
+    /// let config = extra.active_configuration().unwrap();
+    /// let (interfaces, endpoints) = config.interface_endpoint_counts();
+    /// assert_eq!(interfaces, config.interface_summary().interface_count());
+    /// assert!(endpoints > 0);
+    /// ```
+    pub fn interface_endpoint_counts(&self) -> (usize, usize) {
+        let mut defaults: Vec<&USBInterface> = Vec::new();
+        for interface in self.interfaces.iter() {
+            match defaults.iter_mut().find(|d| d.number == interface.number) {
+                Some(d) if interface.alt_setting == 0 => *d = interface,
+                Some(_) => (),
+                None => defaults.push(interface),
+            }
+        }
+
+        (
+            defaults.len(),
+            defaults.iter().map(|i| i.endpoints.len()).sum(),
+        )
+    }
+
     /// Converts attributes into a ';' separated String
     pub fn attributes_string(&self) -> String {
         ConfigAttributes::attributes_to_string(&self.attributes)
@@ -1060,11 +1733,36 @@ impl USBConfiguration {
 
         ret
     }
+
+    /// Interface Association Descriptors grouping the interfaces into functions
+    ///
+    /// IADs generally follow the configuration descriptor but can be found after the interface or endpoint preceding the first interface of the function, so all are searched.
+    pub fn interface_associations(&self) -> Vec<&InterfaceAssociationDescriptor> {
+        self.descriptors()
+            .filter_map(|d| match d {
+                Descriptor::InterfaceAssociation(iad) => Some(iad),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// All extra descriptors in the configuration in the order they follow the configuration descriptor, then those of each interface alternate setting followed by its endpoints
+    pub fn descriptors(&self) -> impl Iterator<Item = &Descriptor> {
+        let interface_extra = self.interfaces.iter().flat_map(|i| {
+            i.extra
+                .iter()
+                .flatten()
+                .chain(i.endpoints.iter().flat_map(|e| e.extra.iter().flatten()))
+        });
+
+        self.extra.iter().flatten().chain(interface_extra)
+    }
 }
 
 /// Extra USB device data for verbose printing
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub struct USBDeviceExtra {
     /// Maximum packet size in bytes
     pub max_packet_size: u8,
@@ -1091,6 +1789,263 @@ pub struct USBDeviceExtra {
     pub qualifier: Option<DeviceQualifierDescriptor>,
     /// Hub descriptor if present (is a hub)
     pub hub: Option<HubDescriptor>,
+    /// Runtime power management state from sysfs on Linux only
+    #[serde(default)]
+    pub power_state: Option<PowerState>,
+    /// bConfigurationValue of the active configuration if it could be determined
+    #[serde(default)]
+    pub active_config: Option<u8>,
+    /// bNumConfigurations from the device descriptor; the configurations declared, which may be more than could be retrieved
+    #[serde(default)]
+    pub num_configurations: Option<u8>,
+    /// Other Speed Configuration descriptors of a high-speed capable device; the configurations it would have when operating at the other speed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub other_speed_configurations: Vec<USBConfiguration>,
+}
+
+impl USBDeviceExtra {
+    /// Maximum packet size of the default control endpoint in bytes when operating at `speed`
+    ///
+    /// bMaxPacketSize0 is the size in bytes (8, 16, 32 or 64) for Low, Full and High Speed but an exponent at SuperSpeed, where it is always 9 for 2^9 = 512 bytes.
+    ///
+    /// ```
+    /// use cyme::system_profiler::SPUSBDataType;
+    /// use cyme::usb::Speed;
+    ///
+    /// let sp_usb = SPUSBDataType::example();
+    /// let mut extra = sp_usb.get_node("1-1.2").unwrap().extra.clone().unwrap();
+    /// extra.max_packet_size = 64;
+    /// assert_eq!(extra.control_max_packet_size(Speed::HighSpeed), 64);
+    /// extra.max_packet_size = 9;
+    /// assert_eq!(extra.control_max_packet_size(Speed::SuperSpeed), 512);
+    /// ```
+    pub fn control_max_packet_size(&self, speed: Speed) -> u32 {
+        match speed {
+            Speed::SuperSpeed | Speed::SuperSpeedPlus => 1u32
+                .checked_shl(self.max_packet_size as u32)
+                .unwrap_or_default(),
+            _ => self.max_packet_size as u32,
+        }
+    }
+
+    /// The active [`USBConfiguration`]; the first configuration if which is active is unknown
+    pub fn active_configuration(&self) -> Option<&USBConfiguration> {
+        self.active_config
+            .and_then(|n| self.configurations.iter().find(|c| c.number == n))
+            .or(self.configurations.first())
+    }
+
+    /// Whether the descriptors of the device are the same as `other`, ignoring strings, the driver, sysfs path and runtime state such as the active configuration; see [`crate::system_profiler::USBDevice::semantically_eq`]
+    pub fn semantically_eq(&self, other: &USBDeviceExtra) -> bool {
+        self.max_packet_size == other.max_packet_size
+            && self.string_indexes == other.string_indexes
+            && self.num_configurations == other.num_configurations
+            && self.debug == other.debug
+            && self.binary_object_store == other.binary_object_store
+            && self.qualifier == other.qualifier
+            // port statuses are runtime state, not part of the hub descriptor
+            && self.hub.clone().map(Vec::<u8>::from) == other.hub.clone().map(Vec::<u8>::from)
+            && pairwise_eq(
+                &self.configurations,
+                &other.configurations,
+                USBConfiguration::semantically_eq,
+            )
+            && pairwise_eq(
+                &self.other_speed_configurations,
+                &other.other_speed_configurations,
+                USBConfiguration::semantically_eq,
+            )
+    }
+}
+
+/// Raw bytes of each descriptor in `extra`; these hold string indexes but not the strings resolved from them so do not depend on language
+fn descriptor_bytes(extra: &Option<Vec<Descriptor>>) -> Vec<Vec<u8>> {
+    extra.iter().flatten().cloned().map(Vec::from).collect()
+}
+
+/// Pairwise `eq` of `a` and `b` in order; descriptors are kept in the order the device returns them so reordering is a change
+fn pairwise_eq<T>(a: &[T], b: &[T], eq: impl Fn(&T, &T) -> bool) -> bool {
+    a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| eq(a, b))
+}
+
+/// Count of the interfaces in a [`USBConfiguration`] and their alternate settings
+///
+/// Audio and video streaming interfaces typically use alternate settings to reserve different bandwidths, so these are worth knowing before looking at the verbose dump.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InterfaceSummary {
+    /// bInterfaceNumber and number of alternate settings of each interface in order
+    pub alt_settings: Vec<(u8, usize)>,
+}
+
+impl InterfaceSummary {
+    /// Number of distinct interfaces, not counting alternate settings
+    pub fn interface_count(&self) -> usize {
+        self.alt_settings.len()
+    }
+
+    /// Interfaces with more than one alternate setting and their count
+    pub fn with_multiple_alt_settings(&self) -> Vec<(u8, usize)> {
+        self.alt_settings
+            .iter()
+            .filter(|(_, n)| *n > 1)
+            .copied()
+            .collect()
+    }
+}
+
+impl fmt::Display for InterfaceSummary {
+    /// Like "3 interfaces, 1 with multiple alt settings (interface 1 has 4)"
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let multiple = self.with_multiple_alt_settings();
+        write!(
+            f,
+            "{} interface{}, {} with multiple alt settings",
+            self.interface_count(),
+            if self.interface_count() == 1 { "" } else { "s" },
+            multiple.len()
+        )?;
+        if !multiple.is_empty() {
+            write!(
+                f,
+                " ({})",
+                multiple
+                    .iter()
+                    .map(|(i, n)| format!("interface {} has {}", i, n))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Bus number and chain of ports to a device; the parsed form of a port path `bus-port.port.port`
+///
+/// The root hub has no ports and is "bus-0". Interface paths such as "1-1.2:1.0" are not port paths.
+///
+/// ```
+/// use cyme::usb::PortPath;
+///
+/// let path: PortPath = "2-1.4.3".parse().unwrap();
+/// assert_eq!(path.bus(), 2);
+/// assert_eq!(path.ports(), &[1, 4, 3]);
+/// assert_eq!(path.depth(), 3);
+/// assert_eq!(path.to_string(), "2-1.4.3");
+/// assert_eq!(path.parent().unwrap().to_string(), "2-1.4");
+/// assert_eq!(path.trunk().to_string(), "2-1");
+///
+/// let root: PortPath = "2-0".parse().unwrap();
+/// assert_eq!(root.depth(), 0);
+/// assert!(root.parent().is_none());
+///
+/// assert!("2-1.x".parse::<PortPath>().is_err());
+/// assert!("2-1.4:1.0".parse::<PortPath>().is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PortPath {
+    bus: u8,
+    ports: Vec<u8>,
+}
+
+impl PortPath {
+    /// New [`PortPath`] from `bus` and chain of `ports` from the root hub
+    pub fn new(bus: u8, ports: Vec<u8>) -> Self {
+        PortPath { bus, ports }
+    }
+
+    /// Bus number
+    pub fn bus(&self) -> u8 {
+        self.bus
+    }
+
+    /// Port number at each hub from the root hub down
+    pub fn ports(&self) -> &[u8] {
+        &self.ports
+    }
+
+    /// Number of hubs deep; 0 for the root hub, 1 for trunk devices
+    pub fn depth(&self) -> usize {
+        self.ports.len()
+    }
+
+    /// Path of the hub the device is attached to; `None` for the root hub
+    pub fn parent(&self) -> Option<PortPath> {
+        self.ports
+            .split_last()
+            .map(|(_, parent)| PortPath::new(self.bus, parent.to_vec()))
+    }
+
+    /// Path of the trunk device on the bus
+    pub fn trunk(&self) -> PortPath {
+        PortPath::new(self.bus, self.ports.iter().take(1).copied().collect())
+    }
+
+    /// Name of the device in /sys/bus/usb/devices used for sysfs and udev lookups; the root hub is the USB controller "usbX"
+    ///
+    /// ```
+    /// use cyme::usb::PortPath;
+    ///
+    /// assert_eq!(PortPath::new(2, vec![1, 4]).sysfs_name(), "2-1.4");
+    /// assert_eq!(PortPath::new(2, vec![]).sysfs_name(), "usb2");
+    /// ```
+    pub fn sysfs_name(&self) -> String {
+        get_sysfs_name(self.bus, &self.ports)
+    }
+}
+
+impl FromStr for PortPath {
+    type Err = Error;
+
+    fn from_str(s: &str) -> error::Result<Self> {
+        let s = s.trim();
+        let invalid = |reason: &str| {
+            Error::new(
+                ErrorKind::Parsing,
+                &format!("Invalid port path '{}': {}", s, reason),
+            )
+        };
+        let parse_u8 = |v: &str| {
+            v.parse::<u8>()
+                .map_err(|e| invalid(&format!("{} '{}'", e, v)))
+        };
+
+        let (bus, ports) = s
+            .split_once('-')
+            .ok_or_else(|| invalid("expected 'bus-port.port'"))?;
+        if ports.contains(':') {
+            return Err(invalid("interface paths are not port paths"));
+        }
+        let bus = parse_u8(bus)?;
+
+        // special case for root_hub
+        if ports == "0" {
+            return Ok(PortPath::new(bus, Vec::new()));
+        }
+
+        let ports = ports
+            .split('.')
+            .map(|p| match parse_u8(p)? {
+                0 => Err(invalid("port numbers start at 1")),
+                p => Ok(p),
+            })
+            .collect::<error::Result<Vec<u8>>>()?;
+
+        Ok(PortPath::new(bus, ports))
+    }
+}
+
+impl TryFrom<&str> for PortPath {
+    type Error = Error;
+
+    fn try_from(s: &str) -> error::Result<Self> {
+        s.parse()
+    }
+}
+
+impl fmt::Display for PortPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", get_port_path(self.bus, &self.ports))
+    }
 }
 
 /// Builds a replica of sysfs path; excludes config.interface
@@ -1212,6 +2167,47 @@ pub fn get_sysfs_name(bus: u8, ports: &[u8]) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::usb::descriptors::{DescriptorChain, DescriptorParser, JunkPolicy};
+
+    #[test]
+    fn test_descriptor_parser_byte_at_a_time() {
+        let sp_usb = crate::system_profiler::SPUSBDataType::example();
+        let mut blob: Vec<u8> = Vec::new();
+        for device in sp_usb.flatten_devices() {
+            for config in &device.extra.as_ref().unwrap().configurations {
+                for interface in &config.interfaces {
+                    for d in interface.extra.iter().flatten() {
+                        blob.extend(Vec::<u8>::from(d.to_owned()));
+                    }
+                    for endpoint in &interface.endpoints {
+                        for d in endpoint.extra.iter().flatten() {
+                            blob.extend(Vec::<u8>::from(d.to_owned()));
+                        }
+                    }
+                }
+            }
+        }
+        // junk byte mid chain and a truncated descriptor at the end
+        blob.insert(blob[0] as usize, 0x00);
+        blob.extend([0x09, 0x04, 0x00]);
+
+        let batch: Vec<_> = DescriptorChain::new(&blob, JunkPolicy::SkipOne)
+            .map(|r| match r {
+                Ok(d) => Descriptor::try_from(d),
+                Err(j) => Ok(Descriptor::Junk(j.to_vec())),
+            })
+            .collect();
+
+        let mut parser = DescriptorParser::new();
+        let mut streamed = Vec::new();
+        for b in &blob {
+            streamed.extend(parser.push_bytes(&[*b]));
+        }
+        streamed.extend(parser.finish());
+
+        assert!(batch.len() > 10);
+        assert_eq!(streamed, batch);
+    }
 
     #[test]
     fn test_version_to_string() {
@@ -1228,4 +2224,332 @@ mod tests {
         assert_eq!(Version::try_from(2.01).unwrap(), Version(2, 0, 1));
         assert_eq!(Version::try_from(2.31).unwrap(), Version(2, 1, 15));
     }
+
+    #[test]
+    fn test_power_state_from_str() {
+        assert_eq!(
+            "suspended\n".parse::<PowerState>().unwrap(),
+            PowerState::Suspended
+        );
+        assert_eq!("active".parse::<PowerState>().unwrap(), PowerState::Active);
+        assert!(PowerState::Suspending.is_suspended());
+        assert!(!PowerState::Unsupported.is_suspended());
+        assert_eq!(
+            "asleep".parse::<PowerState>().unwrap_err().kind(),
+            ErrorKind::Parsing
+        );
+    }
+
+    fn json_keys<T: Serialize>(value: &T) -> Vec<String> {
+        match serde_json::to_value(value).unwrap() {
+            serde_json::Value::Object(m) => m.keys().cloned().sorted().collect(),
+            v => panic!("Expected JSON object, got {}", v),
+        }
+    }
+
+    fn example_endpoint() -> USBEndpoint {
+        USBEndpoint {
+            length: 7,
+            address: EndpointAddress::from(0x81),
+            transfer_type: TransferType::Interrupt,
+            sync_type: SyncType::None,
+            usage_type: UsageType::Data,
+            max_packet_size: 8,
+            interval: 10,
+            extra: None,
+            refresh: None,
+            synch_address: None,
+        }
+    }
+
+    #[test]
+    fn test_endpoint_json_fields() {
+        let ep = example_endpoint();
+        assert_eq!(
+            json_keys(&ep),
+            vec![
+                "address",
+                "extra",
+                "interval",
+                "length",
+                "max_packet_size",
+                "sync_type",
+                "transfer_type",
+                "usage_type"
+            ]
+        );
+        assert_eq!(
+            json_keys(&ep.address),
+            vec!["address", "direction", "number"]
+        );
+    }
+
+    #[test]
+    fn test_audio_endpoint_refresh_synch_address() {
+        // 7 byte standard form has neither
+        let ep = example_endpoint();
+        assert_eq!(ep.refresh(), None);
+        assert_eq!(ep.synch_address(), None);
+
+        // 9 byte audio form: feedback endpoint reporting every 2^5 ms
+        let feedback = USBEndpoint {
+            length: 9,
+            address: EndpointAddress::from(0x83),
+            transfer_type: TransferType::Isochronous,
+            usage_type: UsageType::Feedback,
+            refresh: Some(5),
+            synch_address: Some(0),
+            ..example_endpoint()
+        };
+        assert_eq!(feedback.refresh(), Some(5));
+        assert_eq!(feedback.synch_address(), None);
+        assert!(json_keys(&feedback).contains(&String::from("refresh")));
+
+        let data = USBEndpoint {
+            length: 9,
+            address: EndpointAddress::from(0x03),
+            transfer_type: TransferType::Isochronous,
+            refresh: Some(0),
+            synch_address: Some(0x83),
+            ..example_endpoint()
+        };
+        assert_eq!(data.refresh(), None);
+        assert_eq!(data.synch_address(), Some(0x83));
+
+        let read: USBEndpoint =
+            serde_json::from_str(&serde_json::to_string(&feedback).unwrap()).unwrap();
+        assert_eq!(read.refresh, Some(5));
+        assert_eq!(read.synch_address, Some(0));
+    }
+
+    #[test]
+    fn test_interface_json_fields() {
+        let interface = USBInterface {
+            name: String::from("HID"),
+            string_index: 0,
+            number: 0,
+            path: String::from("1-1:1.0"),
+            class: ClassCode::HID,
+            sub_class: 1,
+            protocol: 1,
+            alt_setting: 0,
+            driver: None,
+            syspath: None,
+            endpoints: vec![example_endpoint()],
+            length: 9,
+            extra: None,
+            uac3_clusters: None,
+            uac3_connectors: None,
+            active_alt_setting: None,
+        };
+        assert_eq!(
+            json_keys(&interface),
+            vec![
+                "alt_setting",
+                "class",
+                "driver",
+                "endpoints",
+                "extra",
+                "length",
+                "name",
+                "number",
+                "path",
+                "protocol",
+                "string_index",
+                "sub_class",
+                "syspath"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_wire_adaptor_class_context() {
+        // Wire Adapter class descriptor with class type masked like the profiler
+        let bytes = [
+            0x0e, 0x04, 0x00, 0x01, 0x04, 0x00, 0x10, 0x00, 0x00, 0x01, 0x08, 0x32, 0x03, 0x00,
+        ];
+        let mut desc = Descriptor::try_from(bytes.as_slice()).unwrap();
+        desc.update_with_class_context((0xe0, 0x02, 0x01)).unwrap();
+
+        match desc {
+            Descriptor::Interface(ClassDescriptor::WireAdaptor(ref wad)) => {
+                assert_eq!(wad.wa_version, Version(1, 0, 0));
+                assert_eq!(wad.num_ports, 4);
+                assert_eq!(wad.num_mmc_ies, 3);
+            }
+            _ => panic!("Wire Adapter descriptor not parsed: {:?}", desc),
+        }
+        assert_eq!(Vec::<u8>::from(desc), bytes);
+
+        // Bluetooth is also a Wireless Controller but has no Wire Adapter descriptor
+        let mut desc = Descriptor::try_from(bytes.as_slice()).unwrap();
+        desc.update_with_class_context((0xe0, 0x01, 0x01)).unwrap();
+        assert!(matches!(
+            desc,
+            Descriptor::Interface(ClassDescriptor::Generic(..))
+        ));
+    }
+
+    #[test]
+    fn test_uac3_cluster_resolution() {
+        // AS_GENERAL referencing cluster 2 with class type masked like the profiler
+        let mut as_general = vec![0x17, 0x04, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00];
+        as_general.extend([0x01, 0, 0, 0, 0, 0, 0, 0, 0x02, 0x10, 0x00, 0x00, 0x00]);
+        let mut desc = Descriptor::try_from(as_general.as_slice()).unwrap();
+        desc.update_with_class_context((0x01, 0x02, 0x30)).unwrap();
+
+        let mut interface = USBInterface {
+            name: String::from("UAC3 Streaming"),
+            string_index: 0,
+            number: 1,
+            path: String::from("1-1:1.1"),
+            class: ClassCode::Audio,
+            sub_class: 2,
+            protocol: 0x30,
+            alt_setting: 1,
+            driver: None,
+            syspath: None,
+            endpoints: vec![],
+            length: 9,
+            extra: Some(vec![desc]),
+            uac3_clusters: None,
+            uac3_connectors: None,
+            active_alt_setting: None,
+        };
+        assert_eq!(interface.uac3_cluster_ids(), vec![2]);
+        assert!(interface.uac3_cluster(2).is_none());
+
+        let cluster = audio::ClusterDescriptor3::try_from(
+            [
+                0x10, 0x00, 0x26, 0x00, 0x02, 0x00, 0x01, 0x06, 0x00, 0x20, 0x00, 0x01, 0x00, 0x03,
+                0x00, 0xff,
+            ]
+            .as_slice(),
+        )
+        .unwrap();
+        interface.uac3_clusters = Some(vec![cluster]);
+        assert_eq!(
+            interface.uac3_cluster(2).map(|c| c.channel_names()),
+            Some(vec!["Mono"])
+        );
+    }
+
+    #[test]
+    fn test_uac2_feature_unit_controls() {
+        // stereo feature unit: master mute and volume host programmable, channels volume read-only
+        let data = [
+            0x12, 0x24, 0x06, 0x02, 0x01, 0x0f, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04,
+            0x00, 0x00, 0x00, 0x00,
+        ];
+        let mut desc = ClassDescriptor::try_from(data.as_slice()).unwrap();
+        desc.update_with_class_context((0x01, 0x01, 0x20)).unwrap();
+
+        match desc {
+            ClassDescriptor::Audio(
+                audio::UacDescriptor {
+                    interface: audio::UacInterfaceDescriptor::FeatureUnit2(ref unit),
+                    ..
+                },
+                audio::UacProtocol::Uac2,
+            ) => {
+                assert_eq!(unit.unit_id, 2);
+                assert_eq!(unit.source_id, 1);
+                assert_eq!(unit.controls, vec![0x0f, 0x04, 0x04]);
+                assert_eq!(unit.feature_index, 0);
+                assert_eq!(
+                    audio::ControlSetting::from(((unit.controls[1] >> 2) & 0x3) as u8),
+                    audio::ControlSetting::ReadOnly
+                );
+                assert_eq!(Vec::<u8>::from(unit.to_owned()), data[3..].to_vec());
+            }
+            _ => panic!("expected UAC2 feature unit, got {:?}", desc),
+        }
+        // UAC2 numbers the subtype differently to UAC3
+        assert_eq!(Vec::<u8>::from(desc), data.to_vec());
+    }
+
+    #[test]
+    fn test_audio_format_type() {
+        // UAC2 FORMAT_TYPE_I, 2 byte subslot, 16 bit resolution
+        let mut desc =
+            ClassDescriptor::try_from([0x06, 0x24, 0x02, 0x01, 0x02, 0x10].as_slice()).unwrap();
+        desc.update_with_class_context((0x01, 0x02, 0x20)).unwrap();
+
+        let interface = USBInterface {
+            name: String::from("UAC2 Streaming"),
+            string_index: 0,
+            number: 1,
+            path: String::from("1-1:1.1"),
+            class: ClassCode::Audio,
+            sub_class: 2,
+            protocol: 0x20,
+            alt_setting: 1,
+            driver: None,
+            syspath: None,
+            endpoints: vec![],
+            length: 9,
+            extra: Some(vec![Descriptor::Interface(desc)]),
+            uac3_clusters: None,
+            uac3_connectors: None,
+            active_alt_setting: None,
+        };
+        let format_type = interface.audio_format_type().unwrap();
+        assert_eq!(format_type, audio::StreamingFormatType::TypeI);
+        assert_eq!(format!("{:#}", format_type), "FORMAT_TYPE_I");
+    }
+
+    #[test]
+    fn test_bos_unknown_capability_retained() {
+        use crate::usb::descriptors::bos;
+        // BOS with a USB 2.0 Extension and a capability type not in the specification
+        let bytes = [
+            0x05, 0x0f, 0x11, 0x00, 0x02, 0x07, 0x10, 0x02, 0x06, 0x00, 0x00, 0x00, 0x05, 0x10,
+            0x7f, 0xaa, 0x55,
+        ];
+        let bosd = bos::BinaryObjectStoreDescriptor::try_from(bytes.as_slice()).unwrap();
+        assert_eq!(bosd.capabilities.len(), 2);
+        assert_eq!(
+            bosd.capabilities[1],
+            bos::BosCapability::Unknown(0x7f, vec![0xaa, 0x55])
+        );
+        assert_eq!(Vec::<u8>::from(bosd.capabilities[1].clone()), bytes[12..]);
+    }
+
+    #[test]
+    fn test_raw_bytes_json_hex() {
+        use crate::usb::descriptors::video;
+        let desc = video::UvcInterfaceDescriptor::Generic(vec![0x0d, 0x24, 0x10, 0xab]);
+        let json = serde_json::to_string(&desc).unwrap();
+        assert_eq!(json, r#"{"Generic":"0d2410ab"}"#);
+        let read: video::UvcInterfaceDescriptor = serde_json::from_str(&json).unwrap();
+        assert_eq!(read, desc);
+
+        let bad = serde_json::from_str::<video::UvcInterfaceDescriptor>(r#"{"Generic":"0d2"}"#);
+        assert!(bad.is_err());
+    }
+
+    #[test]
+    fn test_omit_raw_bytes_restored_after_panic() {
+        use crate::usb::descriptors::raw_bytes::OmitRawBytes;
+
+        struct Panics;
+        impl Serialize for Panics {
+            fn serialize<S: serde::Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+                panic!("serialize failed")
+            }
+        }
+
+        let desc = Descriptor::Unknown(vec![0x03, 0xff, 0x01]);
+        assert_eq!(
+            serde_json::to_string(&OmitRawBytes(&desc)).unwrap(),
+            r#"{"unknown":null}"#
+        );
+        assert!(
+            std::panic::catch_unwind(|| serde_json::to_string(&OmitRawBytes(&Panics))).is_err()
+        );
+        assert_eq!(
+            serde_json::to_string(&desc).unwrap(),
+            r#"{"unknown":"03ff01"}"#
+        );
+    }
 }
@@ -8,6 +8,7 @@ use crate::error::{self, Error, ErrorKind};
 pub mod audio;
 pub mod bos;
 pub mod cdc;
+pub mod raw_bytes;
 pub mod video;
 
 /// USB descritor types
@@ -111,6 +112,48 @@ impl From<DescriptorType> for u8 {
     }
 }
 
+/// Short name of the descriptor type for diagnostics, like "Interface Association"
+///
+/// ```
+/// use cyme::usb::descriptors::DescriptorType;
+/// assert_eq!(DescriptorType::InterfaceAssociation.to_string(), "Interface Association");
+/// assert_eq!(DescriptorType::SsEndpointCompanion.to_string(), "SS Endpoint Companion");
+/// assert_eq!(DescriptorType::Unknown(0x42).to_string(), "Unknown (0x42)");
+/// ```
+impl fmt::Display for DescriptorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            DescriptorType::Device => "Device",
+            DescriptorType::Config => "Configuration",
+            DescriptorType::String => "String",
+            DescriptorType::Interface => "Interface",
+            DescriptorType::Endpoint => "Endpoint",
+            DescriptorType::DeviceQualifier => "Device Qualifier",
+            DescriptorType::OtherSpeedConfiguration => "Other Speed Configuration",
+            DescriptorType::InterfacePower => "Interface Power",
+            DescriptorType::Otg => "OTG",
+            DescriptorType::Debug => "Debug",
+            DescriptorType::InterfaceAssociation => "Interface Association",
+            DescriptorType::Security => "Security",
+            DescriptorType::Key => "Key",
+            DescriptorType::Encrypted => "Encryption Type",
+            DescriptorType::Bos => "BOS",
+            DescriptorType::DeviceCapability => "Device Capability",
+            DescriptorType::WirelessEndpointCompanion => "Wireless Endpoint Companion",
+            DescriptorType::WireAdaptor => "Wire Adaptor",
+            DescriptorType::Report => "Report",
+            DescriptorType::Physical => "Physical",
+            DescriptorType::Pipe => "Pipe",
+            DescriptorType::Hub => "Hub",
+            DescriptorType::SuperSpeedHub => "SuperSpeed Hub",
+            DescriptorType::SsEndpointCompanion => "SS Endpoint Companion",
+            DescriptorType::SsIsocEndpointCompanion => "SS Isoc Endpoint Companion",
+            DescriptorType::Unknown(b) => return write!(f, "Unknown (0x{:02x})", b),
+        };
+        write!(f, "{}", name)
+    }
+}
+
 /// USB descriptor encloses type specific descriptor structs
 ///
 /// Not all descriptors are implemented
@@ -121,12 +164,14 @@ impl From<DescriptorType> for u8 {
 pub enum Descriptor {
     Device(ClassDescriptor),
     Config(ClassDescriptor),
-    String(String),
+    String(StringDescriptor),
     Interface(ClassDescriptor),
     Endpoint(ClassDescriptor),
+    StandardEndpoint(EndpointDescriptor),
     DeviceQualifier(DeviceQualifierDescriptor),
     Otg(OnTheGoDescriptor),
     Debug(DebugDescriptor),
+    InterfacePower(InterfacePowerDescriptor),
     InterfaceAssociation(InterfaceAssociationDescriptor),
     Security(SecurityDescriptor),
     Encrypted(EncryptionDescriptor),
@@ -135,9 +180,43 @@ pub enum Descriptor {
     Hub(HubDescriptor),
     SuperSpeedHub(HubDescriptor),
     SsEndpointCompanion(SsEndpointCompanionDescriptor),
+    SsIsocEndpointCompanion(SsIsocEndpointCompanionDescriptor),
+    WirelessEndpointCompanion(WirelessEndpointCompanionDescriptor),
     // these are internal
-    Unknown(Vec<u8>),
-    Junk(Vec<u8>),
+    Unknown(#[serde(with = "raw_bytes")] Vec<u8>),
+    Junk(#[serde(with = "raw_bytes")] Vec<u8>),
+}
+
+/// Class specific descriptor type 0x21 shared by HID, DFU, CCID and others, or the device and interface types the profiler masks it to
+pub(crate) const CLASS_DESCRIPTOR_TYPES: &[u8] = &[0x21, 0x01, 0x04];
+
+/// Checks bDescriptorType is one of `expected` so that passing the wrong descriptor to a parser fails rather than producing garbage
+///
+/// ```
+/// use cyme::usb::descriptors::HidDescriptor;
+///
+/// // endpoint descriptor
+/// let err = HidDescriptor::try_from([0x07, 0x05, 0x81, 0x03, 0x08, 0x00, 0x0a].as_slice()).unwrap_err();
+/// assert_eq!(err.kind(), cyme::error::ErrorKind::InvalidArg);
+/// assert!(err.to_string().contains("expected descriptor type 0x21, 0x01 or 0x04 but got 0x05"));
+/// ```
+pub(crate) fn check_descriptor_type(name: &str, actual: u8, expected: &[u8]) -> error::Result<()> {
+    if expected.contains(&actual) {
+        Ok(())
+    } else {
+        let expected: Vec<String> = expected.iter().map(|e| format!("{:#04x}", e)).collect();
+        let expected = match expected.split_last() {
+            Some((last, rest)) if !rest.is_empty() => format!("{} or {}", rest.join(", "), last),
+            _ => expected.join(""),
+        };
+        Err(Error::new(
+            ErrorKind::InvalidArg,
+            &format!(
+                "{} expected descriptor type {} but got {:#04x}",
+                name, expected, actual
+            ),
+        ))
+    }
 }
 
 impl Descriptor {
@@ -148,10 +227,11 @@ impl Descriptor {
             Descriptor::Config(_) => DescriptorType::Config,
             Descriptor::String(_) => DescriptorType::String,
             Descriptor::Interface(_) => DescriptorType::Interface,
-            Descriptor::Endpoint(_) => DescriptorType::Endpoint,
+            Descriptor::Endpoint(_) | Descriptor::StandardEndpoint(_) => DescriptorType::Endpoint,
             Descriptor::DeviceQualifier(_) => DescriptorType::DeviceQualifier,
             Descriptor::Otg(_) => DescriptorType::Otg,
             Descriptor::Debug(_) => DescriptorType::Debug,
+            Descriptor::InterfacePower(_) => DescriptorType::InterfacePower,
             Descriptor::InterfaceAssociation(_) => DescriptorType::InterfaceAssociation,
             Descriptor::Security(_) => DescriptorType::Security,
             Descriptor::Encrypted(_) => DescriptorType::Encrypted,
@@ -160,10 +240,44 @@ impl Descriptor {
             Descriptor::Hub(_) => DescriptorType::Hub,
             Descriptor::SuperSpeedHub(_) => DescriptorType::SuperSpeedHub,
             Descriptor::SsEndpointCompanion(_) => DescriptorType::SsEndpointCompanion,
+            Descriptor::SsIsocEndpointCompanion(_) => DescriptorType::SsIsocEndpointCompanion,
+            Descriptor::WirelessEndpointCompanion(_) => DescriptorType::WirelessEndpointCompanion,
             Descriptor::Unknown(d) => DescriptorType::Unknown(d.get(1).copied().unwrap_or(0)),
             Descriptor::Junk(d) => DescriptorType::Unknown(d.get(1).copied().unwrap_or(0)),
         }
     }
+
+    /// Returns the bDescriptorType value of the descriptor; the field of the enclosed struct where it has one, otherwise the value of [`Descriptor::descriptor_type`]
+    ///
+    /// Class specific descriptors are stored with the type masked to the descriptor they extend, so CS_INTERFACE 0x24 is 0x04.
+    ///
+    /// ```
+    /// use cyme::usb::descriptors::{Descriptor, DescriptorType};
+    ///
+    /// let iad = Descriptor::try_from([0x08, 0x0b, 0x00, 0x02, 0x0e, 0x03, 0x00, 0x05].as_slice()).unwrap();
+    /// assert_eq!(iad.type_code(), 0x0b);
+    /// assert_eq!(iad.descriptor_type(), DescriptorType::InterfaceAssociation);
+    /// ```
+    pub fn type_code(&self) -> u8 {
+        match self {
+            Descriptor::DeviceQualifier(d) => d.descriptor_type,
+            Descriptor::Otg(d) => d.descriptor_type,
+            Descriptor::Debug(d) => d.descriptor_type,
+            Descriptor::InterfacePower(d) => d.descriptor_type,
+            Descriptor::InterfaceAssociation(d) => d.descriptor_type,
+            Descriptor::Security(d) => d.descriptor_type,
+            Descriptor::Encrypted(d) => d.descriptor_type,
+            Descriptor::Bos(d) => d.descriptor_type,
+            Descriptor::Report(d) => d.descriptor_type,
+            Descriptor::Hub(d) | Descriptor::SuperSpeedHub(d) => d.descriptor_type,
+            Descriptor::StandardEndpoint(d) => d.descriptor_type,
+            Descriptor::String(d) => d.descriptor_type,
+            Descriptor::SsEndpointCompanion(d) => d.descriptor_type,
+            Descriptor::SsIsocEndpointCompanion(d) => d.descriptor_type,
+            Descriptor::WirelessEndpointCompanion(d) => d.descriptor_type,
+            _ => self.descriptor_type().into(),
+        }
+    }
 }
 
 impl TryFrom<&[u8]> for Descriptor {
@@ -182,16 +296,21 @@ impl TryFrom<&[u8]> for Descriptor {
         match v[1].into() {
             DescriptorType::Device => Ok(Descriptor::Device(ClassDescriptor::try_from(v)?)),
             DescriptorType::Config => Ok(Descriptor::Config(ClassDescriptor::try_from(v)?)),
-            DescriptorType::String => {
-                Ok(Descriptor::String(String::from_utf8_lossy(v).to_string()))
-            }
+            DescriptorType::String => Ok(Descriptor::String(StringDescriptor::try_from(v)?)),
             DescriptorType::Interface => Ok(Descriptor::Interface(ClassDescriptor::try_from(v)?)),
+            // standard endpoint is 7 bytes or 9 with the audio bRefresh and bSynchAddress; anything else is class specific
+            DescriptorType::Endpoint if v[1] == 0x05 && (v.len() == 7 || v.len() == 9) => Ok(
+                Descriptor::StandardEndpoint(EndpointDescriptor::try_from(v)?),
+            ),
             DescriptorType::Endpoint => Ok(Descriptor::Endpoint(ClassDescriptor::try_from(v)?)),
             DescriptorType::DeviceQualifier => Ok(Descriptor::DeviceQualifier(
                 DeviceQualifierDescriptor::try_from(v)?,
             )),
             DescriptorType::Otg => Ok(Descriptor::Otg(OnTheGoDescriptor::try_from(v)?)),
             DescriptorType::Debug => Ok(Descriptor::Debug(DebugDescriptor::try_from(v)?)),
+            DescriptorType::InterfacePower => Ok(Descriptor::InterfacePower(
+                InterfacePowerDescriptor::try_from(v)?,
+            )),
             DescriptorType::InterfaceAssociation => Ok(Descriptor::InterfaceAssociation(
                 InterfaceAssociationDescriptor::try_from(v)?,
             )),
@@ -210,6 +329,12 @@ impl TryFrom<&[u8]> for Descriptor {
             DescriptorType::SsEndpointCompanion => Ok(Descriptor::SsEndpointCompanion(
                 SsEndpointCompanionDescriptor::try_from(v)?,
             )),
+            DescriptorType::SsIsocEndpointCompanion => Ok(Descriptor::SsIsocEndpointCompanion(
+                SsIsocEndpointCompanionDescriptor::try_from(v)?,
+            )),
+            DescriptorType::WirelessEndpointCompanion => Ok(Descriptor::WirelessEndpointCompanion(
+                WirelessEndpointCompanionDescriptor::try_from(v)?,
+            )),
             _ => Ok(Descriptor::Unknown(v.to_vec())),
         }
     }
@@ -220,11 +345,13 @@ impl From<Descriptor> for Vec<u8> {
         match dt {
             Descriptor::Device(d) => d.into(),
             Descriptor::Config(c) => c.into(),
-            Descriptor::String(s) => s.into_bytes(),
+            Descriptor::String(s) => s.into(),
             Descriptor::Interface(i) => i.into(),
             Descriptor::Endpoint(e) => e.into(),
+            Descriptor::StandardEndpoint(e) => e.into(),
             Descriptor::DeviceQualifier(dq) => dq.into(),
             Descriptor::Debug(d) => d.into(),
+            Descriptor::InterfacePower(ip) => ip.into(),
             Descriptor::InterfaceAssociation(ia) => ia.into(),
             Descriptor::Security(s) => s.into(),
             Descriptor::Encrypted(e) => e.into(),
@@ -234,13 +361,271 @@ impl From<Descriptor> for Vec<u8> {
             Descriptor::Otg(o) => o.into(),
             Descriptor::SuperSpeedHub(h) => h.into(),
             Descriptor::SsEndpointCompanion(s) => s.into(),
+            Descriptor::SsIsocEndpointCompanion(s) => s.into(),
+            Descriptor::WirelessEndpointCompanion(w) => w.into(),
             Descriptor::Unknown(u) => u,
             Descriptor::Junk(j) => j,
         }
     }
 }
 
+/// How a [`DescriptorChain`] handles bytes that cannot be a descriptor, such as a bLength of 0 or one that overruns the buffer
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum JunkPolicy {
+    /// Return the rest of the chain as junk and stop
+    #[default]
+    StopOnJunk,
+    /// Return the single bad byte as junk and try the next byte as a bLength
+    SkipOne,
+    /// Scan forward to the next plausible bLength/bDescriptorType pair, returning the bytes skipped as junk
+    SkipToNextValid,
+}
+
+/// Iterator over a chain of descriptors such as the extra bytes following an interface
+///
+/// Yields `Ok` with the bytes of each descriptor and `Err` with junk bytes that could not be a descriptor, handled according to the [`JunkPolicy`].
+///
+/// ```
+/// use cyme::usb::descriptors::{DescriptorChain, JunkPolicy};
+///
+/// let bytes = [0x03, 0x24, 0x01, 0x00, 0x04, 0x24, 0x02, 0x01];
+/// let stop: Vec<_> = DescriptorChain::new(&bytes, JunkPolicy::StopOnJunk).collect();
+/// assert_eq!(stop, vec![Ok(&bytes[..3]), Err(&bytes[3..])]);
+/// let skip: Vec<_> = DescriptorChain::new(&bytes, JunkPolicy::SkipToNextValid).collect();
+/// assert_eq!(skip, vec![Ok(&bytes[..3]), Err(&bytes[3..4]), Ok(&bytes[4..])]);
+/// // a bLength overrunning the buffer
+/// let bytes = [0x03, 0x24, 0x01, 0x08, 0x24, 0x03, 0x24, 0x02];
+/// let skip: Vec<_> = DescriptorChain::new(&bytes, JunkPolicy::SkipOne).collect();
+/// assert_eq!(skip, vec![Ok(&bytes[..3]), Err(&bytes[3..4]), Err(&bytes[4..5]), Ok(&bytes[5..])]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DescriptorChain<'a> {
+    remaining: &'a [u8],
+    policy: JunkPolicy,
+}
+
+impl<'a> DescriptorChain<'a> {
+    /// Walk `bytes` as a chain of descriptors using `policy` for junk
+    pub fn new(bytes: &'a [u8], policy: JunkPolicy) -> Self {
+        DescriptorChain {
+            remaining: bytes,
+            policy,
+        }
+    }
+
+    /// Whether a descriptor could start at the beginning of `bytes`: a bLength that fits and a known bDescriptorType
+    fn is_plausible(bytes: &[u8]) -> bool {
+        match bytes {
+            [len, dt, ..] => {
+                *len >= 2
+                    && *len as usize <= bytes.len()
+                    && !matches!(DescriptorType::from(*dt), DescriptorType::Unknown(_))
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<'a> Iterator for DescriptorChain<'a> {
+    type Item = Result<&'a [u8], &'a [u8]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        let len = self.remaining[0] as usize;
+        if len >= 2 && len <= self.remaining.len() {
+            let (desc, rest) = self.remaining.split_at(len);
+            self.remaining = rest;
+            return Some(Ok(desc));
+        }
+
+        let junk_len = match self.policy {
+            JunkPolicy::StopOnJunk => self.remaining.len(),
+            JunkPolicy::SkipOne => 1,
+            JunkPolicy::SkipToNextValid => (1..self.remaining.len())
+                .find(|&i| Self::is_plausible(&self.remaining[i..]))
+                .unwrap_or(self.remaining.len()),
+        };
+        let (junk, rest) = self.remaining.split_at(junk_len);
+        self.remaining = rest;
+        Some(Err(junk))
+    }
+}
+
+/// Incremental parser for backends which deliver a descriptor chain in pieces
+///
+/// Bytes are buffered until a whole descriptor has arrived so the caller does not have to assemble the full configuration blob first. A bLength of 0 or 1 is returned as a single byte of [`Descriptor::Junk`] like [`JunkPolicy::SkipOne`], so pushing a chain in any number of pieces gives the same descriptors as parsing it in one go.
+///
+/// ```
+/// use cyme::usb::descriptors::{Descriptor, DescriptorParser};
+///
+/// let mut parser = DescriptorParser::new();
+/// // IAD split across two pushes
+/// assert!(parser.push_bytes(&[0x08, 0x0b, 0x00, 0x02]).is_empty());
+/// assert_eq!(parser.pending(), &[0x08, 0x0b, 0x00, 0x02]);
+/// let descriptors = parser.push_bytes(&[0x0e, 0x03, 0x00, 0x00, 0x09]);
+/// assert!(matches!(descriptors[..], [Ok(Descriptor::InterfaceAssociation(_))]));
+/// // trailing partial descriptor is junk when finished
+/// assert_eq!(parser.pending(), &[0x09]);
+/// assert_eq!(parser.finish(), vec![Ok(Descriptor::Junk(vec![0x09]))]);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct DescriptorParser {
+    buffer: Vec<u8>,
+}
+
+impl DescriptorParser {
+    /// New parser with nothing buffered
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add `bytes` to the buffer and return the descriptors which are now complete, in order
+    ///
+    /// Each descriptor is converted with [`Descriptor::try_from`] so a descriptor which fails to parse is returned as an `Err` without stopping the rest.
+    pub fn push_bytes(&mut self, bytes: &[u8]) -> Vec<error::Result<Descriptor>> {
+        self.buffer.extend_from_slice(bytes);
+
+        let mut ret = Vec::new();
+        let mut start = 0;
+        while let Some(&len) = self.buffer.get(start) {
+            let len = len as usize;
+            if len < 2 {
+                ret.push(Ok(Descriptor::Junk(vec![self.buffer[start]])));
+                start += 1;
+            } else if start + len <= self.buffer.len() {
+                ret.push(Descriptor::try_from(&self.buffer[start..start + len]));
+                start += len;
+            } else {
+                break;
+            }
+        }
+        self.buffer.drain(..start);
+
+        ret
+    }
+
+    /// Bytes buffered waiting for the rest of a descriptor
+    pub fn pending(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// End of the chain; any partial descriptor still buffered can never complete so it is parsed as [`DescriptorChain`] with [`JunkPolicy::SkipOne`] would
+    pub fn finish(self) -> Vec<error::Result<Descriptor>> {
+        DescriptorChain::new(&self.buffer, JunkPolicy::SkipOne)
+            .map(|r| match r {
+                Ok(d) => Descriptor::try_from(d),
+                Err(j) => Ok(Descriptor::Junk(j.to_vec())),
+            })
+            .collect()
+    }
+}
+
+/// Format the 16 bytes of a GUID as found in a descriptor in the canonical braced `{8-4-4-4-12}` form
+///
+/// Descriptors store GUIDs in the Microsoft mixed-endian layout, so the first three groups are little-endian and the last two are in byte order. Used for Container ID, Platform capability and UVC GUIDs.
+///
+/// ```
+/// use cyme::usb::descriptors::format_guid;
+///
+/// // WebUSB platform capability
+/// let webusb = [
+///     0x38, 0xb6, 0x08, 0x34, 0xa9, 0x09, 0xa0, 0x47, 0x8b, 0xfd, 0xa0, 0x76, 0x88, 0x15, 0xb6, 0x65,
+/// ];
+/// assert_eq!(format_guid(&webusb), "{3408b638-09a9-47a0-8bfd-a0768815b665}");
+/// // Microsoft OS 2.0 descriptors platform capability
+/// let ms_os_20 = [
+///     0xdf, 0x60, 0xdd, 0xd8, 0x89, 0x45, 0xc7, 0x4c, 0x9c, 0xd2, 0x65, 0x9d, 0x9e, 0x64, 0x8a, 0x9f,
+/// ];
+/// assert_eq!(format_guid(&ms_os_20), "{d8dd60df-4589-4cc7-9cd2-659d9e648a9f}");
+/// // UVC YUY2 format
+/// let yuy2 = [
+///     0x59, 0x55, 0x59, 0x32, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xaa, 0x00, 0x38, 0x9b, 0x71,
+/// ];
+/// assert_eq!(format_guid(&yuy2), "{32595559-0000-0010-8000-00aa00389b71}");
+/// ```
+pub fn format_guid(bytes: &[u8; 16]) -> String {
+    format!(
+        "{{{:02x}{:02x}{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}}}",
+        bytes[3], bytes[2], bytes[1], bytes[0],
+        bytes[5], bytes[4],
+        bytes[7], bytes[6],
+        bytes[8], bytes[9],
+        bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15]
+    )
+}
+
+/// Read a JSON fixture of [`Descriptor`]s, with fields as they are serialized, and convert to the raw bytes a device would return
+///
+/// The fixture can be a single descriptor or an array of descriptors, which are concatenated in order. Useful for crafting edge-case descriptors without hand-writing byte arrays.
+///
+/// ```
+/// use cyme::usb::descriptors::fixture_to_bytes;
+///
+/// let bytes = fixture_to_bytes("tests/data/descriptor_fixture.json").unwrap();
+/// assert_eq!(
+///     bytes,
+///     vec![0x09, 0x21, 0x0b, 0xff, 0x00, 0x00, 0x04, 0x1a, 0x01, 0x03, 0xff, 0x01]
+/// );
+/// ```
+pub fn fixture_to_bytes<P: AsRef<std::path::Path>>(path: P) -> error::Result<Vec<u8>> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Fixture {
+        Single(Descriptor),
+        Many(Vec<Descriptor>),
+    }
+
+    let data = std::fs::read_to_string(path.as_ref())?;
+    let fixture: Fixture = serde_json::from_str(&data).map_err(|e| {
+        Error::new(
+            ErrorKind::Parsing,
+            &format!(
+                "Failed to parse descriptor fixture at {:?}; Error({})",
+                path.as_ref(),
+                e
+            ),
+        )
+        .with_source(e)
+    })?;
+
+    Ok(match fixture {
+        Fixture::Single(d) => d.into(),
+        Fixture::Many(ds) => ds.into_iter().flat_map(Vec::<u8>::from).collect(),
+    })
+}
+
+/// Looks up string descriptor `index` with `lookup`; index 0 means no string so is never looked up
+pub(crate) fn lookup_string<F: FnMut(u8) -> Option<String>>(
+    index: u8,
+    lookup: &mut F,
+) -> Option<String> {
+    match index {
+        0 => None,
+        i => lookup(i),
+    }
+}
+
 impl Descriptor {
+    /// Fills the string fields of the descriptor from their string descriptor indexes using `lookup`
+    ///
+    /// `lookup` is given the string index and should return the string from the device's string table. Descriptors without string indexes are left untouched.
+    pub fn resolve_strings<F: FnMut(u8) -> Option<String>>(&mut self, lookup: &mut F) {
+        match self {
+            Descriptor::InterfaceAssociation(iad) => {
+                iad.function_string = lookup_string(iad.function_string_index, lookup);
+            }
+            Descriptor::Device(c)
+            | Descriptor::Config(c)
+            | Descriptor::Interface(c)
+            | Descriptor::Endpoint(c) => c.resolve_strings(lookup),
+            _ => (),
+        }
+    }
+
     /// Uses [`ClassCodeTriplet`] to update the [`ClassDescriptor`] with [`ClassCode`] for class specific descriptors
     pub fn update_with_class_context<T: Into<ClassCode> + Copy>(
         &mut self,
@@ -251,6 +636,14 @@ impl Descriptor {
             Descriptor::Config(c) => c.update_with_class_context(class_triplet),
             Descriptor::Interface(i) => i.update_with_class_context(class_triplet),
             Descriptor::Endpoint(e) => e.update_with_class_context(class_triplet),
+            // class context means this follows an endpoint as a class specific descriptor masked to 0x05, which can have the same length as a standard one
+            Descriptor::StandardEndpoint(e) => {
+                let bytes: Vec<u8> = e.to_owned().into();
+                let mut c = ClassDescriptor::try_from(bytes.as_slice())?;
+                c.update_with_class_context(class_triplet)?;
+                *self = Descriptor::Endpoint(c);
+                Ok(())
+            }
             _ => Ok(()),
         }
     }
@@ -275,7 +668,7 @@ pub enum DeviceCapability {
 
 /// Extra USB device data for unknown descriptors
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
-pub struct DescriptorData(pub Vec<u8>);
+pub struct DescriptorData(#[serde(with = "raw_bytes")] pub Vec<u8>);
 
 /// The Interface Association Descriptor is a specific type of USB descriptor used to associate a group of interfaces with a particular function or feature of a USB device
 ///
@@ -306,6 +699,8 @@ impl TryFrom<&[u8]> for InterfaceAssociationDescriptor {
             ));
         }
 
+        check_descriptor_type("InterfaceAssociationDescriptor", value[1], &[0x0b])?;
+
         Ok(InterfaceAssociationDescriptor {
             length: value[0],
             descriptor_type: value[1],
@@ -320,6 +715,31 @@ impl TryFrom<&[u8]> for InterfaceAssociationDescriptor {
     }
 }
 
+impl InterfaceAssociationDescriptor {
+    /// Whether interface `number` is one of the interfaces grouped into the function
+    ///
+    /// ```
+    /// use cyme::usb::descriptors::InterfaceAssociationDescriptor;
+    ///
+    /// let iad = InterfaceAssociationDescriptor::try_from([0x08, 0x0b, 0x03, 0x03, 0x01, 0x00, 0x00, 0x00].as_slice()).unwrap();
+    /// assert!(!iad.contains(2));
+    /// assert!(iad.contains(3));
+    /// assert!(iad.contains(5));
+    /// assert!(!iad.contains(6));
+    /// assert_eq!(iad.last_interface(), 5);
+    /// ```
+    pub fn contains(&self, number: u8) -> bool {
+        (self.first_interface as u16..self.first_interface as u16 + self.interface_count as u16)
+            .contains(&(number as u16))
+    }
+
+    /// Number of the last interface grouped into the function
+    pub fn last_interface(&self) -> u8 {
+        self.first_interface
+            .saturating_add(self.interface_count.saturating_sub(1))
+    }
+}
+
 impl From<InterfaceAssociationDescriptor> for Vec<u8> {
     fn from(iad: InterfaceAssociationDescriptor) -> Self {
         vec![
@@ -335,7 +755,332 @@ impl From<InterfaceAssociationDescriptor> for Vec<u8> {
     }
 }
 
+/// USB standard Endpoint descriptor
+///
+/// wMaxPacketSize and bmAttributes are kept raw as in the descriptor; the accessors decode them. Audio class endpoints append bRefresh and bSynchAddress.
+///
+/// ```
+/// # use cyme::usb::descriptors::*;
+/// # use cyme::usb::{Direction, TransferType};
+/// // high-bandwidth isochronous IN endpoint 1: 3 x 1024 byte transactions per microframe
+/// let d = Descriptor::try_from([0x07, 0x05, 0x81, 0x05, 0x00, 0x14, 0x01].as_slice()).unwrap();
+/// match d {
+///     Descriptor::StandardEndpoint(ref e) => {
+///         assert_eq!(e.number(), 1);
+///         assert_eq!(e.direction(), Direction::In);
+///         assert_eq!(e.transfer_type(), TransferType::Isochronous);
+///         assert_eq!(e.max_packet_size(), 1024);
+///         assert_eq!(e.packets_per_microframe(), 3);
+///     }
+///     _ => panic!("not parsed as Endpoint"),
+/// }
+/// assert_eq!(Vec::<u8>::from(d), vec![0x07, 0x05, 0x81, 0x05, 0x00, 0x14, 0x01]);
+/// ```
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct EndpointDescriptor {
+    pub length: u8,
+    pub descriptor_type: u8,
+    pub endpoint_address: u8,
+    pub attributes: u8,
+    /// wMaxPacketSize including the additional transactions per microframe in bits 11..12
+    pub max_packet_size: u16,
+    pub interval: u8,
+    /// bRefresh of audio class endpoints
+    pub refresh: Option<u8>,
+    /// bSynchAddress of audio class endpoints
+    pub synch_address: Option<u8>,
+}
+
+impl TryFrom<&[u8]> for EndpointDescriptor {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> error::Result<Self> {
+        if value.len() < 7 {
+            return Err(Error::new_descriptor_len(
+                "EndpointDescriptor",
+                7,
+                value.len(),
+            ));
+        }
+
+        check_descriptor_type("EndpointDescriptor", value[1], &[0x05])?;
+
+        Ok(EndpointDescriptor {
+            length: value[0],
+            descriptor_type: value[1],
+            endpoint_address: value[2],
+            attributes: value[3],
+            max_packet_size: u16::from_le_bytes([value[4], value[5]]),
+            interval: value[6],
+            refresh: value.get(7).copied(),
+            synch_address: value.get(8).copied(),
+        })
+    }
+}
+
+impl From<EndpointDescriptor> for Vec<u8> {
+    fn from(ed: EndpointDescriptor) -> Self {
+        let mut ret = vec![
+            ed.length,
+            ed.descriptor_type,
+            ed.endpoint_address,
+            ed.attributes,
+        ];
+        ret.extend_from_slice(&ed.max_packet_size.to_le_bytes());
+        ret.push(ed.interval);
+        ret.extend(ed.refresh);
+        ret.extend(ed.synch_address);
+        ret
+    }
+}
+
+impl EndpointDescriptor {
+    /// Decoded bEndpointAddress
+    pub fn address(&self) -> EndpointAddress {
+        EndpointAddress::from(self.endpoint_address)
+    }
+
+    /// Endpoint number from bits 0..3 of bEndpointAddress
+    pub fn number(&self) -> u8 {
+        self.endpoint_address & 0x0f
+    }
+
+    /// Data direction from bit 7 of bEndpointAddress
+    pub fn direction(&self) -> Direction {
+        self.address().direction
+    }
+
+    /// Raw bits 0..1 of bmAttributes: 0 control, 1 isochronous, 2 bulk, 3 interrupt
+    pub fn transfer_type_bits(&self) -> u8 {
+        self.attributes & 0x03
+    }
+
+    /// Raw bits 2..3 of bmAttributes: 0 none, 1 asynchronous, 2 adaptive, 3 synchronous
+    pub fn sync_type_bits(&self) -> u8 {
+        (self.attributes >> 2) & 0x03
+    }
+
+    /// Raw bits 4..5 of bmAttributes: 0 data, 1 feedback, 2 implicit feedback data, 3 reserved
+    pub fn usage_type_bits(&self) -> u8 {
+        (self.attributes >> 4) & 0x03
+    }
+
+    /// Transfer type from bits 0..1 of bmAttributes
+    pub fn transfer_type(&self) -> TransferType {
+        TransferType::from(self.attributes)
+    }
+
+    /// Synchronisation type from bits 2..3 of bmAttributes; only meaningful for isochronous endpoints
+    pub fn sync_type(&self) -> SyncType {
+        SyncType::from(self.attributes)
+    }
+
+    /// Usage type from bits 4..5 of bmAttributes; only meaningful for isochronous endpoints
+    pub fn usage_type(&self) -> UsageType {
+        UsageType::from(self.attributes)
+    }
+
+    /// Maximum packet size in bytes of a single transaction from bits 0..10 of wMaxPacketSize
+    pub fn max_packet_size(&self) -> u16 {
+        self.max_packet_size & 0x7ff
+    }
+
+    /// Transactions per microframe from bits 11..12 of wMaxPacketSize; more than one only for high-bandwidth high-speed periodic endpoints
+    pub fn packets_per_microframe(&self) -> u8 {
+        ((self.max_packet_size >> 11) & 0x03) as u8 + 1
+    }
+
+    /// Polling interval in milliseconds of an interrupt or isochronous endpoint when the device operates at `speed`
+    ///
+    /// See [`TransferType::service_interval_us`]. 0 for bulk and control endpoints, where bInterval is at most a NAK rate.
+    ///
+    /// ```
+    /// # use cyme::usb::descriptors::EndpointDescriptor;
+    /// # use cyme::usb::Speed;
+    /// // HID interrupt IN endpoint with bInterval 10
+    /// let mut e = EndpointDescriptor::try_from([0x07, 0x05, 0x81, 0x03, 0x08, 0x00, 0x0a].as_slice()).unwrap();
+    /// assert_eq!(e.polling_interval_ms(Speed::FullSpeed), 10.0);
+    /// assert_eq!(e.polling_interval_ms(Speed::HighSpeed), 64.0);
+    /// e.interval = 4;
+    /// assert_eq!(e.polling_interval_ms(Speed::HighSpeed), 1.0);
+    /// // isochronous
+    /// e.attributes = 0x05;
+    /// e.interval = 1;
+    /// assert_eq!(e.polling_interval_ms(Speed::FullSpeed), 1.0);
+    /// assert_eq!(e.polling_interval_ms(Speed::SuperSpeed), 0.125);
+    /// // bulk
+    /// e.attributes = 0x02;
+    /// assert_eq!(e.polling_interval_ms(Speed::HighSpeed), 0.0);
+    /// ```
+    pub fn polling_interval_ms(&self, speed: Speed) -> f32 {
+        self.transfer_type()
+            .service_interval_us(self.interval, speed)
+            .map_or(0.0, |us| us as f32 / 1000.0)
+    }
+}
+
+/// lsusb style bmAttributes block; one line each for the transfer, synch and usage type
+///
+/// ```
+/// # use cyme::usb::descriptors::EndpointDescriptor;
+/// // asynchronous isochronous data endpoint
+/// let e = EndpointDescriptor::try_from([0x07, 0x05, 0x01, 0x05, 0xc0, 0x00, 0x01].as_slice()).unwrap();
+/// assert_eq!(e.sync_type_bits(), 1);
+/// assert_eq!(
+///     e.to_string(),
+///     "Transfer Type          Isochronous\nSynch Type             Asynchronous\nUsage Type             Data"
+/// );
+/// ```
+impl fmt::Display for EndpointDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Transfer Type          {}", self.transfer_type())?;
+        writeln!(f, "Synch Type             {}", self.sync_type())?;
+        write!(f, "Usage Type             {}", self.usage_type())
+    }
+}
+
+/// USB String descriptor
+///
+/// bString is UTF-16LE following the two byte header. A trailing odd byte is dropped and unpaired surrogates are replaced rather than failing the whole descriptor. The descriptor at string index 0 instead holds the supported LANGIDs; see [`StringDescriptor::language_ids`].
+///
+/// ```
+/// # use cyme::usb::descriptors::*;
+/// let d = Descriptor::try_from([0x0a, 0x03, 0x63, 0x00, 0x79, 0x00, 0x6d, 0x00, 0x65, 0x00].as_slice()).unwrap();
+/// match d {
+///     Descriptor::String(ref s) => assert_eq!(s.string, "cyme"),
+///     _ => panic!("not parsed as String"),
+/// }
+/// assert_eq!(Vec::<u8>::from(d), vec![0x0a, 0x03, 0x63, 0x00, 0x79, 0x00, 0x6d, 0x00, 0x65, 0x00]);
+///
+/// // odd byte count
+/// let s = StringDescriptor::try_from([0x05, 0x03, 0x4f, 0x00, 0x4b].as_slice()).unwrap();
+/// assert_eq!(s.string, "O");
+///
+/// // index 0: English (US) and German (Standard)
+/// let langs = StringDescriptor::try_from([0x06, 0x03, 0x09, 0x04, 0x07, 0x04].as_slice()).unwrap();
+/// assert_eq!(langs.language_ids(), vec![0x0409, 0x0407]);
+/// ```
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct StringDescriptor {
+    pub length: u8,
+    pub descriptor_type: u8,
+    /// Decoded bString
+    pub string: String,
+    /// bString bytes as read
+    #[serde(
+        default,
+        skip_serializing_if = "raw_bytes::omit",
+        with = "raw_bytes::option"
+    )]
+    pub data: Option<Vec<u8>>,
+}
+
+impl TryFrom<&[u8]> for StringDescriptor {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> error::Result<Self> {
+        if value.len() < 2 {
+            return Err(Error::new_descriptor_len(
+                "StringDescriptor",
+                2,
+                value.len(),
+            ));
+        }
+
+        check_descriptor_type("StringDescriptor", value[1], &[0x03])?;
+
+        let end = (value[0] as usize).clamp(2, value.len());
+        let data = value[2..end].to_vec();
+        if data.len() % 2 != 0 {
+            log::debug!("String descriptor has odd bString length {}", data.len());
+        }
+
+        Ok(StringDescriptor {
+            length: value[0],
+            descriptor_type: value[1],
+            string: String::from_utf16_lossy(&utf16_le_units(&data)),
+            data: Some(data),
+        })
+    }
+}
+
+impl From<StringDescriptor> for Vec<u8> {
+    fn from(sd: StringDescriptor) -> Self {
+        let mut ret = vec![sd.length, sd.descriptor_type];
+        match sd.data {
+            Some(data) => ret.extend(data),
+            None => ret.extend(sd.string.encode_utf16().flat_map(u16::to_le_bytes)),
+        }
+
+        ret
+    }
+}
+
+impl StringDescriptor {
+    /// LANGIDs of the descriptor at string index 0, which lists the languages of the other strings rather than holding text
+    pub fn language_ids(&self) -> Vec<u16> {
+        match &self.data {
+            Some(data) => utf16_le_units(data),
+            None => self.string.encode_utf16().collect(),
+        }
+    }
+
+    /// [`StringDescriptor::language_ids`] as hex with the [`crate::lsusb::names::langid`] name like lsusb; just the hex if the LANGID is unknown
+    ///
+    /// ```
+    /// # use cyme::usb::descriptors::StringDescriptor;
+    /// let langs = StringDescriptor::try_from([0x06, 0x03, 0x09, 0x04, 0xff, 0x03].as_slice()).unwrap();
+    /// assert_eq!(langs.language_names(), vec!["0x0409 English (US)", "0x03ff"]);
+    /// ```
+    pub fn language_names(&self) -> Vec<String> {
+        self.language_ids()
+            .into_iter()
+            .map(|id| match crate::lsusb::names::langid(id) {
+                Some(name) => format!("{:#06x} {}", id, name),
+                None => format!("{:#06x}", id),
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for StringDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.string)
+    }
+}
+
+/// Names from `bit_string` of the bits set in `bitmap`, skipping bits without a name
+fn set_bit_strings(
+    bitmap: u32,
+    bit_string: fn(usize) -> Option<&'static str>,
+) -> Vec<&'static str> {
+    (0..32)
+        .filter(|i| bitmap & (1 << i) != 0)
+        .filter_map(bit_string)
+        .collect()
+}
+
+/// Little endian 16 bit units of `bytes`, dropping a trailing odd byte
+fn utf16_le_units(bytes: &[u8]) -> Vec<u16> {
+    bytes
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .collect()
+}
+
 /// USB SS Endpoint Companion descriptor
+///
+/// ```
+/// # use cyme::usb::descriptors::*;
+/// let d = SsEndpointCompanionDescriptor::try_from([0x06, 0x30, 0x0f, 0x00, 0x00, 0x40].as_slice()).unwrap();
+/// assert_eq!(d.bytes_per_interval, Some(0x4000));
+/// assert_eq!(Vec::<u8>::from(d), vec![0x06, 0x30, 0x0f, 0x00, 0x00, 0x40]);
+/// // truncated before wBytesPerInterval
+/// let d = SsEndpointCompanionDescriptor::try_from([0x04, 0x30, 0x0f, 0x00].as_slice()).unwrap();
+/// assert_eq!(d.bytes_per_interval, None);
+/// ```
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct SsEndpointCompanionDescriptor {
@@ -343,6 +1088,9 @@ pub struct SsEndpointCompanionDescriptor {
     pub descriptor_type: u8,
     pub max_burst: u8,
     pub attributes: u8,
+    /// wBytesPerInterval total number of bytes a periodic endpoint will transfer every service interval; None if the descriptor is truncated before it
+    #[serde(default)]
+    pub bytes_per_interval: Option<u16>,
 }
 
 impl TryFrom<&[u8]> for SsEndpointCompanionDescriptor {
@@ -357,23 +1105,93 @@ impl TryFrom<&[u8]> for SsEndpointCompanionDescriptor {
             ));
         }
 
+        check_descriptor_type("SsEndpointCompanionDescriptor", value[1], &[0x30])?;
+
         Ok(SsEndpointCompanionDescriptor {
             length: value[0],
             descriptor_type: value[1],
             max_burst: value[2],
             attributes: value[3],
+            bytes_per_interval: value.get(4..6).map(|b| u16::from_le_bytes([b[0], b[1]])),
         })
     }
 }
 
 impl From<SsEndpointCompanionDescriptor> for Vec<u8> {
     fn from(sec: SsEndpointCompanionDescriptor) -> Self {
-        vec![
+        let mut ret = vec![
             sec.length,
             sec.descriptor_type,
             sec.max_burst,
             sec.attributes,
-        ]
+        ];
+        if let Some(bytes_per_interval) = sec.bytes_per_interval {
+            ret.extend_from_slice(&bytes_per_interval.to_le_bytes());
+        }
+        ret
+    }
+}
+
+impl SsEndpointCompanionDescriptor {
+    /// Bit 7 of bmAttributes for an isochronous endpoint; a [`SsIsocEndpointCompanionDescriptor`] follows this descriptor
+    pub fn has_isoc_companion(&self) -> bool {
+        self.attributes & 0x80 != 0
+    }
+}
+
+/// USB SuperSpeedPlus Isochronous Endpoint Companion descriptor
+///
+/// Follows the [`SsEndpointCompanionDescriptor`] of a SuperSpeedPlus isochronous endpoint requiring more than 48 KB per service interval. `bytes_per_interval` replaces wBytesPerInterval of the SS companion as the bandwidth reserved.
+///
+/// ```
+/// # use cyme::usb::descriptors::*;
+/// let d = Descriptor::try_from([0x08, 0x31, 0x00, 0x00, 0x00, 0x80, 0x01, 0x00].as_slice()).unwrap();
+/// match d {
+///     Descriptor::SsIsocEndpointCompanion(ref c) => assert_eq!(c.bytes_per_interval, 0x18000),
+///     _ => panic!("not parsed as SS Isoc Endpoint Companion"),
+/// }
+/// assert_eq!(Vec::<u8>::from(d), vec![0x08, 0x31, 0x00, 0x00, 0x00, 0x80, 0x01, 0x00]);
+/// ```
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct SsIsocEndpointCompanionDescriptor {
+    pub length: u8,
+    pub descriptor_type: u8,
+    /// wReserved; should be zero
+    pub reserved: u16,
+    /// dwBytesPerInterval total number of bytes the endpoint will transfer every service interval
+    pub bytes_per_interval: u32,
+}
+
+impl TryFrom<&[u8]> for SsIsocEndpointCompanionDescriptor {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> error::Result<Self> {
+        if value.len() < 8 {
+            return Err(Error::new_descriptor_len(
+                "SsIsocEndpointCompanionDescriptor",
+                8,
+                value.len(),
+            ));
+        }
+
+        check_descriptor_type("SsIsocEndpointCompanionDescriptor", value[1], &[0x31])?;
+
+        Ok(SsIsocEndpointCompanionDescriptor {
+            length: value[0],
+            descriptor_type: value[1],
+            reserved: u16::from_le_bytes([value[2], value[3]]),
+            bytes_per_interval: u32::from_le_bytes([value[4], value[5], value[6], value[7]]),
+        })
+    }
+}
+
+impl From<SsIsocEndpointCompanionDescriptor> for Vec<u8> {
+    fn from(sic: SsIsocEndpointCompanionDescriptor) -> Self {
+        let mut ret = vec![sic.length, sic.descriptor_type];
+        ret.extend_from_slice(&sic.reserved.to_le_bytes());
+        ret.extend_from_slice(&sic.bytes_per_interval.to_le_bytes());
+        ret
     }
 }
 
@@ -399,6 +1217,8 @@ impl TryFrom<&[u8]> for SecurityDescriptor {
             ));
         }
 
+        check_descriptor_type("SecurityDescriptor", value[1], &[0x0c])?;
+
         Ok(SecurityDescriptor {
             length: value[0],
             descriptor_type: value[1],
@@ -481,6 +1301,8 @@ impl TryFrom<&[u8]> for EncryptionDescriptor {
             ));
         }
 
+        check_descriptor_type("EncryptionDescriptor", value[1], &[0x0e])?;
+
         Ok(EncryptionDescriptor {
             length: value[0],
             descriptor_type: value[1],
@@ -525,6 +1347,8 @@ pub enum ClassDescriptor {
     Video(video::UvcDescriptor, u8),
     /// Device Firmware Upgrade (DFU) descriptor
     Dfu(DfuDescriptor),
+    /// Wireless USB Wire Adapter class descriptor
+    WireAdaptor(WireAdaptorDescriptor),
     /// Generic descriptor with Option<ClassCode>
     ///
     /// Used for most descriptors and allows for TryFrom without knowing the [`ClassCode`]
@@ -558,11 +1382,36 @@ impl From<ClassDescriptor> for Vec<u8> {
             ClassDescriptor::Audio(ad, _) => ad.into(),
             ClassDescriptor::Video(vd, _) => vd.into(),
             ClassDescriptor::Dfu(dd) => dd.into(),
+            ClassDescriptor::WireAdaptor(wd) => wd.into(),
         }
     }
 }
 
 impl ClassDescriptor {
+    /// Start a [`ClassDescriptorBuilder`] for a synthetic class specific descriptor, mainly for tests
+    pub fn builder(descriptor_type: u8, descriptor_subtype: u8) -> ClassDescriptorBuilder {
+        ClassDescriptorBuilder {
+            generic: GenericDescriptor::builder(descriptor_type, descriptor_subtype),
+            class_context: None,
+        }
+    }
+
+    /// Fills the string fields of class specific descriptors from their string descriptor indexes using `lookup`
+    pub fn resolve_strings<F: FnMut(u8) -> Option<String>>(&mut self, lookup: &mut F) {
+        match self {
+            ClassDescriptor::Printer(p) => {
+                for pd in p.descriptors.iter_mut() {
+                    pd.uuid_string = lookup_string(pd.uuid_string_index, lookup);
+                }
+            }
+            ClassDescriptor::Communication(cdc) => cdc.interface.resolve_strings(lookup),
+            ClassDescriptor::Midi(md, _) => md.interface.resolve_strings(lookup),
+            ClassDescriptor::Audio(ad, _) => ad.interface.resolve_strings(lookup),
+            ClassDescriptor::Video(vd, _) => vd.interface.resolve_strings(lookup),
+            _ => (),
+        }
+    }
+
     /// Uses [`ClassCodeTriplet`] to update the [`ClassDescriptor`] with [`ClassCode`] and descriptor if it is not [`GenericDescriptor`]
     pub fn update_with_class_context<T: Into<ClassCode> + Copy>(
         &mut self,
@@ -586,7 +1435,9 @@ impl ClassDescriptor {
                 }
                 // For legacy purposes, MIDI is defined as a SubClass of Audio Class
                 // but we define at as a separate ClassDescriptor
-                (ClassCode::Audio, 3, p) => {
+                (ClassCode::Audio, s, p)
+                    if audio::AudioSubclass::from(s) == audio::AudioSubclass::MidiStreaming =>
+                {
                     *self =
                         ClassDescriptor::Midi(audio::MidiDescriptor::try_from(gd.to_owned())?, p)
                 }
@@ -607,12 +1458,57 @@ impl ClassDescriptor {
                 (ClassCode::ApplicationSpecificInterface, 1, _) => {
                     *self = ClassDescriptor::Dfu(DfuDescriptor::try_from(gd.to_owned())?)
                 }
+                // Host Wire Adapter and Device Wire Adapter interfaces
+                (ClassCode::WirelessController, 2, 1..=3) => {
+                    *self = ClassDescriptor::WireAdaptor(WireAdaptorDescriptor::try_from(
+                        gd.to_owned(),
+                    )?)
+                }
                 ct => *self = ClassDescriptor::Generic(Some(ct), gd.to_owned()),
             }
         }
 
         Ok(())
     }
+
+    /// bDescriptorSubtype of the descriptor; None for descriptors without a subtype such as HID or DFU, or a CDC subtype that was not recognised
+    ///
+    /// ```
+    /// use cyme::usb::descriptors::ClassDescriptor;
+    ///
+    /// // UAC2 feature unit
+    /// let mut cd = ClassDescriptor::try_from([0x0a, 0x24, 0x06, 0x02, 0x01, 0x0f, 0x00, 0x00, 0x00, 0x00].as_slice()).unwrap();
+    /// assert_eq!(cd.descriptor_subtype(), Some(0x06));
+    /// cd.update_with_class_context((1, 1, 0x20)).unwrap();
+    /// assert_eq!(cd.descriptor_subtype(), Some(0x06));
+    /// ```
+    pub fn descriptor_subtype(&self) -> Option<u8> {
+        match self {
+            ClassDescriptor::Generic(_, gd) => Some(gd.descriptor_subtype),
+            ClassDescriptor::Communication(cd) => match cd.descriptor_subtype {
+                cdc::CdcType::Unknown => None,
+                ref t => Some(t.to_owned() as u8),
+            },
+            ClassDescriptor::Midi(md, _) => Some(u8::from(md.descriptor_subtype.to_owned())),
+            ClassDescriptor::Audio(ad, p) => Some(ad.descriptor_subtype.to_uac_subtype(p)),
+            ClassDescriptor::Video(vd, _) => Some(u8::from(vd.descriptor_subtype.to_owned())),
+            _ => None,
+        }
+    }
+
+    /// The [`audio::AudioSubclass`] of the interface the descriptor belongs to if it is an Audio class descriptor
+    ///
+    /// MIDIStreaming descriptors are [`ClassDescriptor::Midi`], AudioControl and AudioStreaming are [`ClassDescriptor::Audio`]. Generic descriptors only know their subclass if updated with class context.
+    pub fn audio_subclass(&self) -> Option<audio::AudioSubclass> {
+        match self {
+            ClassDescriptor::Midi(_, _) => Some(audio::AudioSubclass::MidiStreaming),
+            ClassDescriptor::Audio(uacd, _) => Some(uacd.descriptor_subtype.subclass()),
+            ClassDescriptor::Generic(Some((ClassCode::Audio, s, _)), _) => {
+                Some(audio::AudioSubclass::from(*s))
+            }
+            _ => None,
+        }
+    }
 }
 
 /// USB HID report descriptor
@@ -623,6 +1519,11 @@ impl ClassDescriptor {
 pub struct HidReportDescriptor {
     pub descriptor_type: u8,
     pub length: u16,
+    #[serde(
+        default,
+        skip_serializing_if = "raw_bytes::omit",
+        with = "raw_bytes::option"
+    )]
     pub data: Option<Vec<u8>>,
 }
 
@@ -668,6 +1569,65 @@ impl From<HidReportDescriptor> for Vec<u8> {
     }
 }
 
+/// Builds a [`ClassDescriptor`] from a [`GenericDescriptorBuilder`] and optional class context, which decodes it like the profiler would
+///
+/// ```
+/// # use cyme::usb::ClassCode;
+/// # use cyme::usb::descriptors::ClassDescriptor;
+/// let cd = ClassDescriptor::builder(0x24, 0x01)
+///     .class_context((ClassCode::Audio, 0x01, 0x00))
+///     .word(0x0100)
+///     .word(0x001e)
+///     .data(&[0x01, 0x01])
+///     .build()
+///     .unwrap();
+/// assert!(matches!(cd, ClassDescriptor::Audio(..)));
+/// assert_eq!(Vec::<u8>::from(cd)[0], 0x09);
+///
+/// // without context it stays generic
+/// let cd = ClassDescriptor::builder(0x24, 0x01).build().unwrap();
+/// assert!(matches!(cd, ClassDescriptor::Generic(None, _)));
+///
+/// // too long for bLength
+/// assert!(ClassDescriptor::builder(0x24, 0x01).data(&[0; 253]).build().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClassDescriptorBuilder {
+    generic: GenericDescriptorBuilder,
+    class_context: Option<ClassCodeTriplet<ClassCode>>,
+}
+
+impl ClassDescriptorBuilder {
+    /// Append bytes following bDescriptorSubtype
+    pub fn data(mut self, data: &[u8]) -> Self {
+        self.generic = self.generic.data(data);
+        self
+    }
+
+    /// Append a little endian word
+    pub fn word(mut self, value: u16) -> Self {
+        self.generic = self.generic.word(value);
+        self
+    }
+
+    /// (class, sub-class, protocol) of the owning interface used to decode the descriptor
+    pub fn class_context<T: Into<ClassCode>>(mut self, triplet: ClassCodeTriplet<T>) -> Self {
+        self.class_context = Some((triplet.0.into(), triplet.1, triplet.2));
+        self
+    }
+
+    /// Build the descriptor, decoding it with the class context if set
+    ///
+    /// Errors if the data does not fit the 255 byte bLength or cannot be decoded with the class context
+    pub fn build(self) -> error::Result<ClassDescriptor> {
+        let mut cd = ClassDescriptor::Generic(None, self.generic.build()?);
+        if let Some(triplet) = self.class_context {
+            cd.update_with_class_context(triplet)?;
+        }
+        Ok(cd)
+    }
+}
+
 /// USB generic descriptor
 ///
 /// Used for most [`ClassDescriptor`]s
@@ -677,6 +1637,11 @@ pub struct GenericDescriptor {
     pub length: u8,
     pub descriptor_type: u8,
     pub descriptor_subtype: u8,
+    #[serde(
+        default,
+        skip_serializing_if = "raw_bytes::omit",
+        with = "raw_bytes::option"
+    )]
     pub data: Option<Vec<u8>>,
 }
 
@@ -748,8 +1713,114 @@ impl GenericDescriptor {
     pub fn to_vec(&self) -> Vec<u8> {
         self.clone().into()
     }
+
+    /// Start a [`GenericDescriptorBuilder`] for a synthetic descriptor, mainly for tests
+    pub fn builder(descriptor_type: u8, descriptor_subtype: u8) -> GenericDescriptorBuilder {
+        GenericDescriptorBuilder {
+            descriptor_type,
+            descriptor_subtype,
+            data: Vec::new(),
+        }
+    }
+}
+
+/// Builds a [`GenericDescriptor`] with bLength set from the data so tests don't need hand counted byte arrays
+///
+/// ```
+/// # use cyme::usb::descriptors::GenericDescriptor;
+/// // UAC1 AC header with one streaming interface
+/// let gd = GenericDescriptor::builder(0x24, 0x01)
+///     .data(&[0x00, 0x01, 0x1e, 0x00])
+///     .data(&[0x01, 0x01])
+///     .build()
+///     .unwrap();
+/// assert_eq!(gd.length, 9);
+/// assert_eq!(gd.to_vec(), vec![0x09, 0x24, 0x01, 0x00, 0x01, 0x1e, 0x00, 0x01, 0x01]);
+///
+/// assert!(GenericDescriptor::builder(0x24, 0x01).data(&[0; 253]).build().is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GenericDescriptorBuilder {
+    descriptor_type: u8,
+    descriptor_subtype: u8,
+    data: Vec<u8>,
+}
+
+impl GenericDescriptorBuilder {
+    /// Append bytes following bDescriptorSubtype
+    pub fn data(mut self, data: &[u8]) -> Self {
+        self.data.extend_from_slice(data);
+        self
+    }
+
+    /// Append a little endian word, as used by most multi-byte descriptor fields
+    pub fn word(mut self, value: u16) -> Self {
+        self.data.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    /// Build the descriptor with `length` of the header plus data
+    ///
+    /// Errors if the data does not fit the 255 byte bLength
+    pub fn build(self) -> error::Result<GenericDescriptor> {
+        let length = u8::try_from(self.data.len() + 3).map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidDescriptor,
+                &format!(
+                    "descriptor of {} bytes does not fit the 255 byte bLength",
+                    self.data.len() + 3
+                ),
+            )
+        })?;
+        Ok(GenericDescriptor {
+            length,
+            descriptor_type: self.descriptor_type,
+            descriptor_subtype: self.descriptor_subtype,
+            data: Some(self.data),
+        })
+    }
 }
 
+/// HID 1.11 section 6.2.1 bCountryCode names indexed by code; codes above are reserved
+pub(crate) const HID_COUNTRY_CODES: [&str; 36] = [
+    "Not supported",
+    "Arabic",
+    "Belgian",
+    "Canadian-Bilingual",
+    "Canadian-French",
+    "Czech Republic",
+    "Danish",
+    "Finnish",
+    "French",
+    "German",
+    "Greek",
+    "Hebrew",
+    "Hungary",
+    "International (ISO)",
+    "Italian",
+    "Japan (Katakana)",
+    "Korean",
+    "Latin American",
+    "Netherlands/Dutch",
+    "Norwegian",
+    "Persian (Farsi)",
+    "Poland",
+    "Portuguese",
+    "Russia",
+    "Slovakia",
+    "Spanish",
+    "Swedish",
+    "Swiss/French",
+    "Swiss/German",
+    "Switzerland",
+    "Taiwan",
+    "Turkish-Q",
+    "UK",
+    "US",
+    "Yugoslavia",
+    "Turkish-F",
+];
+
 /// USB HID descriptor
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 #[allow(missing_docs)]
@@ -769,6 +1840,8 @@ impl TryFrom<&[u8]> for HidDescriptor {
             return Err(Error::new_descriptor_len("HidDescriptor", 6, value.len()));
         }
 
+        check_descriptor_type("HidDescriptor", value[1], CLASS_DESCRIPTOR_TYPES)?;
+
         let num_descriptors = value[5] as usize;
         let mut descriptors_vec = value[6..].to_vec();
         let mut descriptors = Vec::<HidReportDescriptor>::with_capacity(num_descriptors);
@@ -795,6 +1868,29 @@ impl TryFrom<&[u8]> for HidDescriptor {
     }
 }
 
+impl HidDescriptor {
+    /// Name of the localized hardware country code; "Reserved" for codes the HID specification does not define
+    ///
+    /// ```
+    /// use cyme::usb::descriptors::HidDescriptor;
+    ///
+    /// let mut hid = HidDescriptor::try_from(&[0x06, 0x21, 0x11, 0x01, 0x00, 0x00][..]).unwrap();
+    /// assert_eq!(hid.country_name(), "Not supported");
+    /// hid.country_code = 8;
+    /// assert_eq!(hid.country_name(), "French");
+    /// hid.country_code = 33;
+    /// assert_eq!(hid.country_name(), "US");
+    /// hid.country_code = 36;
+    /// assert_eq!(hid.country_name(), "Reserved");
+    /// ```
+    pub fn country_name(&self) -> &'static str {
+        HID_COUNTRY_CODES
+            .get(self.country_code as usize)
+            .copied()
+            .unwrap_or("Reserved")
+    }
+}
+
 impl TryFrom<GenericDescriptor> for HidDescriptor {
     type Error = Error;
 
@@ -847,6 +1943,59 @@ pub struct CcidDescriptor {
     pub max_ccid_busy_slots: u8,
 }
 
+impl CcidDescriptor {
+    /// Name of the dwSyncProtocols bit at `index`
+    pub fn sync_protocol_string(index: usize) -> Option<&'static str> {
+        match index {
+            0 => Some("2-wire"),
+            1 => Some("3-wire"),
+            2 => Some("I2C"),
+            _ => None,
+        }
+    }
+
+    /// Name of the dwMechanical bit at `index`
+    pub fn mechanical_string(index: usize) -> Option<&'static str> {
+        match index {
+            0 => Some("accept"),
+            1 => Some("eject"),
+            2 => Some("capture"),
+            3 => Some("lock"),
+            _ => None,
+        }
+    }
+
+    /// Synchronous protocols supported for synchronous cards: 2-wire, 3-wire and I2C
+    ///
+    /// ```
+    /// # use cyme::usb::descriptors::CcidDescriptor;
+    /// let mut bytes = [0u8; 54];
+    /// bytes[0] = 54;
+    /// bytes[1] = 0x21;
+    /// bytes[32] = 0x05;
+    /// let ccid = CcidDescriptor::try_from(bytes.as_slice()).unwrap();
+    /// assert_eq!(ccid.sync_protocols_supported(), vec!["2-wire", "I2C"]);
+    /// ```
+    pub fn sync_protocols_supported(&self) -> Vec<&'static str> {
+        set_bit_strings(self.sync_protocols, Self::sync_protocol_string)
+    }
+
+    /// Card mechanical features of the reader: accept, eject, capture and lock. Empty if the reader has no card mechanism
+    ///
+    /// ```
+    /// # use cyme::usb::descriptors::CcidDescriptor;
+    /// let mut bytes = [0u8; 54];
+    /// bytes[0] = 54;
+    /// bytes[1] = 0x21;
+    /// bytes[36] = 0x0a;
+    /// let ccid = CcidDescriptor::try_from(bytes.as_slice()).unwrap();
+    /// assert_eq!(ccid.mechanical_features(), vec!["eject", "lock"]);
+    /// ```
+    pub fn mechanical_features(&self) -> Vec<&'static str> {
+        set_bit_strings(self.mechanical, Self::mechanical_string)
+    }
+}
+
 impl TryFrom<&[u8]> for CcidDescriptor {
     type Error = Error;
 
@@ -855,6 +2004,8 @@ impl TryFrom<&[u8]> for CcidDescriptor {
             return Err(Error::new_descriptor_len("CcidDescriptor", 54, value.len()));
         }
 
+        check_descriptor_type("CcidDescriptor", value[1], CLASS_DESCRIPTOR_TYPES)?;
+
         let lcd_layout = (value[50], value[51]);
 
         Ok(CcidDescriptor {
@@ -946,6 +2097,8 @@ impl TryFrom<&[u8]> for PrinterDescriptor {
             ));
         }
 
+        check_descriptor_type("PrinterDescriptor", value[1], CLASS_DESCRIPTOR_TYPES)?;
+
         let num_descriptors = value[3] as usize;
         let mut descriptors_vec = value[4..].to_vec();
         let mut descriptors = Vec::<PrinterReportDescriptor>::with_capacity(num_descriptors);
@@ -1011,6 +2164,11 @@ pub struct PrinterReportDescriptor {
     pub versions_supported: u8,
     pub uuid_string_index: u8,
     pub uuid_string: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "raw_bytes::omit",
+        with = "raw_bytes::option"
+    )]
     pub data: Option<Vec<u8>>,
 }
 
@@ -1060,6 +2218,7 @@ pub struct HubDescriptor {
     pub characteristics: u16,
     pub power_on_to_power_good: u8,
     pub control_current: u8,
+    #[serde(default, skip_serializing_if = "raw_bytes::omit", with = "raw_bytes")]
     pub data: Vec<u8>,
     pub port_statuses: Option<Vec<[u8; 8]>>,
 }
@@ -1072,6 +2231,8 @@ impl TryFrom<&[u8]> for HubDescriptor {
             return Err(Error::new_descriptor_len("HubDescriptor", 9, value.len()));
         }
 
+        check_descriptor_type("HubDescriptor", value[1], &[0x29, 0x2a])?;
+
         Ok(HubDescriptor {
             length: value[0],
             descriptor_type: value[1],
@@ -1113,20 +2274,156 @@ impl HubDescriptor {
     pub fn latency(&self) -> Option<u8> {
         self.data.first().copied()
     }
+
+    /// How port power is switched, from wHubCharacteristics bits 1:0
+    ///
+    /// ```
+    /// use cyme::usb::descriptors::{HubDescriptor, OverCurrentProtectionMode, PowerSwitchingMode};
+    ///
+    /// let hd = HubDescriptor::try_from([0x09, 0x29, 0x04, 0xe9, 0x00, 0x32, 0x64, 0x00, 0xff].as_slice()).unwrap();
+    /// assert_eq!(hd.power_switching_mode(), PowerSwitchingMode::PerPort);
+    /// assert_eq!(hd.over_current_protection_mode(), OverCurrentProtectionMode::PerPort);
+    /// assert!(!hd.compound_device());
+    /// assert_eq!(hd.tt_think_time(), Some(32));
+    /// assert!(hd.port_indicators());
+    ///
+    /// // SuperSpeed hubs have no TT or port indicators
+    /// let hd = HubDescriptor::try_from([0x0c, 0x2a, 0x04, 0xe4, 0x00, 0x32, 0x64, 0x00, 0x00, 0x00, 0x00, 0x00].as_slice()).unwrap();
+    /// assert_eq!(hd.power_switching_mode(), PowerSwitchingMode::Ganged);
+    /// assert_eq!(hd.over_current_protection_mode(), OverCurrentProtectionMode::Ganged);
+    /// assert!(hd.compound_device());
+    /// assert_eq!(hd.tt_think_time(), None);
+    /// assert!(!hd.port_indicators());
+    /// ```
+    pub fn power_switching_mode(&self) -> PowerSwitchingMode {
+        match self.characteristics & 0x03 {
+            0 => PowerSwitchingMode::Ganged,
+            1 => PowerSwitchingMode::PerPort,
+            _ => PowerSwitchingMode::None,
+        }
+    }
+
+    /// How over-current is reported, from wHubCharacteristics bits 4:3
+    pub fn over_current_protection_mode(&self) -> OverCurrentProtectionMode {
+        match (self.characteristics >> 3) & 0x03 {
+            0 => OverCurrentProtectionMode::Ganged,
+            1 => OverCurrentProtectionMode::PerPort,
+            _ => OverCurrentProtectionMode::None,
+        }
+    }
+
+    /// Hub is part of a compound device with non-removable devices attached, wHubCharacteristics bit 2
+    pub fn compound_device(&self) -> bool {
+        self.characteristics & 0x04 != 0
+    }
+
+    /// Maximum Transaction Translator think time in full-speed bit times, wHubCharacteristics bits 6:5. None for SuperSpeed hubs, which have no TT
+    pub fn tt_think_time(&self) -> Option<u8> {
+        (self.descriptor_type == 0x29).then(|| (((self.characteristics >> 5) & 0x03) as u8 + 1) * 8)
+    }
+
+    /// Port indicators are supported, wHubCharacteristics bit 7. Always false for SuperSpeed hubs, which have no indicators
+    pub fn port_indicators(&self) -> bool {
+        self.descriptor_type == 0x29 && self.characteristics & (1 << 7) != 0
+    }
+}
+
+/// Logical power switching mode of a hub; see [`HubDescriptor::power_switching_mode`]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PowerSwitchingMode {
+    /// All ports are powered at once
+    Ganged,
+    /// Each port is powered individually
+    PerPort,
+    /// Ports are always powered; USB 1.0 hubs
+    None,
+}
+
+impl fmt::Display for PowerSwitchingMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PowerSwitchingMode::Ganged => write!(f, "Ganged power switching"),
+            PowerSwitchingMode::PerPort => write!(f, "Per-port power switching"),
+            PowerSwitchingMode::None => write!(f, "No power switching (usb 1.0)"),
+        }
+    }
 }
 
+/// Over-current protection mode of a hub; see [`HubDescriptor::over_current_protection_mode`]
+///
+/// With ganged protection an over-current caused by one device removes power from every port, so one misbehaving device browns out its neighbours.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OverCurrentProtectionMode {
+    /// Over-current is reported for all ports together
+    Ganged,
+    /// Over-current is reported for each port
+    PerPort,
+    /// No over-current protection; only allowed for bus-powered hubs
+    None,
+}
+
+impl fmt::Display for OverCurrentProtectionMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OverCurrentProtectionMode::Ganged => write!(f, "Ganged overcurrent protection"),
+            OverCurrentProtectionMode::PerPort => write!(f, "Per-port overcurrent protection"),
+            OverCurrentProtectionMode::None => write!(f, "No overcurrent protection"),
+        }
+    }
+}
+
+/// Device Firmware Upgrade (DFU) functional descriptor found on Application Specific interfaces with SubClass 0x01
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
-#[allow(missing_docs)]
 pub struct DfuDescriptor {
+    /// Size of descriptor in bytes
     pub length: u8,
+    /// DFU FUNCTIONAL descriptor type (0x21)
     pub descriptor_type: u8,
+    /// bmAttributes capability bitmap; see the accessor methods
     pub attributes: u8,
+    /// Time in milliseconds the device will wait for a USB reset after DFU_DETACH
     pub detach_timeout: u16,
+    /// Maximum number of bytes the device can accept per control-write transaction
     pub transfer_size: u16,
-    // not all have version
+    /// DFU specification release; not all devices include it
     pub dfu_version: Option<Version>,
 }
 
+impl DfuDescriptor {
+    /// Device supports download (bitCanDnload)
+    ///
+    /// ```
+    /// use cyme::usb::descriptors::DfuDescriptor;
+    ///
+    /// let dfud = DfuDescriptor::try_from([0x09, 0x21, 0x0b, 0xff, 0x00, 0x00, 0x04, 0x1a, 0x01].as_slice()).unwrap();
+    /// assert!(dfud.can_download());
+    /// assert!(dfud.can_upload());
+    /// assert!(!dfud.manifestation_tolerant());
+    /// assert!(dfud.will_detach());
+    /// assert_eq!(dfud.transfer_size, 1024);
+    /// ```
+    pub fn can_download(&self) -> bool {
+        self.attributes & 0x01 != 0
+    }
+
+    /// Device supports upload (bitCanUpload)
+    pub fn can_upload(&self) -> bool {
+        self.attributes & 0x02 != 0
+    }
+
+    /// Device can communicate on the bus after the manifestation phase (bitManifestationTolerant)
+    pub fn manifestation_tolerant(&self) -> bool {
+        self.attributes & 0x04 != 0
+    }
+
+    /// Device will perform a bus detach-attach sequence itself on DFU_DETACH rather than waiting for a host reset (bitWillDetach)
+    pub fn will_detach(&self) -> bool {
+        self.attributes & 0x08 != 0
+    }
+}
+
 impl TryFrom<&[u8]> for DfuDescriptor {
     type Error = Error;
 
@@ -1135,6 +2432,8 @@ impl TryFrom<&[u8]> for DfuDescriptor {
             return Err(Error::new_descriptor_len("DfuDescriptor", 7, value.len()));
         }
 
+        check_descriptor_type("DfuDescriptor", value[1], CLASS_DESCRIPTOR_TYPES)?;
+
         let dfu_version = if value.len() >= 9 {
             Some(Version::from_bcd(u16::from_le_bytes([value[7], value[8]])))
         } else {
@@ -1177,6 +2476,168 @@ impl TryFrom<GenericDescriptor> for DfuDescriptor {
     }
 }
 
+/// Wireless USB Wire Adapter class descriptor (0x21) found on Host and Device Wire Adapter interfaces; Wireless USB 1.0 8.4.3.1
+///
+/// ```
+/// use cyme::usb::descriptors::WireAdaptorDescriptor;
+///
+/// let wad = WireAdaptorDescriptor::try_from(
+///     [0x0e, 0x21, 0x00, 0x01, 0x04, 0x00, 0x10, 0x00, 0x00, 0x01, 0x08, 0x32, 0x03, 0x00].as_slice(),
+/// )
+/// .unwrap();
+/// assert_eq!(wad.num_ports, 4);
+/// assert_eq!(wad.num_rpipes, 16);
+/// assert_eq!(wad.rpipe_max_block, 256);
+/// assert_eq!(wad.device_removable, vec![0x00]);
+/// assert_eq!(Vec::<u8>::from(wad).len(), 14);
+/// ```
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WireAdaptorDescriptor {
+    /// Size of descriptor in bytes
+    pub length: u8,
+    /// Wire Adapter class descriptor type (0x21)
+    pub descriptor_type: u8,
+    /// bcdWAVersion Wire Adapter specification release
+    pub wa_version: Version,
+    /// Number of downstream ports; 0 for a Device Wire Adapter
+    pub num_ports: u8,
+    /// bmAttributes
+    pub attributes: u8,
+    /// Number of remote pipes (RPipes) supported
+    pub num_rpipes: u16,
+    /// Number of transfer buffer blocks shared by the RPipes
+    pub rpipe_max_block: u16,
+    /// Size of each transfer buffer block as a power of 2 bytes
+    pub rpipe_block_size: u8,
+    /// Time in 2 ms units from power on of a port to power good
+    pub pwr_on_2_pwr_good: u8,
+    /// Number of MMC Information Elements the adapter can hold
+    pub num_mmc_ies: u8,
+    /// DeviceRemovable bitmap of the ports
+    pub device_removable: Vec<u8>,
+}
+
+impl TryFrom<&[u8]> for WireAdaptorDescriptor {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> error::Result<Self> {
+        if value.len() < 13 {
+            return Err(Error::new_descriptor_len(
+                "WireAdaptorDescriptor",
+                13,
+                value.len(),
+            ));
+        }
+
+        check_descriptor_type("WireAdaptorDescriptor", value[1], CLASS_DESCRIPTOR_TYPES)?;
+
+        Ok(WireAdaptorDescriptor {
+            length: value[0],
+            descriptor_type: value[1],
+            wa_version: Version::from_bcd(u16::from_le_bytes([value[2], value[3]])),
+            num_ports: value[4],
+            attributes: value[5],
+            num_rpipes: u16::from_le_bytes([value[6], value[7]]),
+            rpipe_max_block: u16::from_le_bytes([value[8], value[9]]),
+            rpipe_block_size: value[10],
+            pwr_on_2_pwr_good: value[11],
+            num_mmc_ies: value[12],
+            device_removable: value[13..].to_vec(),
+        })
+    }
+}
+
+impl From<WireAdaptorDescriptor> for Vec<u8> {
+    fn from(wad: WireAdaptorDescriptor) -> Self {
+        let mut ret = vec![wad.length, wad.descriptor_type];
+        ret.extend(u16::from(wad.wa_version).to_le_bytes());
+        ret.push(wad.num_ports);
+        ret.push(wad.attributes);
+        ret.extend(wad.num_rpipes.to_le_bytes());
+        ret.extend(wad.rpipe_max_block.to_le_bytes());
+        ret.push(wad.rpipe_block_size);
+        ret.push(wad.pwr_on_2_pwr_good);
+        ret.push(wad.num_mmc_ies);
+        ret.extend(wad.device_removable);
+
+        ret
+    }
+}
+
+impl TryFrom<GenericDescriptor> for WireAdaptorDescriptor {
+    type Error = Error;
+
+    fn try_from(gd: GenericDescriptor) -> error::Result<Self> {
+        let gd_vec: Vec<u8> = gd.into();
+        WireAdaptorDescriptor::try_from(&gd_vec[..])
+    }
+}
+
+/// Wireless USB Endpoint Companion descriptor (0x11) following each endpoint of a Wireless USB device; Wireless USB 1.0 7.4.4
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WirelessEndpointCompanionDescriptor {
+    /// Size of descriptor in bytes
+    pub length: u8,
+    /// Wireless Endpoint Companion descriptor type (0x11)
+    pub descriptor_type: u8,
+    /// Maximum number of packets the endpoint can send or receive in a burst
+    pub max_burst: u8,
+    /// Maximum data sequence value
+    pub max_sequence: u8,
+    /// Maximum time a data stream can be delayed for isochronous endpoints; µs
+    pub max_stream_delay: u16,
+    /// Maximum packet size sent over the air
+    pub over_the_air_packet_size: u16,
+    /// Service interval over the air for isochronous endpoints
+    pub over_the_air_interval: u8,
+    /// bmCompAttributes
+    pub attributes: u8,
+}
+
+impl TryFrom<&[u8]> for WirelessEndpointCompanionDescriptor {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> error::Result<Self> {
+        if value.len() < 10 {
+            return Err(Error::new_descriptor_len(
+                "WirelessEndpointCompanionDescriptor",
+                10,
+                value.len(),
+            ));
+        }
+
+        check_descriptor_type("WirelessEndpointCompanionDescriptor", value[1], &[0x11])?;
+
+        Ok(WirelessEndpointCompanionDescriptor {
+            length: value[0],
+            descriptor_type: value[1],
+            max_burst: value[2],
+            max_sequence: value[3],
+            max_stream_delay: u16::from_le_bytes([value[4], value[5]]),
+            over_the_air_packet_size: u16::from_le_bytes([value[6], value[7]]),
+            over_the_air_interval: value[8],
+            attributes: value[9],
+        })
+    }
+}
+
+impl From<WirelessEndpointCompanionDescriptor> for Vec<u8> {
+    fn from(wec: WirelessEndpointCompanionDescriptor) -> Self {
+        let mut ret = vec![
+            wec.length,
+            wec.descriptor_type,
+            wec.max_burst,
+            wec.max_sequence,
+        ];
+        ret.extend(wec.max_stream_delay.to_le_bytes());
+        ret.extend(wec.over_the_air_packet_size.to_le_bytes());
+        ret.push(wec.over_the_air_interval);
+        ret.push(wec.attributes);
+
+        ret
+    }
+}
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct DebugDescriptor {
@@ -1194,12 +2655,7 @@ impl TryFrom<&[u8]> for DebugDescriptor {
             return Err(Error::new_descriptor_len("DebugDescriptor", 4, value.len()));
         }
 
-        if value[1] != 0x0a {
-            return Err(Error::new(
-                ErrorKind::InvalidArg,
-                "Debug descriptor must have descriptor type 0x0a",
-            ));
-        }
+        check_descriptor_type("DebugDescriptor", value[1], &[0x0a])?;
 
         Ok(DebugDescriptor {
             length: value[0],
@@ -1221,6 +2677,109 @@ impl From<DebugDescriptor> for Vec<u8> {
     }
 }
 
+/// bmCapabilitiesFlags bit names of an [`InterfacePowerDescriptor`] from LSB
+const INTERFACE_POWER_CAPABILITIES: [&str; 6] = [
+    "D0 Command",
+    "D1 Command",
+    "D2 Command",
+    "D3 Command",
+    "D1 Wakeup",
+    "D2 Wakeup",
+];
+
+/// Interface Power descriptor from the USB Interface Power Management specification; the device power states an interface supports
+///
+/// Rarely implemented, so only bmCapabilitiesFlags is decoded and the remaining bytes are kept in `data`. A descriptor without bmCapabilitiesFlags is retained rather than dropped.
+///
+/// ```
+/// use cyme::usb::descriptors::{Descriptor, InterfacePowerDescriptor};
+///
+/// let bytes = [0x0f, 0x08, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+/// let ipd = InterfacePowerDescriptor::try_from(bytes.as_slice()).unwrap();
+/// assert_eq!(ipd.capabilities, Some(0x13));
+/// assert_eq!(ipd.capability_strings(), vec!["D0 Command", "D1 Command", "D1 Wakeup"]);
+/// assert_eq!(ipd.data.len(), 12);
+///
+/// let desc = Descriptor::try_from([0x02, 0x08].as_slice()).unwrap();
+/// assert!(matches!(desc, Descriptor::InterfacePower(InterfacePowerDescriptor { capabilities: None, .. })));
+/// assert_eq!(Vec::<u8>::from(desc), vec![0x02, 0x08]);
+/// ```
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InterfacePowerDescriptor {
+    /// bLength
+    pub length: u8,
+    /// bDescriptorType
+    pub descriptor_type: u8,
+    /// bmCapabilitiesFlags; None if the descriptor is too short to contain it
+    pub capabilities: Option<u8>,
+    /// Bytes following bmCapabilitiesFlags
+    #[serde(with = "raw_bytes")]
+    pub data: Vec<u8>,
+}
+
+impl TryFrom<&[u8]> for InterfacePowerDescriptor {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> error::Result<Self> {
+        if value.len() < 2 {
+            return Err(Error::new_descriptor_len(
+                "InterfacePowerDescriptor",
+                2,
+                value.len(),
+            ));
+        }
+
+        check_descriptor_type("InterfacePowerDescriptor", value[1], &[0x08])?;
+
+        Ok(InterfacePowerDescriptor {
+            length: value[0],
+            descriptor_type: value[1],
+            capabilities: value.get(2).copied(),
+            data: value.get(3..).unwrap_or_default().to_vec(),
+        })
+    }
+}
+
+impl From<InterfacePowerDescriptor> for Vec<u8> {
+    fn from(ipd: InterfacePowerDescriptor) -> Self {
+        let mut ret = vec![ipd.length, ipd.descriptor_type];
+        ret.extend(ipd.capabilities);
+        ret.extend(ipd.data);
+        ret
+    }
+}
+
+impl InterfacePowerDescriptor {
+    /// Name of bmCapabilitiesFlags `bit`
+    pub fn capability_name(bit: usize) -> Option<&'static str> {
+        INTERFACE_POWER_CAPABILITIES.get(bit).copied()
+    }
+
+    /// Names of the bmCapabilitiesFlags set
+    pub fn capability_strings(&self) -> Vec<&'static str> {
+        self.capabilities.map_or(Vec::new(), |c| {
+            (0..INTERFACE_POWER_CAPABILITIES.len())
+                .filter(|b| c & (1 << b) != 0)
+                .filter_map(Self::capability_name)
+                .collect()
+        })
+    }
+}
+
+/// Device Qualifier descriptor of a high-speed capable device; the device descriptor fields that would change if it were operating at the other speed
+///
+/// ```
+/// use cyme::usb::descriptors::DeviceQualifierDescriptor;
+/// use cyme::usb::{ClassCode, Version};
+///
+/// let bytes = [0x0a, 0x06, 0x00, 0x02, 0x09, 0x00, 0x01, 0x40, 0x01, 0x00];
+/// let dqd = DeviceQualifierDescriptor::try_from(bytes.as_slice()).unwrap();
+/// assert_eq!(dqd.version, Version(2, 0, 0));
+/// assert_eq!(dqd.device_class, ClassCode::Hub);
+/// assert_eq!(dqd.max_packet_size, 64);
+/// assert_eq!(dqd.num_configurations, 1);
+/// assert_eq!(Vec::<u8>::from(dqd), bytes);
+/// ```
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct DeviceQualifierDescriptor {
@@ -1246,12 +2805,7 @@ impl TryFrom<&[u8]> for DeviceQualifierDescriptor {
             ));
         }
 
-        if value[1] != 0x06 {
-            return Err(Error::new(
-                ErrorKind::InvalidArg,
-                "Device Qualifier descriptor must have descriptor type 0x06",
-            ));
-        }
+        check_descriptor_type("DeviceQualifierDescriptor", value[1], &[0x06])?;
 
         Ok(DeviceQualifierDescriptor {
             length: value[0],
@@ -1277,6 +2831,10 @@ impl From<DeviceQualifierDescriptor> for Vec<u8> {
         ret.push(dqd.device_protocol);
         ret.push(dqd.max_packet_size);
         ret.push(dqd.num_configurations);
+        // bReserved
+        if dqd.length >= 10 {
+            ret.push(0);
+        }
 
         ret
     }
@@ -1302,12 +2860,7 @@ impl TryFrom<&[u8]> for OnTheGoDescriptor {
             ));
         }
 
-        if value[1] != 0x09 {
-            return Err(Error::new(
-                ErrorKind::InvalidArg,
-                "On-The-Go descriptor must have descriptor type 0x09",
-            ));
-        }
+        check_descriptor_type("OnTheGoDescriptor", value[1], &[0x09])?;
 
         Ok(OnTheGoDescriptor {
             length: value[0],
@@ -81,6 +81,8 @@ impl TryFrom<&[u8]> for MidiDescriptor {
             return Err(Error::new_descriptor_len("MidiDescriptor", 4, value.len()));
         }
 
+        check_descriptor_type("MidiDescriptor", value[1], &[0x24, 0x25, 0x04, 0x05])?;
+
         let length = value[0];
         if length as usize > value.len() {
             return Err(Error::new_descriptor_len(
@@ -282,6 +284,47 @@ pub struct Element {
     pub element_string: Option<String>,
 }
 
+/// Names of the bmElementCaps bits of a MIDI [`Element`] from the USB MIDI 1.0 specification, indexed by bit
+const MIDI_ELEMENT_CAPABILITIES: [&str; 12] = [
+    "Undefined",
+    "MIDI Clock",
+    "MTC (MIDI Time Code)",
+    "MMC (MIDI Machine Control)",
+    "GM1 (General MIDI v.1)",
+    "GM2 (General MIDI v.2)",
+    "GS MIDI Extension",
+    "XG MIDI Extension",
+    "EFX",
+    "MIDI Patch Bay",
+    "DLS1 (Downloadable Sounds Level 1)",
+    "DLS2 (Downloadable Sounds Level 2)",
+];
+
+impl Element {
+    /// Names of the capabilities set in bmElementCaps, lowest bit first; reserved bits are ignored
+    ///
+    /// ```
+    /// use cyme::usb::descriptors::audio::Element;
+    ///
+    /// let element = Element::try_from(
+    ///     [0x01, 0x01, 0x02, 0x01, 0x01, 0x00, 0x00, 0x01, 0x06, 0x00].as_slice(),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(
+    ///     element.capabilities(),
+    ///     vec!["MIDI Clock", "MTC (MIDI Time Code)"]
+    /// );
+    /// ```
+    pub fn capabilities(&self) -> Vec<&'static str> {
+        MIDI_ELEMENT_CAPABILITIES
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| self.element_caps & (1 << i) != 0)
+            .map(|(_, name)| *name)
+            .collect()
+    }
+}
+
 impl TryFrom<&[u8]> for Element {
     type Error = Error;
 
@@ -418,11 +461,27 @@ pub enum MidiInterfaceDescriptor {
     OutputJack(OutputJack),
     Element(Element),
     Endpoint(MidiEndpointDescriptor),
-    Invalid(Vec<u8>),
-    Undefined(Vec<u8>),
+    Invalid(#[serde(with = "super::raw_bytes")] Vec<u8>),
+    Undefined(#[serde(with = "super::raw_bytes")] Vec<u8>),
 }
 
 impl MidiInterfaceDescriptor {
+    /// Fills the jack and element strings from their string descriptor indexes using `lookup`
+    pub fn resolve_strings<F: FnMut(u8) -> Option<String>>(&mut self, lookup: &mut F) {
+        match self {
+            MidiInterfaceDescriptor::InputJack(mh) => {
+                mh.jack_string = lookup_string(mh.jack_string_index, lookup);
+            }
+            MidiInterfaceDescriptor::OutputJack(mh) => {
+                mh.jack_string = lookup_string(mh.jack_string_index, lookup);
+            }
+            MidiInterfaceDescriptor::Element(mh) => {
+                mh.element_string = lookup_string(mh.element_string_index, lookup);
+            }
+            _ => (),
+        }
+    }
+
     /// Try to parse the MIDI interface descriptor from the main descriptor data
     pub fn from_midi_descriptor(
         descriptor_type: &DescriptorType,
@@ -513,7 +572,7 @@ impl From<UacDescriptor> for Vec<u8> {
         let mut ret: Vec<u8> = Vec::new();
         ret.push(acd.length);
         ret.push(acd.descriptor_type);
-        ret.push(u8::from(acd.descriptor_subtype));
+        ret.push(acd.descriptor_subtype.to_uac_subtype(&acd.get_protocol()));
         let data: Vec<u8> = acd.interface.into();
         ret.extend(&data);
 
@@ -595,11 +654,11 @@ pub enum UacInterfaceDescriptor {
     DatastreamingEndpoint2(DataStreamingEndpoint2),
     DataStreamingEndpoint3(DataStreamingEndpoint3),
     /// Invalid descriptor for failing to parse matched
-    Invalid(Vec<u8>),
+    Invalid(#[serde(with = "super::raw_bytes")] Vec<u8>),
     /// Generic descriptor for known but unsupported descriptors
-    Generic(Vec<u8>),
+    Generic(#[serde(with = "super::raw_bytes")] Vec<u8>),
     /// Undefined descriptor
-    Undefined(Vec<u8>),
+    Undefined(#[serde(with = "super::raw_bytes")] Vec<u8>),
 }
 
 impl From<UacInterfaceDescriptor> for Vec<u8> {
@@ -656,6 +715,36 @@ impl From<UacInterfaceDescriptor> for Vec<u8> {
     }
 }
 
+impl UacInterfaceDescriptor {
+    /// wClusterDescrID references of UAC3 descriptors, which are resolved with [`ClusterDescriptor3`]
+    pub fn cluster_descr_ids(&self) -> Vec<u16> {
+        match self {
+            UacInterfaceDescriptor::InputTerminal3(a) => vec![a.cluster_descr_id],
+            UacInterfaceDescriptor::MixerUnit3(a) => vec![a.cluster_descr_id],
+            UacInterfaceDescriptor::ExtensionUnit3(a) => vec![a.cluster_descr_id],
+            UacInterfaceDescriptor::StreamingInterface3(a) => vec![a.cluster_descr_id],
+            UacInterfaceDescriptor::ProcessingUnit3(a) => match &a.specific {
+                Some(AudioProcessingUnit3Specific::UpDownMix(u)) => u.cluster_descr_ids.clone(),
+                Some(AudioProcessingUnit3Specific::MultiFunction(m)) => vec![m.cluster_descr_id],
+                _ => Vec::new(),
+            },
+            _ => Vec::new(),
+        }
+    }
+
+    /// wConnectorsDescrID references of UAC3 terminal descriptors, which are resolved with [`ConnectorsDescriptor3`]; zero for no connectors is not included
+    pub fn connectors_descr_ids(&self) -> Vec<u16> {
+        match self {
+            UacInterfaceDescriptor::InputTerminal3(a) => vec![a.connectors_descr_id],
+            UacInterfaceDescriptor::OutputTerminal3(a) => vec![a.connectors_descr_id],
+            _ => Vec::new(),
+        }
+        .into_iter()
+        .filter(|&id| id != 0)
+        .collect()
+    }
+}
+
 /// USB Audio Class (UAC) protocol 1 channel names based on the "wChannelConfig" field
 ///
 /// Decoded as bitstring; each bit corresponds to a channel name
@@ -838,6 +927,83 @@ impl ChannelNames {
 }
 
 impl UacInterfaceDescriptor {
+    /// Fills the string fields of UAC1 and UAC2 descriptors from their string descriptor indexes using `lookup`
+    ///
+    /// UAC3 descriptors reference class-specific string IDs rather than string descriptor indexes so are not resolved.
+    pub fn resolve_strings<F: FnMut(u8) -> Option<String>>(&mut self, lookup: &mut F) {
+        match self {
+            UacInterfaceDescriptor::InputTerminal1(ah) => {
+                ah.channel_names = lookup_string(ah.channel_names_index, lookup);
+                ah.terminal = lookup_string(ah.terminal_index, lookup);
+            }
+            UacInterfaceDescriptor::InputTerminal2(ah) => {
+                ah.channel_names = lookup_string(ah.channel_names_index, lookup);
+                ah.terminal = lookup_string(ah.terminal_index, lookup);
+            }
+            UacInterfaceDescriptor::OutputTerminal1(ah) => {
+                ah.terminal = lookup_string(ah.terminal_index, lookup);
+            }
+            UacInterfaceDescriptor::OutputTerminal2(ah) => {
+                ah.terminal = lookup_string(ah.terminal_index, lookup);
+            }
+            UacInterfaceDescriptor::StreamingInterface2(ah) => {
+                ah.channel_names = lookup_string(ah.channel_names_index, lookup);
+            }
+            UacInterfaceDescriptor::MixerUnit1(ah) => {
+                ah.channel_names = lookup_string(ah.channel_names_index, lookup);
+                ah.mixer = lookup_string(ah.mixer_index, lookup);
+            }
+            UacInterfaceDescriptor::MixerUnit2(ah) => {
+                ah.channel_names = lookup_string(ah.channel_names_index, lookup);
+                ah.mixer = lookup_string(ah.mixer_index, lookup);
+            }
+            UacInterfaceDescriptor::SelectorUnit1(ah) => {
+                ah.selector = lookup_string(ah.selector_index, lookup);
+            }
+            UacInterfaceDescriptor::SelectorUnit2(ah) => {
+                ah.selector = lookup_string(ah.selector_index, lookup);
+            }
+            UacInterfaceDescriptor::ProcessingUnit1(ah) => {
+                ah.channel_names = lookup_string(ah.channel_names_index, lookup);
+                ah.processing = lookup_string(ah.processing_index, lookup);
+            }
+            UacInterfaceDescriptor::ProcessingUnit2(ah) => {
+                ah.channel_names = lookup_string(ah.channel_names_index, lookup);
+                ah.processing = lookup_string(ah.processing_index, lookup);
+            }
+            UacInterfaceDescriptor::EffectUnit2(ah) => {
+                ah.effect = lookup_string(ah.effect_index, lookup);
+            }
+            UacInterfaceDescriptor::FeatureUnit1(ah) => {
+                ah.feature = lookup_string(ah.feature_index, lookup);
+            }
+            UacInterfaceDescriptor::FeatureUnit2(ah) => {
+                ah.feature = lookup_string(ah.feature_index, lookup);
+            }
+            UacInterfaceDescriptor::ExtensionUnit1(ah) => {
+                ah.channel_names = lookup_string(ah.channel_names_index, lookup);
+                ah.extension = lookup_string(ah.extension_index, lookup);
+            }
+            UacInterfaceDescriptor::ExtensionUnit2(ah) => {
+                ah.channel_names = lookup_string(ah.channel_names_index, lookup);
+                ah.extension = lookup_string(ah.extension_index, lookup);
+            }
+            UacInterfaceDescriptor::ClockSource2(ah) => {
+                ah.clock_source = lookup_string(ah.clock_source_index, lookup);
+            }
+            UacInterfaceDescriptor::ClockSelector2(ah) => {
+                ah.clock_selector = lookup_string(ah.clock_selector_index, lookup);
+            }
+            UacInterfaceDescriptor::ClockMultiplier2(ah) => {
+                ah.clock_multiplier = lookup_string(ah.clock_multiplier_index, lookup);
+            }
+            UacInterfaceDescriptor::SampleRateConverter2(ah) => {
+                ah.src = lookup_string(ah.src_index, lookup);
+            }
+            _ => (),
+        }
+    }
+
     /// Get the UAC AC interface descriptor from the UAC AC interface
     pub fn from_uac_ac_interface(
         uac_interface: &ControlSubtype,
@@ -1044,6 +1210,22 @@ impl UacInterfaceDescriptor {
         }
     }
 
+    /// Get the UAC AS_GENERAL interface descriptor, as opposed to the endpoint descriptor of the same subtype
+    fn from_uac_as_general_interface(protocol: &UacProtocol, data: &[u8]) -> Result<Self, Error> {
+        match protocol {
+            UacProtocol::Uac1 => {
+                StreamingInterface1::try_from(data).map(UacInterfaceDescriptor::StreamingInterface1)
+            }
+            UacProtocol::Uac2 => {
+                StreamingInterface2::try_from(data).map(UacInterfaceDescriptor::StreamingInterface2)
+            }
+            UacProtocol::Uac3 => {
+                StreamingInterface3::try_from(data).map(UacInterfaceDescriptor::StreamingInterface3)
+            }
+            _ => Ok(UacInterfaceDescriptor::Invalid(data.to_vec())),
+        }
+    }
+
     /// Get the UAC Audio Data Endpoint descriptor from the UAC AS interface
     pub fn from_uac_as_iso_data_endpoint(
         protocol: &UacProtocol,
@@ -1203,6 +1385,23 @@ impl From<UacProtocol> for u8 {
     }
 }
 
+impl std::str::FromStr for UacProtocol {
+    type Err = Error;
+
+    /// Parse "uac1", "uac2" or "uac3", case insensitive
+    fn from_str(s: &str) -> error::Result<Self> {
+        match s.to_lowercase().as_str() {
+            "uac1" => Ok(UacProtocol::Uac1),
+            "uac2" => Ok(UacProtocol::Uac2),
+            "uac3" => Ok(UacProtocol::Uac3),
+            _ => Err(Error::new(
+                ErrorKind::InvalidArg,
+                &format!("Invalid UAC version '{}'; expected uac1, uac2 or uac3", s),
+            )),
+        }
+    }
+}
+
 impl std::fmt::Display for UacProtocol {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -1214,6 +1413,54 @@ impl std::fmt::Display for UacProtocol {
     }
 }
 
+/// Audio class interface bSubClass; selects whether class descriptors are AudioControl, AudioStreaming or MIDIStreaming
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+#[serde(rename_all = "kebab-case")]
+pub enum AudioSubclass {
+    Undefined,
+    Control,
+    Streaming,
+    MidiStreaming,
+    Unknown(u8),
+}
+
+impl From<u8> for AudioSubclass {
+    fn from(b: u8) -> Self {
+        match b {
+            0x00 => AudioSubclass::Undefined,
+            0x01 => AudioSubclass::Control,
+            0x02 => AudioSubclass::Streaming,
+            0x03 => AudioSubclass::MidiStreaming,
+            b => AudioSubclass::Unknown(b),
+        }
+    }
+}
+
+impl From<AudioSubclass> for u8 {
+    fn from(asc: AudioSubclass) -> u8 {
+        match asc {
+            AudioSubclass::Undefined => 0x00,
+            AudioSubclass::Control => 0x01,
+            AudioSubclass::Streaming => 0x02,
+            AudioSubclass::MidiStreaming => 0x03,
+            AudioSubclass::Unknown(b) => b,
+        }
+    }
+}
+
+impl fmt::Display for AudioSubclass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AudioSubclass::Undefined => write!(f, "Undefined"),
+            AudioSubclass::Control => write!(f, "AudioControl"),
+            AudioSubclass::Streaming => write!(f, "AudioStreaming"),
+            AudioSubclass::MidiStreaming => write!(f, "MIDIStreaming"),
+            AudioSubclass::Unknown(b) => write!(f, "Unknown ({:#04x})", b),
+        }
+    }
+}
+
 /// USB Audio Class (UAC) subtype based on the bDescriptorSubtype
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
@@ -1231,11 +1478,35 @@ impl TryFrom<(u8, u8, u8)> for UacType {
     type Error = Error;
 
     fn try_from((sub_class, descriptor_sub, protocol): (u8, u8, u8)) -> error::Result<Self> {
-        match (sub_class, descriptor_sub, protocol) {
-            (1, d, p) => Ok(UacType::Control(ControlSubtype::get_uac_subtype(d, p))),
-            (2, d, _) => Ok(UacType::Streaming(StreamingSubtype::from(d))),
-            (3, d, _) => Ok(UacType::Midi(MidiSubtype::from(d))),
-            _ => Err(Error::new(ErrorKind::InvalidArg, "Invalid UAC subtype")),
+        match (AudioSubclass::from(sub_class), descriptor_sub, protocol) {
+            (AudioSubclass::Control, d, p) => {
+                Ok(UacType::Control(ControlSubtype::get_uac_subtype(d, p)))
+            }
+            (AudioSubclass::Streaming, d, _) => Ok(UacType::Streaming(StreamingSubtype::from(d))),
+            (AudioSubclass::MidiStreaming, d, _) => Ok(UacType::Midi(MidiSubtype::from(d))),
+            (asc, _, _) => Err(Error::new(
+                ErrorKind::InvalidArg,
+                &format!("Invalid UAC subtype for {} interface", asc),
+            )),
+        }
+    }
+}
+
+impl UacType {
+    /// bDescriptorSubtype of the subtype for `protocol`; the inverse of [`ControlSubtype::get_uac_subtype`]
+    pub fn to_uac_subtype(&self, protocol: &UacProtocol) -> u8 {
+        match self {
+            UacType::Control(aci) => aci.to_uac_subtype(protocol),
+            _ => u8::from(self.to_owned()),
+        }
+    }
+
+    /// The interface [`AudioSubclass`] this subtype belongs to
+    pub fn subclass(&self) -> AudioSubclass {
+        match self {
+            UacType::Control(_) => AudioSubclass::Control,
+            UacType::Streaming(_) => AudioSubclass::Streaming,
+            UacType::Midi(_) => AudioSubclass::MidiStreaming,
         }
     }
 }
@@ -1291,14 +1562,27 @@ impl UacType {
         gd: GenericDescriptor,
         protocol: u8,
     ) -> Result<UacInterfaceDescriptor, Error> {
+        let uacp = UacProtocol::from(protocol);
         match gd.data {
-            Some(data) => match self.get_uac_descriptor(protocol, &data) {
-                Ok(d) => Ok(d),
-                Err(e) => {
-                    log::warn!("Error parsing UVC descriptor: {}", e);
-                    Ok(UacInterfaceDescriptor::Invalid(data))
+            Some(data) => {
+                // AS_GENERAL is the subtype of both the interface and the endpoint descriptor, which the UAC1 lengths cannot tell apart; use the descriptor type when there is one
+                let parsed = match (self, gd.descriptor_type) {
+                    (UacType::Streaming(StreamingSubtype::General), 0x05 | 0x25) => {
+                        UacInterfaceDescriptor::from_uac_as_iso_data_endpoint(&uacp, &data)
+                    }
+                    (UacType::Streaming(StreamingSubtype::General), 0x04 | 0x24) => {
+                        UacInterfaceDescriptor::from_uac_as_general_interface(&uacp, &data)
+                    }
+                    _ => self.get_uac_descriptor(protocol, &data),
+                };
+                match parsed {
+                    Ok(d) => Ok(d),
+                    Err(e) => {
+                        log::warn!("Error parsing UVC descriptor: {}", e);
+                        Ok(UacInterfaceDescriptor::Invalid(data))
+                    }
                 }
-            },
+            }
             None => Err(Error::new(
                 ErrorKind::InvalidArg,
                 "GenericDescriptor data is None",
@@ -1372,6 +1656,28 @@ impl From<u8> for ControlSubtype {
 }
 
 impl ControlSubtype {
+    /// bDescriptorSubtype of the subtype for `protocol`, which UAC1 and UAC2 number differently to UAC3
+    pub fn to_uac_subtype(&self, protocol: &UacProtocol) -> u8 {
+        match (protocol, self) {
+            (UacProtocol::Uac1, ControlSubtype::MixerUnit) => 0x04,
+            (UacProtocol::Uac1, ControlSubtype::SelectorUnit) => 0x05,
+            (UacProtocol::Uac1, ControlSubtype::FeatureUnit) => 0x06,
+            (UacProtocol::Uac1, ControlSubtype::ProcessingUnit) => 0x07,
+            (UacProtocol::Uac1, ControlSubtype::ExtensionUnit) => 0x08,
+            (UacProtocol::Uac2, ControlSubtype::MixerUnit) => 0x04,
+            (UacProtocol::Uac2, ControlSubtype::SelectorUnit) => 0x05,
+            (UacProtocol::Uac2, ControlSubtype::FeatureUnit) => 0x06,
+            (UacProtocol::Uac2, ControlSubtype::EffectUnit) => 0x07,
+            (UacProtocol::Uac2, ControlSubtype::ProcessingUnit) => 0x08,
+            (UacProtocol::Uac2, ControlSubtype::ExtensionUnit) => 0x09,
+            (UacProtocol::Uac2, ControlSubtype::ClockSource) => 0x0a,
+            (UacProtocol::Uac2, ControlSubtype::ClockSelector) => 0x0b,
+            (UacProtocol::Uac2, ControlSubtype::ClockMultiplier) => 0x0c,
+            (UacProtocol::Uac2, ControlSubtype::SampleRateConverter) => 0x0d,
+            _ => self.to_owned() as u8,
+        }
+    }
+
     /// UAC1, UAC2, and UAC3 define bDescriptorSubtype differently for the
     /// AudioControl interface, so we need to do some ugly remapping:
     pub fn get_uac_subtype(subtype: u8, protocol: u8) -> Self {
@@ -1460,6 +1766,7 @@ impl StreamingSubtype {
     }
 }
 
+/// Audio Data Formats bFormatType of a FORMAT_TYPE AS interface descriptor; alternate `Display` is the lsusb name
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 #[repr(u8)]
@@ -1557,8 +1864,8 @@ pub enum StreamingFormatInterface {
     FormatTypeIII2(FormatTypeIII2),
     FormatSpecificMpeg(FormatSpecificMpeg),
     FormatSpecificAc3(FormatSpecificAc3),
-    Invalid(Vec<u8>),
-    Undefined(Vec<u8>),
+    Invalid(#[serde(with = "super::raw_bytes")] Vec<u8>),
+    Undefined(#[serde(with = "super::raw_bytes")] Vec<u8>),
 }
 
 impl From<StreamingFormatInterface> for Vec<u8> {
@@ -2634,9 +2941,11 @@ pub struct MixerUnit1 {
     pub source_ids: Vec<u8>,
     pub nr_channels: u8,
     pub channel_config: u16,
-    pub channel_names: u8,
+    pub channel_names_index: u8,
+    pub channel_names: Option<String>,
     pub controls: Vec<u8>,
-    pub mixer: u8,
+    pub mixer_index: u8,
+    pub mixer: Option<String>,
 }
 
 impl TryFrom<&[u8]> for MixerUnit1 {
@@ -2648,8 +2957,8 @@ impl TryFrom<&[u8]> for MixerUnit1 {
         }
 
         let nr_in_pins = value[1] as usize;
-        let nr_channels = value[3] as usize;
-        let expected_len = 6 + nr_in_pins + nr_channels;
+        // bmControls is a bit per input and output channel pair, so fills the space up to iMixer
+        let expected_len = 7 + nr_in_pins;
         if value.len() < expected_len {
             return Err(Error::new(
                 ErrorKind::InvalidDescriptor,
@@ -2663,9 +2972,11 @@ impl TryFrom<&[u8]> for MixerUnit1 {
             source_ids: value[2..2 + nr_in_pins].to_vec(),
             nr_channels: value[2 + nr_in_pins],
             channel_config: u16::from_le_bytes([value[3 + nr_in_pins], value[4 + nr_in_pins]]),
-            channel_names: value[5 + nr_in_pins],
-            controls: value[6 + nr_in_pins..6 + nr_in_pins + nr_channels].to_vec(),
-            mixer: value[6 + nr_in_pins + nr_channels],
+            channel_names_index: value[5 + nr_in_pins],
+            channel_names: None,
+            controls: value[6 + nr_in_pins..value.len() - 1].to_vec(),
+            mixer_index: value[value.len() - 1],
+            mixer: None,
         })
     }
 }
@@ -2678,9 +2989,9 @@ impl From<MixerUnit1> for Vec<u8> {
         data.extend_from_slice(&val.source_ids);
         data.push(val.nr_channels);
         data.extend_from_slice(&val.channel_config.to_le_bytes());
-        data.push(val.channel_names);
+        data.push(val.channel_names_index);
         data.extend_from_slice(&val.controls);
-        data.push(val.mixer);
+        data.push(val.mixer_index);
         data
     }
 }
@@ -2694,10 +3005,12 @@ pub struct MixerUnit2 {
     pub source_ids: Vec<u8>,
     pub nr_channels: u8,
     pub channel_config: u32,
-    pub channel_names: u8,
+    pub channel_names_index: u8,
+    pub channel_names: Option<String>,
     pub mixer_controls: Vec<u8>,
     pub controls: u8,
-    pub mixer: u8,
+    pub mixer_index: u8,
+    pub mixer: Option<String>,
 }
 
 impl TryFrom<&[u8]> for MixerUnit2 {
@@ -2729,10 +3042,12 @@ impl TryFrom<&[u8]> for MixerUnit2 {
                 value[5 + nr_in_pins],
                 value[6 + nr_in_pins],
             ]),
-            channel_names: value[7 + nr_in_pins],
+            channel_names_index: value[7 + nr_in_pins],
+            channel_names: None,
             mixer_controls: value[8 + nr_in_pins..8 + nr_in_pins + nr_channels].to_vec(),
             controls: value[8 + nr_in_pins + nr_channels],
-            mixer: value[9 + nr_in_pins + nr_channels],
+            mixer_index: value[9 + nr_in_pins + nr_channels],
+            mixer: None,
         })
     }
 }
@@ -2745,10 +3060,10 @@ impl From<MixerUnit2> for Vec<u8> {
         data.extend_from_slice(&val.source_ids);
         data.push(val.nr_channels);
         data.extend_from_slice(&val.channel_config.to_le_bytes());
-        data.push(val.channel_names);
+        data.push(val.channel_names_index);
         data.extend_from_slice(&val.mixer_controls);
         data.push(val.controls);
-        data.push(val.mixer);
+        data.push(val.mixer_index);
         data
     }
 }
@@ -3364,22 +3679,44 @@ impl From<(UacProtocol, u16)> for AudioProcessingUnitType {
     }
 }
 
-impl fmt::Display for AudioProcessingUnitType {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+impl AudioProcessingUnitType {
+    /// Name of the processing unit type as shown by lsusb
+    pub fn name(&self) -> &'static str {
         match self {
-            AudioProcessingUnitType::Undefined => write!(f, "Undefined"),
-            AudioProcessingUnitType::UpDownMix => write!(f, "Up/Down-mix"),
-            AudioProcessingUnitType::DolbyPrologic => write!(f, "Dolby Prologic"),
-            AudioProcessingUnitType::StereoExtender3d => write!(f, "3D Stereo Extender"),
-            AudioProcessingUnitType::StereoExtender => write!(f, "Stereo Extender"),
-            AudioProcessingUnitType::Reverberation => write!(f, "Reverberation"),
-            AudioProcessingUnitType::Chorus => write!(f, "Chorus"),
-            AudioProcessingUnitType::DynRangeComp => write!(f, "Dyn Range Comp"),
-            AudioProcessingUnitType::MultiFunction => write!(f, "Multi-Function"),
+            AudioProcessingUnitType::Undefined => "Undefined",
+            AudioProcessingUnitType::UpDownMix => "Up/Down-mix",
+            AudioProcessingUnitType::DolbyPrologic => "Dolby Prologic",
+            AudioProcessingUnitType::StereoExtender3d => "3D Stereo Extender",
+            AudioProcessingUnitType::StereoExtender => "Stereo Extender",
+            AudioProcessingUnitType::Reverberation => "Reverberation",
+            AudioProcessingUnitType::Chorus => "Chorus",
+            AudioProcessingUnitType::DynRangeComp => "Dyn Range Comp",
+            AudioProcessingUnitType::MultiFunction => "Multi-Function",
         }
     }
 }
 
+impl fmt::Display for AudioProcessingUnitType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Name of a Processing Unit wProcessType for the UAC `protocol`; UAC1 A.7, UAC2 A.15 and UAC3 A.12 Processing Unit Process Types
+///
+/// The same value maps to different processes between versions so the protocol is required. Unknown values return "Undefined".
+///
+/// ```
+/// use cyme::usb::descriptors::audio::{processing_type_name, UacProtocol};
+/// assert_eq!(processing_type_name(2, UacProtocol::Uac1), "Dolby Prologic");
+/// assert_eq!(processing_type_name(2, UacProtocol::Uac3), "Stereo Extender");
+/// assert_eq!(processing_type_name(3, UacProtocol::Uac1), "3D Stereo Extender");
+/// assert_eq!(processing_type_name(0xff, UacProtocol::Uac2), "Undefined");
+/// ```
+pub fn processing_type_name(process_type: u16, protocol: UacProtocol) -> &'static str {
+    AudioProcessingUnitType::from((protocol, process_type)).name()
+}
+
 /// UAC1: Up/Down-mix and Dolby Prologic proc unit descriptor extensions Table 4-9, Table 4-10.
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 #[allow(missing_docs)]
@@ -4134,6 +4471,7 @@ pub struct FeatureUnit1 {
     pub unit_id: u8,
     pub source_id: u8,
     pub control_size: u8,
+    /// bmaControls for the master channel followed by each logical channel; `control_size` bytes each
     pub controls: Vec<u8>,
     pub feature_index: u8,
     pub feature: Option<String>,
@@ -4156,14 +4494,15 @@ impl TryFrom<&[u8]> for FeatureUnit1 {
             ));
         }
 
-        let controls = value[3..(3 + control_size as usize)].to_vec();
+        // master channel and each logical channel up to iFeature
+        let controls = value[3..value.len() - 1].to_vec();
 
         Ok(FeatureUnit1 {
             unit_id: value[0],
             source_id: value[1],
             control_size,
             controls,
-            feature_index: value[expected_length - 1],
+            feature_index: value[value.len() - 1],
             feature: None,
         })
     }
@@ -4187,7 +4526,8 @@ impl From<FeatureUnit1> for Vec<u8> {
 pub struct FeatureUnit2 {
     pub unit_id: u8,
     pub source_id: u8,
-    pub controls: [u8; 4],
+    /// bmaControls for the master channel followed by each logical channel; 2 bits per control
+    pub controls: Vec<u32>,
     pub feature_index: u8,
     pub feature: Option<String>,
 }
@@ -4200,11 +4540,24 @@ impl TryFrom<&[u8]> for FeatureUnit2 {
             return Err(Error::new_descriptor_len("FeatureUnit2", 7, value.len()));
         }
 
+        let controls_bytes = &value[2..value.len() - 1];
+        if controls_bytes.len() % 4 != 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidDescriptor,
+                "Audio Feature Unit 2 bmaControls are not a multiple of 4 bytes",
+            ));
+        }
+
+        let controls = controls_bytes
+            .chunks_exact(4)
+            .map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+            .collect();
+
         Ok(FeatureUnit2 {
             unit_id: value[0],
             source_id: value[1],
-            controls: value[2..6].try_into().unwrap(),
-            feature_index: value[7],
+            controls,
+            feature_index: value[value.len() - 1],
             feature: None,
         })
     }
@@ -4215,7 +4568,9 @@ impl From<FeatureUnit2> for Vec<u8> {
         let mut data = Vec::new();
         data.push(val.unit_id);
         data.push(val.source_id);
-        data.extend_from_slice(&val.controls);
+        for control in val.controls {
+            data.extend_from_slice(&control.to_le_bytes());
+        }
         data.push(val.feature_index);
         data
     }
@@ -4227,7 +4582,8 @@ impl From<FeatureUnit2> for Vec<u8> {
 pub struct FeatureUnit3 {
     pub unit_id: u8,
     pub source_id: u8,
-    pub controls: [u8; 4],
+    /// bmaControls for the master channel followed by each logical channel; 2 bits per control
+    pub controls: Vec<u32>,
     pub feature_descr_str: u16,
 }
 
@@ -4239,11 +4595,24 @@ impl TryFrom<&[u8]> for FeatureUnit3 {
             return Err(Error::new_descriptor_len("FeatureUnit3", 8, value.len()));
         }
 
+        let controls_bytes = &value[2..value.len() - 2];
+        if controls_bytes.len() % 4 != 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidDescriptor,
+                "Audio Feature Unit 3 bmaControls are not a multiple of 4 bytes",
+            ));
+        }
+
+        let controls = controls_bytes
+            .chunks_exact(4)
+            .map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+            .collect();
+
         Ok(FeatureUnit3 {
             unit_id: value[0],
             source_id: value[1],
-            controls: value[2..6].try_into().unwrap(),
-            feature_descr_str: u16::from_le_bytes([value[6], value[7]]),
+            controls,
+            feature_descr_str: u16::from_le_bytes([value[value.len() - 2], value[value.len() - 1]]),
         })
     }
 }
@@ -4253,7 +4622,9 @@ impl From<FeatureUnit3> for Vec<u8> {
         let mut data = Vec::new();
         data.push(val.unit_id);
         data.push(val.source_id);
-        data.extend_from_slice(&val.controls);
+        for control in val.controls {
+            data.extend_from_slice(&control.to_le_bytes());
+        }
         data.extend_from_slice(&val.feature_descr_str.to_le_bytes());
         data
     }
@@ -4573,7 +4944,8 @@ impl TryFrom<&[u8]> for ClockSelector2 {
         }
 
         let nr_in_pins = value[1] as usize;
-        let expected_length = 3 + nr_in_pins;
+        // bClockID, bNrInPins, baCSourceID, bmControls, iClockSelector
+        let expected_length = 4 + nr_in_pins;
         if value.len() < expected_length {
             return Err(Error::new(
                 ErrorKind::InvalidDescriptor,
@@ -4626,7 +4998,8 @@ impl TryFrom<&[u8]> for ClockSelector3 {
         }
 
         let nr_in_pins = value[1] as usize;
-        let expected_length = 5 + nr_in_pins;
+        // bClockID, bNrInPins, baCSourceID, bmControls, wCSelectorDescrStr
+        let expected_length = 8 + nr_in_pins;
         if value.len() < expected_length {
             return Err(Error::new(
                 ErrorKind::InvalidDescriptor,
@@ -4845,3 +5218,378 @@ impl From<SampleRateConverter3> for Vec<u8> {
         data
     }
 }
+
+/// UAC3: 4.3.1 High Capability Cluster Descriptor; Table 4-2.
+///
+/// Not part of the configuration descriptor; retrieved from the AudioControl interface with a class-specific request using the wClusterDescrID referenced by other UAC3 descriptors.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClusterDescriptor3 {
+    /// Total length of the descriptor including segments
+    pub length: u16,
+    /// CS_CLUSTER descriptor type
+    pub descriptor_type: u8,
+    /// SUBTYPE_UNDEFINED
+    pub descriptor_subtype: u8,
+    /// wClusterDescrID this descriptor resolves
+    pub descriptor_id: u16,
+    /// Number of logical channels in the cluster
+    pub nr_channels: u8,
+    /// Segments describing the cluster and each channel in order
+    pub segments: Vec<ClusterSegment3>,
+}
+
+/// UAC3: 4.3.1.1 Cluster Descriptor Segment; Table 4-3.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClusterSegment3 {
+    /// Length of the segment including header
+    pub length: u16,
+    /// bSegmentType
+    pub segment_type: u8,
+    /// Segment specific data following the header
+    #[serde(
+        default,
+        skip_serializing_if = "super::raw_bytes::omit",
+        with = "super::raw_bytes"
+    )]
+    pub data: Vec<u8>,
+}
+
+impl ClusterSegment3 {
+    /// CHANNEL_INFORMATION segment type
+    pub const CHANNEL_INFORMATION: u8 = 0x20;
+    /// END_SEGMENT type terminating the segments of a channel
+    pub const END_SEGMENT: u8 = 0xff;
+}
+
+impl TryFrom<&[u8]> for ClusterDescriptor3 {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> error::Result<Self> {
+        if value.len() < 7 {
+            return Err(Error::new_descriptor_len(
+                "ClusterDescriptor3",
+                7,
+                value.len(),
+            ));
+        }
+
+        let length = u16::from_le_bytes([value[0], value[1]]);
+        let end = (length as usize).min(value.len());
+        let mut segments = Vec::new();
+        let mut remaining = &value[7..end];
+        while remaining.len() >= 3 {
+            let seg_len = u16::from_le_bytes([remaining[0], remaining[1]]) as usize;
+            if seg_len < 3 || seg_len > remaining.len() {
+                break;
+            }
+            segments.push(ClusterSegment3 {
+                length: seg_len as u16,
+                segment_type: remaining[2],
+                data: remaining[3..seg_len].to_vec(),
+            });
+            remaining = &remaining[seg_len..];
+        }
+
+        Ok(ClusterDescriptor3 {
+            length,
+            descriptor_type: value[2],
+            descriptor_subtype: value[3],
+            descriptor_id: u16::from_le_bytes([value[4], value[5]]),
+            nr_channels: value[6],
+            segments,
+        })
+    }
+}
+
+impl From<ClusterDescriptor3> for Vec<u8> {
+    fn from(val: ClusterDescriptor3) -> Self {
+        let mut data = Vec::new();
+        data.extend_from_slice(&val.length.to_le_bytes());
+        data.push(val.descriptor_type);
+        data.push(val.descriptor_subtype);
+        data.extend_from_slice(&val.descriptor_id.to_le_bytes());
+        data.push(val.nr_channels);
+        for segment in val.segments {
+            data.extend_from_slice(&segment.length.to_le_bytes());
+            data.push(segment.segment_type);
+            data.extend(segment.data);
+        }
+        data
+    }
+}
+
+impl ClusterDescriptor3 {
+    /// bChRelationship of each channel from the CHANNEL_INFORMATION segments, in channel order
+    ///
+    /// ```
+    /// use cyme::usb::descriptors::audio::ClusterDescriptor3;
+    ///
+    /// let cluster = ClusterDescriptor3::try_from([
+    ///     0x19, 0x00, 0x26, 0x00, 0x01, 0x00, 0x02,
+    ///     0x06, 0x00, 0x20, 0x00, 0x80, 0x00, 0x03, 0x00, 0xff,
+    ///     0x06, 0x00, 0x20, 0x00, 0x81, 0x00, 0x03, 0x00, 0xff,
+    /// ].as_slice()).unwrap();
+    /// assert_eq!(cluster.descriptor_id, 1);
+    /// assert_eq!(cluster.channel_relationships(), vec![0x80, 0x81]);
+    /// assert_eq!(cluster.channel_names(), vec!["Front Left", "Front Right"]);
+    /// ```
+    pub fn channel_relationships(&self) -> Vec<u8> {
+        self.segments
+            .iter()
+            .filter(|s| s.segment_type == ClusterSegment3::CHANNEL_INFORMATION)
+            .filter_map(|s| s.data.get(1).copied())
+            .collect()
+    }
+
+    /// Names of each channel from bChRelationship
+    pub fn channel_names(&self) -> Vec<&'static str> {
+        self.channel_relationships()
+            .into_iter()
+            .map(uac3_channel_relationship)
+            .collect()
+    }
+}
+
+/// UAC3: A.9 Channel Relationship Codes; name of a bChRelationship
+pub fn uac3_channel_relationship(relationship: u8) -> &'static str {
+    match relationship {
+        0x00 => "Undefined",
+        0x01 => "Mono",
+        0x02 => "Left",
+        0x03 => "Right",
+        0x04 => "Array",
+        0x20 => "Pattern X",
+        0x21 => "Pattern Y",
+        0x22 => "Pattern A",
+        0x23 => "Pattern B",
+        0x24 => "Pattern M",
+        0x25 => "Pattern S",
+        0x80 => "Front Left",
+        0x81 => "Front Right",
+        0x82 => "Front Center",
+        0x83 => "Front Left of Center",
+        0x84 => "Front Right of Center",
+        0x85 => "Front Wide Left",
+        0x86 => "Front Wide Right",
+        0x87 => "Side Left",
+        0x88 => "Side Right",
+        0x89 => "Surround Array Left",
+        0x8a => "Surround Array Right",
+        0x8b => "Back Left",
+        0x8c => "Back Right",
+        0x8d => "Back Center",
+        0x8e => "Back Left of Center",
+        0x8f => "Back Right of Center",
+        0x90 => "Back Wide Left",
+        0x91 => "Back Wide Right",
+        0x92 => "Top Center",
+        0x93 => "Top Front Left",
+        0x94 => "Top Front Right",
+        0x95 => "Top Front Center",
+        0x96 => "Top Front Left of Center",
+        0x97 => "Top Front Right of Center",
+        0x98 => "Top Front Wide Left",
+        0x99 => "Top Front Wide Right",
+        0x9a => "Top Side Left",
+        0x9b => "Top Side Right",
+        0x9c => "Top Surround Array Left",
+        0x9d => "Top Surround Array Right",
+        0x9e => "Top Back Left",
+        0x9f => "Top Back Right",
+        0xa0 => "Top Back Center",
+        0xa1 => "Top Back Left of Center",
+        0xa2 => "Top Back Right of Center",
+        0xa3 => "Top Back Wide Left",
+        0xa4 => "Top Back Wide Right",
+        0xa5 => "Bottom Center",
+        0xa6 => "Bottom Front Left",
+        0xa7 => "Bottom Front Right",
+        0xa8 => "Bottom Front Center",
+        0xa9 => "Bottom Front Left of Center",
+        0xaa => "Bottom Front Right of Center",
+        0xab => "Bottom Front Wide Left",
+        0xac => "Bottom Front Wide Right",
+        0xad => "Bottom Side Left",
+        0xae => "Bottom Side Right",
+        0xaf => "Bottom Surround Array Left",
+        0xb0 => "Bottom Surround Array Right",
+        0xb1 => "Bottom Back Left",
+        0xb2 => "Bottom Back Right",
+        0xb3 => "Bottom Back Center",
+        0xb4 => "Bottom Back Left of Center",
+        0xb5 => "Bottom Back Right of Center",
+        0xb6 => "Bottom Back Wide Left",
+        0xb7 => "Bottom Back Wide Right",
+        0xb8 => "Low Frequency Effects",
+        0xb9 => "LFE Left",
+        0xba => "LFE Right",
+        0xbb => "Headphone Left",
+        0xbc => "Headphone Right",
+        _ => "Reserved",
+    }
+}
+
+/// Bit 24 of a UAC3 connector dwConColor; set when the connector has no defined colour
+pub const CONNECTOR_COLOR_UNDEFINED: u32 = 1 << 24;
+
+/// UAC3 Connectors Descriptor; name of a dwConColor RGB value with the PC 99 jack use for the colour codes jacks follow
+///
+/// None for an undefined colour or one without a name; see [`connector_color_string`] for the hex fallback.
+///
+/// ```
+/// use cyme::usb::descriptors::audio::{connector_color_name, CONNECTOR_COLOR_UNDEFINED};
+///
+/// assert_eq!(connector_color_name(0x00ff00), Some("Green (line out)"));
+/// assert_eq!(connector_color_name(0x123456), None);
+/// assert_eq!(connector_color_name(CONNECTOR_COLOR_UNDEFINED | 0x00ff00), None);
+/// ```
+pub fn connector_color_name(value: u32) -> Option<&'static str> {
+    if value & CONNECTOR_COLOR_UNDEFINED != 0 {
+        return None;
+    }
+
+    match value & 0x00ff_ffff {
+        0x000000 => Some("Black (rear surround)"),
+        0x808080 => Some("Grey (side surround)"),
+        0x0000ff => Some("Blue (line in)"),
+        0x00ff00 => Some("Green (line out)"),
+        0xffa500 => Some("Orange (centre/subwoofer)"),
+        0xffc0cb => Some("Pink (microphone)"),
+        0xffd700 => Some("Gold (game port/MIDI)"),
+        0xff0000 => Some("Red (right channel)"),
+        0xffffff => Some("White (left channel)"),
+        0xffff00 => Some("Yellow (video)"),
+        _ => None,
+    }
+}
+
+/// dwConColor as the [`connector_color_name`] or otherwise the RGB hex
+///
+/// ```
+/// use cyme::usb::descriptors::audio::{connector_color_string, CONNECTOR_COLOR_UNDEFINED};
+///
+/// assert_eq!(connector_color_string(0xffc0cb), "Pink (microphone)");
+/// assert_eq!(connector_color_string(0x123456), "#123456");
+/// assert_eq!(connector_color_string(CONNECTOR_COLOR_UNDEFINED), "Undefined");
+/// ```
+pub fn connector_color_string(value: u32) -> String {
+    if value & CONNECTOR_COLOR_UNDEFINED != 0 {
+        return String::from("Undefined");
+    }
+
+    connector_color_name(value)
+        .map(String::from)
+        .unwrap_or_else(|| format!("#{:06x}", value & 0x00ff_ffff))
+}
+
+/// UAC3: 4.5.2.4 High Capability Connectors Descriptor.
+///
+/// Not part of the configuration descriptor; retrieved from the AudioControl interface with a class-specific request using the wConnectorsDescrID referenced by UAC3 terminal descriptors.
+///
+/// ```
+/// use cyme::usb::descriptors::audio::ConnectorsDescriptor3;
+///
+/// let connectors = ConnectorsDescriptor3::try_from([
+///     0x12, 0x00, 0x24, 0x0f, 0x02, 0x00, 0x01,
+///     0x01, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0xff, 0x00, 0x00,
+/// ].as_slice()).unwrap();
+/// assert_eq!(connectors.descriptor_id, 2);
+/// assert_eq!(connectors.connectors.len(), 1);
+/// assert_eq!(connectors.connectors[0].con_color, 0x00ff00);
+/// assert_eq!(Vec::<u8>::from(connectors).len(), 0x12);
+/// ```
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConnectorsDescriptor3 {
+    /// Total length of the descriptor including connectors
+    pub length: u16,
+    /// CS_INTERFACE descriptor type
+    pub descriptor_type: u8,
+    /// CONNECTORS descriptor subtype
+    pub descriptor_subtype: u8,
+    /// wConnectorsDescrID this descriptor resolves
+    pub descriptor_id: u16,
+    /// Number of connectors
+    pub nr_connectors: u8,
+    /// Connectors in order
+    pub connectors: Vec<Connector3>,
+}
+
+/// A connector within a [`ConnectorsDescriptor3`]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Connector3 {
+    /// baConID; ID of the connector within the descriptor
+    pub con_id: u8,
+    /// wClusterDescrID of the channels carried by the connector
+    pub cluster_descr_id: u16,
+    /// bConType
+    pub con_type: u8,
+    /// bmConAttributes
+    pub con_attributes: u8,
+    /// wConDescrStr
+    pub con_descr_str: u16,
+    /// dwConColor; RGB value with [`CONNECTOR_COLOR_UNDEFINED`] set if the connector has no defined colour
+    pub con_color: u32,
+}
+
+impl Connector3 {
+    /// Length of each connector within the descriptor
+    pub const LENGTH: usize = 11;
+}
+
+impl TryFrom<&[u8]> for ConnectorsDescriptor3 {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> error::Result<Self> {
+        if value.len() < 7 {
+            return Err(Error::new_descriptor_len(
+                "ConnectorsDescriptor3",
+                7,
+                value.len(),
+            ));
+        }
+
+        let length = u16::from_le_bytes([value[0], value[1]]);
+        let end = (length as usize).min(value.len());
+        let connectors = value[7..end]
+            .chunks_exact(Connector3::LENGTH)
+            .take(value[6] as usize)
+            .map(|c| Connector3 {
+                con_id: c[0],
+                cluster_descr_id: u16::from_le_bytes([c[1], c[2]]),
+                con_type: c[3],
+                con_attributes: c[4],
+                con_descr_str: u16::from_le_bytes([c[5], c[6]]),
+                con_color: u32::from_le_bytes([c[7], c[8], c[9], c[10]]),
+            })
+            .collect();
+
+        Ok(ConnectorsDescriptor3 {
+            length,
+            descriptor_type: value[2],
+            descriptor_subtype: value[3],
+            descriptor_id: u16::from_le_bytes([value[4], value[5]]),
+            nr_connectors: value[6],
+            connectors,
+        })
+    }
+}
+
+impl From<ConnectorsDescriptor3> for Vec<u8> {
+    fn from(val: ConnectorsDescriptor3) -> Self {
+        let mut data = Vec::new();
+        data.extend_from_slice(&val.length.to_le_bytes());
+        data.push(val.descriptor_type);
+        data.push(val.descriptor_subtype);
+        data.extend_from_slice(&val.descriptor_id.to_le_bytes());
+        data.push(val.nr_connectors);
+        for connector in val.connectors {
+            data.push(connector.con_id);
+            data.extend_from_slice(&connector.cluster_descr_id.to_le_bytes());
+            data.push(connector.con_type);
+            data.push(connector.con_attributes);
+            data.extend_from_slice(&connector.con_descr_str.to_le_bytes());
+            data.extend_from_slice(&connector.con_color.to_le_bytes());
+        }
+        data
+    }
+}
@@ -73,6 +73,8 @@ pub enum BosCapability {
     ContainerId(ContainerIdCapability),
     Platform(PlatformDeviceCompatibility),
     WebUsbPlatform(WebUsbPlatformCapability),
+    /// Capability type not defined by the specification with the raw payload following bDevCapabilityType
+    Unknown(u8, #[serde(with = "super::raw_bytes")] Vec<u8>),
 }
 
 impl TryFrom<&[u8]> for BosCapability {
@@ -83,11 +85,10 @@ impl TryFrom<&[u8]> for BosCapability {
             return Err(Error::new_descriptor_len("BosCapability", 3, value.len()));
         }
 
+        check_descriptor_type("BosCapability", value[1], &[0x10])?;
+
         match value[2].into() {
-            BosType::Unknown(_) => Err(Error::new(
-                ErrorKind::InvalidArg,
-                "BOS capability descriptor has unknown capability type",
-            )),
+            BosType::Unknown(t) => Ok(BosCapability::Unknown(t, value[3..].to_vec())),
             BosType::Usb2Extension => Ok(BosCapability::Usb2Extension(
                 ExtensionCapability::try_from(value)?,
             )),
@@ -138,6 +139,11 @@ impl From<BosCapability> for Vec<u8> {
             BosCapability::ContainerId(cic) => Vec::<u8>::from(cic),
             BosCapability::Platform(pdc) => Vec::<u8>::from(pdc),
             BosCapability::WebUsbPlatform(wpc) => Vec::<u8>::from(wpc),
+            BosCapability::Unknown(t, data) => {
+                let mut ret = vec![(data.len() + 3) as u8, 0x10, t];
+                ret.extend(data);
+                ret
+            }
         }
     }
 }
@@ -164,6 +170,8 @@ impl TryFrom<&[u8]> for BinaryObjectStoreDescriptor {
             ));
         }
 
+        check_descriptor_type("BinaryObjectStoreDescriptor", value[1], &[0x0f])?;
+
         let length = value[0];
         let descriptor_type = value[1];
         let total_length = u16::from_le_bytes([value[2], value[3]]);
@@ -234,6 +242,7 @@ pub struct GenericCapability {
     pub length: u8,
     pub descriptor_type: u8,
     pub capability_type: BosType,
+    #[serde(default, with = "super::raw_bytes")]
     pub data: Vec<u8>,
 }
 
@@ -278,6 +287,11 @@ pub struct PlatformDeviceCompatibility {
     pub compatibility_type: u8,
     pub reserved: u8,
     pub guid: Uuid,
+    #[serde(
+        default,
+        skip_serializing_if = "super::raw_bytes::omit",
+        with = "super::raw_bytes"
+    )]
     pub data: Vec<u8>,
 }
 
@@ -462,6 +476,82 @@ impl From<SuperSpeedCapability> for Vec<u8> {
     }
 }
 
+impl SuperSpeedCapability {
+    /// Bit 1 of bmAttributes; the device is capable of generating Latency Tolerance Messages (LTM)
+    pub fn ltm_supported(&self) -> bool {
+        self.attributes & 0x02 != 0
+    }
+
+    /// Speeds set in the wSpeedsSupported bitmap, slowest first
+    pub fn speeds_supported(&self) -> Vec<Speed> {
+        [
+            Speed::LowSpeed,
+            Speed::FullSpeed,
+            Speed::HighSpeed,
+            Speed::SuperSpeed,
+        ]
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| self.speed_supported & (1 << i) != 0)
+        .map(|(_, s)| s)
+        .collect()
+    }
+
+    /// Worst case bU1DevExitLat in microseconds; None if the value is reserved (above 10)
+    pub fn u1_exit_latency_us(&self) -> Option<u8> {
+        (self.u1_device_exit_latency <= 0x0a).then_some(self.u1_device_exit_latency)
+    }
+
+    /// Worst case wU2DevExitLat in microseconds; None if the value is reserved (above 2047)
+    pub fn u2_exit_latency_us(&self) -> Option<u16> {
+        (self.u2_device_exit_latency <= 0x07ff).then_some(self.u2_device_exit_latency)
+    }
+}
+
+/// Lists the supported speeds, LTM support and U1/U2 exit latencies
+///
+/// ```
+/// # use cyme::usb::descriptors::bos::*;
+/// # use cyme::usb::Speed;
+/// let ssc = SuperSpeedCapability::try_from(
+///     [0x0a, 0x10, 0x03, 0x02, 0x0e, 0x00, 0x01, 0x0a, 0xff, 0x07].as_slice(),
+/// )
+/// .unwrap();
+/// assert!(ssc.ltm_supported());
+/// assert_eq!(ssc.speeds_supported(), vec![Speed::FullSpeed, Speed::HighSpeed, Speed::SuperSpeed]);
+/// assert_eq!(ssc.u2_exit_latency_us(), Some(2047));
+/// assert_eq!(
+///     ssc.to_string(),
+///     "speeds Full Speed, High Speed, SuperSpeed; LTM supported; U1 exit latency 10 us; U2 exit latency 2047 us"
+/// );
+/// ```
+impl fmt::Display for SuperSpeedCapability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "speeds {}; LTM {}",
+            self.speeds_supported()
+                .iter()
+                .map(|s| s.name())
+                .collect::<Vec<&str>>()
+                .join(", "),
+            if self.ltm_supported() {
+                "supported"
+            } else {
+                "not supported"
+            }
+        )?;
+        match self.u1_exit_latency_us() {
+            Some(us) => write!(f, "; U1 exit latency {} us", us)?,
+            None => write!(f, "; U1 exit latency reserved")?,
+        }
+        match self.u2_exit_latency_us() {
+            Some(us) => write!(f, "; U2 exit latency {} us", us),
+            None => write!(f, "; U2 exit latency reserved"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct SuperSpeedPlusCapability {
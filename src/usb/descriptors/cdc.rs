@@ -176,6 +176,27 @@ impl TryFrom<&[u8]> for CallManagement {
     }
 }
 
+impl CallManagement {
+    /// Device handles call management itself (D0 of bmCapabilities)
+    ///
+    /// ```
+    /// use cyme::usb::descriptors::cdc::CallManagement;
+    ///
+    /// let cm = CallManagement::try_from([0x03, 0x01].as_slice()).unwrap();
+    /// assert!(cm.handles_call_management());
+    /// assert!(cm.over_data_interface());
+    /// assert_eq!(cm.data_interface, 1);
+    /// ```
+    pub fn handles_call_management(&self) -> bool {
+        self.capabilities & 0x01 != 0
+    }
+
+    /// Call management can be sent and received over the Data Class interface rather than only the Communications Class interface (D1 of bmCapabilities)
+    pub fn over_data_interface(&self) -> bool {
+        self.capabilities & 0x02 != 0
+    }
+}
+
 impl From<CallManagement> for Vec<u8> {
     fn from(cm: CallManagement) -> Self {
         vec![cm.capabilities, cm.data_interface]
@@ -206,6 +227,38 @@ impl TryFrom<&[u8]> for AbstractControlManagement {
     }
 }
 
+impl AbstractControlManagement {
+    /// Supports Set_Comm_Feature, Clear_Comm_Feature and Get_Comm_Feature (D0 of bmCapabilities)
+    ///
+    /// ```
+    /// use cyme::usb::descriptors::cdc::AbstractControlManagement;
+    ///
+    /// let acm = AbstractControlManagement::try_from([0x06].as_slice()).unwrap();
+    /// assert!(!acm.comm_features());
+    /// assert!(acm.line_coding());
+    /// assert!(acm.send_break());
+    /// assert!(!acm.network_connection());
+    /// ```
+    pub fn comm_features(&self) -> bool {
+        self.capabilities & 0x01 != 0
+    }
+
+    /// Supports Set_Line_Coding, Set_Control_Line_State, Get_Line_Coding and the Serial_State notification (D1 of bmCapabilities)
+    pub fn line_coding(&self) -> bool {
+        self.capabilities & 0x02 != 0
+    }
+
+    /// Supports Send_Break (D2 of bmCapabilities)
+    pub fn send_break(&self) -> bool {
+        self.capabilities & 0x04 != 0
+    }
+
+    /// Supports the Network_Connection notification (D3 of bmCapabilities)
+    pub fn network_connection(&self) -> bool {
+        self.capabilities & 0x08 != 0
+    }
+}
+
 impl From<AbstractControlManagement> for Vec<u8> {
     fn from(acm: AbstractControlManagement) -> Self {
         vec![acm.capabilities]
@@ -312,6 +365,32 @@ impl TryFrom<&[u8]> for TelephoneOperations {
     }
 }
 
+impl TelephoneOperations {
+    /// Supports Simple mode (D0 of bmCapabilities)
+    ///
+    /// ```
+    /// use cyme::usb::descriptors::cdc::TelephoneOperations;
+    ///
+    /// let to = TelephoneOperations::try_from([0x05].as_slice()).unwrap();
+    /// assert!(to.simple_mode());
+    /// assert!(!to.standalone_mode());
+    /// assert!(to.computer_centric_mode());
+    /// ```
+    pub fn simple_mode(&self) -> bool {
+        self.capabilities & 0x01 != 0
+    }
+
+    /// Supports Standalone mode (D1 of bmCapabilities)
+    pub fn standalone_mode(&self) -> bool {
+        self.capabilities & 0x02 != 0
+    }
+
+    /// Supports Computer Centric mode (D2 of bmCapabilities)
+    pub fn computer_centric_mode(&self) -> bool {
+        self.capabilities & 0x04 != 0
+    }
+}
+
 impl From<TelephoneOperations> for Vec<u8> {
     fn from(to: TelephoneOperations) -> Self {
         vec![to.capabilities]
@@ -449,6 +528,11 @@ impl From<MobileDirectLineModelFunctional> for Vec<u8> {
 #[allow(missing_docs)]
 pub struct MobileDirectLineModelDetail {
     pub guid_descriptor_type: u8,
+    #[serde(
+        default,
+        skip_serializing_if = "super::raw_bytes::omit",
+        with = "super::raw_bytes"
+    )]
     pub detail_data: Vec<u8>,
 }
 
@@ -706,11 +790,30 @@ pub enum CdcInterfaceDescriptor {
     Ncm(Ncm),
     Mbim(Mbim),
     MbimExtended(MbimExtended),
-    Invalid(Vec<u8>),
-    Undefined(Vec<u8>),
+    Invalid(#[serde(with = "super::raw_bytes")] Vec<u8>),
+    Undefined(#[serde(with = "super::raw_bytes")] Vec<u8>),
 }
 
 impl CdcInterfaceDescriptor {
+    /// Fills the string fields of the descriptor from their string descriptor indexes using `lookup`
+    pub fn resolve_strings<F: FnMut(u8) -> Option<String>>(&mut self, lookup: &mut F) {
+        match self {
+            CdcInterfaceDescriptor::CountrySelection(d) => {
+                d.country_code_date = lookup_string(d.country_code_date_index, lookup);
+            }
+            CdcInterfaceDescriptor::NetworkChannel(d) => {
+                d.name = lookup_string(d.name_string_index, lookup);
+            }
+            CdcInterfaceDescriptor::EthernetNetworking(d) => {
+                d.mac_address = lookup_string(d.mac_address_index, lookup);
+            }
+            CdcInterfaceDescriptor::CommandSet(d) => {
+                d.command_set_string = lookup_string(d.command_set_string_index, lookup);
+            }
+            _ => (),
+        }
+    }
+
     /// Create a [`CdcInterfaceDescriptor`] from CDC descriptor data
     pub fn from_cdc_descriptor(
         _descriptor_type: &DescriptorType,
@@ -813,6 +916,8 @@ impl TryFrom<&[u8]> for CommunicationDescriptor {
             ));
         }
 
+        check_descriptor_type("CommunicationDescriptor", value[1], &[0x24, 0x04])?;
+
         let communication_type = CdcType::from(value[2]);
         let interface = CdcInterfaceDescriptor::from_cdc_descriptor(
             &DescriptorType::Interface,
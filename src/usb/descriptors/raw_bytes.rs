@@ -0,0 +1,191 @@
+//! Serde helpers for raw descriptor bytes kept alongside decoded fields
+//!
+//! Bytes are serialized as a lowercase hex string with two characters per byte and no separator, so `[0x12, 0x01]` becomes `"1201"`. This is far more compact than a json array of integers and is reversible: deserializing accepts the hex string, or an array of integers as written by older versions.
+//!
+//! Raw bytes can be omitted entirely by serializing through [`OmitRawBytes`], leaving only the decoded fields; `Option` fields are skipped and other fields are serialized as `null`. Omitted bytes deserialize as `None` or empty.
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+use std::cell::Cell;
+use std::fmt;
+
+thread_local! {
+    // serde has no way to pass state to nested `serialize_with` functions so this is set by `OmitRawBytes` for the duration of its serialize
+    static INCLUDE_RAW_BYTES: Cell<bool> = const { Cell::new(true) };
+}
+
+/// Serializes the wrapped value with raw bytes omitted
+///
+/// ```
+/// use cyme::usb::descriptors::{raw_bytes::OmitRawBytes, Descriptor};
+///
+/// let desc = Descriptor::Unknown(vec![0x03, 0xff, 0x01]);
+/// assert_eq!(serde_json::to_string(&desc).unwrap(), r#"{"unknown":"03ff01"}"#);
+/// let omitted = serde_json::to_string(&OmitRawBytes(&desc)).unwrap();
+/// assert_eq!(omitted, r#"{"unknown":null}"#);
+///
+/// // older dumps with integer arrays still read
+/// let read: Descriptor = serde_json::from_str(r#"{"unknown":[3,255,1]}"#).unwrap();
+/// assert_eq!(read, desc);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct OmitRawBytes<'a, T: ?Sized>(pub &'a T);
+
+impl<T: Serialize + ?Sized> Serialize for OmitRawBytes<'_, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let _guard = IncludeGuard::new(false);
+        self.0.serialize(serializer)
+    }
+}
+
+/// Sets `INCLUDE_RAW_BYTES` and restores the previous setting when dropped, including on panic
+struct IncludeGuard {
+    previous: bool,
+}
+
+impl IncludeGuard {
+    fn new(include: bool) -> Self {
+        IncludeGuard {
+            previous: INCLUDE_RAW_BYTES.with(|c| c.replace(include)),
+        }
+    }
+}
+
+impl Drop for IncludeGuard {
+    fn drop(&mut self) {
+        INCLUDE_RAW_BYTES.with(|c| c.set(self.previous));
+    }
+}
+
+fn include_raw_bytes() -> bool {
+    INCLUDE_RAW_BYTES.with(|c| c.get())
+}
+
+/// For `skip_serializing_if`; true when raw bytes are being omitted
+pub fn omit<T>(_: &T) -> bool {
+    !include_raw_bytes()
+}
+
+/// Encode `bytes` as the lowercase hex string used for serialization
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Decode a hex string as written by [`to_hex`]
+pub fn from_hex(s: &str) -> Result<Vec<u8>, String> {
+    if s.len() % 2 != 0 {
+        return Err(format!("raw bytes hex string has odd length {}", s.len()));
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| {
+            s.get(i..i + 2)
+                .and_then(|h| u8::from_str_radix(h, 16).ok())
+                .ok_or_else(|| format!("raw bytes hex string has invalid byte at {}", i))
+        })
+        .collect()
+}
+
+/// Serialize `bytes` as a hex string, or `null` when omitted
+pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if include_raw_bytes() {
+        serializer.serialize_str(&to_hex(bytes))
+    } else {
+        serializer.serialize_none()
+    }
+}
+
+/// Deserialize bytes from a hex string or an array of integers; `null` is empty
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(RawBytesVisitor)
+}
+
+/// [`serialize`] and [`deserialize`] for `Option<Vec<u8>>` fields
+pub mod option {
+    use super::*;
+
+    /// Serialize `Some` bytes as a hex string, otherwise `null`
+    pub fn serialize<S>(bytes: &Option<Vec<u8>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match bytes {
+            Some(b) if include_raw_bytes() => serializer.serialize_str(&to_hex(b)),
+            _ => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize bytes from a hex string or an array of integers; `null` is `None`
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Vec<u8>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_option(OptionVisitor)
+    }
+
+    struct OptionVisitor;
+
+    impl<'de> Visitor<'de> for OptionVisitor {
+        type Value = Option<Vec<u8>>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("raw bytes as a hex string, an array of integers or null")
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            super::deserialize(deserializer).map(Some)
+        }
+    }
+}
+
+struct RawBytesVisitor;
+
+impl<'de> Visitor<'de> for RawBytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("raw bytes as a hex string, an array of integers or null")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        from_hex(v).map_err(E::custom)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(b) = seq.next_element::<u8>()? {
+            bytes.push(b);
+        }
+        Ok(bytes)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(Vec::new())
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(Vec::new())
+    }
+}
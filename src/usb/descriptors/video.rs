@@ -321,11 +321,38 @@ pub enum UvcInterfaceDescriptor {
     FormatMPEG2TS(FormatMPEG2TS),
     ColorFormat(ColorFormat),
     /// Invalid descriptor for failing to parse matched
-    Invalid(Vec<u8>),
+    Invalid(#[serde(with = "super::raw_bytes")] Vec<u8>),
     /// Generic descriptor for known but unsupported descriptors
-    Generic(Vec<u8>),
+    Generic(#[serde(with = "super::raw_bytes")] Vec<u8>),
     /// Undefined descriptor
-    Undefined(Vec<u8>),
+    Undefined(#[serde(with = "super::raw_bytes")] Vec<u8>),
+}
+
+impl UvcInterfaceDescriptor {
+    /// Fills the terminal and unit strings from their string descriptor indexes using `lookup`
+    pub fn resolve_strings<F: FnMut(u8) -> Option<String>>(&mut self, lookup: &mut F) {
+        match self {
+            UvcInterfaceDescriptor::InputTerminal(vh) => {
+                vh.terminal = lookup_string(vh.terminal_index, lookup);
+            }
+            UvcInterfaceDescriptor::OutputTerminal(vh) => {
+                vh.terminal = lookup_string(vh.terminal_index, lookup);
+            }
+            UvcInterfaceDescriptor::SelectorUnit(vh) => {
+                vh.selector = lookup_string(vh.selector_index, lookup);
+            }
+            UvcInterfaceDescriptor::ProcessingUnit(vh) => {
+                vh.processing = lookup_string(vh.processing_index, lookup);
+            }
+            UvcInterfaceDescriptor::ExtensionUnit(vh) => {
+                vh.extension = lookup_string(vh.extension_index, lookup);
+            }
+            UvcInterfaceDescriptor::EncodingUnit(vh) => {
+                vh.encoding = lookup_string(vh.encoding_index, lookup);
+            }
+            _ => (),
+        }
+    }
 }
 
 impl From<UvcInterfaceDescriptor> for Vec<u8> {
@@ -686,6 +713,58 @@ impl TryFrom<&[u8]> for ExtensionUnit {
     }
 }
 
+impl ExtensionUnit {
+    /// Vendor control selectors enabled in bmControls; bit n is control selector n + 1
+    pub fn enabled_controls(&self) -> Vec<u8> {
+        self.controls
+            .iter()
+            .enumerate()
+            .flat_map(|(i, byte)| {
+                (0..8)
+                    .filter(move |b| byte & (1 << b) != 0)
+                    .map(move |b| (i * 8 + b + 1) as u8)
+            })
+            .collect()
+    }
+}
+
+/// The unit ID, guidExtensionCode that vendor controls are matched on, enabled control selectors and source IDs
+///
+/// ```
+/// # use cyme::usb::descriptors::video::ExtensionUnit;
+/// // Logitech video pipe extension unit of a C920
+/// let eu = ExtensionUnit::try_from(
+///     [
+///         0x0b, 0x82, 0x06, 0x61, 0x63, 0x70, 0x50, 0xab, 0x49, 0xb8, 0xcc, 0xb3, 0x85, 0x5e,
+///         0x8d, 0x22, 0x1d, 0x04, 0x01, 0x03, 0x02, 0x0b, 0x01, 0x00,
+///     ]
+///     .as_slice(),
+/// )
+/// .unwrap();
+/// assert_eq!(eu.num_controls, 4);
+/// assert_eq!(eu.source_ids, vec![3]);
+/// assert_eq!(eu.enabled_controls(), vec![1, 2, 4, 9]);
+/// assert_eq!(
+///     eu.to_string(),
+///     "Extension Unit 11 {63610682-5070-49ab-b8cc-b3855e8d221d} controls 1, 2, 4, 9 from 3"
+/// );
+/// ```
+impl fmt::Display for ExtensionUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Extension Unit {} {} controls {}",
+            self.unit_id,
+            format_guid(&self.guid_extension_code.to_bytes_le()),
+            self.enabled_controls().iter().join(", ")
+        )?;
+        if !self.source_ids.is_empty() {
+            write!(f, " from {}", self.source_ids.iter().join(", "))?;
+        }
+        Ok(())
+    }
+}
+
 impl From<ExtensionUnit> for Vec<u8> {
     fn from(eu: ExtensionUnit) -> Self {
         let mut ret = Vec::new();
@@ -1470,3 +1549,210 @@ impl From<FrameFrameBased> for Vec<u8> {
         ret
     }
 }
+
+/// Frame of a [`UvcFormat`] with the resolution and default frame rate
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UvcFrame {
+    /// bFrameIndex
+    pub frame_index: u8,
+    /// wWidth in pixels
+    pub width: u16,
+    /// wHeight in pixels
+    pub height: u16,
+    /// dwDefaultFrameInterval in 100 ns units
+    pub default_frame_interval: u32,
+}
+
+impl UvcFrame {
+    /// Frames per second at the default frame interval; None if the interval is zero
+    pub fn default_fps(&self) -> Option<f64> {
+        (self.default_frame_interval > 0).then(|| 10_000_000.0 / self.default_frame_interval as f64)
+    }
+}
+
+/// " @ 30fps" with one decimal place for fractional frame rates, empty without a frame rate
+fn fps_suffix(fps: Option<f64>) -> String {
+    match fps {
+        Some(fps) if (fps - fps.round()).abs() < 0.01 => format!(" @ {:.0}fps", fps),
+        Some(fps) => format!(" @ {:.1}fps", fps),
+        None => String::new(),
+    }
+}
+
+/// "1920x1080 @ 30fps"
+impl fmt::Display for UvcFrame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}x{}{}",
+            self.width,
+            self.height,
+            fps_suffix(self.default_fps())
+        )
+    }
+}
+
+/// Video format of a VideoStreaming interface and the frames which follow its format descriptor
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UvcFormat {
+    /// Format descriptor subtype; uncompressed, MJPEG or frame based
+    pub subtype: StreamingSubtype,
+    /// bFormatIndex
+    pub format_index: u8,
+    /// bDefaultFrameIndex
+    pub default_frame_index: u8,
+    /// guidFormat of uncompressed and frame based formats
+    pub guid_format: Option<Uuid>,
+    /// Frame descriptors of the format
+    pub frames: Vec<UvcFrame>,
+}
+
+impl UvcFormat {
+    /// The frame matching bDefaultFrameIndex, or the first frame if there is no match
+    pub fn default_frame(&self) -> Option<&UvcFrame> {
+        self.frames
+            .iter()
+            .find(|f| f.frame_index == self.default_frame_index)
+            .or(self.frames.first())
+    }
+
+    /// Short name of the format: "MJPEG", the FourCC at the start of guidFormat such as "YUY2" or "H264", otherwise the GUID
+    pub fn name(&self) -> String {
+        match (&self.subtype, &self.guid_format) {
+            (StreamingSubtype::FormatMJPEG, _) => String::from("MJPEG"),
+            (_, Some(guid)) => {
+                let bytes = guid.to_bytes_le();
+                let fourcc = &bytes[..4];
+                if fourcc
+                    .iter()
+                    .all(|b| b.is_ascii_alphanumeric() || *b == b' ')
+                {
+                    String::from_utf8_lossy(fourcc).trim().to_string()
+                } else {
+                    format_guid(&bytes)
+                }
+            }
+            (s, None) => s.to_string(),
+        }
+    }
+}
+
+/// Formats advertised by the class descriptors of a UVC VideoStreaming interface
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UvcStreamingInterface {
+    /// Formats in descriptor order with their frames
+    pub formats: Vec<UvcFormat>,
+}
+
+impl UvcStreamingInterface {
+    /// Gather the formats and frames from the VideoStreaming `descriptors` in the order they appear; frames belong to the format descriptor preceding them
+    pub fn from_descriptors<'a>(
+        descriptors: impl IntoIterator<Item = &'a UvcInterfaceDescriptor>,
+    ) -> Self {
+        let mut formats: Vec<UvcFormat> = Vec::new();
+
+        for d in descriptors {
+            let (subtype, format_index, default_frame_index, guid_format) = match d {
+                UvcInterfaceDescriptor::FormatMJPEG(f) => (
+                    StreamingSubtype::FormatMJPEG,
+                    f.format_index,
+                    f.default_frame_index,
+                    None,
+                ),
+                UvcInterfaceDescriptor::FormatUncompressed(f) => (
+                    StreamingSubtype::FormatUncompressed,
+                    f.format_index,
+                    f.default_frame_index,
+                    Some(f.guid_format),
+                ),
+                UvcInterfaceDescriptor::FormatFrameBased(f) => (
+                    StreamingSubtype::FormatFrameBased,
+                    f.format_index,
+                    f.default_frame_index,
+                    Some(f.guid_format),
+                ),
+                UvcInterfaceDescriptor::FrameUncompressed(f)
+                | UvcInterfaceDescriptor::FrameMJPEG(f) => {
+                    if let Some(format) = formats.last_mut() {
+                        format.frames.push(UvcFrame {
+                            frame_index: f.common.frame_index,
+                            width: f.common.width,
+                            height: f.common.height,
+                            default_frame_interval: f.default_frame_interval,
+                        });
+                    }
+                    continue;
+                }
+                UvcInterfaceDescriptor::FrameFrameBased(f) => {
+                    if let Some(format) = formats.last_mut() {
+                        format.frames.push(UvcFrame {
+                            frame_index: f.common.frame_index,
+                            width: f.common.width,
+                            height: f.common.height,
+                            default_frame_interval: f.default_frame_interval,
+                        });
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+
+            formats.push(UvcFormat {
+                subtype,
+                format_index,
+                default_frame_index,
+                guid_format,
+                frames: Vec::new(),
+            });
+        }
+
+        UvcStreamingInterface { formats }
+    }
+
+    /// The default format; bFormatIndex 1, which the probe control starts with, or the first format if there is no match
+    pub fn default_format(&self) -> Option<&UvcFormat> {
+        self.formats
+            .iter()
+            .find(|f| f.format_index == 1)
+            .or(self.formats.first())
+    }
+
+    /// Default mode of the default format and its default frame like "1920x1080 MJPEG @ 30fps"
+    ///
+    /// ```
+    /// use cyme::usb::descriptors::video::*;
+    ///
+    /// let format = FormatMJPEG::try_from([0x01, 0x02, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00].as_slice()).unwrap();
+    /// let frame = |index: u8, width: u16, height: u16, interval: u32| {
+    ///     let mut bytes = vec![index, 0x00];
+    ///     bytes.extend(width.to_le_bytes());
+    ///     bytes.extend(height.to_le_bytes());
+    ///     bytes.extend([0x00; 12]);
+    ///     bytes.extend(interval.to_le_bytes());
+    ///     bytes.push(0x01);
+    ///     bytes.extend(interval.to_le_bytes());
+    ///     FrameMJPEG::try_from(bytes.as_slice()).unwrap()
+    /// };
+    /// let descriptors = vec![
+    ///     UvcInterfaceDescriptor::FormatMJPEG(format),
+    ///     UvcInterfaceDescriptor::FrameMJPEG(frame(1, 640, 480, 333333)),
+    ///     UvcInterfaceDescriptor::FrameMJPEG(frame(2, 1920, 1080, 333333)),
+    /// ];
+    ///
+    /// let streaming = UvcStreamingInterface::from_descriptors(&descriptors);
+    /// let default_format = streaming.default_format().unwrap();
+    /// assert_eq!(default_format.default_frame().unwrap().width, 1920);
+    /// assert_eq!(streaming.default_mode().unwrap(), "1920x1080 MJPEG @ 30fps");
+    /// ```
+    pub fn default_mode(&self) -> Option<String> {
+        let format = self.default_format()?;
+        let frame = format.default_frame()?;
+        Some(format!(
+            "{}x{} {}{}",
+            frame.width,
+            frame.height,
+            format.name(),
+            fps_suffix(frame.default_fps())
+        ))
+    }
+}
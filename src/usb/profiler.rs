@@ -16,32 +16,84 @@ use crate::error::{self, Error, ErrorKind};
 use itertools::Itertools;
 use rusb as libusb;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Once;
+use std::thread;
 use std::time::Duration;
 use usb_ids::{self, FromId};
 
 use crate::lsusb::names;
+use crate::system_profiler::InfoSource;
 #[cfg(all(target_os = "linux", feature = "udev"))]
 use crate::udev;
 use crate::{system_profiler, types::NumericalUnit, usb};
+use usb::descriptors::{DescriptorChain, JunkPolicy};
+
+impl From<&libusb::Error> for ErrorKind {
+    fn from(error: &libusb::Error) -> Self {
+        match error {
+            libusb::Error::Access => ErrorKind::Permission,
+            libusb::Error::Timeout => ErrorKind::Timeout,
+            libusb::Error::NotFound | libusb::Error::NoDevice => ErrorKind::NotFound,
+            libusb::Error::NotSupported => ErrorKind::Unsupported,
+            libusb::Error::Io => ErrorKind::Io,
+            _ => ErrorKind::LibUSB,
+        }
+    }
+}
 
 impl From<libusb::Error> for Error {
     fn from(error: libusb::Error) -> Self {
-        Error {
-            kind: ErrorKind::LibUSB,
-            message: format!(
+        Error::new(
+            ErrorKind::from(&error),
+            &format!(
                 "Failed to gather system USB data from libusb: Error({})",
                 &error.to_string()
             ),
-        }
+        )
+        .with_source(error)
     }
 }
 
 struct UsbDevice<T: libusb::UsbContext> {
     handle: libusb::DeviceHandle<T>,
-    language: libusb::Language,
+    /// None when strings are not fetched, see [`ProfilerSettings::fetch_strings`]
+    language: Option<libusb::Language>,
     timeout: Duration,
 }
 
+/// Settings for how the profiler gathers device data, see [`get_spusb_with_settings`]
+#[derive(Debug, Clone)]
+pub struct ProfilerSettings {
+    /// Gather [`usb::USBDeviceExtra`]: configurations, interfaces, endpoints and device descriptors
+    pub with_extra: bool,
+    /// Print non-critical errors (normally due to permissions) to stderr rather than log
+    pub print_stderr: bool,
+    /// Number of threads used to build devices; 0 or 1 builds them sequentially
+    ///
+    /// Device order in the returned tree is the same either way. Opening and reading descriptors of each device blocks on control transfers with a 1 s timeout, so enumeration time is roughly the sum of device response times when sequential; with `jobs` workers it tends towards the slowest device instead.
+    pub jobs: usize,
+    /// Linux only: parse the descriptors cached by the kernel at `/sys/bus/usb/devices/<path>/descriptors` rather than requesting them from the device. Control transfers are only made for string descriptors if the device can be opened, otherwise sysfs strings are used. HID report descriptors are not cached by the kernel so are not read in this mode
+    pub sysfs_descriptors: bool,
+    /// Only profile devices on this bus number; devices on other buses are not opened
+    pub bus: Option<u8>,
+    /// Request string descriptors from devices; default true. When false no string transfers are made, which is much faster for topology only. Strings then come from the sysfs cache on Linux, otherwise names from the USB IDs database only and serial numbers will be missing
+    pub fetch_strings: bool,
+}
+
+impl Default for ProfilerSettings {
+    fn default() -> Self {
+        ProfilerSettings {
+            with_extra: false,
+            print_stderr: false,
+            jobs: 0,
+            sysfs_descriptors: false,
+            bus: None,
+            fetch_strings: true,
+        }
+    }
+}
+
 /// Set log level for rusb
 pub fn set_log_level(debug: u8) {
     let log_level = match debug {
@@ -76,6 +128,16 @@ fn get_sysfs_configuration_string(sysfs_name: &str) -> Option<(u8, String)> {
     None
 }
 
+/// bConfigurationValue of the active configuration from sysfs, falling back to asking libusb
+fn get_active_config<T: libusb::UsbContext>(
+    device: &libusb::Device<T>,
+    sysfs_name: &str,
+) -> Option<u8> {
+    get_sysfs_string(sysfs_name, "bConfigurationValue")
+        .and_then(|s| s.parse::<u8>().ok())
+        .or_else(|| device.active_config_descriptor().ok().map(|c| c.number()))
+}
+
 #[allow(unused_variables)]
 fn get_sysfs_string(sysfs_name: &str, name: &str) -> Option<String> {
     #[cfg(target_os = "linux")]
@@ -88,30 +150,78 @@ fn get_sysfs_string(sysfs_name: &str, name: &str) -> Option<String> {
     None
 }
 
+/// Read the raw descriptors cached by the kernel: the device descriptor followed by each full configuration descriptor
+#[allow(unused_variables)]
+fn get_sysfs_descriptors(sysfs_name: &str) -> Option<Vec<u8>> {
+    #[cfg(target_os = "linux")]
+    return std::fs::read(format!("/sys/bus/usb/devices/{}/descriptors", sysfs_name)).ok();
+
+    #[cfg(not(target_os = "linux"))]
+    None
+}
+
+/// Driver bound to the device at `port_path` from udev
+#[allow(unused_variables)]
+fn get_udev_driver_name(port_path: &usb::PortPath) -> Result<Option<String>, Error> {
+    #[cfg(all(target_os = "linux", feature = "udev"))]
+    return udev::get_udev_driver_name(&port_path.sysfs_name());
+    #[cfg(not(all(target_os = "linux", feature = "udev")))]
+    return Ok(None);
+}
+
+/// Syspath of the device at `port_path` from udev
+#[allow(unused_variables)]
+fn get_udev_syspath(port_path: &usb::PortPath) -> Result<Option<String>, Error> {
+    #[cfg(all(target_os = "linux", feature = "udev"))]
+    return udev::get_udev_syspath(&port_path.sysfs_name());
+    #[cfg(not(all(target_os = "linux", feature = "udev")))]
+    return Ok(None);
+}
+
+/// Driver bound to the interface at `intf_path`, such as "1-1.2:1.0", from udev
 #[allow(unused_variables)]
-fn get_udev_driver_name(port_path: &str) -> Result<Option<String>, Error> {
+fn get_udev_interface_driver_name(intf_path: &str) -> Result<Option<String>, Error> {
     #[cfg(all(target_os = "linux", feature = "udev"))]
-    return udev::get_udev_driver_name(port_path);
+    return udev::get_udev_driver_name(intf_path);
     #[cfg(not(all(target_os = "linux", feature = "udev")))]
     return Ok(None);
 }
 
+/// Syspath of the interface at `intf_path` from udev
 #[allow(unused_variables)]
-fn get_udev_syspath(port_path: &str) -> Result<Option<String>, Error> {
+fn get_udev_interface_syspath(intf_path: &str) -> Result<Option<String>, Error> {
     #[cfg(all(target_os = "linux", feature = "udev"))]
-    return udev::get_udev_syspath(port_path);
+    return udev::get_udev_syspath(intf_path);
     #[cfg(not(all(target_os = "linux", feature = "udev")))]
     return Ok(None);
 }
 
+/// Runtime power state of the device at `port_path`; udev when available, otherwise sysfs directly
+fn get_power_state(port_path: &usb::PortPath) -> Option<usb::PowerState> {
+    let sysfs_name = port_path.sysfs_name();
+    #[cfg(all(target_os = "linux", feature = "udev"))]
+    return udev::get_power_state(&sysfs_name).ok();
+    #[cfg(not(all(target_os = "linux", feature = "udev")))]
+    return get_sysfs_string(&sysfs_name, "power/runtime_status").and_then(|s| s.parse().ok());
+}
+
+/// Active alternate setting of the interface; udev when available, otherwise sysfs directly
+fn get_active_alt_setting(intf_path: &str) -> Option<u8> {
+    #[cfg(all(target_os = "linux", feature = "udev"))]
+    return udev::get_active_alt_setting(intf_path).ok();
+    #[cfg(not(all(target_os = "linux", feature = "udev")))]
+    return get_sysfs_string(intf_path, "bAlternateSetting").and_then(|s| s.parse().ok());
+}
+
 fn get_product_string<T: libusb::UsbContext>(
     device_desc: &libusb::DeviceDescriptor,
     handle: &mut Option<UsbDevice<T>>,
 ) -> Option<String> {
     handle.as_mut().and_then(|h| {
+        let language = h.language?;
         match h
             .handle
-            .read_product_string(h.language, device_desc, h.timeout)
+            .read_product_string(language, device_desc, h.timeout)
         {
             Ok(s) => Some(s.trim().trim_end_matches('\0').to_string()),
             Err(_) => None,
@@ -124,9 +234,10 @@ fn get_manufacturer_string<T: libusb::UsbContext>(
     handle: &mut Option<UsbDevice<T>>,
 ) -> Option<String> {
     handle.as_mut().and_then(|h| {
+        let language = h.language?;
         match h
             .handle
-            .read_manufacturer_string(h.language, device_desc, h.timeout)
+            .read_manufacturer_string(language, device_desc, h.timeout)
         {
             Ok(s) => Some(s.trim().trim_end_matches('\0').to_string()),
             Err(_) => None,
@@ -139,9 +250,10 @@ fn get_serial_string<T: libusb::UsbContext>(
     handle: &mut Option<UsbDevice<T>>,
 ) -> Option<String> {
     handle.as_mut().and_then(|h| {
+        let language = h.language?;
         match h
             .handle
-            .read_serial_number_string(h.language, device_desc, h.timeout)
+            .read_serial_number_string(language, device_desc, h.timeout)
         {
             Ok(s) => Some(s.trim().trim_end_matches('\0').to_string()),
             Err(_) => None,
@@ -149,14 +261,20 @@ fn get_serial_string<T: libusb::UsbContext>(
     })
 }
 
+/// Pair a looked up string with where it came from
+fn with_source(value: Option<String>, source: InfoSource) -> Option<(String, InfoSource)> {
+    value.map(|v| (v, source))
+}
+
 fn get_configuration_string<T: libusb::UsbContext>(
     config_desc: &libusb::ConfigDescriptor,
     handle: &mut Option<UsbDevice<T>>,
 ) -> Option<String> {
     handle.as_mut().and_then(|h| {
+        let language = h.language?;
         match h
             .handle
-            .read_configuration_string(h.language, config_desc, h.timeout)
+            .read_configuration_string(language, config_desc, h.timeout)
         {
             Ok(s) => Some(s.trim().trim_end_matches('\0').to_string()),
             Err(_) => None,
@@ -169,9 +287,10 @@ fn get_interface_string<T: libusb::UsbContext>(
     handle: &mut Option<UsbDevice<T>>,
 ) -> Option<String> {
     handle.as_mut().and_then(|h| {
+        let language = h.language?;
         match h
             .handle
-            .read_interface_string(h.language, interface_desc, h.timeout)
+            .read_interface_string(language, interface_desc, h.timeout)
         {
             Ok(s) => Some(s.trim().trim_end_matches('\0').to_string()),
             Err(_) => None,
@@ -184,9 +303,10 @@ fn get_descriptor_string<T: libusb::UsbContext>(
     handle: &mut Option<UsbDevice<T>>,
 ) -> Option<String> {
     handle.as_mut().and_then(|h| {
+        let language = h.language?;
         match h
             .handle
-            .read_string_descriptor(h.language, string_index, h.timeout)
+            .read_string_descriptor(language, string_index, h.timeout)
         {
             Ok(s) => Some(s.trim().trim_end_matches('\0').to_string()),
             Err(_) => None,
@@ -220,15 +340,18 @@ fn get_control_msg<T: libusb::UsbContext>(
                         Ok(buf)
                     }
                 })
-                .map_err(|e| Error {
-                    kind: ErrorKind::LibUSB,
-                    message: format!("Failed to get control message: {}", e),
+                .map_err(|e| {
+                    Error::new(
+                        ErrorKind::from(&e),
+                        &format!("Failed to get control message: {}", e),
+                    )
+                    .with_source(e)
                 })
         }
-        None => Err(Error {
-            kind: ErrorKind::LibUSB,
-            message: "Failed to get control message, no handle".to_string(),
-        }),
+        None => Err(Error::new(
+            ErrorKind::LibUSB,
+            "Failed to get control message, no handle",
+        )),
     }
 }
 
@@ -247,6 +370,108 @@ fn get_report_descriptor<T: libusb::UsbContext>(
     get_control_msg(handle, request_type, request, value, index, length as usize)
 }
 
+/// UAC3 class specific request to get a High Capability descriptor such as a cluster descriptor
+const UAC3_HIGH_CAPABILITY_DESCRIPTOR: u8 = 0x06;
+
+/// bRequest, wValue and wIndex of the UAC3 HIGH_CAPABILITY_DESCRIPTOR request for descriptor `id` from AudioControl `interface`; the entity ID in the wIndex high byte is zero for the interface itself
+fn uac3_high_capability_request(interface: u8, id: u16) -> (u8, u16, u16) {
+    (UAC3_HIGH_CAPABILITY_DESCRIPTOR, id, interface as u16)
+}
+
+/// Request the UAC3 High Capability descriptor `id`, such as a cluster or connectors descriptor, from AudioControl `interface`
+fn get_uac3_high_capability<T: libusb::UsbContext>(
+    handle: &mut Option<UsbDevice<T>>,
+    interface: u8,
+    id: u16,
+) -> Result<Vec<u8>, Error> {
+    let request_type = libusb::request_type(
+        libusb::Direction::In,
+        libusb::RequestType::Class,
+        libusb::Recipient::Interface,
+    );
+    let (request, value, index) = uac3_high_capability_request(interface, id);
+    // header first for the total wLength
+    let header = get_control_msg(handle, request_type, request, value, index, 7)?;
+    let length = u16::from_le_bytes([header[0], header[1]]);
+    get_control_msg(handle, request_type, request, value, index, length as usize)
+}
+
+/// Request High Capability descriptors `ids` from AudioControl interface `ac`, caching by `(ac, id)` since interfaces of a function share them
+fn resolve_uac3_ids<T, D>(
+    handle: &mut Option<UsbDevice<T>>,
+    cache: &mut HashMap<(u8, u16), Option<D>>,
+    ac: u8,
+    ids: Vec<u16>,
+    kind: &str,
+) -> Vec<D>
+where
+    T: libusb::UsbContext,
+    D: for<'a> TryFrom<&'a [u8], Error = Error> + Clone,
+{
+    ids.into_iter()
+        .filter_map(|id| {
+            cache
+                .entry((ac, id))
+                .or_insert_with(|| {
+                    match get_uac3_high_capability(handle, ac, id)
+                        .and_then(|data| D::try_from(data.as_slice()))
+                    {
+                        Ok(d) => Some(d),
+                        Err(e) => {
+                            log::debug!("Failed to get UAC3 {} {}: {}", kind, id, e);
+                            None
+                        }
+                    }
+                })
+                .clone()
+        })
+        .collect()
+}
+
+/// Request cluster and connectors descriptors for the IDs referenced by UAC3 interfaces from the AudioControl interface of their function
+fn resolve_uac3_descriptors<T: libusb::UsbContext>(
+    handle: &mut Option<UsbDevice<T>>,
+    interfaces: &mut [usb::USBInterface],
+) {
+    let mut control_interface: Option<u8> = None;
+    let mut clusters = HashMap::new();
+    let mut connectors = HashMap::new();
+
+    // interfaces are in order so the AudioControl precedes the streaming interfaces of the function
+    for interface in interfaces.iter_mut() {
+        if interface.class != usb::ClassCode::Audio || interface.protocol != 0x30 {
+            continue;
+        }
+        if interface.sub_class == 0x01 {
+            control_interface = Some(interface.number);
+        }
+        let Some(ac) = control_interface else {
+            continue;
+        };
+
+        let cluster_ids = interface.uac3_cluster_ids();
+        if !cluster_ids.is_empty() {
+            interface.uac3_clusters = Some(resolve_uac3_ids(
+                handle,
+                &mut clusters,
+                ac,
+                cluster_ids,
+                "cluster",
+            ));
+        }
+        let connectors_ids = interface.uac3_connectors_ids();
+        if !connectors_ids.is_empty() {
+            interface.uac3_connectors = Some(resolve_uac3_ids(
+                handle,
+                &mut connectors,
+                ac,
+                connectors_ids,
+                "connectors",
+            ));
+        }
+    }
+}
+
 fn get_hub_descriptor<T: libusb::UsbContext>(
     handle: &mut Option<UsbDevice<T>>,
     protocol: u8,
@@ -393,6 +618,46 @@ fn get_device_qualifier<T: libusb::UsbContext>(
     usb::DeviceQualifierDescriptor::try_from(data.as_slice())
 }
 
+/// Gets the Other Speed Configuration descriptors of a high-speed capable device, which have the same layout as a configuration descriptor
+///
+/// Interfaces at the other speed are not bound to drivers so udev is not used.
+fn get_other_speed_configurations<T: libusb::UsbContext>(
+    location: &system_profiler::DeviceLocation,
+    handle: &mut Option<UsbDevice<T>>,
+    num_configurations: u8,
+) -> Vec<usb::USBConfiguration> {
+    let request_type = libusb::request_type(
+        libusb::Direction::In,
+        libusb::RequestType::Standard,
+        libusb::Recipient::Device,
+    );
+    let request = libusb::constants::LIBUSB_REQUEST_GET_DESCRIPTOR;
+
+    let mut ret = Vec::new();
+    for index in 0..num_configurations {
+        let value = (0x07 << 8) | index as u16;
+        let config = get_control_msg(handle, request_type, request, value, 0, 9)
+            .and_then(|header| {
+                let total_length = u16::from_le_bytes([header[2], header[3]]) as usize;
+                get_control_msg(handle, request_type, request, value, 0, total_length)
+            })
+            .and_then(|data| {
+                log::trace!("Other Speed Configuration descriptor data: {:?}", data);
+                build_sysfs_configuration(location, handle, &data, &None, false)
+            });
+        match config {
+            Ok(c) => ret.push(c),
+            Err(e) => log::debug!(
+                "Failed to get Other Speed Configuration descriptor {}: {}",
+                index,
+                e
+            ),
+        }
+    }
+
+    ret
+}
+
 /// Gets the WebUSB URL from the device, parsed and formatted as a URL
 ///
 /// https://github.com/gregkh/usbutils/blob/master/lsusb.c#L3261
@@ -412,32 +677,35 @@ fn get_webusb_url<T: libusb::UsbContext>(
     let len = data[0] as usize;
 
     if data[1] != usb::USB_DT_WEBUSB_URL {
-        return Err(Error {
-            kind: ErrorKind::Parsing,
-            message: "Failed to parse WebUSB URL: Bad URL descriptor type".to_string(),
-        });
+        return Err(Error::new(
+            ErrorKind::Parsing,
+            "Failed to parse WebUSB URL: Bad URL descriptor type",
+        ));
     }
 
     if data.len() < len {
-        return Err(Error {
-            kind: ErrorKind::Parsing,
-            message: "Failed to parse WebUSB URL: Data length mismatch".to_string(),
-        });
+        return Err(Error::new(
+            ErrorKind::Parsing,
+            "Failed to parse WebUSB URL: Data length mismatch",
+        ));
     }
 
-    let url = String::from_utf8(data[3..len].to_vec()).map_err(|e| Error {
-        kind: ErrorKind::Parsing,
-        message: format!("Failed to parse WebUSB URL: {}", e),
+    let url = String::from_utf8(data[3..len].to_vec()).map_err(|e| {
+        Error::new(
+            ErrorKind::Parsing,
+            &format!("Failed to parse WebUSB URL: {}", e),
+        )
+        .with_source(e)
     })?;
 
     match data[2] {
         0x00 => Ok(format!("http://{}", url)),
         0x01 => Ok(format!("https://{}", url)),
         0xFF => Ok(url),
-        _ => Err(Error {
-            kind: ErrorKind::Parsing,
-            message: "Failed to parse WebUSB URL: Bad URL scheme".to_string(),
-        }),
+        _ => Err(Error::new(
+            ErrorKind::Parsing,
+            "Failed to parse WebUSB URL: Bad URL scheme",
+        )),
     }
 }
 
@@ -504,9 +772,12 @@ impl From<libusb::Version> for usb::Version {
 }
 
 /// Build fully described USB device descriptor with extra bytes
+///
+/// `class_context` is the (class, sub-class, protocol) of the owning interface if any and `interface_number` is used to request the HID report descriptor; pass `None` to skip the request.
 fn build_descriptor_extra<T: libusb::UsbContext>(
     handle: &mut Option<UsbDevice<T>>,
-    interface_desc: Option<&libusb::InterfaceDescriptor>,
+    class_context: Option<(u8, u8, u8)>,
+    interface_number: Option<u8>,
     extra_bytes: &[u8],
 ) -> Result<usb::Descriptor, Error> {
     // Get any extra descriptors into a known type and add any handle data while we have it
@@ -519,184 +790,55 @@ fn build_descriptor_extra<T: libusb::UsbContext>(
     };
 
     // Assign class context to interface since descriptor did not know it
-    if let Some(interface_desc) = interface_desc {
-        if let Err(e) = dt.update_with_class_context((
-            interface_desc.class_code(),
-            interface_desc.sub_class_code(),
-            interface_desc.protocol_code(),
-        )) {
+    if let Some(class_context) = class_context {
+        if let Err(e) = dt.update_with_class_context(class_context) {
             log::debug!(
-                "Failed to update extra descriptor with class context: {}",
+                "Failed to update {} descriptor with class context: {}",
+                dt.descriptor_type(),
                 e
             );
         }
     }
 
     // get any strings at string indexes while we have handle
+    dt.resolve_strings(&mut |i| get_descriptor_string(i, handle));
+
+    // grab report descriptor data using usb_control_msg
     match dt {
-        usb::Descriptor::InterfaceAssociation(ref mut iad) => {
-            iad.function_string = get_descriptor_string(iad.function_string_index, handle);
-        }
-        usb::Descriptor::Device(ref mut c)
-        | usb::Descriptor::Interface(ref mut c)
-        | usb::Descriptor::Endpoint(ref mut c) => match c {
-            usb::ClassDescriptor::Printer(ref mut p) => {
-                for pd in p.descriptors.iter_mut() {
-                    pd.uuid_string = get_descriptor_string(pd.uuid_string_index, handle);
+        usb::Descriptor::Device(usb::ClassDescriptor::Hid(ref mut hd))
+        | usb::Descriptor::Interface(usb::ClassDescriptor::Hid(ref mut hd))
+        | usb::Descriptor::Endpoint(usb::ClassDescriptor::Hid(ref mut hd)) => {
+            for rd in hd.descriptors.iter_mut() {
+                if let Some(index) = interface_number {
+                    rd.data = get_report_descriptor(handle, index as u16, rd.length).ok();
                 }
             }
-            usb::ClassDescriptor::Communication(ref mut cdc) => match cdc.interface {
-                usb::descriptors::cdc::CdcInterfaceDescriptor::CountrySelection(ref mut d) => {
-                    d.country_code_date = get_descriptor_string(d.country_code_date_index, handle);
-                }
-                usb::descriptors::cdc::CdcInterfaceDescriptor::NetworkChannel(ref mut d) => {
-                    d.name = get_descriptor_string(d.name_string_index, handle);
-                }
-                usb::descriptors::cdc::CdcInterfaceDescriptor::EthernetNetworking(ref mut d) => {
-                    d.mac_address = get_descriptor_string(d.mac_address_index, handle);
-                }
-                usb::descriptors::cdc::CdcInterfaceDescriptor::CommandSet(ref mut d) => {
-                    d.command_set_string =
-                        get_descriptor_string(d.command_set_string_index, handle);
-                }
-                _ => (),
-            },
-            // grab report descriptor data using usb_control_msg
-            usb::ClassDescriptor::Hid(ref mut hd) => {
-                for rd in hd.descriptors.iter_mut() {
-                    if let Some(index) = interface_desc.map(|i| i.interface_number() as u16) {
-                        rd.data = get_report_descriptor(handle, index, rd.length).ok();
-                    }
-                }
-            }
-            usb::ClassDescriptor::Midi(ref mut md, _) => match md.interface {
-                usb::descriptors::audio::MidiInterfaceDescriptor::InputJack(ref mut mh) => {
-                    mh.jack_string = get_descriptor_string(mh.jack_string_index, handle);
-                }
-                usb::descriptors::audio::MidiInterfaceDescriptor::OutputJack(ref mut mh) => {
-                    mh.jack_string = get_descriptor_string(mh.jack_string_index, handle);
-                }
-                usb::descriptors::audio::MidiInterfaceDescriptor::Element(ref mut mh) => {
-                    mh.element_string = get_descriptor_string(mh.element_string_index, handle);
-                }
-                _ => (),
-            },
-            usb::ClassDescriptor::Audio(ref mut ad, _) => match ad.interface {
-                usb::descriptors::audio::UacInterfaceDescriptor::InputTerminal1(ref mut ah) => {
-                    ah.channel_names = get_descriptor_string(ah.channel_names_index, handle);
-                    ah.terminal = get_descriptor_string(ah.terminal_index, handle);
-                }
-                usb::descriptors::audio::UacInterfaceDescriptor::InputTerminal2(ref mut ah) => {
-                    ah.channel_names = get_descriptor_string(ah.channel_names_index, handle);
-                    ah.terminal = get_descriptor_string(ah.terminal_index, handle);
-                }
-                usb::descriptors::audio::UacInterfaceDescriptor::OutputTerminal1(ref mut ah) => {
-                    ah.terminal = get_descriptor_string(ah.terminal_index, handle);
-                }
-                usb::descriptors::audio::UacInterfaceDescriptor::OutputTerminal2(ref mut ah) => {
-                    ah.terminal = get_descriptor_string(ah.terminal_index, handle);
-                }
-                usb::descriptors::audio::UacInterfaceDescriptor::StreamingInterface2(
-                    ref mut ah,
-                ) => {
-                    ah.channel_names = get_descriptor_string(ah.channel_names_index, handle);
-                }
-                usb::descriptors::audio::UacInterfaceDescriptor::SelectorUnit1(ref mut ah) => {
-                    ah.selector = get_descriptor_string(ah.selector_index, handle);
-                }
-                usb::descriptors::audio::UacInterfaceDescriptor::SelectorUnit2(ref mut ah) => {
-                    ah.selector = get_descriptor_string(ah.selector_index, handle);
-                }
-                usb::descriptors::audio::UacInterfaceDescriptor::ProcessingUnit1(ref mut ah) => {
-                    ah.channel_names = get_descriptor_string(ah.channel_names_index, handle);
-                    ah.processing = get_descriptor_string(ah.processing_index, handle);
-                }
-                usb::descriptors::audio::UacInterfaceDescriptor::ProcessingUnit2(ref mut ah) => {
-                    ah.channel_names = get_descriptor_string(ah.channel_names_index, handle);
-                    ah.processing = get_descriptor_string(ah.processing_index, handle);
-                }
-                usb::descriptors::audio::UacInterfaceDescriptor::EffectUnit2(ref mut ah) => {
-                    ah.effect = get_descriptor_string(ah.effect_index, handle);
-                }
-                usb::descriptors::audio::UacInterfaceDescriptor::FeatureUnit1(ref mut ah) => {
-                    ah.feature = get_descriptor_string(ah.feature_index, handle);
-                }
-                usb::descriptors::audio::UacInterfaceDescriptor::FeatureUnit2(ref mut ah) => {
-                    ah.feature = get_descriptor_string(ah.feature_index, handle);
-                }
-                usb::descriptors::audio::UacInterfaceDescriptor::ExtensionUnit1(ref mut ah) => {
-                    ah.channel_names = get_descriptor_string(ah.channel_names_index, handle);
-                    ah.extension = get_descriptor_string(ah.extension_index, handle);
-                }
-                usb::descriptors::audio::UacInterfaceDescriptor::ExtensionUnit2(ref mut ah) => {
-                    ah.channel_names = get_descriptor_string(ah.channel_names_index, handle);
-                    ah.extension = get_descriptor_string(ah.extension_index, handle);
-                }
-                usb::descriptors::audio::UacInterfaceDescriptor::ClockSource2(ref mut ah) => {
-                    ah.clock_source = get_descriptor_string(ah.clock_source_index, handle);
-                }
-                usb::descriptors::audio::UacInterfaceDescriptor::ClockSelector2(ref mut ah) => {
-                    ah.clock_selector = get_descriptor_string(ah.clock_selector_index, handle);
-                }
-                usb::descriptors::audio::UacInterfaceDescriptor::ClockMultiplier2(ref mut ah) => {
-                    ah.clock_multiplier = get_descriptor_string(ah.clock_multiplier_index, handle);
-                }
-                usb::descriptors::audio::UacInterfaceDescriptor::SampleRateConverter2(
-                    ref mut ah,
-                ) => {
-                    ah.src = get_descriptor_string(ah.src_index, handle);
-                }
-                _ => (),
-            },
-            usb::ClassDescriptor::Video(ref mut vd, _) => match vd.interface {
-                usb::descriptors::video::UvcInterfaceDescriptor::InputTerminal(ref mut vh) => {
-                    vh.terminal = get_descriptor_string(vh.terminal_index, handle);
-                }
-                usb::descriptors::video::UvcInterfaceDescriptor::OutputTerminal(ref mut vh) => {
-                    vh.terminal = get_descriptor_string(vh.terminal_index, handle);
-                }
-                usb::descriptors::video::UvcInterfaceDescriptor::SelectorUnit(ref mut vh) => {
-                    vh.selector = get_descriptor_string(vh.selector_index, handle);
-                }
-                usb::descriptors::video::UvcInterfaceDescriptor::ProcessingUnit(ref mut vh) => {
-                    vh.processing = get_descriptor_string(vh.processing_index, handle);
-                }
-                usb::descriptors::video::UvcInterfaceDescriptor::ExtensionUnit(ref mut vh) => {
-                    vh.extension = get_descriptor_string(vh.extension_index, handle);
-                }
-                usb::descriptors::video::UvcInterfaceDescriptor::EncodingUnit(ref mut vh) => {
-                    vh.encoding = get_descriptor_string(vh.encoding_index, handle);
-                }
-                _ => (),
-            },
-            _ => (),
-        },
+        }
         _ => (),
     }
 
     Ok(dt)
 }
 
+/// Keep bytes that could not be a descriptor so they are dumped rather than hiding the rest of the chain
+fn junk_descriptor(junk: &[u8]) -> usb::Descriptor {
+    log::debug!("Junk in descriptor chain: {:02x?}", junk);
+    usb::Descriptor::Junk(junk.to_vec())
+}
+
 fn build_config_descriptor_extra<T: libusb::UsbContext>(
     handle: &mut Option<UsbDevice<T>>,
-    config_desc: &libusb::ConfigDescriptor,
+    extra_bytes: &[u8],
 ) -> Result<Vec<usb::Descriptor>, Error> {
-    let mut extra_bytes = config_desc.extra().to_owned();
-    let extra_len = extra_bytes.len();
-    let mut taken = 0;
     let mut ret = Vec::new();
 
-    // Iterate on chunks of the header length
-    while taken < extra_len && extra_len >= 2 {
-        let dt_len = extra_bytes[0] as usize;
-        let dt = build_descriptor_extra(
-            handle,
-            None,
-            &extra_bytes.drain(..dt_len).collect::<Vec<u8>>(),
-        )?;
+    for chunk in DescriptorChain::new(extra_bytes, JunkPolicy::default()) {
+        let dt = match chunk {
+            Ok(desc) => build_descriptor_extra(handle, None, None, desc)?,
+            Err(junk) => junk_descriptor(junk),
+        };
         log::trace!("Config descriptor extra: {:?}", dt);
         ret.push(dt);
-        taken += dt_len;
     }
 
     Ok(ret)
@@ -704,34 +846,33 @@ fn build_config_descriptor_extra<T: libusb::UsbContext>(
 
 fn build_interface_descriptor_extra<T: libusb::UsbContext>(
     handle: &mut Option<UsbDevice<T>>,
-    interface_desc: &libusb::InterfaceDescriptor,
+    class_context: (u8, u8, u8),
+    interface_number: Option<u8>,
+    extra_bytes: &[u8],
 ) -> Result<Vec<usb::Descriptor>, Error> {
-    let mut extra_bytes = interface_desc.extra().to_owned();
-    let extra_len = extra_bytes.len();
-    let mut taken = 0;
     let mut ret = Vec::new();
 
-    // Iterate on chunks of the header length
-    while taken < extra_len && extra_len >= 2 {
-        let dt_len = extra_bytes[0] as usize;
-        if let Some(b) = extra_bytes.get_mut(1) {
-            // Mask request type LIBUSB_REQUEST_TYPE_CLASS
-            *b &= !(0x01 << 5);
-            // if not Device or Interface, force it to Interface
-            if *b != 0x01 || *b != 0x04 {
-                *b = 0x04;
-            }
-        }
+    for chunk in DescriptorChain::new(extra_bytes, JunkPolicy::default()) {
+        let dt = match chunk {
+            Ok(desc) => {
+                let mut desc = desc.to_owned();
+                // IAD of the next function can follow the interface and Interface Power is standard; keep them
+                if desc[1] != 0x0b && desc[1] != 0x08 {
+                    // Mask request type LIBUSB_REQUEST_TYPE_CLASS
+                    desc[1] &= !(0x01 << 5);
+                    // if not Device or Interface, force it to Interface
+                    if desc[1] != 0x01 || desc[1] != 0x04 {
+                        desc[1] = 0x04;
+                    }
+                }
 
-        let dt = build_descriptor_extra(
-            handle,
-            Some(interface_desc),
-            &extra_bytes.drain(..dt_len).collect::<Vec<u8>>(),
-        )?;
+                build_descriptor_extra(handle, Some(class_context), interface_number, &desc)?
+            }
+            Err(junk) => junk_descriptor(junk),
+        };
 
         log::trace!("Interface descriptor extra: {:?}", dt);
         ret.push(dt);
-        taken += dt_len;
     }
 
     Ok(ret)
@@ -739,35 +880,35 @@ fn build_interface_descriptor_extra<T: libusb::UsbContext>(
 
 fn build_endpoint_descriptor_extra<T: libusb::UsbContext>(
     handle: &mut Option<UsbDevice<T>>,
-    interface_desc: &libusb::InterfaceDescriptor,
-    endpoint_desc: &libusb::EndpointDescriptor,
+    class_context: (u8, u8, u8),
+    interface_number: Option<u8>,
+    extra_bytes: Option<&[u8]>,
 ) -> Result<Option<Vec<usb::Descriptor>>, Error> {
-    match endpoint_desc.extra() {
+    match extra_bytes {
         Some(extra_bytes) => {
-            let mut extra_bytes = extra_bytes.to_owned();
-            let extra_len = extra_bytes.len();
-            let mut taken = 0;
             let mut ret = Vec::new();
 
-            // Iterate on chunks of the header length
-            while taken < extra_len && extra_len >= 2 {
-                let dt_len = extra_bytes[0] as usize;
-                if let Some(b) = extra_bytes.get_mut(1) {
-                    // Mask request type LIBUSB_REQUEST_TYPE_CLASS for Endpoint: 0x25
-                    if *b == 0x25 {
-                        *b &= !(0x01 << 5);
+            for chunk in DescriptorChain::new(extra_bytes, JunkPolicy::default()) {
+                let dt = match chunk {
+                    Ok(desc) => {
+                        let mut desc = desc.to_owned();
+                        // Mask request type LIBUSB_REQUEST_TYPE_CLASS for Endpoint: 0x25
+                        if desc[1] == 0x25 {
+                            desc[1] &= !(0x01 << 5);
+                        }
+
+                        build_descriptor_extra(
+                            handle,
+                            Some(class_context),
+                            interface_number,
+                            &desc,
+                        )?
                     }
+                    Err(junk) => junk_descriptor(junk),
                 };
 
-                let dt = build_descriptor_extra(
-                    handle,
-                    Some(interface_desc),
-                    &extra_bytes.drain(..dt_len).collect::<Vec<u8>>(),
-                )?;
-
                 log::trace!("Endpoint descriptor extra: {:?}", dt);
                 ret.push(dt);
-                taken += dt_len;
             }
 
             Ok(Some(ret))
@@ -781,6 +922,11 @@ fn build_endpoints<T: libusb::UsbContext>(
     interface_desc: &libusb::InterfaceDescriptor,
 ) -> Vec<usb::USBEndpoint> {
     let mut ret: Vec<usb::USBEndpoint> = Vec::new();
+    let class_context = (
+        interface_desc.class_code(),
+        interface_desc.sub_class_code(),
+        interface_desc.protocol_code(),
+    );
 
     for endpoint_desc in interface_desc.endpoint_descriptors() {
         ret.push(usb::USBEndpoint {
@@ -795,9 +941,17 @@ fn build_endpoints<T: libusb::UsbContext>(
             max_packet_size: endpoint_desc.max_packet_size(),
             interval: endpoint_desc.interval(),
             length: endpoint_desc.length(),
-            extra: build_endpoint_descriptor_extra(handle, interface_desc, &endpoint_desc)
-                .ok()
-                .flatten(),
+            // audio endpoint descriptors are extended with bRefresh and bSynchAddress
+            refresh: (endpoint_desc.length() >= 9).then(|| endpoint_desc.refresh()),
+            synch_address: (endpoint_desc.length() >= 9).then(|| endpoint_desc.synch_address()),
+            extra: build_endpoint_descriptor_extra(
+                handle,
+                class_context,
+                Some(interface_desc.interface_number()),
+                endpoint_desc.extra(),
+            )
+            .ok()
+            .flatten(),
         });
     }
 
@@ -836,20 +990,35 @@ fn build_interfaces<T: libusb::UsbContext>(
                 syspath: None,
                 length: interface_desc.length(),
                 endpoints: build_endpoints(handle, &interface_desc),
-                extra: build_interface_descriptor_extra(handle, &interface_desc).ok(),
+                extra: build_interface_descriptor_extra(
+                    handle,
+                    (
+                        interface_desc.class_code(),
+                        interface_desc.sub_class_code(),
+                        interface_desc.protocol_code(),
+                    ),
+                    Some(interface_desc.interface_number()),
+                    interface_desc.extra(),
+                )
+                .ok(),
+                uac3_clusters: None,
+                uac3_connectors: None,
+                active_alt_setting: None,
             };
 
             // flag allows us to try again without udev if it raises an error
             // but record the error for printing
             if with_udev {
-                interface.driver = get_udev_driver_name(&interface.path)?;
-                interface.syspath = get_udev_syspath(&interface.path)?;
+                interface.driver = get_udev_interface_driver_name(&interface.path)?;
+                interface.syspath = get_udev_interface_syspath(&interface.path)?;
             };
 
             ret.push(interface);
         }
     }
 
+    resolve_uac3_descriptors(handle, &mut ret);
+
     Ok(ret)
 }
 
@@ -865,9 +1034,13 @@ fn build_configurations<T: libusb::UsbContext>(
     let mut ret: Vec<usb::USBConfiguration> = Vec::new();
 
     for n in 0..device_desc.num_configurations() {
+        // keep going so one bad configuration does not hide the rest; the shortfall is checked by verify
         let config_desc = match device.config_descriptor(n) {
             Ok(c) => c,
-            Err(_) => continue,
+            Err(e) => {
+                log::warn!("Failed to get configuration {} of {}: {}", n, sp_device, e);
+                continue;
+            }
         };
 
         let mut attributes = Vec::new();
@@ -903,16 +1076,217 @@ fn build_configurations<T: libusb::UsbContext>(
                 unit: String::from("mA"),
                 description: None,
             },
+            // libusb reports bMaxPower in 2 mA units
+            max_power_units: Some((config_desc.max_power() / 2) as u8),
             length: config_desc.length(),
             total_length: config_desc.total_length(),
             interfaces: build_interfaces(device, handle, &config_desc, with_udev)?,
-            extra: build_config_descriptor_extra(handle, &config_desc).ok(),
+            extra: build_config_descriptor_extra(handle, config_desc.extra()).ok(),
         });
     }
 
     Ok(ret)
 }
 
+/// Raw interface descriptor and the descriptors following it within a sysfs configuration
+struct SysfsInterface<'a> {
+    descriptor: &'a [u8],
+    extra: Vec<u8>,
+    endpoints: Vec<(&'a [u8], Vec<u8>)>,
+}
+
+/// Walk a full configuration descriptor (as found in the sysfs descriptors blob) into a [`usb::USBConfiguration`]
+///
+/// Like libusb, any class descriptors are assigned to the last standard descriptor preceding them: endpoint, interface or configuration.
+fn build_sysfs_configuration<T: libusb::UsbContext>(
+    location: &system_profiler::DeviceLocation,
+    handle: &mut Option<UsbDevice<T>>,
+    config_bytes: &[u8],
+    cur_config: &Option<(u8, String)>,
+    with_udev: bool,
+) -> error::Result<usb::USBConfiguration> {
+    if config_bytes.len() < 9 {
+        return Err(Error::new_descriptor_len(
+            "ConfigurationDescriptor",
+            9,
+            config_bytes.len(),
+        ));
+    }
+
+    let number = config_bytes[5];
+    let string_index = config_bytes[6];
+    let mut attributes = Vec::new();
+    if config_bytes[7] & 0x20 != 0 {
+        attributes.push(usb::ConfigAttributes::RemoteWakeup);
+    }
+    if config_bytes[7] & 0x40 != 0 {
+        attributes.push(usb::ConfigAttributes::SelfPowered);
+    }
+
+    let mut config_extra: Vec<u8> = Vec::new();
+    let mut raw_interfaces: Vec<SysfsInterface> = Vec::new();
+    let remaining = config_bytes
+        .get(config_bytes[0] as usize..)
+        .unwrap_or_default();
+
+    for chunk in DescriptorChain::new(remaining, JunkPolicy::default()) {
+        let desc_type = match chunk {
+            Ok(desc) => Some(desc[1]),
+            Err(junk) => {
+                log::warn!(
+                    "Invalid descriptor length {} with {} bytes remaining in configuration {}",
+                    junk[0],
+                    junk.len(),
+                    number
+                );
+                None
+            }
+        };
+        let desc = chunk.unwrap_or_else(|junk| junk);
+
+        match desc_type {
+            Some(0x04) if desc.len() >= 9 => raw_interfaces.push(SysfsInterface {
+                descriptor: desc,
+                extra: Vec::new(),
+                endpoints: Vec::new(),
+            }),
+            Some(0x05) if desc.len() >= 7 => match raw_interfaces.last_mut() {
+                Some(i) => i.endpoints.push((desc, Vec::new())),
+                None => log::warn!("Endpoint descriptor before interface descriptor"),
+            },
+            // class specific and junk are extra of the preceding descriptor
+            _ => match raw_interfaces.last_mut() {
+                Some(i) => match i.endpoints.last_mut() {
+                    Some((_, e)) => e.extend_from_slice(desc),
+                    None => i.extra.extend_from_slice(desc),
+                },
+                None => config_extra.extend_from_slice(desc),
+            },
+        }
+    }
+
+    let mut interfaces = Vec::with_capacity(raw_interfaces.len());
+    for raw in raw_interfaces {
+        let d = raw.descriptor;
+        let class_context = (d[5], d[6], d[7]);
+        let path = usb::get_interface_path(location.bus, &location.tree_positions, number, d[2]);
+
+        let mut interface = usb::USBInterface {
+            name: get_sysfs_string(&path, "interface")
+                .or(get_descriptor_string(d[8], handle))
+                .unwrap_or_default(),
+            string_index: d[8],
+            number: d[2],
+            path,
+            class: usb::ClassCode::from(d[5]),
+            sub_class: d[6],
+            protocol: d[7],
+            alt_setting: d[3],
+            driver: None,
+            syspath: None,
+            length: d[0],
+            endpoints: raw
+                .endpoints
+                .iter()
+                .map(|(e, extra)| usb::USBEndpoint {
+                    address: usb::EndpointAddress::from(e[2]),
+                    transfer_type: usb::TransferType::from(e[3]),
+                    sync_type: usb::SyncType::from(e[3]),
+                    usage_type: usb::UsageType::from(e[3]),
+                    max_packet_size: u16::from_le_bytes([e[4], e[5]]),
+                    interval: e[6],
+                    length: e[0],
+                    refresh: e.get(7).copied().filter(|_| e[0] >= 9),
+                    synch_address: e.get(8).copied().filter(|_| e[0] >= 9),
+                    extra: build_endpoint_descriptor_extra(
+                        handle,
+                        class_context,
+                        None,
+                        (!extra.is_empty()).then_some(extra.as_slice()),
+                    )
+                    .ok()
+                    .flatten(),
+                })
+                .collect(),
+            // no interface number so HID report descriptors are skipped: they are not in the sysfs blob
+            extra: build_interface_descriptor_extra(handle, class_context, None, &raw.extra).ok(),
+            uac3_clusters: None,
+            uac3_connectors: None,
+            active_alt_setting: None,
+        };
+
+        if with_udev {
+            interface.driver = get_udev_interface_driver_name(&interface.path)?;
+            interface.syspath = get_udev_interface_syspath(&interface.path)?;
+        };
+
+        interfaces.push(interface);
+    }
+    resolve_uac3_descriptors(handle, &mut interfaces);
+
+    let config_name = match cur_config {
+        Some((config_num, config_name)) if *config_num == number => Some(config_name.to_owned()),
+        _ => None,
+    };
+
+    Ok(usb::USBConfiguration {
+        name: get_descriptor_string(string_index, handle)
+            .or(config_name)
+            .unwrap_or_default(),
+        string_index,
+        number,
+        attributes,
+        max_power: NumericalUnit {
+            value: config_bytes[8] as u32 * 2,
+            unit: String::from("mA"),
+            description: None,
+        },
+        max_power_units: Some(config_bytes[8]),
+        length: config_bytes[0],
+        total_length: u16::from_le_bytes([config_bytes[2], config_bytes[3]]),
+        interfaces,
+        extra: build_config_descriptor_extra(handle, &config_extra).ok(),
+    })
+}
+
+/// Build configurations from the kernel cached `descriptors` blob; the device descriptor followed by each configuration with all its descriptors
+fn build_sysfs_configurations<T: libusb::UsbContext>(
+    handle: &mut Option<UsbDevice<T>>,
+    descriptors: &[u8],
+    sp_device: &system_profiler::USBDevice,
+    with_udev: bool,
+) -> error::Result<Vec<usb::USBConfiguration>> {
+    let cur_config = get_sysfs_configuration_string(&sp_device.sysfs_name());
+    let mut ret: Vec<usb::USBConfiguration> = Vec::new();
+    // skip the device descriptor
+    let mut remaining = descriptors
+        .first()
+        .and_then(|l| descriptors.get(*l as usize..))
+        .unwrap_or_default();
+
+    while remaining.len() >= 9 {
+        if remaining[1] != 0x02 {
+            log::warn!(
+                "Expected configuration descriptor in sysfs descriptors, got {:#04x}",
+                remaining[1]
+            );
+            break;
+        }
+        let total_length = u16::from_le_bytes([remaining[2], remaining[3]]) as usize;
+        let (config_bytes, rest) = remaining.split_at(total_length.clamp(9, remaining.len()));
+        remaining = rest;
+        ret.push(build_sysfs_configuration(
+            &sp_device.location_id,
+            handle,
+            config_bytes,
+            &cur_config,
+            with_udev,
+        )?);
+    }
+
+    Ok(ret)
+}
+
 #[allow(unused_variables)]
 fn build_spdevice_extra<T: libusb::UsbContext>(
     device: &libusb::Device<T>,
@@ -920,7 +1294,21 @@ fn build_spdevice_extra<T: libusb::UsbContext>(
     device_desc: &libusb::DeviceDescriptor,
     sp_device: &system_profiler::USBDevice,
     with_udev: bool,
+    sysfs_descriptors: bool,
 ) -> error::Result<usb::USBDeviceExtra> {
+    let sysfs_blob = if sysfs_descriptors {
+        let blob = get_sysfs_descriptors(&sp_device.sysfs_name());
+        if blob.is_none() {
+            log::warn!(
+                "Unable to read sysfs descriptors for {}, requesting from device",
+                sp_device
+            );
+        }
+        blob
+    } else {
+        None
+    };
+
     let mut extra = usb::USBDeviceExtra {
         max_packet_size: device_desc.max_packet_size(),
         string_indexes: (
@@ -937,28 +1325,67 @@ fn build_spdevice_extra<T: libusb::UsbContext>(
             usb_ids::Device::from_vid_pid(device_desc.vendor_id(), device_desc.product_id())
                 .map(|v| v.name().to_owned()),
         ),
-        configurations: build_configurations(device, handle, device_desc, sp_device, with_udev)?,
-        status: get_device_status(handle).ok(),
-        debug: get_debug_descriptor(handle).ok(),
+        configurations: match sysfs_blob.as_ref() {
+            Some(blob) => build_sysfs_configurations(handle, blob, sp_device, with_udev)?,
+            None => build_configurations(device, handle, device_desc, sp_device, with_udev)?,
+        },
+        status: None,
+        debug: None,
         binary_object_store: None,
         qualifier: None,
+        other_speed_configurations: Vec::new(),
         hub: None,
+        power_state: get_power_state(&sp_device.location_id.to_port_path()),
+        active_config: get_active_config(device, &sp_device.sysfs_name()),
+        num_configurations: Some(device_desc.num_configurations()),
     };
 
-    // flag allows us to try again without udev if it raises an nting
+    // bMaxPower units depend on the operating speed, which is only known for the device
+    if let Some(system_profiler::DeviceSpeed::SpeedValue(speed)) = sp_device.device_speed.as_ref() {
+        for config in extra.configurations.iter_mut() {
+            config.max_power.value = config.max_power_ma(*speed);
+        }
+    }
+
+    // only interfaces of the active configuration have a current alternate setting; other speed configurations share their paths so are never set
+    if let Some(config) = extra
+        .configurations
+        .iter_mut()
+        .find(|c| Some(c.number) == extra.active_config)
+    {
+        for interface in config.interfaces.iter_mut() {
+            interface.active_alt_setting = get_active_alt_setting(&interface.path);
+        }
+    }
+
+    // flag allows us to try again without udev if it raises an error
     // but record the error for printing
     if with_udev {
-        let sysfs_name = sp_device.sysfs_name();
-        extra.driver = get_udev_driver_name(&sysfs_name)?;
-        extra.syspath = get_udev_syspath(&sysfs_name)?;
+        let port_path = sp_device.location_id.to_port_path();
+        extra.driver = get_udev_driver_name(&port_path)?;
+        extra.syspath = get_udev_syspath(&port_path)?;
+    }
+
+    // remaining descriptors are not cached by the kernel so require control transfers
+    if sysfs_blob.is_some() {
+        return Ok(extra);
     }
 
     // Get device specific stuff: bos, hub, dualspeed, debug and status
+    extra.status = get_device_status(handle).ok();
+    extra.debug = get_debug_descriptor(handle).ok();
     if device_desc.usb_version() >= rusb::Version::from_bcd(0x0201) {
         extra.binary_object_store = get_bos_descriptor(handle).ok();
     }
     if device_desc.usb_version() >= rusb::Version::from_bcd(0x0200) {
         extra.qualifier = get_device_qualifier(handle).ok();
+        if let Some(qualifier) = extra.qualifier.as_ref() {
+            extra.other_speed_configurations = get_other_speed_configurations(
+                &sp_device.location_id,
+                handle,
+                qualifier.num_configurations,
+            );
+        }
     }
     if device_desc.class_code() == usb::ClassCode::Hub as u8 {
         let has_ssp = if let Some(bos) = &extra.binary_object_store {
@@ -977,29 +1404,50 @@ fn build_spdevice_extra<T: libusb::UsbContext>(
 
 /// Builds a [`system_profiler::USBDevice`] from a [`libusb::Device`] by using `device_descriptor()` and intrograting for configuration strings. Optionally with `with_extra` will gather full device information, including from udev if feature is present.
 ///
-/// [`system_profiler::USBDevice.profiler_error`] `Option<String>` will contain any non-critical error during gather of `with_extra` data - normally due to permissions preventing open of device descriptors.
+/// [`system_profiler::USBDevice.profiler_error`] `Option<Error>` will contain any non-critical error during gather of `with_extra` data - normally due to permissions preventing open of device descriptors.
 pub fn build_spdevice<T: libusb::UsbContext>(
     device: &libusb::Device<T>,
     with_extra: bool,
 ) -> error::Result<system_profiler::USBDevice> {
+    build_spdevice_with_settings(
+        device,
+        &ProfilerSettings {
+            with_extra,
+            ..Default::default()
+        },
+    )
+}
+
+/// Builds a [`system_profiler::USBDevice`] like [`build_spdevice`] using [`ProfilerSettings`] to determine what and how data is gathered
+pub fn build_spdevice_with_settings<T: libusb::UsbContext>(
+    device: &libusb::Device<T>,
+    settings: &ProfilerSettings,
+) -> error::Result<system_profiler::USBDevice> {
+    let with_extra = settings.with_extra;
     let timeout = Duration::from_secs(1);
     let speed = match usb::Speed::from(device.speed()) {
         usb::Speed::Unknown => None,
         v => Some(system_profiler::DeviceSpeed::SpeedValue(v)),
     };
 
-    let mut error_str = None;
+    let mut profiler_error = None;
     let device_desc = device.device_descriptor()?;
 
     // try to get open device for strings but allowed to continue if this fails - get string functions will return empty
     let mut usb_device = {
         match device.open() {
+            // the language table is string descriptor 0 so is not read either
+            Ok(h) if !settings.fetch_strings => Some(UsbDevice {
+                handle: h,
+                language: None,
+                timeout,
+            }),
             Ok(h) => match h.read_languages(timeout) {
                 Ok(l) => {
                     if !l.is_empty() {
                         Some(UsbDevice {
                             handle: h,
-                            language: l[0],
+                            language: Some(l[0]),
                             timeout,
                         })
                     } else {
@@ -1007,18 +1455,30 @@ pub fn build_spdevice<T: libusb::UsbContext>(
                     }
                 }
                 Err(e) => {
-                    error_str = Some(format!(
-                        "Failed to open {:?}, will be unable to obtain all data: {}",
-                        device, e
-                    ));
+                    profiler_error = Some(
+                        Error::new(
+                            ErrorKind::from(&e),
+                            &format!(
+                                "Failed to open {:?}, will be unable to obtain all data: {}",
+                                device, e
+                            ),
+                        )
+                        .with_source(e),
+                    );
                     None
                 }
             },
             Err(e) => {
-                error_str = Some(format!(
-                    "Failed to open {:?}, will be unable to obtain all data: {}",
-                    device, e
-                ));
+                profiler_error = Some(
+                    Error::new(
+                        ErrorKind::from(&e),
+                        &format!(
+                            "Failed to open {:?}, will be unable to obtain all data: {}",
+                            device, e
+                        ),
+                    )
+                    .with_source(e),
+                );
                 None
             }
         }
@@ -1046,37 +1506,64 @@ pub fn build_spdevice<T: libusb::UsbContext>(
     // 1. Read directly from the device descriptor (usually requires root access)
     // 2. (on Linux) Read from sysfs, which is a cached copy of the device descriptor
     //    TODO (does macOS and Windows have an equivalent/similar way to retrieve this info?)
-    // 3. Lookup iManufacturer and iProduct from the USB IDs list (iSerial has no alternative)
-    sp_device.manufacturer = get_manufacturer_string(&device_desc, &mut usb_device) // descriptor
-        // sysfs cache
-        .or(get_sysfs_string(&sp_device.sysfs_name(), "manufacturer"))
-        // udev-hwdb
-        .or(names::vendor(device_desc.vendor_id())) // udev, usb-ids if error
-        // usb-ids
-        .or(usb_ids::Vendor::from_id(device_desc.vendor_id())
-            .map(|vendor| vendor.name().to_owned()));
-
-    sp_device.name = get_product_string(&device_desc, &mut usb_device) // descriptor
-        // sysfs cache
-        .or(get_sysfs_string(&sp_device.sysfs_name(), "product"))
-        // udev-hwdb
-        .or(names::product(
-            device_desc.vendor_id(),
-            device_desc.product_id(),
-        ))
-        // usb-ids
-        .or(
-            usb_ids::Device::from_vid_pid(device_desc.vendor_id(), device_desc.product_id())
-                .map(|device| device.name().to_owned()),
+    // 3. Lookup iManufacturer and iProduct from udev-hwdb or the USB IDs list (iSerial has no alternative)
+    // The source of each is recorded in `info_source` so limited info can be flagged
+    // If not fetching strings 1. is skipped
+    let sysfs_name = sp_device.sysfs_name();
+    let sysfs_string = |name: &str| get_sysfs_string(&sysfs_name, name);
+    let mut info_source = system_profiler::DeviceInfoSource::default();
+
+    (sp_device.manufacturer, info_source.manufacturer) = with_source(
+        get_manufacturer_string(&device_desc, &mut usb_device),
+        InfoSource::Device,
+    )
+    .or_else(|| with_source(sysfs_string("manufacturer"), InfoSource::Sysfs))
+    .or_else(|| with_source(names::vendor(device_desc.vendor_id()), InfoSource::Database))
+    .unzip();
+
+    let (name, name_source) = with_source(
+        get_product_string(&device_desc, &mut usb_device),
+        InfoSource::Device,
+    )
+    .or_else(|| with_source(sysfs_string("product"), InfoSource::Sysfs))
+    .or_else(|| {
+        with_source(
+            names::product(device_desc.vendor_id(), device_desc.product_id()),
+            InfoSource::Database,
         )
-        // empty
-        .unwrap_or_default();
-
-    sp_device.serial_num = get_serial_string(&device_desc, &mut usb_device)
-        .or(get_sysfs_string(&sp_device.sysfs_name(), "serial"));
-
-    let extra_error_str = if with_extra {
-        match build_spdevice_extra(device, &mut usb_device, &device_desc, &sp_device, true) {
+    })
+    .unzip();
+    // empty if not found
+    sp_device.name = name.unwrap_or_default();
+    info_source.name = name_source;
+
+    (sp_device.serial_num, info_source.serial_num) = with_source(
+        get_serial_string(&device_desc, &mut usb_device),
+        InfoSource::Device,
+    )
+    .or_else(|| with_source(sysfs_string("serial"), InfoSource::Sysfs))
+    .unzip();
+
+    // limited info is expected if strings were not fetched; strings with index 0 are not provided by the device so are not limited
+    let string_indexes = (
+        device_desc.product_string_index().unwrap_or(0),
+        device_desc.manufacturer_string_index().unwrap_or(0),
+        device_desc.serial_number_string_index().unwrap_or(0),
+    );
+    if settings.fetch_strings && (usb_device.is_none() || info_source.is_limited(string_indexes)) {
+        warn_limited_info(settings);
+    }
+    sp_device.info_source = Some(info_source);
+
+    let extra_error = if with_extra {
+        match build_spdevice_extra(
+            device,
+            &mut usb_device,
+            &device_desc,
+            &sp_device,
+            true,
+            settings.sysfs_descriptors,
+        ) {
             Ok(extra) => {
                 sp_device.extra = Some(extra);
                 None
@@ -1090,13 +1577,17 @@ pub fn build_spdevice<T: libusb::UsbContext>(
                         &device_desc,
                         &sp_device,
                         false,
+                        settings.sysfs_descriptors,
                     )?);
-                    Some(format!(
-                        "Failed to get udev data for {}, probably requires elevated permissions",
-                        sp_device
+                    Some(Error::new(
+                        ErrorKind::Udev,
+                        &format!(
+                            "Failed to get udev data for {}, probably requires elevated permissions",
+                            sp_device
+                        ),
                     ))
                 } else {
-                    Some(format!( "Failed to get some extra data for {}, probably requires elevated permissions: {}", sp_device, e ))
+                    Some(Error::new(e.kind(), &format!( "Failed to get some extra data for {}, probably requires elevated permissions: {}", sp_device, e )).with_source(e))
                 }
             }
         }
@@ -1104,18 +1595,186 @@ pub fn build_spdevice<T: libusb::UsbContext>(
         None
     };
 
-    if error_str.is_none() {
-        error_str = extra_error_str;
+    if profiler_error.is_none() {
+        profiler_error = extra_error;
     }
 
-    sp_device.profiler_error = error_str;
+    sp_device.profiler_error = profiler_error;
     Ok(sp_device)
 }
 
-fn _get_spusb(
-    with_extra: bool,
-    print_stderr: bool,
-) -> Result<system_profiler::SPUSBDataType, Error> {
+/// Parse a raw descriptor blob into a [`system_profiler::USBDevice`] with [`usb::USBDeviceExtra`] without the device present
+///
+/// The blob is the device descriptor followed by each configuration with all its descriptors, as in the sysfs `descriptors` file or a capture of the GET_DESCRIPTOR responses. String descriptors are not part of the blob so names are from the USB IDs database. The device is placed at port path "0-1" since bus 0 is never used by a host controller.
+///
+/// ```
+/// use cyme::usb::profiler;
+///
+/// let mut blob = vec![
+///     0x12, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00, 0x40, 0x50, 0x1d, 0x18, 0x60, 0x00, 0x01,
+///     0x00, 0x00, 0x00, 0x01,
+/// ];
+/// // configuration with a single vendor specific interface and bulk endpoint
+/// blob.extend([0x09, 0x02, 0x19, 0x00, 0x01, 0x01, 0x00, 0x80, 0x32]);
+/// blob.extend([0x09, 0x04, 0x00, 0x00, 0x01, 0xff, 0x00, 0x00, 0x00]);
+/// blob.extend([0x07, 0x05, 0x81, 0x02, 0x00, 0x02, 0x00]);
+///
+/// let device = profiler::parse_descriptors(&blob).unwrap();
+/// assert_eq!(device.vendor_id, Some(0x1d50));
+/// assert_eq!(device.product_id, Some(0x6018));
+/// let config = &device.extra.as_ref().unwrap().configurations[0];
+/// assert_eq!(config.max_power.value, 100);
+/// assert_eq!(config.interfaces[0].endpoints[0].max_packet_size, 512);
+///
+/// assert!(profiler::parse_descriptors(&blob[9..]).is_err());
+/// ```
+pub fn parse_descriptors(descriptors: &[u8]) -> error::Result<system_profiler::USBDevice> {
+    if descriptors.len() < 18 {
+        return Err(Error::new_descriptor_len(
+            "DeviceDescriptor",
+            18,
+            descriptors.len(),
+        ));
+    }
+    if descriptors[1] != 0x01 {
+        return Err(Error::new(
+            ErrorKind::InvalidDescriptor,
+            &format!(
+                "Expected device descriptor at start of descriptors, got {:#04x}",
+                descriptors[1]
+            ),
+        ));
+    }
+
+    let d = &descriptors[..18];
+    let vid = u16::from_le_bytes([d[8], d[9]]);
+    let pid = u16::from_le_bytes([d[10], d[11]]);
+    let mut sp_device = system_profiler::USBDevice {
+        vendor_id: Some(vid),
+        product_id: Some(pid),
+        location_id: system_profiler::DeviceLocation {
+            bus: 0,
+            number: 1,
+            tree_positions: vec![1],
+        },
+        bcd_device: Some(usb::Version::from_bcd(u16::from_le_bytes([d[12], d[13]]))),
+        bcd_usb: Some(usb::Version::from_bcd(u16::from_le_bytes([d[2], d[3]]))),
+        class: Some(usb::ClassCode::from(d[4])),
+        sub_class: Some(d[5]),
+        protocol: Some(d[6]),
+        ..Default::default()
+    };
+
+    let mut info_source = system_profiler::DeviceInfoSource::default();
+    (sp_device.manufacturer, info_source.manufacturer) =
+        with_source(names::vendor(vid), InfoSource::Database).unzip();
+    let (name, name_source) = with_source(names::product(vid, pid), InfoSource::Database).unzip();
+    sp_device.name = name.unwrap_or_default();
+    info_source.name = name_source;
+    sp_device.info_source = Some(info_source);
+
+    let mut handle: Option<UsbDevice<libusb::Context>> = None;
+    let configurations = build_sysfs_configurations(&mut handle, descriptors, &sp_device, false)?;
+
+    sp_device.extra = Some(usb::USBDeviceExtra {
+        max_packet_size: d[7],
+        string_indexes: (d[15], d[14], d[16]),
+        driver: None,
+        syspath: None,
+        vendor: names::vendor(vid).or(usb_ids::Vendor::from_id(vid).map(|v| v.name().to_owned())),
+        product_name: names::product(vid, pid)
+            .or(usb_ids::Device::from_vid_pid(vid, pid).map(|v| v.name().to_owned())),
+        active_config: configurations.first().map(|c| c.number),
+        configurations,
+        status: None,
+        debug: None,
+        binary_object_store: None,
+        qualifier: None,
+        other_speed_configurations: Vec::new(),
+        hub: None,
+        power_state: None,
+        num_configurations: Some(d[17]),
+    });
+
+    Ok(sp_device)
+}
+
+/// Read and [`parse_descriptors`] of the raw descriptor blob at `path`
+pub fn parse_descriptor_file<P: AsRef<std::path::Path>>(
+    path: P,
+) -> error::Result<system_profiler::USBDevice> {
+    let path = path.as_ref();
+    let descriptors = std::fs::read(path).map_err(|e| {
+        Error::new(
+            ErrorKind::Io,
+            &format!("Failed to read descriptor file {}: {}", path.display(), e),
+        )
+    })?;
+    parse_descriptors(&descriptors)
+}
+
+/// Builds [`system_profiler::USBDevice`]s for `devices` using up to [`ProfilerSettings::jobs`] worker threads. Results are returned in the same order as `devices` regardless of which worker built them.
+///
+/// Each worker takes the next unclaimed device and opens its own handle within [`build_spdevice`], so no handle is shared between threads. Devices are independent so the blocking control transfers for strings and descriptors can overlap; on a busy hub this is where most of the profile time goes.
+fn build_spdevices<T: libusb::UsbContext>(
+    devices: &[libusb::Device<T>],
+    settings: &ProfilerSettings,
+) -> Vec<error::Result<system_profiler::USBDevice>> {
+    let jobs = settings.jobs.clamp(1, devices.len().max(1));
+    if jobs == 1 {
+        return devices
+            .iter()
+            .map(|d| build_spdevice_with_settings(d, settings))
+            .collect();
+    }
+
+    log::debug!("Building {} devices with {} jobs", devices.len(), jobs);
+    let next = AtomicUsize::new(0);
+    let mut built: Vec<(usize, error::Result<system_profiler::USBDevice>)> = thread::scope(|s| {
+        let workers: Vec<_> = (0..jobs)
+            .map(|_| {
+                s.spawn(|| {
+                    let mut ret = Vec::new();
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        match devices.get(i) {
+                            Some(device) => {
+                                ret.push((i, build_spdevice_with_settings(device, settings)))
+                            }
+                            None => break,
+                        }
+                    }
+                    ret
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|w| w.join().unwrap_or_else(|e| std::panic::resume_unwind(e)))
+            .collect()
+    });
+
+    // restore DeviceList order so output is deterministic
+    built.sort_by_key(|(i, _)| *i);
+    built.into_iter().map(|(_, r)| r).collect()
+}
+
+/// Only warn about limited device info once per process, not each profile
+static LIMITED_INFO_WARNING: Once = Once::new();
+
+fn warn_limited_info(settings: &ProfilerSettings) {
+    LIMITED_INFO_WARNING.call_once(|| {
+        let msg = "Some device info is limited; run as root for full details";
+        if settings.print_stderr {
+            eprintln!("{}", msg);
+        } else {
+            log::warn!("{}", msg);
+        }
+    });
+}
+
+fn _get_spusb(settings: &ProfilerSettings) -> Result<system_profiler::SPUSBDataType, Error> {
     let mut spusb = system_profiler::SPUSBDataType { buses: Vec::new() };
     // temporary store of devices created when iterating through DeviceList
     let mut cache: Vec<system_profiler::USBDevice> = Vec::new();
@@ -1125,15 +1784,20 @@ fn _get_spusb(
     log::info!("Building SPUSBDataType with libusb {:?}", libusb::version());
 
     // run through devices building USBDevice types
-    for device in libusb::DeviceList::new()?.iter() {
-        match build_spdevice(&device, with_extra) {
+    let devices: Vec<libusb::Device<libusb::GlobalContext>> = libusb::DeviceList::new()?
+        .iter()
+        .filter(|d| settings.bus.map_or(true, |b| d.bus_number() == b))
+        .collect();
+    let built = build_spdevices(&devices, settings);
+    for (device, result) in devices.iter().zip(built) {
+        match result {
             Ok(sp_device) => {
                 cache.push(sp_device.to_owned());
 
                 // print any non-critical error during extra capture
                 sp_device.profiler_error.iter().for_each(|e| {
-                    if print_stderr {
-                        eprintln!("{}", e);
+                    if settings.print_stderr {
+                        eprintln!("{:#}", e);
                     } else {
                         log::warn!("Non-critical error during profile: {}", e);
                     }
@@ -1181,18 +1845,18 @@ fn _get_spusb(
         }
 
         // group into parent groups with parent path as key or trunk devices so they end up in same place
-        let parent_groups = group.group_by(|d| d.parent_path().unwrap_or(d.trunk_path()));
+        let parent_groups = group.group_by(|d| {
+            let path = d.location_id.to_port_path();
+            path.parent().unwrap_or_else(|| path.trunk())
+        });
 
         // now go through parent paths inserting devices owned by that parent
         // this is not perfect...if the sort of devices does not result in order of depth, it will panic because the parent of a device will not exist. But that won't happen, right...
-        // sort key - ends_with to ensure root_hubs, which will have same str length as trunk devices will still be ahead
-        for (parent_path, children) in parent_groups
-            .into_iter()
-            .sorted_by_key(|x| x.0.len() - x.0.ends_with("-0") as usize)
-        {
+        // sort key - depth so root_hubs and trunk devices, which share the bus as parent, are ahead
+        for (parent_path, children) in parent_groups.into_iter().sorted_by_key(|x| x.0.depth()) {
             log::debug!("Adding devices to parent {}", parent_path);
             // if root devices, add them to bus
-            if parent_path.ends_with("-0") {
+            if parent_path.depth() == 0 {
                 // if parent_path == "-" {
                 let devices = std::mem::take(&mut new_bus.devices);
                 if let Some(mut d) = devices {
@@ -1208,7 +1872,7 @@ fn _get_spusb(
             // else find and add parent - this should work because we are sorted to accend the tree so parents should be created before their children
             } else {
                 let parent_node = new_bus
-                    .get_node_mut(&parent_path)
+                    .get_node_mut(&parent_path.to_string())
                     .expect("Parent node does not exist in new bus!");
                 let devices = std::mem::take(&mut parent_node.devices);
                 if let Some(mut d) = devices {
@@ -1224,6 +1888,10 @@ fn _get_spusb(
             }
         }
 
+        for hub in new_bus.flattened_devices() {
+            hub.warn_children_outside_ports();
+        }
+
         spusb.buses.push(new_bus);
     }
 
@@ -1236,14 +1904,70 @@ fn _get_spusb(
 ///
 /// Building the [`system_profiler::SPUSBDataType`] depends on system; on Linux, the root devices are at buses where as macOS the buses are not listed
 pub fn get_spusb(print_stderr: bool) -> Result<system_profiler::SPUSBDataType, Error> {
-    _get_spusb(false, print_stderr)
+    _get_spusb(&ProfilerSettings {
+        print_stderr,
+        ..Default::default()
+    })
 }
 
 /// Get [`system_profiler::SPUSBDataType`] using `libusb` including [`usb::USBDeviceExtra`] - the main function to use for most use cases unless one does not want verbose data.
 ///
 /// Like `get_spusb`, runs through `libusb::DeviceList` creating a cache of [`system_profiler::USBDevice`]. On Linux and with the 'udev' feature enabled, the syspath and driver will attempt to be obtained.
 pub fn get_spusb_with_extra(print_stderr: bool) -> Result<system_profiler::SPUSBDataType, Error> {
-    _get_spusb(true, print_stderr)
+    _get_spusb(&ProfilerSettings {
+        with_extra: true,
+        print_stderr,
+        ..Default::default()
+    })
+}
+
+/// Get [`system_profiler::SPUSBDataType`] using `libusb` with [`ProfilerSettings`] controlling what is gathered and how.
+///
+/// With [`ProfilerSettings::sysfs_descriptors`] on Linux, configuration, interface and endpoint descriptors are parsed from the kernel cache so the device does not need to be opened and is not disturbed; this makes unprivileged profiling far more complete. Device status, BOS, qualifier, debug and hub descriptors are not cached so are not gathered in this mode.
+///
+/// ```no_run
+/// use cyme::usb::profiler::{self, ProfilerSettings};
+///
+/// let settings = ProfilerSettings {
+///     with_extra: true,
+///     sysfs_descriptors: true,
+///     jobs: 4,
+///     ..Default::default()
+/// };
+/// let spusb = profiler::get_spusb_with_settings(&settings).unwrap();
+/// println!("{:#}", spusb);
+/// ```
+pub fn get_spusb_with_settings(
+    settings: &ProfilerSettings,
+) -> Result<system_profiler::SPUSBDataType, Error> {
+    _get_spusb(settings)
+}
+
+/// Get the [`system_profiler::USBBus`] `bus_number` with [`usb::USBDeviceExtra`] using `libusb`, only opening devices on that bus
+///
+/// Useful on systems with many host controllers when only one bus is of interest. Returns an [`ErrorKind::NotFound`] error if there are no devices on the bus.
+///
+/// ```no_run
+/// use cyme::usb::profiler;
+///
+/// let bus = profiler::profile_bus(2).unwrap();
+/// println!("{:#}", bus);
+/// ```
+pub fn profile_bus(bus_number: u8) -> Result<system_profiler::USBBus, Error> {
+    _get_spusb(&ProfilerSettings {
+        with_extra: true,
+        bus: Some(bus_number),
+        ..Default::default()
+    })?
+    .buses
+    .into_iter()
+    .find(|b| b.get_bus_number() == bus_number)
+    .ok_or_else(|| {
+        Error::new(
+            ErrorKind::NotFound,
+            &format!("No devices found on bus {}", bus_number),
+        )
+    })
 }
 
 /// Fills a passed mutable `spusb` reference to fill using `get_spusb`. Will replace existing [`system_profiler::USBDevice`]s found in the libusb build but leave others and the buses.
@@ -1268,3 +1992,17 @@ pub fn fill_spusb(spusb: &mut system_profiler::SPUSBDataType) -> Result<(), Erro
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uac3_high_capability_request() {
+        // wValue is the wClusterDescrID and wIndex the interface with entity ID 0
+        assert_eq!(
+            uac3_high_capability_request(2, 0x1234),
+            (0x06, 0x1234, 0x0002)
+        );
+    }
+}
@@ -0,0 +1,349 @@
+//! Advisory checks of profiled device descriptors for mistakes commonly made in firmware
+//!
+//! The checks only report [`Violation`]s; they never change the parsed data.
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use usb_ids::{self, FromId};
+
+use crate::system_profiler::{SPUSBDataType, USBBus, USBDevice};
+use crate::usb::{ClassCode, USBConfiguration, Version};
+
+/// bcdUSB values defined by USB-IF specifications
+const KNOWN_BCD_USB: &[u16] = &[
+    0x0100, 0x0110, 0x0200, 0x0201, 0x0210, 0x0220, 0x0250, 0x0300, 0x0310, 0x0320,
+];
+/// Smallest wTotalLength possible; the configuration descriptor itself
+const MIN_TOTAL_LENGTH: u16 = 9;
+/// Largest wTotalLength considered plausible; real configurations are well below this
+const MAX_TOTAL_LENGTH: u16 = 0x2000;
+
+/// Type of [`Violation`] found
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ViolationKind {
+    /// Multi-byte field is implausible as little-endian but plausible when byte swapped
+    PossibleEndianness,
+    /// Fewer configurations were retrieved than bNumConfigurations declares; a firmware or permission problem
+    MissingConfigurations,
+    /// Multi-function configuration without an Interface Association Descriptor; Windows may not bind a driver to each function
+    MissingInterfaceAssociation,
+    /// Bus-powered device drawing more than its bus-powered parent hub can supply per port
+    OverPowerBudget,
+    /// Hub has a child on a port outside of the bNbrPorts its hub descriptor reports
+    HubPortOutOfRange,
+    /// Periodic endpoints on a bus reserve more bandwidth than the bus can schedule
+    OverBandwidthBudget,
+}
+
+impl ViolationKind {
+    /// Whether the violation is advice rather than an error in the descriptors
+    pub fn is_informational(&self) -> bool {
+        matches!(self, ViolationKind::MissingInterfaceAssociation)
+    }
+}
+
+/// Problem found in the descriptors of a device
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Violation {
+    /// Type of problem
+    pub kind: ViolationKind,
+    /// Port path of the device
+    pub path: String,
+    /// Descriptor field the problem was found in
+    pub field: String,
+    /// Description of the problem
+    pub message: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.kind.is_informational() {
+            write!(f, "note: ")?;
+        }
+        write!(f, "{}: {}: {}", self.path, self.field, self.message)
+    }
+}
+
+impl Violation {
+    fn endianness(device: &USBDevice, field: &str, value: u16) -> Self {
+        Violation {
+            kind: ViolationKind::PossibleEndianness,
+            path: device.port_path(),
+            field: field.to_string(),
+            message: format!(
+                "possible endianness bug: {:#06x} is implausible but {:#06x} byte swapped is plausible",
+                value,
+                value.swap_bytes()
+            ),
+        }
+    }
+}
+
+/// Raw BCD value of `version`; [`u16::from`] is not reversible for majors above 9
+fn bcd_raw(version: Version) -> u16 {
+    let Version(major, minor, sub_minor) = version;
+    ((major as u16 / 10) << 12)
+        | ((major as u16 % 10) << 8)
+        | ((minor as u16) << 4)
+        | (sub_minor as u16)
+}
+
+/// Distinct interface classes of `config` if it has more than one function; CDC Data is counted as part of the CDC Communications function it carries data for
+fn function_classes(device: &USBDevice, config: &USBConfiguration) -> Option<Vec<ClassCode>> {
+    let mut classes: Vec<ClassCode> = config
+        .interfaces
+        .iter()
+        .map(|i| match i.class {
+            ClassCode::CDCData => ClassCode::CDCCommunications,
+            c => c,
+        })
+        .collect();
+    classes.sort();
+    classes.dedup();
+
+    // CDC Data and audio streaming interfaces belong to the control interface of their function, alternate settings to their interface
+    let functions = config
+        .interfaces
+        .iter()
+        .filter(|i| i.alt_setting == 0)
+        .filter(|i| {
+            !matches!(
+                (i.class, i.sub_class),
+                (ClassCode::CDCData, _) | (ClassCode::Audio, 0x02 | 0x03)
+            )
+        })
+        .count();
+    let multi_function_class = matches!(
+        device.class,
+        Some(ClassCode::Miscellaneous | ClassCode::CDCCommunications | ClassCode::Audio)
+    ) && functions > 1;
+
+    (classes.len() > 1 || multi_function_class).then_some(classes)
+}
+
+/// Whether `value` is implausible as read but plausible when byte swapped according to `plausible`
+fn is_swapped(value: u16, plausible: impl Fn(u16) -> bool) -> bool {
+    !plausible(value) && plausible(value.swap_bytes())
+}
+
+/// Check the descriptors of `device`, not including its children
+///
+/// ```
+/// use cyme::system_profiler::SPUSBDataType;
+/// use cyme::usb::{ClassCode, Version};
+/// use cyme::verify::{self, ViolationKind};
+///
+/// let sp_usb = SPUSBDataType::example();
+/// let mut device = sp_usb.flatten_devices()[1].clone();
+/// assert!(verify::verify_device(&device).is_empty());
+///
+/// // 0x0200 stored big-endian reads as 0x0002
+/// device.bcd_usb = Some(Version::from_bcd(0x0002));
+/// let violations = verify::verify_device(&device);
+/// assert_eq!(violations.len(), 1);
+/// assert_eq!(violations[0].kind, ViolationKind::PossibleEndianness);
+/// assert_eq!(violations[0].field, "bcdUSB");
+///
+/// device.bcd_usb = Some(Version::from_bcd(0x0200));
+/// device.extra.as_mut().unwrap().num_configurations = Some(2);
+/// let violations = verify::verify_device(&device);
+/// assert_eq!(violations[0].kind, ViolationKind::MissingConfigurations);
+///
+/// // the example composite groups its functions with IADs; without them it is flagged
+/// let extra = device.extra.as_mut().unwrap();
+/// extra.num_configurations = Some(1);
+/// let config = &mut extra.configurations[0];
+/// config.extra = None;
+/// for interface in config.interfaces.iter_mut() {
+///     interface.extra = None;
+///     interface.endpoints.iter_mut().for_each(|e| e.extra = None);
+/// }
+/// let violations = verify::verify_device(&device);
+/// assert_eq!(violations.len(), 1);
+/// assert_eq!(violations[0].kind, ViolationKind::MissingInterfaceAssociation);
+/// assert!(violations[0].to_string().starts_with("note: "));
+///
+/// // a CDC ACM device: Communications and Data interfaces are one function so need no IAD
+/// device.class = Some(ClassCode::CDCCommunications);
+/// let config = &mut device.extra.as_mut().unwrap().configurations[0];
+/// config.interfaces.truncate(2);
+/// (config.interfaces[0].class, config.interfaces[0].sub_class) = (ClassCode::CDCCommunications, 0x02);
+/// config.interfaces[1].class = ClassCode::CDCData;
+/// assert!(verify::verify_device(&device).is_empty());
+///
+/// // a second ACM port is a second function
+/// let config = &mut device.extra.as_mut().unwrap().configurations[0];
+/// let mut second = config.interfaces.clone();
+/// second.iter_mut().for_each(|i| i.number += 2);
+/// config.interfaces.extend(second);
+/// let violations = verify::verify_device(&device);
+/// assert_eq!(violations[0].kind, ViolationKind::MissingInterfaceAssociation);
+/// ```
+pub fn verify_device(device: &USBDevice) -> Vec<Violation> {
+    let mut violations = Vec::new();
+
+    if let Some(bcd_usb) = device.bcd_usb.map(bcd_raw) {
+        if is_swapped(bcd_usb, |v| KNOWN_BCD_USB.contains(&v)) {
+            violations.push(Violation::endianness(device, "bcdUSB", bcd_usb));
+        }
+    }
+
+    if let Some(vid) = device.vendor_id {
+        if is_swapped(vid, |v| usb_ids::Vendor::from_id(v).is_some()) {
+            violations.push(Violation::endianness(device, "idVendor", vid));
+        }
+    }
+
+    if let Some(extra) = device.extra.as_ref() {
+        if let Some(declared) = extra.num_configurations {
+            if extra.configurations.len() < declared as usize {
+                violations.push(Violation {
+                    kind: ViolationKind::MissingConfigurations,
+                    path: device.port_path(),
+                    field: String::from("bNumConfigurations"),
+                    message: format!(
+                        "declares {} configurations but only {} could be retrieved",
+                        declared,
+                        extra.configurations.len()
+                    ),
+                });
+            }
+        }
+
+        for config in &extra.configurations {
+            if is_swapped(config.total_length, |v| {
+                (MIN_TOTAL_LENGTH..=MAX_TOTAL_LENGTH).contains(&v)
+            }) {
+                violations.push(Violation::endianness(
+                    device,
+                    &format!("Configuration {} wTotalLength", config.number),
+                    config.total_length,
+                ));
+            }
+
+            if config.interface_associations().is_empty() {
+                if let Some(classes) = function_classes(device, config) {
+                    violations.push(Violation {
+                        kind: ViolationKind::MissingInterfaceAssociation,
+                        path: device.port_path(),
+                        field: format!("Configuration {} IAD", config.number),
+                        message: format!(
+                            "multi-function configuration ({}) has no Interface Association Descriptor",
+                            classes.iter().map(|c| c.to_string()).join(", ")
+                        ),
+                    });
+                }
+            }
+        }
+    }
+
+    violations.extend(verify_hub_ports(device));
+
+    violations
+}
+
+/// Check the children of a hub sit on ports its hub descriptor reports
+fn verify_hub_ports(device: &USBDevice) -> Vec<Violation> {
+    let num_ports = device
+        .hub_descriptor()
+        .map(|h| h.num_ports)
+        .unwrap_or_default();
+    device
+        .children_outside_ports()
+        .into_iter()
+        .map(|child| {
+            let port = child.get_branch_position();
+            Violation {
+                kind: ViolationKind::HubPortOutOfRange,
+                path: device.port_path(),
+                field: String::from("bNbrPorts"),
+                message: if port == 0 {
+                    String::from("child on port 0 but hub ports start at 1")
+                } else {
+                    format!("child on port {} exceeds hub's {} ports", port, num_ports)
+                },
+            }
+        })
+        .collect()
+}
+
+/// Check the power draw of `device` against the supply of its `parent` hub
+fn verify_power_budget(device: &USBDevice, parent: &USBDevice) -> Option<Violation> {
+    device.exceeds_hub_budget(parent).then(|| Violation {
+        kind: ViolationKind::OverPowerBudget,
+        path: device.port_path(),
+        field: String::from("bMaxPower"),
+        message: format!(
+            "draws {} mA but bus-powered hub {} can only supply {} mA per port",
+            device.max_power_ma().unwrap_or_default(),
+            parent.port_path(),
+            parent.bus_powered_hub_port_ma()
+        ),
+    })
+}
+
+/// Check the bandwidth reserved by periodic endpoints on `bus` against its periodic budget
+fn verify_bus_bandwidth(bus: &USBBus) -> Option<Violation> {
+    let budget = bus.periodic_bandwidth_budget()?;
+    let used = bus.periodic_bandwidth_used();
+    (used > budget).then(|| Violation {
+        kind: ViolationKind::OverBandwidthBudget,
+        path: bus.path(),
+        field: String::from("periodic bandwidth"),
+        message: format!(
+            "periodic endpoints reserve {} bytes/s but the bus can only schedule {} bytes/s",
+            used, budget
+        ),
+    })
+}
+
+/// Check the descriptors of every device in `sp_usb`, the power draw of devices attached to bus-powered hubs and the periodic bandwidth of each bus
+///
+/// ```
+/// use cyme::system_profiler::{DeviceSpeed, SPUSBDataType};
+/// use cyme::usb::Speed;
+/// use cyme::verify::{self, ViolationKind};
+///
+/// let mut sp_usb = SPUSBDataType::example();
+/// assert!(verify::verify(&sp_usb).is_empty());
+///
+/// let device = sp_usb.get_node_mut("1-1.2").unwrap();
+/// let config = &mut device.extra.as_mut().unwrap().configurations[0];
+/// config.total_length = config.total_length.swap_bytes();
+/// let violations = verify::verify(&sp_usb);
+/// assert_eq!(violations.len(), 1);
+/// assert_eq!(violations[0].path, "1-1.2");
+/// assert_eq!(violations[0].field, "Configuration 1 wTotalLength");
+///
+/// // the composite draws well over 100 mA so cannot sit behind a bus-powered hub
+/// let config = &mut sp_usb.get_node_mut("1-1.2").unwrap().extra.as_mut().unwrap().configurations[0];
+/// config.total_length = config.total_length.swap_bytes();
+/// sp_usb.get_node_mut("1-1").unwrap().extra.as_mut().unwrap().status = Some(0);
+/// let violations = verify::verify(&sp_usb);
+/// assert_eq!(violations.len(), 1);
+/// assert_eq!(violations[0].kind, ViolationKind::OverPowerBudget);
+/// assert_eq!(violations[0].field, "bMaxPower");
+///
+/// // 120 mA is over the 100 mA of a high speed hub port but within the 150 mA of a SuperSpeed one
+/// let config = &mut sp_usb.get_node_mut("1-1.2").unwrap().extra.as_mut().unwrap().configurations[0];
+/// config.max_power_units = Some(15);
+/// assert!(verify::verify(&sp_usb)[0].message.ends_with("can only supply 100 mA per port"));
+/// let hub = sp_usb.get_node_mut("1-1").unwrap();
+/// hub.device_speed = Some(DeviceSpeed::SpeedValue(Speed::SuperSpeed));
+/// assert!(verify::verify(&sp_usb).is_empty());
+/// ```
+pub fn verify(sp_usb: &SPUSBDataType) -> Vec<Violation> {
+    sp_usb
+        .flatten_devices()
+        .into_iter()
+        .flat_map(|device| {
+            let mut violations = verify_device(device);
+            if let Some(parent) = sp_usb.parent(&device.port_path()) {
+                violations.extend(verify_power_budget(device, parent));
+            }
+            violations
+        })
+        .chain(sp_usb.buses.iter().filter_map(verify_bus_bandwidth))
+        .collect()
+}